@@ -12,7 +12,7 @@ use api_models::analytics::{
 use bigdecimal::ToPrimitive;
 use common_enums::Currency;
 use common_utils::{errors::CustomResult, types::TimeRange};
-use currency_conversion::{conversion::convert, types::ExchangeRates};
+use currency_conversion::{conversion::convert_at, types::HistoricalExchangeRates};
 use error_stack::ResultExt;
 use router_env::{
     instrument, logger,
@@ -68,10 +68,19 @@ pub async fn get_sankey(
 #[instrument(skip_all)]
 pub async fn get_metrics(
     pool: &AnalyticsProvider,
-    ex_rates: &Option<ExchangeRates>,
+    ex_rates: &Option<HistoricalExchangeRates>,
     auth: &AuthInfo,
     req: GetPaymentIntentMetricRequest,
 ) -> AnalyticsResult<PaymentIntentsMetricsResponse<MetricsBucketResponse>> {
+    // Amounts are converted at the exchange rates of the day they fall in
+    let req = GetPaymentIntentMetricRequest {
+        time_series: crate::utils::get_metrics_time_series(
+            req.time_series,
+            &req.metrics,
+            ex_rates.is_some(),
+        ),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<
         PaymentIntentMetricsBucketIdentifier,
         PaymentIntentMetricsAccumulator,
@@ -208,11 +217,17 @@ pub async fn get_metrics(
                                 .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
                                 .ok()
                                 .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
+                                    convert_at(
+                                        ex_rates,
+                                        id.start_time,
+                                        currency,
+                                        Currency::USD,
+                                        amount_i64,
+                                    )
+                                    .inspect_err(|e| {
+                                        logger::error!("Currency conversion error: {:?}", e)
+                                    })
+                                    .ok()
                                 })
                         })
                         .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
@@ -234,11 +249,17 @@ pub async fn get_metrics(
                                 .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
                                 .ok()
                                 .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
+                                    convert_at(
+                                        ex_rates,
+                                        id.start_time,
+                                        currency,
+                                        Currency::USD,
+                                        amount_i64,
+                                    )
+                                    .inspect_err(|e| {
+                                        logger::error!("Currency conversion error: {:?}", e)
+                                    })
+                                    .ok()
                                 })
                         })
                         .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
@@ -259,11 +280,17 @@ pub async fn get_metrics(
                                 .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
                                 .ok()
                                 .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
+                                    convert_at(
+                                        ex_rates,
+                                        id.start_time,
+                                        currency,
+                                        Currency::USD,
+                                        amount_i64,
+                                    )
+                                    .inspect_err(|e| {
+                                        logger::error!("Currency conversion error: {:?}", e)
+                                    })
+                                    .ok()
                                 })
                         })
                         .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
@@ -286,11 +313,17 @@ pub async fn get_metrics(
                                 .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
                                 .ok()
                                 .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
+                                    convert_at(
+                                        ex_rates,
+                                        id.start_time,
+                                        currency,
+                                        Currency::USD,
+                                        amount_i64,
+                                    )
+                                    .inspect_err(|e| {
+                                        logger::error!("Currency conversion error: {:?}", e)
+                                    })
+                                    .ok()
                                 })
                         })
                         .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
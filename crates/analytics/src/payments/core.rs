@@ -12,7 +12,7 @@ use api_models::analytics::{
 use bigdecimal::ToPrimitive;
 use common_enums::Currency;
 use common_utils::errors::CustomResult;
-use currency_conversion::{conversion::convert, types::ExchangeRates};
+use currency_conversion::{conversion::convert_at, types::HistoricalExchangeRates};
 use error_stack::ResultExt;
 use router_env::{
     instrument, logger,
@@ -48,10 +48,19 @@ pub enum TaskType {
 #[instrument(skip_all)]
 pub async fn get_metrics(
     pool: &AnalyticsProvider,
-    ex_rates: &Option<ExchangeRates>,
+    ex_rates: &Option<HistoricalExchangeRates>,
     auth: &AuthInfo,
     req: GetPaymentMetricRequest,
 ) -> AnalyticsResult<PaymentsMetricsResponse<MetricsBucketResponse>> {
+    // Amounts are converted at the exchange rates of the day they fall in
+    let req = GetPaymentMetricRequest {
+        time_series: crate::utils::get_metrics_time_series(
+            req.time_series,
+            &req.metrics,
+            ex_rates.is_some(),
+        ),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<
         PaymentMetricsBucketIdentifier,
         PaymentMetricsAccumulator,
@@ -244,11 +253,17 @@ pub async fn get_metrics(
                                 .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
                                 .ok()
                                 .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
+                                    convert_at(
+                                        ex_rates,
+                                        id.start_time,
+                                        currency,
+                                        Currency::USD,
+                                        amount_i64,
+                                    )
+                                    .inspect_err(|e| {
+                                        logger::error!("Currency conversion error: {:?}", e)
+                                    })
+                                    .ok()
                                 })
                         })
                         .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
@@ -271,11 +286,17 @@ pub async fn get_metrics(
                                 .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
                                 .ok()
                                 .and_then(|amount_i64| {
-                                    convert(ex_rates, currency, Currency::USD, amount_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
+                                    convert_at(
+                                        ex_rates,
+                                        id.start_time,
+                                        currency,
+                                        Currency::USD,
+                                        amount_i64,
+                                    )
+                                    .inspect_err(|e| {
+                                        logger::error!("Currency conversion error: {:?}", e)
+                                    })
+                                    .ok()
                                 })
                         })
                         .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
@@ -310,11 +331,17 @@ pub async fn get_metrics(
                                 })
                                 .ok()
                                 .and_then(|savings_i64| {
-                                    convert(ex_rates, currency, Currency::USD, savings_i64)
-                                        .inspect_err(|e| {
-                                            logger::error!("Currency conversion error: {:?}", e)
-                                        })
-                                        .ok()
+                                    convert_at(
+                                        ex_rates,
+                                        id.start_time,
+                                        currency,
+                                        Currency::USD,
+                                        savings_i64,
+                                    )
+                                    .inspect_err(|e| {
+                                        logger::error!("Currency conversion error: {:?}", e)
+                                    })
+                                    .ok()
                                 })
                         })
                         .map(|savings| (savings * rust_decimal::Decimal::new(100, 0)).to_u64())
@@ -7,7 +7,7 @@ use api_models::analytics::{
     payments::{PaymentDimensions, PaymentMetrics},
     refunds::{RefundDimensions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
-    NameDescription,
+    ForexMetric, Granularity, NameDescription, TimeSeries,
 };
 use strum::IntoEnumIterator;
 
@@ -131,3 +131,51 @@ pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
 pub fn get_dispute_dimensions() -> Vec<NameDescription> {
     DisputeDimensions::iter().map(Into::into).collect()
 }
+
+/// Returns the time series metrics are to be queried with.
+///
+/// Amounts are converted at the exchange rates in effect at the start of their bucket, so requests
+/// converting amounts are queried with buckets of at most a day, instead of a single bucket spanning
+/// the time range of the request.
+pub fn get_metrics_time_series<'a, T: ForexMetric + 'a>(
+    time_series: Option<TimeSeries>,
+    metrics: impl IntoIterator<Item = &'a T>,
+    converts_amounts: bool,
+) -> Option<TimeSeries> {
+    if converts_amounts && metrics.into_iter().any(ForexMetric::is_forex_metric) {
+        time_series.or(Some(TimeSeries {
+            granularity: Granularity::OneDay,
+        }))
+    } else {
+        time_series
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_time_series_for_conversion() {
+        let amount_metrics = [PaymentMetrics::PaymentProcessedAmount];
+        let count_metrics = [PaymentMetrics::PaymentCount];
+        let hourly = Some(TimeSeries {
+            granularity: Granularity::OneHour,
+        });
+
+        assert!(matches!(
+            get_metrics_time_series(None, &amount_metrics, true),
+            Some(TimeSeries {
+                granularity: Granularity::OneDay
+            })
+        ));
+        assert!(matches!(
+            get_metrics_time_series(hourly, &amount_metrics, true),
+            Some(TimeSeries {
+                granularity: Granularity::OneHour
+            })
+        ));
+        assert!(get_metrics_time_series(None, &amount_metrics, false).is_none());
+        assert!(get_metrics_time_series(None, &count_metrics, true).is_none());
+    }
+}
@@ -3271,6 +3271,44 @@ pub struct ConnectorAgnosticMitChoice {
 
 impl common_utils::events::ApiEventMetric for ConnectorAgnosticMitChoice {}
 
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSecretRotationRequest {
+    /// The duration (in seconds) for which outgoing webhooks will continue to be signed using the previous secret, in addition to the new secret. Defaults to 24 hours.
+    #[schema(example = 86400, maximum = 604800)]
+    pub overlap_duration_in_seconds: Option<u32>,
+}
+
+#[cfg(feature = "v1")]
+impl common_utils::events::ApiEventMetric for WebhookSecretRotationRequest {}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct WebhookSecretRotationResponse {
+    /// The identifier for the profile
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The new hash key used for calculating the signature for webhooks and redirect response
+    #[schema(max_length = 255, example = "xkkdf909012sdjki2dkh5sdf")]
+    pub payment_response_hash_key: String,
+
+    /// The time until which outgoing webhooks will also be signed using the previous secret, in the `X-Webhook-Signature-512-Previous` header
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_secret_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+#[cfg(feature = "v1")]
+impl common_utils::events::ApiEventMetric for WebhookSecretRotationResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
 impl common_utils::events::ApiEventMetric for payment_methods::PaymentMethodMigrate {}
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -98,6 +98,26 @@ impl AnalyticsRequest {
                 .map(|req| req.metrics.iter().any(|metric| metric.is_forex_metric()))
                 .unwrap_or_default()
     }
+
+    /// Returns the smallest time range covering the time ranges of all the requests, the end time
+    /// is left open if any of the requests has an open end time.
+    pub fn get_time_range(&self) -> Option<TimeRange> {
+        [
+            self.payment_intent.as_ref().map(|req| req.time_range),
+            self.payment_attempt.as_ref().map(|req| req.time_range),
+            self.refund.as_ref().map(|req| req.time_range),
+            self.dispute.as_ref().map(|req| req.time_range),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(|time_range, other| TimeRange {
+            start_time: time_range.start_time.min(other.start_time),
+            end_time: time_range
+                .end_time
+                .zip(other.end_time)
+                .map(|(end_time, other_end_time)| end_time.max(other_end_time)),
+        })
+    }
 }
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
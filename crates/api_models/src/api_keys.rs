@@ -23,6 +23,22 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permission groups the API Key is restricted to. If not provided, the API Key can
+    /// access every API available to the merchant.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["operations_view", "analytics_view"]))]
+    pub permission_groups: Option<Vec<common_enums::PermissionGroup>>,
+
+    /// The resources the API Key is restricted to. This can be used along with
+    /// `permission_groups` to further narrow down the access of the API Key, for example to
+    /// payments alone.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["payment", "customer"]))]
+    pub resources: Option<Vec<common_enums::Resource>>,
+
+    /// The business profiles the API Key is restricted to. If not provided, the API Key can
+    /// access all the profiles of the merchant.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["pro_abcdefghijklmnop"]))]
+    pub profile_ids: Option<Vec<common_utils::id_type::ProfileId>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +76,18 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permission groups the API Key is restricted to, if any.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["operations_view", "analytics_view"]))]
+    pub permission_groups: Option<Vec<common_enums::PermissionGroup>>,
+
+    /// The resources the API Key is restricted to, if any.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["payment", "customer"]))]
+    pub resources: Option<Vec<common_enums::Resource>>,
+
+    /// The business profiles the API Key is restricted to, if any.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["pro_abcdefghijklmnop"]))]
+    pub profile_ids: Option<Vec<common_utils::id_type::ProfileId>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +130,18 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The permission groups the API Key is restricted to, if any.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["operations_view", "analytics_view"]))]
+    pub permission_groups: Option<Vec<common_enums::PermissionGroup>>,
+
+    /// The resources the API Key is restricted to, if any.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["payment", "customer"]))]
+    pub resources: Option<Vec<common_enums::Resource>>,
+
+    /// The business profiles the API Key is restricted to, if any.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["pro_abcdefghijklmnop"]))]
+    pub profile_ids: Option<Vec<common_utils::id_type::ProfileId>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -130,6 +170,21 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The permission groups the API Key is restricted to. Replaces the existing permission
+    /// groups of the API Key, if provided.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["operations_view", "analytics_view"]))]
+    pub permission_groups: Option<Vec<common_enums::PermissionGroup>>,
+
+    /// The resources the API Key is restricted to. Replaces the existing resources of the API
+    /// Key, if provided.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["payment", "customer"]))]
+    pub resources: Option<Vec<common_enums::Resource>>,
+
+    /// The business profiles the API Key is restricted to. Replaces the existing profiles of the
+    /// API Key, if provided.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["pro_abcdefghijklmnop"]))]
+    pub profile_ids: Option<Vec<common_utils::id_type::ProfileId>>,
+
     #[serde(skip_deserializing)]
     #[schema(value_type = String)]
     pub key_id: common_utils::id_type::ApiKeyId,
@@ -0,0 +1,67 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The state of the circuit breaker of a merchant connector account.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitBreakerState {
+    /// Connector calls go through, and their outcomes are counted.
+    Closed,
+    /// Connector calls are rejected without reaching the connector, and the connector account is
+    /// skipped by routing.
+    Open,
+    /// A limited number of trial calls go through, to decide whether the circuit is closed again.
+    HalfOpen,
+}
+
+/// The circuit breaker of a merchant connector account.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CircuitBreakerResponse {
+    /// The identifier for the merchant connector account.
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The name of the connector.
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+
+    /// The state of the circuit breaker.
+    pub state: CircuitBreakerState,
+
+    /// The number of connector calls counted in the current window.
+    #[schema(example = 42)]
+    pub total_requests: u32,
+
+    /// The number of failed connector calls counted in the current window.
+    #[schema(example = 3)]
+    pub failed_requests: u32,
+
+    /// The time at which the circuit was last opened, if it is open or half-open.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub opened_at: Option<PrimitiveDateTime>,
+}
+
+/// The circuit breakers of the connector accounts of a merchant.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CircuitBreakerListResponse {
+    /// The circuit breakers of the enabled connector accounts of the merchant.
+    pub data: Vec<CircuitBreakerResponse>,
+}
+
+impl ApiEventMetric for CircuitBreakerResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for CircuitBreakerListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
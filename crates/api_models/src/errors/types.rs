@@ -99,6 +99,7 @@ pub enum ApiErrorResponse {
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
     DomainError(ApiError),
+    TooManyRequests(ApiError),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -128,6 +129,7 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
+            | Self::TooManyRequests(i)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -146,6 +148,7 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
+            | Self::TooManyRequests(i)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -161,7 +164,8 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
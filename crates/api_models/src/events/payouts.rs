@@ -1,9 +1,11 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::payouts::{
-    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
-    PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
-    PayoutRetrieveRequest,
+    PayoutActionRequest, PayoutBatchCreateRequest, PayoutBatchIdInternal,
+    PayoutBatchItemListRequestInternal, PayoutBatchItemListResponse, PayoutBatchListConstraints,
+    PayoutBatchListResponse, PayoutBatchResponse, PayoutCreateRequest, PayoutCreateResponse,
+    PayoutLinkInitiateRequest, PayoutListConstraints, PayoutListFilterConstraints,
+    PayoutListFilters, PayoutListResponse, PayoutRetrieveRequest,
 };
 
 impl ApiEventMetric for PayoutRetrieveRequest {
@@ -69,3 +71,53 @@ impl ApiEventMetric for PayoutLinkInitiateRequest {
         })
     }
 }
+
+impl ApiEventMetric for PayoutBatchCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PayoutBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchIdInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PayoutBatchListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PayoutBatchItemListRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchItemListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
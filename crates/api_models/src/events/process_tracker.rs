@@ -0,0 +1,20 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::process_tracker::{
+    ProcessTrackerBulkActionRequest, ProcessTrackerBulkActionResponse, ProcessTrackerId,
+    ProcessTrackerListConstraints, ProcessTrackerResponse, ProcessTrackerRetrieveResponse,
+    ProcessTrackerUpdateRequest,
+};
+
+common_utils::impl_api_event_type!(
+    ProcessTracker,
+    (
+        ProcessTrackerBulkActionRequest,
+        ProcessTrackerBulkActionResponse,
+        ProcessTrackerId,
+        ProcessTrackerListConstraints,
+        ProcessTrackerResponse,
+        ProcessTrackerRetrieveResponse,
+        ProcessTrackerUpdateRequest
+    )
+);
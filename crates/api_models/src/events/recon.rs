@@ -2,7 +2,9 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 use masking::PeekInterface;
 
 use crate::recon::{
-    ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest, VerifyTokenResponse,
+    ReconJobCreateRequest, ReconJobId, ReconJobListConstraints, ReconJobResponse,
+    ReconMismatchListRequest, ReconMismatchResponse, ReconStatusResponse, ReconTokenResponse,
+    ReconUpdateMerchantRequest, VerifyTokenResponse,
 };
 
 impl ApiEventMetric for ReconUpdateMerchantRequest {
@@ -30,3 +32,15 @@ impl ApiEventMetric for VerifyTokenResponse {
         })
     }
 }
+
+common_utils::impl_api_event_type!(
+    Recon,
+    (
+        ReconJobCreateRequest,
+        ReconJobId,
+        ReconJobListConstraints,
+        ReconJobResponse,
+        ReconMismatchListRequest,
+        ReconMismatchResponse
+    )
+);
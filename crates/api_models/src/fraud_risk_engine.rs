@@ -0,0 +1,136 @@
+use common_types::fraud_risk_engine::FraudRiskRule;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use euclid::frontend::ast::Program;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::admin::FrmConfigs;
+
+/// The attribute of a payment on which the number of payments is limited.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VelocityDimension {
+    /// The fingerprint of the card used for the payment
+    CardFingerprint,
+    /// The customer making the payment
+    Customer,
+    /// The IP address from which the payment was made
+    IpAddress,
+    /// The email of the customer making the payment
+    Email,
+}
+
+impl VelocityDimension {
+    /// Returns the key of the risk signal raised when the limit on the dimension is exceeded.
+    pub fn get_signal_key(self) -> &'static str {
+        match self {
+            Self::CardFingerprint => "card_fingerprint_velocity_exceeded",
+            Self::Customer => "customer_velocity_exceeded",
+            Self::IpAddress => "ip_address_velocity_exceeded",
+            Self::Email => "email_velocity_exceeded",
+        }
+    }
+}
+
+/// Limits the number of payments sharing the same value of a dimension within a time window.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityLimit {
+    /// The attribute of the payment on which the number of payments is limited.
+    pub dimension: VelocityDimension,
+
+    /// The length of the time window in seconds.
+    #[schema(example = 3600)]
+    pub window_in_seconds: u32,
+
+    /// The maximum number of payments allowed within the time window, the
+    /// `<dimension>_velocity_exceeded` signal is raised for payments beyond this number.
+    #[schema(example = 5)]
+    pub max_payments: u32,
+}
+
+/// Flags payments whose amount is much larger than the recent payments of the same customer.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AmountAnomalyConfig {
+    /// The length of the time window in seconds over which the recent payments are considered.
+    #[schema(example = 2592000)]
+    pub window_in_seconds: u32,
+
+    /// The minimum number of recent payments needed for the amount to be compared against them.
+    #[schema(example = 3)]
+    pub min_payments: u32,
+
+    /// The `amount_anomaly` signal is raised when the amount exceeds the average amount of the
+    /// recent payments by this factor.
+    #[schema(example = 5)]
+    pub max_amount_multiplier: u32,
+}
+
+/// The configuration of the rules based fraud risk engine of a business profile.
+///
+/// The rules are evaluated with the risk signals computed for the payment available as metadata,
+/// with the values `true` or `false`: `card_fingerprint_velocity_exceeded`,
+/// `customer_velocity_exceeded`, `ip_address_velocity_exceeded`, `email_velocity_exceeded`,
+/// `bin_country_mismatch`, `amount_anomaly` and `blocklist_hit`.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FraudRiskEngineConfig {
+    /// The name of the configuration.
+    #[schema(example = "Default risk rules")]
+    pub name: String,
+
+    /// The rules deciding whether payments are accepted, reviewed or declined.
+    #[schema(value_type = Object)]
+    pub program: Program<FraudRiskRule>,
+
+    /// The payment methods checked by the risk engine, along with whether they are checked
+    /// before (`pre`) or after (`post`) the payment is authorized.
+    pub frm_configs: Vec<FrmConfigs>,
+
+    /// The limits on the number of payments used to compute the velocity signals.
+    #[serde(default)]
+    pub velocity_limits: Vec<VelocityLimit>,
+
+    /// The configuration used to compute the amount anomaly signal.
+    pub amount_anomaly: Option<AmountAnomalyConfig>,
+}
+
+/// The stored configuration of the fraud risk engine of a business profile.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct FraudRiskEngineRecord {
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    #[serde(flatten)]
+    pub config: FraudRiskEngineConfig,
+
+    /// Unix timestamp at which the configuration was created.
+    pub created_at: i64,
+
+    /// Unix timestamp at which the configuration was last modified.
+    pub modified_at: i64,
+}
+
+impl ApiEventMetric for FraudRiskEngineRecord {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FraudRiskEngineConfigRequestInternal {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub request: FraudRiskEngineConfig,
+}
+
+impl ApiEventMetric for FraudRiskEngineConfigRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
@@ -0,0 +1,216 @@
+use common_enums::{FrmReviewAction, FrmReviewStatus};
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::{IntoParams, ToSchema};
+
+/// The query parameters for listing manual reviews.
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewListConstraints {
+    /// Only list the reviews with this status. Defaults to `pending`.
+    #[param(value_type = Option<FrmReviewStatus>)]
+    pub status: Option<FrmReviewStatus>,
+
+    /// The maximum number of reviews to be listed. Defaults to 20 and cannot exceed 100.
+    #[param(example = 20)]
+    pub limit: Option<u32>,
+
+    /// The number of reviews to skip, for paginating through the reviews.
+    #[param(example = 0)]
+    pub offset: Option<u32>,
+}
+
+/// The request body for assigning a manual review to a dashboard user.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewAssignRequest {
+    /// The identifier of the dashboard user the review is assigned to. The review is unassigned
+    /// if not provided.
+    #[schema(max_length = 64, example = "2ac5a3c4-2ae2-4df0-8d6b-4d6e3a7a9c1e")]
+    pub user_id: Option<String>,
+}
+
+/// The request body for approving or rejecting a payment under manual review.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewDecisionRequest {
+    /// A note explaining the decision, recorded against the review.
+    #[schema(example = "Customer confirmed the order over the phone")]
+    pub note: Option<String>,
+}
+
+/// The request body for recording a note on a manual review.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewNoteRequest {
+    /// The note to be recorded.
+    #[schema(example = "Shipping address matches previous orders")]
+    pub note: String,
+}
+
+/// A note recorded by a reviewer on a manual review.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FrmReviewNoteResponse {
+    /// The identifier for the note.
+    #[schema(max_length = 64, example = "frn_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub note_id: String,
+
+    /// The identifier of the dashboard user who recorded the note.
+    #[schema(max_length = 64)]
+    pub user_id: String,
+
+    /// The note.
+    pub note: String,
+
+    /// Time at which the note was recorded.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body for manual review APIs.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FrmReviewResponse {
+    /// The identifier for the review.
+    #[schema(max_length = 64, example = "frr_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub review_id: String,
+
+    /// The identifier for the payment under review.
+    #[schema(max_length = 64, value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: common_utils::id_type::PaymentId,
+
+    /// The identifier for the payment attempt under review.
+    #[schema(max_length = 64)]
+    pub attempt_id: String,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The FRM provider which flagged the payment for review.
+    #[schema(example = "signifyd")]
+    pub frm_name: String,
+
+    /// The status of the review.
+    pub status: FrmReviewStatus,
+
+    /// The risk signals and reasons returned by the FRM provider.
+    #[schema(value_type = Option<Object>)]
+    pub risk_signals: Option<serde_json::Value>,
+
+    /// The risk score returned by the FRM provider.
+    #[schema(example = 60)]
+    pub frm_score: Option<i32>,
+
+    /// The identifier of the dashboard user the review is assigned to.
+    #[schema(max_length = 64)]
+    pub assigned_to: Option<String>,
+
+    /// The action taken on the payment once the review was resolved.
+    pub decision: Option<FrmReviewAction>,
+
+    /// The identifier of the dashboard user who resolved the review. Not set for expired reviews.
+    #[schema(max_length = 64)]
+    pub reviewed_by: Option<String>,
+
+    /// Time at which the review was resolved.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub reviewed_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the review expires, after which the default action is taken on the payment.
+    #[schema(example = "2022-09-13T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+
+    /// Time at which the review was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// The notes recorded by reviewers, only returned when retrieving a single review.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<FrmReviewNoteResponse>>,
+}
+
+impl ApiEventMetric for FrmReviewResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+/// The response body for listing manual reviews.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FrmReviewListResponse {
+    /// The number of reviews included in the list.
+    pub count: usize,
+
+    /// The list of reviews.
+    pub data: Vec<FrmReviewResponse>,
+}
+
+impl ApiEventMetric for FrmReviewListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+impl ApiEventMetric for FrmReviewNoteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+impl ApiEventMetric for FrmReviewListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrmReviewIdInternal {
+    pub review_id: String,
+}
+
+impl ApiEventMetric for FrmReviewIdInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrmReviewAssignRequestInternal {
+    pub review_id: String,
+    pub request: FrmReviewAssignRequest,
+}
+
+impl ApiEventMetric for FrmReviewAssignRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrmReviewDecisionRequestInternal {
+    pub review_id: String,
+    pub request: FrmReviewDecisionRequest,
+}
+
+impl ApiEventMetric for FrmReviewDecisionRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrmReviewNoteRequestInternal {
+    pub review_id: String,
+    pub request: FrmReviewNoteRequest,
+}
+
+impl ApiEventMetric for FrmReviewNoteRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::FraudCheck)
+    }
+}
@@ -5,6 +5,7 @@ pub mod apple_pay_certificates_migration;
 pub mod authentication;
 pub mod blocklist;
 pub mod cards_info;
+pub mod circuit_breaker;
 pub mod conditional_configs;
 pub mod connector_enums;
 pub mod connector_onboarding;
@@ -20,6 +21,8 @@ pub mod events;
 pub mod external_service_auth;
 pub mod feature_matrix;
 pub mod files;
+pub mod fraud_risk_engine;
+pub mod frm_review;
 pub mod gsm;
 pub mod health_check;
 pub mod locker_migration;
@@ -41,6 +44,8 @@ pub mod recon;
 pub mod refunds;
 pub mod relay;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod subscriptions;
 pub mod surcharge_decision_configs;
 pub mod three_ds_decision_rule;
 #[cfg(feature = "tokenization_v2")]
@@ -49,6 +54,7 @@ pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
 
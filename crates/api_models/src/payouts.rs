@@ -823,6 +823,220 @@ pub struct PayoutListFilters {
     pub payout_method: Vec<common_enums::PayoutType>,
 }
 
+/// The request body for creating a batch of payouts from a file.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The identifier of the CSV or JSON file holding the payouts of the batch, uploaded through the
+    /// files API with the `payout_batch` purpose. Each row of a CSV file follows
+    /// `PayoutBatchCsvRecord`, while a JSON file holds an array of payout create requests. A file can
+    /// only be used by a single batch.
+    #[schema(example = "file_4iU7sFO8Af2jGLbAuEfG")]
+    pub file_id: String,
+
+    /// The identifier for the Business Profile the payouts are created for. Required when the
+    /// merchant has multiple profiles.
+    #[schema(max_length = 64, value_type = Option<String>, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The merchant's own reference for the batch, which must be unique across the batches of the
+    /// merchant.
+    #[schema(max_length = 255, example = "payouts_2024_05_01")]
+    pub merchant_batch_reference_id: Option<String>,
+}
+
+/// A row of a CSV payout batch file.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct PayoutBatchCsvRecord {
+    pub payout_id: Option<id_type::PayoutId>,
+    pub merchant_order_reference_id: Option<String>,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: api_enums::Currency,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub payout_type: Option<api_enums::PayoutType>,
+    pub payout_method_id: Option<String>,
+    pub payout_token: Option<String>,
+    pub connector: Option<api_enums::PayoutConnectors>,
+    pub priority: Option<api_enums::PayoutSendPriority>,
+    pub description: Option<String>,
+}
+
+impl From<PayoutBatchCsvRecord> for PayoutCreateRequest {
+    fn from(record: PayoutBatchCsvRecord) -> Self {
+        Self {
+            payout_id: record.payout_id,
+            merchant_order_reference_id: record.merchant_order_reference_id,
+            amount: Some(record.amount.into()),
+            currency: Some(record.currency),
+            customer_id: record.customer_id,
+            payout_type: record.payout_type,
+            payout_method_id: record.payout_method_id,
+            payout_token: record.payout_token,
+            connector: record.connector.map(|connector| vec![connector]),
+            priority: record.priority,
+            description: record.description,
+            ..Default::default()
+        }
+    }
+}
+
+/// The details of a batch of payouts created from a file.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// The identifier for the batch.
+    #[schema(example = "pbatch_4iU7sFO8Af2jGLbAuEfG")]
+    pub batch_id: String,
+
+    /// The identifier for the merchant account.
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier for the Business Profile the payouts are created for.
+    #[schema(value_type = String, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The merchant's own reference for the batch.
+    #[schema(example = "payouts_2024_05_01")]
+    pub merchant_batch_reference_id: Option<String>,
+
+    /// The identifier of the file the batch was created from.
+    #[schema(example = "file_4iU7sFO8Af2jGLbAuEfG")]
+    pub file_id: String,
+
+    /// The status of the batch.
+    #[schema(value_type = PayoutBatchStatus, example = "processing")]
+    pub status: common_enums::PayoutBatchStatus,
+
+    /// The number of rows in the file.
+    #[schema(example = 1000)]
+    pub total_rows: u32,
+
+    /// The number of rows for which a payout was created.
+    #[schema(example = 990)]
+    pub created_rows: u32,
+
+    /// The number of rows for which a payout could not be created.
+    #[schema(example = 10)]
+    pub failed_rows: u32,
+
+    /// The number of rows which are yet to be processed.
+    #[schema(example = 0)]
+    pub pending_rows: u32,
+
+    /// The identifier of the CSV file holding the result of each row, available once the batch is
+    /// processed. The file can be downloaded through the files API.
+    #[schema(example = "file_Yhf7sFO8Af2jGLbAuEfG")]
+    pub result_file_id: Option<String>,
+
+    /// Time at which the batch was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which every row of the batch was processed.
+    #[schema(example = "2022-09-10T10:15:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+/// The query parameters for listing payout batches.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchListConstraints {
+    /// The maximum number of batches to be listed. Defaults to 10 and cannot exceed 100.
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_payouts_list_limit")]
+    pub limit: u32,
+
+    /// The number of batches to skip, for paginating through the batches.
+    #[schema(example = 0)]
+    pub offset: Option<u32>,
+}
+
+/// The response body for listing payout batches.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBatchListResponse {
+    /// The number of batches included in the list.
+    pub size: usize,
+
+    /// The list of batches, latest first.
+    pub data: Vec<PayoutBatchResponse>,
+}
+
+/// The result of a single row of a payout batch.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    /// The position of the row in the file, starting from 1.
+    #[schema(example = 1)]
+    pub row_number: u32,
+
+    /// The status of the row.
+    #[schema(value_type = PayoutBatchItemStatus, example = "created")]
+    pub status: common_enums::PayoutBatchItemStatus,
+
+    /// The identifier of the payout created for the row.
+    #[schema(value_type = String, example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payout_id: id_type::PayoutId,
+
+    /// The status of the payout created for the row, at the time it was created.
+    #[schema(value_type = Option<PayoutStatus>, example = "success")]
+    pub payout_status: Option<common_enums::PayoutStatus>,
+
+    /// The connector through which the payout of the row was created.
+    #[schema(example = "adyen")]
+    pub connector: Option<String>,
+
+    /// The error code, if the payout of the row could not be created.
+    #[schema(example = "IR_06")]
+    pub error_code: Option<String>,
+
+    /// The error message, if the payout of the row could not be created.
+    #[schema(example = "Missing required param: currency")]
+    pub error_message: Option<String>,
+}
+
+/// The query parameters for listing the rows of a payout batch.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchItemListConstraints {
+    /// Only list the rows with this status.
+    #[schema(value_type = Option<PayoutBatchItemStatus>, example = "failed")]
+    pub status: Option<common_enums::PayoutBatchItemStatus>,
+
+    /// The maximum number of rows to be listed. Defaults to 10 and cannot exceed 100.
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_payouts_list_limit")]
+    pub limit: u32,
+
+    /// The number of rows to skip, for paginating through the rows.
+    #[schema(example = 0)]
+    pub offset: Option<u32>,
+}
+
+/// The response body for listing the rows of a payout batch.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBatchItemListResponse {
+    /// The identifier for the batch.
+    pub batch_id: String,
+
+    /// The number of rows included in the list.
+    pub size: usize,
+
+    /// The list of rows, in the order of the file.
+    pub data: Vec<PayoutBatchItemResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PayoutBatchIdInternal {
+    pub batch_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PayoutBatchItemListRequestInternal {
+    pub batch_id: String,
+    pub constraints: PayoutBatchItemListConstraints,
+}
+
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct PayoutLinkResponse {
     pub payout_link_id: String,
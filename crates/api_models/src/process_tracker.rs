@@ -1,2 +1,131 @@
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
+
+use time::PrimitiveDateTime;
+
+use crate::enums;
+
+/// The maximum number of tasks which can be updated in a single bulk request.
+pub const PROCESS_TRACKER_BULK_ACTION_MAX_TASKS: usize = 100;
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerListConstraints {
+    pub runner: Option<enums::ProcessTrackerRunner>,
+    pub status: Option<enums::ProcessTrackerStatus>,
+    pub business_status: Option<String>,
+    /// Only tasks having this tag are listed
+    pub tag: Option<String>,
+    /// Only tasks scheduled at or after this time are listed
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_after: Option<PrimitiveDateTime>,
+    /// Only tasks scheduled at or before this time are listed
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_before: Option<PrimitiveDateTime>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ProcessTrackerResponse {
+    pub id: String,
+    pub name: Option<String>,
+    pub tag: Vec<String>,
+    pub runner: Option<String>,
+    pub retry_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    pub status: enums::ProcessTrackerStatus,
+    pub business_status: String,
+    pub tracking_data: serde_json::Value,
+    /// Whether the task is scheduled again according to a recurrence once it has finished
+    pub is_recurring: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+/// A change made to a task through the process tracker admin APIs.
+#[derive(Debug, serde::Serialize)]
+pub struct ProcessTrackerAuditResponse {
+    pub audit_id: String,
+    pub action: enums::ProcessTrackerAction,
+    pub previous_status: enums::ProcessTrackerStatus,
+    pub new_status: enums::ProcessTrackerStatus,
+    pub previous_business_status: String,
+    pub new_business_status: String,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_schedule_time: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub new_schedule_time: Option<PrimitiveDateTime>,
+    pub reason: String,
+    pub performed_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ProcessTrackerRetrieveResponse {
+    #[serde(flatten)]
+    pub process: ProcessTrackerResponse,
+    /// The changes made to the task through the process tracker admin APIs, latest first
+    pub audit_trail: Vec<ProcessTrackerAuditResponse>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ProcessTrackerId {
+    pub process_tracker_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerActionRequest {
+    /// The time at which the task is to be run, required when rescheduling a task
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    /// Why the task is being changed, recorded in the audit trail of the task
+    pub reason: String,
+    /// Who is changing the task, recorded in the audit trail of the task
+    pub performed_by: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ProcessTrackerUpdateRequest {
+    pub process_tracker_id: String,
+    pub action: enums::ProcessTrackerAction,
+    pub request: ProcessTrackerActionRequest,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerBulkActionRequest {
+    pub action: enums::ProcessTrackerAction,
+    pub process_tracker_ids: Vec<String>,
+    /// The time at which the tasks are to be run, required when rescheduling tasks
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    /// Why the tasks are being changed, recorded in the audit trail of every task
+    pub reason: String,
+    /// Who is changing the tasks, recorded in the audit trail of every task
+    pub performed_by: String,
+}
+
+/// The outcome of a bulk action for a single task, the action is applied to every task
+/// independently of the others.
+#[derive(Debug, serde::Serialize)]
+pub struct ProcessTrackerBulkActionResult {
+    pub process_tracker_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process: Option<ProcessTrackerResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ProcessTrackerBulkActionResponse {
+    pub action: enums::ProcessTrackerAction,
+    pub succeeded_count: usize,
+    pub failed_count: usize,
+    pub results: Vec<ProcessTrackerBulkActionResult>,
+}
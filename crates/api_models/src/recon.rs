@@ -1,5 +1,6 @@
-use common_utils::{id_type, pii};
+use common_utils::{id_type, pii, types::MinorUnit};
 use masking::Secret;
+use time::PrimitiveDateTime;
 
 use crate::enums;
 
@@ -26,3 +27,96 @@ pub struct VerifyTokenResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acl: Option<String>,
 }
+
+/// A row of a connector settlement file.
+///
+/// Amounts are expected in the minor unit of the currency.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReconSettlementRecord {
+    pub connector_transaction_id: String,
+    pub transaction_type: enums::ReconTransactionType,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub status: enums::ReconSettlementStatus,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconJobResponse {
+    pub job_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub file_id: String,
+    pub connector: String,
+    pub status: enums::ReconJobStatus,
+    pub total_rows: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReconJobListConstraints {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconJobId {
+    pub job_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReconMismatchListConstraints {
+    pub mismatch_type: Option<enums::ReconMismatchType>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconMismatchListRequest {
+    pub job_id: String,
+    pub constraints: ReconMismatchListConstraints,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconMismatchResponse {
+    pub mismatch_id: String,
+    pub job_id: String,
+    /// The line of the settlement file the mismatch was found in, excluding the header
+    pub line_number: i32,
+    pub transaction_type: enums::ReconTransactionType,
+    pub mismatch_type: enums::ReconMismatchType,
+    pub connector_transaction_id: String,
+    pub settlement_amount: MinorUnit,
+    pub settlement_currency: enums::Currency,
+    pub settlement_status: enums::ReconSettlementStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_id: Option<id_type::PaymentId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempt_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_amount: Option<MinorUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_currency: Option<enums::Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_status: Option<enums::ReconSettlementStatus>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconJobCreateRequest {
+    pub connector: enums::Connector,
+    pub file_name: Option<String>,
+    /// Contents of the settlement file, which are not logged
+    #[serde(skip)]
+    pub file: Vec<u8>,
+}
@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, str::FromStr};
 
 use common_types::three_ds_decision_rule_engine::{ThreeDSDecision, ThreeDSDecisionRule};
 use common_utils::{
@@ -46,6 +46,142 @@ impl ConnectorSelection {
         }
     }
 }
+
+/// Parses a connector in the routing DSL, optionally followed by its merchant connector id
+///
+/// ```text
+/// stripe
+/// adyen(mca_123)
+/// ```
+fn parse_routable_connector_choice(
+    input: &str,
+) -> ast::parser::ParseResult<&str, RoutableConnectorChoice> {
+    let connector = nom::combinator::map_res(ast::parser::identifier, |connector: String| {
+        RoutableConnectors::from_str(&connector)
+    });
+
+    let merchant_connector_id = nom::combinator::map_res(
+        nom::sequence::delimited(
+            nom::bytes::complete::tag("("),
+            nom::bytes::complete::take_while1(|c: char| {
+                c.is_ascii_alphanumeric() || matches!(c, '_' | '-')
+            }),
+            nom::bytes::complete::tag(")"),
+        ),
+        |merchant_connector_id: &str| {
+            common_utils::id_type::MerchantConnectorAccountId::wrap(
+                merchant_connector_id.to_string(),
+            )
+        },
+    );
+
+    nom::error::context(
+        "routable_connector",
+        nom::combinator::map(
+            nom::sequence::pair(connector, nom::combinator::opt(merchant_connector_id)),
+            |(connector, merchant_connector_id)| RoutableConnectorChoice {
+                choice_kind: RoutableChoiceKind::FullStruct,
+                connector,
+                merchant_connector_id,
+            },
+        ),
+    )(input)
+}
+
+fn print_routable_connector_choice(
+    choice: &RoutableConnectorChoice,
+) -> Result<String, ast::printer::PrintError> {
+    let connector = ast::printer::identifier(&choice.connector.to_string())?;
+    Ok(match &choice.merchant_connector_id {
+        Some(merchant_connector_id) => {
+            format!("{connector}({})", merchant_connector_id.get_string_repr())
+        }
+        None => connector,
+    })
+}
+
+/// The connector selection of a rule in the routing DSL, either a priority list or a volume split
+///
+/// ```text
+/// [stripe, adyen(mca_123)]
+/// [stripe: 60%, adyen: 40%]
+/// ```
+impl ast::parser::EuclidParsable for ConnectorSelection {
+    fn parse_output(input: &str) -> ast::parser::ParseResult<&str, Self> {
+        let separator = || ast::parser::skip_ws(nom::bytes::complete::tag(","));
+
+        let priority = nom::error::context(
+            "priority",
+            nom::combinator::map(
+                nom::sequence::delimited(
+                    ast::parser::skip_ws(nom::bytes::complete::tag("[")),
+                    nom::multi::separated_list1(
+                        separator(),
+                        ast::parser::skip_ws(parse_routable_connector_choice),
+                    ),
+                    ast::parser::skip_ws(nom::bytes::complete::tag("]")),
+                ),
+                Self::Priority,
+            ),
+        );
+
+        let split = nom::combinator::map(
+            nom::sequence::pair(
+                ast::parser::skip_ws(parse_routable_connector_choice),
+                nom::sequence::preceded(
+                    ast::parser::skip_ws(nom::bytes::complete::tag(":")),
+                    ast::parser::skip_ws(ast::parser::percentage),
+                ),
+            ),
+            |(connector, split)| ConnectorVolumeSplit { connector, split },
+        );
+
+        let volume_split = nom::error::context(
+            "volume_split",
+            nom::combinator::map(
+                nom::sequence::delimited(
+                    ast::parser::skip_ws(nom::bytes::complete::tag("[")),
+                    nom::multi::separated_list1(separator(), split),
+                    ast::parser::skip_ws(nom::bytes::complete::tag("]")),
+                ),
+                Self::VolumeSplit,
+            ),
+        );
+
+        nom::error::context(
+            "connector_selection",
+            nom::branch::alt((priority, volume_split)),
+        )(input)
+    }
+}
+
+impl ast::printer::EuclidPrintable for ConnectorSelection {
+    fn print_output(&self) -> Result<String, ast::printer::PrintError> {
+        let connectors = match self {
+            Self::Priority(connectors) => connectors
+                .iter()
+                .map(print_routable_connector_choice)
+                .collect::<Result<Vec<_>, _>>()?,
+            Self::VolumeSplit(splits) => splits
+                .iter()
+                .map(|split| {
+                    // The grammar accepts percentages of at most two digits
+                    if split.split >= 100 {
+                        return Err(ast::printer::PrintError::InvalidPercentage(split.split));
+                    }
+                    print_routable_connector_choice(&split.connector)
+                        .map(|connector| format!("{connector}: {}%", split.split))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+
+        if connectors.is_empty() {
+            return Err(ast::printer::PrintError::Empty("connector selection"));
+        }
+
+        Ok(format!("[{}]", connectors.join(", ")))
+    }
+}
 #[cfg(feature = "v2")]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct RoutingConfigRequest {
@@ -1444,3 +1580,83 @@ pub enum RoutingResultSource {
     /// Inbuilt Hyperswitch Routing Engine
     HyperswitchRouting,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSimulationRequest {
+    /// The draft algorithm against which the historical payments are replayed
+    pub algorithm: StaticRoutingAlgorithm,
+    /// The profile whose payments are replayed
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The time range in which the replayed payments were created
+    pub time_range: common_utils::types::TimeRange,
+    /// The maximum number of payments to replay
+    #[schema(default = 1000, maximum = 10000)]
+    pub limit: Option<u32>,
+}
+
+impl RoutingSimulationRequest {
+    pub fn validated_limit(&self) -> u32 {
+        self.limit.unwrap_or(1000).min(10000)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    /// The number of historical payments replayed against the draft algorithm
+    pub total_payments: u32,
+    /// The number of payments for which the draft algorithm did not select any connector
+    pub unrouted_payments: u32,
+    /// The success rate of the replayed payments as they were actually processed
+    pub actual_success_rate: Option<f64>,
+    /// The success rate the replayed payments would be expected to have under the draft algorithm, estimated from the historical success rate of the selected connectors
+    pub estimated_success_rate: Option<f64>,
+    /// The distribution of the replayed payments across connectors
+    pub connector_distribution: Vec<RoutingSimulationConnectorDistribution>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RoutingSimulationConnectorDistribution {
+    /// The connector
+    pub connector: String,
+    /// The number of replayed payments which were actually processed by the connector
+    pub actual_count: u32,
+    /// The number of replayed payments which the draft algorithm routed to the connector
+    pub simulated_count: u32,
+    /// The difference between the simulated and the actual count
+    pub count_difference: i64,
+    /// The percentage of replayed payments which were actually processed by the connector
+    pub actual_share: f64,
+    /// The percentage of replayed payments which the draft algorithm routed to the connector
+    pub simulated_share: f64,
+    /// The success rate of the replayed payments actually processed by the connector
+    pub historical_success_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingDslParseRequest {
+    /// The routing program written in the routing DSL
+    #[schema(
+        example = "default: [stripe]\n\ncard_rule: [adyen, stripe]\n{\n    payment_method = card & amount >= 1000\n}\n"
+    )]
+    pub dsl: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RoutingDslParseResponse {
+    /// The parsed routing program, which can be used to create a routing algorithm
+    pub algorithm: StaticRoutingAlgorithm,
+    /// The canonical form of the submitted routing program
+    pub dsl: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RoutingDslResponse {
+    /// The routing algorithm which was rendered
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// The routing program written in the routing DSL
+    pub dsl: String,
+}
@@ -0,0 +1,628 @@
+use common_enums::{
+    BillingInterval, Currency, InvoiceBillingReason, InvoiceStatus, SubscriptionStatus,
+};
+use common_types::subscriptions::InvoiceLineItem;
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, pii,
+    types::MinorUnit,
+};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::{IntoParams, ToSchema};
+
+/// The request body for creating a subscription plan.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanCreateRequest {
+    /// The name of the plan, shown to customers.
+    #[schema(max_length = 255, example = "Pro")]
+    pub name: String,
+
+    /// A description of the plan.
+    #[schema(example = "Unlimited projects and priority support")]
+    pub description: Option<String>,
+
+    /// The identifier for the Business Profile the plan belongs to. Required when the merchant has
+    /// multiple profiles.
+    #[schema(max_length = 64, value_type = Option<String>, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// Additional data related to the plan, stored as key-value pairs.
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "pro" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// The request body for updating a subscription plan.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanUpdateRequest {
+    /// The name of the plan, shown to customers.
+    #[schema(max_length = 255, example = "Pro")]
+    pub name: Option<String>,
+
+    /// A description of the plan.
+    #[schema(example = "Unlimited projects and priority support")]
+    pub description: Option<String>,
+
+    /// Whether new subscriptions can be created for the prices of the plan. Existing
+    /// subscriptions are not affected.
+    pub is_active: Option<bool>,
+
+    /// Additional data related to the plan, stored as key-value pairs.
+    #[schema(value_type = Option<Object>, example = r#"{ "tier": "pro" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// The response body for subscription plan APIs.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubscriptionPlanResponse {
+    /// The identifier for the plan.
+    #[schema(max_length = 64, example = "plan_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub plan_id: String,
+
+    /// The identifier for the Business Profile the plan belongs to.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The name of the plan.
+    #[schema(example = "Pro")]
+    pub name: String,
+
+    /// A description of the plan.
+    pub description: Option<String>,
+
+    /// Whether new subscriptions can be created for the prices of the plan.
+    pub is_active: bool,
+
+    /// Additional data related to the plan.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Time at which the plan was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The query parameters for listing subscription plans.
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPlanListConstraints {
+    /// Only list the plans which are active, or the plans which are not.
+    pub is_active: Option<bool>,
+
+    /// The maximum number of plans to be listed. Defaults to 20 and cannot exceed 100.
+    #[param(example = 20)]
+    pub limit: Option<u32>,
+
+    /// The number of plans to skip, for paginating through the plans.
+    #[param(example = 0)]
+    pub offset: Option<u32>,
+}
+
+/// The response body for listing subscription plans.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubscriptionPlanListResponse {
+    /// The number of plans included in the list.
+    pub count: usize,
+
+    /// The list of plans.
+    pub data: Vec<SubscriptionPlanResponse>,
+}
+
+/// The request body for creating a price of a subscription plan.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionPriceCreateRequest {
+    /// The amount charged on each billing cycle, in the lowest denomination of the currency.
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency of the price.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The unit of the billing interval.
+    #[schema(value_type = BillingInterval, example = "month")]
+    pub billing_interval: BillingInterval,
+
+    /// The number of intervals between two billing cycles. Defaults to 1.
+    #[schema(example = 1)]
+    pub interval_count: Option<u16>,
+
+    /// The number of days for which new subscriptions are not charged.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+}
+
+/// The response body for subscription price APIs.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubscriptionPriceResponse {
+    /// The identifier for the price.
+    #[schema(max_length = 64, example = "price_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub price_id: String,
+
+    /// The identifier for the plan the price belongs to.
+    #[schema(max_length = 64, example = "plan_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub plan_id: String,
+
+    /// The amount charged on each billing cycle, in the lowest denomination of the currency.
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency of the price.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// The unit of the billing interval.
+    #[schema(value_type = BillingInterval, example = "month")]
+    pub billing_interval: BillingInterval,
+
+    /// The number of intervals between two billing cycles.
+    #[schema(example = 1)]
+    pub interval_count: i32,
+
+    /// The number of days for which new subscriptions are not charged.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<i32>,
+
+    /// Whether new subscriptions can be created for the price.
+    pub is_active: bool,
+
+    /// Time at which the price was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body for listing the prices of a subscription plan.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubscriptionPriceListResponse {
+    /// The number of prices included in the list.
+    pub count: usize,
+
+    /// The list of prices.
+    pub data: Vec<SubscriptionPriceResponse>,
+}
+
+/// The request body for creating a subscription.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCreateRequest {
+    /// The identifier for the customer being subscribed.
+    #[schema(max_length = 64, value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier for the price the customer is subscribed to.
+    #[schema(max_length = 64, example = "price_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub price_id: String,
+
+    /// The identifier for the active mandate of the customer, which is charged on each billing
+    /// cycle.
+    #[schema(max_length = 64, example = "man_XWNCwsbUMmYt6q5G9hUr")]
+    pub mandate_id: String,
+
+    /// The number of days for which the subscription is not charged. Overrides the trial period of
+    /// the price.
+    #[schema(example = 14)]
+    pub trial_period_days: Option<u16>,
+
+    /// Additional data related to the subscription, stored as key-value pairs.
+    #[schema(value_type = Option<Object>, example = r#"{ "seats": 5 }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// The request body for updating a subscription.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionUpdateRequest {
+    /// The identifier for the new price of the subscription. The remaining time of the current
+    /// period is prorated between the current price and the new price, and the difference is
+    /// invoiced immediately.
+    #[schema(max_length = 64, example = "price_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub price_id: Option<String>,
+
+    /// The identifier for the mandate charged on the next billing cycles.
+    #[schema(max_length = 64, example = "man_XWNCwsbUMmYt6q5G9hUr")]
+    pub mandate_id: Option<String>,
+
+    /// Additional data related to the subscription, stored as key-value pairs.
+    #[schema(value_type = Option<Object>, example = r#"{ "seats": 5 }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// The request body for canceling a subscription.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionCancelRequest {
+    /// Whether the subscription is canceled at the end of the current period instead of
+    /// immediately. Open invoices are voided when the subscription is canceled immediately.
+    #[serde(default)]
+    pub cancel_at_period_end: bool,
+}
+
+/// The response body for subscription APIs.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SubscriptionResponse {
+    /// The identifier for the subscription.
+    #[schema(max_length = 64, example = "sub_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub subscription_id: String,
+
+    /// The identifier for the Business Profile the subscription belongs to.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The identifier for the subscribed customer.
+    #[schema(max_length = 64, value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The identifier for the plan of the subscription.
+    #[schema(max_length = 64, example = "plan_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub plan_id: String,
+
+    /// The identifier for the price of the subscription.
+    #[schema(max_length = 64, example = "price_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub price_id: String,
+
+    /// The status of the subscription.
+    #[schema(value_type = SubscriptionStatus, example = "active")]
+    pub status: SubscriptionStatus,
+
+    /// The identifier for the mandate charged on each billing cycle.
+    #[schema(max_length = 64, example = "man_XWNCwsbUMmYt6q5G9hUr")]
+    pub mandate_id: String,
+
+    /// Start of the current period of the subscription.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_start: PrimitiveDateTime,
+
+    /// End of the current period of the subscription, at which the next invoice is created.
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_period_end: PrimitiveDateTime,
+
+    /// End of the trial period of the subscription, if it has one.
+    #[schema(example = "2022-09-24T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub trial_end: Option<PrimitiveDateTime>,
+
+    /// Whether the subscription is canceled at the end of the current period.
+    pub cancel_at_period_end: bool,
+
+    /// Time at which the subscription was canceled.
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub canceled_at: Option<PrimitiveDateTime>,
+
+    /// The identifier for the latest invoice of the subscription.
+    #[schema(max_length = 64, example = "inv_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub latest_invoice_id: Option<String>,
+
+    /// Additional data related to the subscription.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Time at which the subscription was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The query parameters for listing subscriptions.
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubscriptionListConstraints {
+    /// Only list the subscriptions of this customer.
+    #[param(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// Only list the subscriptions with this status.
+    #[param(value_type = Option<SubscriptionStatus>)]
+    pub status: Option<SubscriptionStatus>,
+
+    /// The maximum number of subscriptions to be listed. Defaults to 20 and cannot exceed 100.
+    #[param(example = 20)]
+    pub limit: Option<u32>,
+
+    /// The number of subscriptions to skip, for paginating through the subscriptions.
+    #[param(example = 0)]
+    pub offset: Option<u32>,
+}
+
+/// The response body for listing subscriptions.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubscriptionListResponse {
+    /// The number of subscriptions included in the list.
+    pub count: usize,
+
+    /// The list of subscriptions.
+    pub data: Vec<SubscriptionResponse>,
+}
+
+/// The response body for invoice APIs.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct InvoiceResponse {
+    /// The identifier for the invoice.
+    #[schema(max_length = 64, example = "inv_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub invoice_id: String,
+
+    /// The identifier for the subscription the invoice was created for.
+    #[schema(max_length = 64, example = "sub_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub subscription_id: String,
+
+    /// The identifier for the Business Profile the invoice belongs to.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The identifier for the invoiced customer.
+    #[schema(max_length = 64, value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The status of the invoice.
+    #[schema(value_type = InvoiceStatus, example = "paid")]
+    pub status: InvoiceStatus,
+
+    /// The reason for which the invoice was created.
+    #[schema(value_type = InvoiceBillingReason, example = "subscription_cycle")]
+    pub billing_reason: InvoiceBillingReason,
+
+    /// The amount due, in the lowest denomination of the currency.
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency of the invoice.
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+
+    /// Start of the period covered by the invoice.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+
+    /// End of the period covered by the invoice.
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+
+    /// The lines of the invoice.
+    pub line_items: Vec<InvoiceLineItem>,
+
+    /// The identifier for the latest payment made for the invoice.
+    #[schema(max_length = 64, value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<id_type::PaymentId>,
+
+    /// The number of payments made for the invoice.
+    #[schema(example = 1)]
+    pub attempt_count: i32,
+
+    /// Time at which the payment of the invoice is retried next.
+    #[schema(example = "2022-10-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_payment_attempt_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the invoice was paid.
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub paid_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the invoice was created.
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The query parameters for listing the invoices of a subscription.
+#[derive(Debug, Serialize, Deserialize, IntoParams, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceListConstraints {
+    /// The maximum number of invoices to be listed. Defaults to 20 and cannot exceed 100.
+    #[param(example = 20)]
+    pub limit: Option<u32>,
+
+    /// The number of invoices to skip, for paginating through the invoices.
+    #[param(example = 0)]
+    pub offset: Option<u32>,
+}
+
+/// The response body for listing the invoices of a subscription.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct InvoiceListResponse {
+    /// The number of invoices included in the list.
+    pub count: usize,
+
+    /// The list of invoices, latest first.
+    pub data: Vec<InvoiceResponse>,
+}
+
+impl ApiEventMetric for SubscriptionPlanCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionPlanListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionPriceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionPriceListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SubscriptionListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for SubscriptionListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for InvoiceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Invoice {
+            invoice_id: self.invoice_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for InvoiceListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionPlanIdInternal {
+    pub plan_id: String,
+}
+
+impl ApiEventMetric for SubscriptionPlanIdInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionPlanUpdateRequestInternal {
+    pub plan_id: String,
+    pub request: SubscriptionPlanUpdateRequest,
+}
+
+impl ApiEventMetric for SubscriptionPlanUpdateRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionPriceCreateRequestInternal {
+    pub plan_id: String,
+    pub request: SubscriptionPriceCreateRequest,
+}
+
+impl ApiEventMetric for SubscriptionPriceCreateRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::SubscriptionPlan {
+            plan_id: self.plan_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionIdInternal {
+    pub subscription_id: String,
+}
+
+impl ApiEventMetric for SubscriptionIdInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionUpdateRequestInternal {
+    pub subscription_id: String,
+    pub request: SubscriptionUpdateRequest,
+}
+
+impl ApiEventMetric for SubscriptionUpdateRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubscriptionCancelRequestInternal {
+    pub subscription_id: String,
+    pub request: SubscriptionCancelRequest,
+}
+
+impl ApiEventMetric for SubscriptionCancelRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct InvoiceListRequestInternal {
+    pub subscription_id: String,
+    pub constraints: InvoiceListConstraints,
+}
+
+impl ApiEventMetric for InvoiceListRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Subscription {
+            subscription_id: self.subscription_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct InvoiceIdInternal {
+    pub invoice_id: String,
+}
+
+impl ApiEventMetric for InvoiceIdInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Invoice {
+            invoice_id: self.invoice_id.clone(),
+        })
+    }
+}
@@ -0,0 +1,186 @@
+use common_enums::{EventClass, EventType};
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating a webhook endpoint.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which webhooks are delivered.
+    #[schema(value_type = String, example = "https://example.com/webhooks")]
+    pub url: Secret<String>,
+
+    /// A description of the webhook endpoint.
+    #[schema(max_length = 255, example = "Order fulfilment service")]
+    pub description: Option<String>,
+
+    /// The secret used to sign webhooks delivered to this endpoint. A random secret is generated
+    /// if not provided.
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// The event classes delivered to this endpoint. Events of all classes are delivered if not
+    /// provided.
+    #[schema(example = json!(["payments", "refunds"]))]
+    pub enabled_event_classes: Option<Vec<EventClass>>,
+
+    /// The event types delivered to this endpoint. Since event types correspond to the status of
+    /// the object, this can be used to only receive webhooks for specific statuses. Events of all
+    /// types are delivered if not provided.
+    #[schema(example = json!(["payment_succeeded", "payment_failed"]))]
+    pub enabled_event_types: Option<Vec<EventType>>,
+
+    /// Indicates whether webhooks must be delivered to this endpoint. Defaults to `true`.
+    #[schema(example = true)]
+    pub is_enabled: Option<bool>,
+}
+
+/// The request body for updating a webhook endpoint.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which webhooks are delivered.
+    #[schema(value_type = Option<String>, example = "https://example.com/webhooks")]
+    pub url: Option<Secret<String>>,
+
+    /// A description of the webhook endpoint.
+    #[schema(max_length = 255, example = "Order fulfilment service")]
+    pub description: Option<String>,
+
+    /// The event classes delivered to this endpoint.
+    #[schema(example = json!(["payments", "refunds"]))]
+    pub enabled_event_classes: Option<Vec<EventClass>>,
+
+    /// The event types delivered to this endpoint.
+    #[schema(example = json!(["payment_succeeded", "payment_failed"]))]
+    pub enabled_event_types: Option<Vec<EventType>>,
+
+    /// Indicates whether webhooks must be delivered to this endpoint.
+    #[schema(example = false)]
+    pub is_enabled: Option<bool>,
+}
+
+/// The response body for webhook endpoint APIs.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The URL to which webhooks are delivered.
+    #[schema(value_type = String, example = "https://example.com/webhooks")]
+    pub url: Secret<String>,
+
+    /// A description of the webhook endpoint.
+    #[schema(max_length = 255, example = "Order fulfilment service")]
+    pub description: Option<String>,
+
+    /// The secret used to sign webhooks delivered to this endpoint.
+    #[schema(value_type = String)]
+    pub secret: Secret<String>,
+
+    /// The event classes delivered to this endpoint. Events of all classes are delivered if not
+    /// set.
+    pub enabled_event_classes: Option<Vec<EventClass>>,
+
+    /// The event types delivered to this endpoint. Events of all types are delivered if not set.
+    pub enabled_event_types: Option<Vec<EventType>>,
+
+    /// Indicates whether webhooks are delivered to this endpoint.
+    pub is_enabled: bool,
+
+    /// Time at which the webhook endpoint was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the webhook endpoint was last modified.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+/// The response body for deleting a webhook endpoint.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointDeleteResponse {
+    /// The identifier for the webhook endpoint.
+    #[schema(max_length = 64, example = "whe_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub endpoint_id: String,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Indicates whether the webhook endpoint was deleted.
+    pub deleted: bool,
+}
+
+impl ApiEventMetric for WebhookEndpointDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookEndpointCreateRequestInternal {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub request: WebhookEndpointCreateRequest,
+}
+
+impl ApiEventMetric for WebhookEndpointCreateRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookEndpointUpdateRequestInternal {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub endpoint_id: String,
+    pub request: WebhookEndpointUpdateRequest,
+}
+
+impl ApiEventMetric for WebhookEndpointUpdateRequestInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct WebhookEndpointIdInternal {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub endpoint_id: String,
+}
+
+impl ApiEventMetric for WebhookEndpointIdInternal {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.profile_id.clone(),
+        })
+    }
+}
@@ -93,6 +93,11 @@ pub struct EventListItemResponse {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created: PrimitiveDateTime,
+
+    /// The identifier for the webhook endpoint to which the event was delivered. This is not set
+    /// for events delivered to the webhook URL configured on the business profile.
+    #[schema(max_length = 64, example = "whe_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub webhook_endpoint_id: Option<String>,
 }
 
 /// The response body of list initial delivery attempts api call.
@@ -226,3 +231,124 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The request body for replaying webhook events in bulk.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookReplayRequest {
+    /// Replay events associated with the specified business profile ID.
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// Replay events created after the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: PrimitiveDateTime,
+
+    /// Replay events created before the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: PrimitiveDateTime,
+
+    /// Replay events of the specified classes.
+    pub event_classes: Option<HashSet<EventClass>>,
+
+    /// Replay events of the specified types.
+    pub event_types: Option<HashSet<EventType>>,
+
+    /// Replay events by the `is_overall_delivery_successful` field of the event. Only events
+    /// which could not be delivered are replayed by default.
+    #[serde(default)]
+    pub is_delivered: bool,
+}
+
+/// The status of a bulk replay of webhook events.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookReplayStatus {
+    /// The replay is enqueueing events for delivery.
+    InProgress,
+    /// All events matching the replay constraints have been enqueued for delivery.
+    Completed,
+    /// The replay was cancelled before all events could be enqueued for delivery.
+    Cancelled,
+    /// The replay was stopped due to an unexpected error.
+    Failed,
+}
+
+/// The response body for bulk replays of webhook events.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookReplayResponse {
+    /// The identifier for the replay.
+    #[schema(max_length = 64, example = "whr_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub replay_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = String, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: common_utils::id_type::ProfileId,
+
+    /// The status of the replay.
+    pub status: WebhookReplayStatus,
+
+    /// The number of events which matched the replay constraints when the replay was created.
+    pub total_count: i64,
+
+    /// The number of events enqueued for delivery.
+    pub enqueued_count: i64,
+
+    /// The number of events skipped, since their delivery is already being retried.
+    pub skipped_count: i64,
+
+    /// The number of events which could not be enqueued for delivery.
+    pub failed_count: i64,
+
+    /// Time at which the replay was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+
+    /// Time at which the replay was last updated.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookReplayRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub request: WebhookReplayRequest,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookReplayIdInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub replay_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookReplayIdInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
@@ -5,6 +5,8 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
+#[cfg(feature = "v1")]
+use crate::subscriptions;
 use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
@@ -368,6 +370,13 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = SubscriptionResponse, title = "SubscriptionResponse")]
+    SubscriptionDetails(Box<subscriptions::SubscriptionResponse>),
+    #[schema(value_type = InvoiceResponse, title = "InvoiceResponse")]
+    InvoiceDetails(Box<subscriptions::InvoiceResponse>),
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = PayoutBatchResponse, title = "PayoutBatchResponse")]
+    PayoutBatchDetails(Box<payouts::PayoutBatchResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -298,6 +298,170 @@ pub enum FraudCheckStatus {
     TransactionFailure,
 }
 
+/// The status of the manual review of a payment which a fraud check flagged for review.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmReviewStatus {
+    /// The payment is awaiting a decision from a reviewer
+    #[default]
+    Pending,
+    /// A reviewer approved the payment
+    Approved,
+    /// A reviewer rejected the payment
+    Rejected,
+    /// No decision was taken before the review expired, and the default action was taken
+    Expired,
+}
+
+/// The action taken on a payment under manual review.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmReviewAction {
+    /// Continue processing the payment
+    Approve,
+    /// Cancel the payment
+    Reject,
+}
+
+/// The status of a subscription.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SubscriptionStatus {
+    /// The subscription is in its trial period, and the customer has not been charged yet
+    Trialing,
+    /// The latest invoice of the subscription was paid
+    Active,
+    /// The latest invoice of the subscription could not be paid, and its payment is being retried
+    PastDue,
+    /// The subscription was canceled, either by the merchant or after its invoice could not be paid
+    Canceled,
+}
+
+/// The status of an invoice of a subscription.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InvoiceStatus {
+    /// The invoice was created, and is awaiting payment
+    Open,
+    /// The invoice was paid
+    Paid,
+    /// The latest payment of the invoice failed, and will be retried
+    PaymentFailed,
+    /// All the payment attempts of the invoice failed
+    Uncollectible,
+    /// The invoice was voided, as its subscription was canceled
+    Void,
+}
+
+/// The reason for which an invoice of a subscription was created.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InvoiceBillingReason {
+    /// The first invoice of a subscription without a trial period
+    SubscriptionCreate,
+    /// The invoice of a new billing cycle of the subscription
+    SubscriptionCycle,
+    /// The prorated invoice created when the price of the subscription was changed
+    SubscriptionUpdate,
+}
+
+/// The unit of the billing interval of a price.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BillingInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -1424,6 +1588,10 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    Subscriptions,
+    Invoices,
+    #[cfg(feature = "payouts")]
+    PayoutBatches,
 }
 
 impl EventClass {
@@ -1460,6 +1628,21 @@ impl EventClass {
                 EventType::PayoutExpired,
                 EventType::PayoutReversed,
             ]),
+            Self::Subscriptions => HashSet::from([
+                EventType::SubscriptionTrialing,
+                EventType::SubscriptionActive,
+                EventType::SubscriptionPastDue,
+                EventType::SubscriptionCanceled,
+            ]),
+            Self::Invoices => HashSet::from([
+                EventType::InvoiceCreated,
+                EventType::InvoicePaid,
+                EventType::InvoicePaymentFailed,
+                EventType::InvoiceUncollectible,
+                EventType::InvoiceVoided,
+            ]),
+            #[cfg(feature = "payouts")]
+            Self::PayoutBatches => HashSet::from([EventType::PayoutBatchCompleted]),
         }
     }
 }
@@ -1516,6 +1699,17 @@ pub enum EventType {
     PayoutExpired,
     #[cfg(feature = "payouts")]
     PayoutReversed,
+    SubscriptionTrialing,
+    SubscriptionActive,
+    SubscriptionPastDue,
+    SubscriptionCanceled,
+    InvoiceCreated,
+    InvoicePaid,
+    InvoicePaymentFailed,
+    InvoiceUncollectible,
+    InvoiceVoided,
+    #[cfg(feature = "payouts")]
+    PayoutBatchCompleted,
 }
 
 #[derive(
@@ -2257,6 +2451,10 @@ pub enum RelayStatus {
 #[serde(rename_all = "snake_case")]
 pub enum RelayType {
     Refund,
+    Capture,
+    Void,
+    PaymentSync,
+    IncrementalAuthorization,
 }
 
 #[derive(
@@ -6983,6 +7181,62 @@ pub enum PayoutStatus {
     RequiresVendorAccountCreation,
 }
 
+/// The status of a batch of payouts created from an uploaded file.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The payouts of the batch are being created
+    Processing,
+    /// A payout was created for every row of the batch
+    Completed,
+    /// A payout was created for some of the rows of the batch, and could not be created for the others
+    PartiallyCompleted,
+    /// A payout could not be created for any row of the batch
+    Failed,
+}
+
+/// The status of a single row of a payout batch.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// The payout of the row is yet to be created
+    Pending,
+    /// The payout of the row was created, its outcome is given by the status of the payout
+    Created,
+    /// The payout of the row could not be created
+    Failed,
+}
+
 /// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
 #[derive(
     Clone,
@@ -7190,6 +7444,151 @@ pub enum ReconStatus {
     Disabled,
 }
 
+/// The status of a reconciliation job, which matches the rows of a connector settlement file
+/// against the payments and refunds processed through the connector.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconJobStatus {
+    /// The settlement file has been uploaded and is yet to be processed
+    #[default]
+    Pending,
+    /// The settlement file is being matched against payments and refunds
+    Processing,
+    /// All rows of the settlement file have been matched
+    Completed,
+    /// The settlement file could not be processed
+    Failed,
+}
+
+/// The type of transaction a row of a connector settlement file corresponds to.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconTransactionType {
+    Payment,
+    Refund,
+}
+
+/// The status of a transaction, as reported in a connector settlement file.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconSettlementStatus {
+    Success,
+    Failure,
+    Pending,
+}
+
+impl From<AttemptStatus> for ReconSettlementStatus {
+    fn from(status: AttemptStatus) -> Self {
+        match status {
+            AttemptStatus::Charged
+            | AttemptStatus::PartialCharged
+            | AttemptStatus::PartialChargedAndChargeable
+            | AttemptStatus::AutoRefunded => Self::Success,
+            AttemptStatus::Failure
+            | AttemptStatus::AuthenticationFailed
+            | AttemptStatus::AuthorizationFailed
+            | AttemptStatus::RouterDeclined
+            | AttemptStatus::CaptureFailed
+            | AttemptStatus::Voided => Self::Failure,
+            AttemptStatus::Started
+            | AttemptStatus::AuthenticationPending
+            | AttemptStatus::AuthenticationSuccessful
+            | AttemptStatus::Authorized
+            | AttemptStatus::Authorizing
+            | AttemptStatus::CodInitiated
+            | AttemptStatus::VoidInitiated
+            | AttemptStatus::VoidFailed
+            | AttemptStatus::CaptureInitiated
+            | AttemptStatus::Pending
+            | AttemptStatus::PaymentMethodAwaited
+            | AttemptStatus::ConfirmationAwaited
+            | AttemptStatus::DeviceDataCollectionPending
+            | AttemptStatus::Unresolved
+            | AttemptStatus::IntegrityFailure => Self::Pending,
+        }
+    }
+}
+
+impl From<RefundStatus> for ReconSettlementStatus {
+    fn from(status: RefundStatus) -> Self {
+        match status {
+            RefundStatus::Success => Self::Success,
+            RefundStatus::Failure | RefundStatus::TransactionFailure => Self::Failure,
+            RefundStatus::Pending | RefundStatus::ManualReview => Self::Pending,
+        }
+    }
+}
+
+/// The kind of discrepancy found between a row of a connector settlement file and the
+/// corresponding payment or refund.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconMismatchType {
+    /// No payment or refund exists with the connector transaction ID in the settlement file
+    Missing,
+    /// The amount or currency in the settlement file differs from that of the transaction
+    AmountDrift,
+    /// The status in the settlement file differs from that of the transaction
+    StatusDrift,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -7513,8 +7912,21 @@ pub enum ParentGroup {
     Internal,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum Resource {
     Payment,
     Refund,
@@ -8054,6 +8466,16 @@ impl From<RefundStatus> for RelayStatus {
     }
 }
 
+impl From<AuthorizationStatus> for RelayStatus {
+    fn from(authorization_status: AuthorizationStatus) -> Self {
+        match authorization_status {
+            AuthorizationStatus::Success => Self::Success,
+            AuthorizationStatus::Failure => Self::Failure,
+            AuthorizationStatus::Processing | AuthorizationStatus::Unresolved => Self::Pending,
+        }
+    }
+}
+
 impl From<RelayStatus> for RefundStatus {
     fn from(relay_status: RelayStatus) -> Self {
         match relay_status {
@@ -8438,8 +8860,10 @@ pub enum ProcessTrackerStatus {
     Clone,
     Copy,
     Debug,
+    Hash,
     PartialEq,
     Eq,
+    strum::EnumIter,
     strum::EnumString,
     strum::Display,
 )]
@@ -8454,6 +8878,42 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    WebhookSecretRotationWorkflow,
+    WebhookReplayWorkflow,
+    ReconWorkflow,
+    ForexRateSnapshotWorkflow,
+    FrmReviewExpiryWorkflow,
+    SubscriptionBillingWorkflow,
+    PayoutBatchWorkflow,
+}
+
+/// An action performed on a process tracker task through the process tracker admin APIs.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProcessTrackerAction {
+    /// The task was paused, and will not be picked by the producer until it is run or
+    /// rescheduled.
+    Pause,
+    /// The task was scheduled to be run right away.
+    Run,
+    /// The task was scheduled to be run at a different time.
+    Reschedule,
+    /// The task was cancelled, and will not be run again.
+    Cancel,
 }
 
 #[derive(Debug)]
@@ -2,12 +2,13 @@ use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "payouts")]
-use crate::enums::PayoutStatus;
 use crate::enums::{
     AttemptStatus, Country, CountryAlpha2, CountryAlpha3, DisputeStatus, EventType, IntentStatus,
-    MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
+    InvoiceStatus, MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
+    SubscriptionStatus,
 };
+#[cfg(feature = "payouts")]
+use crate::enums::{PayoutBatchStatus, PayoutStatus};
 
 impl Display for NumericCountryCodeParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -2173,6 +2174,18 @@ impl From<PayoutStatus> for Option<EventType> {
     }
 }
 
+#[cfg(feature = "payouts")]
+impl From<PayoutBatchStatus> for Option<EventType> {
+    fn from(value: PayoutBatchStatus) -> Self {
+        match value {
+            PayoutBatchStatus::Completed
+            | PayoutBatchStatus::PartiallyCompleted
+            | PayoutBatchStatus::Failed => Some(EventType::PayoutBatchCompleted),
+            PayoutBatchStatus::Processing => None,
+        }
+    }
+}
+
 impl From<DisputeStatus> for EventType {
     fn from(value: DisputeStatus) -> Self {
         match value {
@@ -2197,6 +2210,29 @@ impl From<MandateStatus> for Option<EventType> {
     }
 }
 
+impl From<SubscriptionStatus> for Option<EventType> {
+    fn from(value: SubscriptionStatus) -> Self {
+        match value {
+            SubscriptionStatus::Trialing => Some(EventType::SubscriptionTrialing),
+            SubscriptionStatus::Active => Some(EventType::SubscriptionActive),
+            SubscriptionStatus::PastDue => Some(EventType::SubscriptionPastDue),
+            SubscriptionStatus::Canceled => Some(EventType::SubscriptionCanceled),
+        }
+    }
+}
+
+impl From<InvoiceStatus> for Option<EventType> {
+    fn from(value: InvoiceStatus) -> Self {
+        match value {
+            InvoiceStatus::Open => Some(EventType::InvoiceCreated),
+            InvoiceStatus::Paid => Some(EventType::InvoicePaid),
+            InvoiceStatus::PaymentFailed => Some(EventType::InvoicePaymentFailed),
+            InvoiceStatus::Uncollectible => Some(EventType::InvoiceUncollectible),
+            InvoiceStatus::Void => Some(EventType::InvoiceVoided),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
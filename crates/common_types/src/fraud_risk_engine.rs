@@ -0,0 +1,46 @@
+use euclid::frontend::dir::{DirKeyKind, EuclidDirFilter};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Enum representing the possible outcomes of the fraud risk engine.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FraudRiskDecision {
+    /// The payment is considered legitimate
+    #[default]
+    Accept,
+    /// The payment has to be reviewed manually by the merchant
+    Review,
+    /// The payment is considered fraudulent
+    Decline,
+}
+
+/// Struct representing the output of a rule of the fraud risk engine.
+#[derive(Serialize, Default, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct FraudRiskRule {
+    /// The decision for payments matching the rule
+    pub decision: FraudRiskDecision,
+}
+
+impl FraudRiskRule {
+    /// Returns the decision
+    pub fn get_decision(&self) -> FraudRiskDecision {
+        self.decision
+    }
+}
+
+impl EuclidDirFilter for FraudRiskRule {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentMethod,
+        DirKeyKind::CardBin,
+        DirKeyKind::CardType,
+        DirKeyKind::CardNetwork,
+        DirKeyKind::MetaData,
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::CaptureMethod,
+        DirKeyKind::BillingCountry,
+        DirKeyKind::BusinessCountry,
+        DirKeyKind::IssuerCountry,
+    ];
+}
@@ -5,11 +5,14 @@
 pub mod consts;
 pub mod customers;
 pub mod domain;
+/// types for the rules based fraud risk engine
+pub mod fraud_risk_engine;
 pub mod payment_methods;
 pub mod payments;
 /// types that are wrappers around primitive types
 pub mod primitive_wrappers;
 pub mod refunds;
+pub mod subscriptions;
 /// types for three ds decision rule engine
 pub mod three_ds_decision_rule_engine;
 
@@ -0,0 +1,54 @@
+//! Subscription related types
+
+use common_utils::{impl_to_sql_from_sql_json, types::MinorUnit};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// A line of an invoice of a subscription
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct InvoiceLineItem {
+    /// Description of the line item
+    #[schema(example = "Remaining time on Pro plan")]
+    pub description: String,
+
+    /// Identifier of the price the line item is billed for
+    #[schema(max_length = 64, example = "price_Kq9cf8Z1FxNnxc2QfhJ8")]
+    pub price_id: String,
+
+    /// Amount of the line item, in the lowest denomination of the currency of the invoice. Credits
+    /// for the unused time on a previous price are negative.
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// Start of the period covered by the line item
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+
+    /// End of the period covered by the line item
+    #[schema(example = "2022-10-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+
+    /// Whether the line item was prorated because of a change of price
+    pub proration: bool,
+}
+
+/// The lines of an invoice of a subscription
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct InvoiceLineItems(pub Vec<InvoiceLineItem>);
+impl_to_sql_from_sql_json!(InvoiceLineItems);
+
+impl InvoiceLineItems {
+    /// Returns the sum of the amounts of the line items
+    pub fn get_total_amount(&self) -> MinorUnit {
+        self.0.iter().fold(MinorUnit::zero(), |total, line_item| {
+            total + line_item.amount
+        })
+    }
+}
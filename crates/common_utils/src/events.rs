@@ -137,6 +137,18 @@ pub enum ApiEventsType {
         profile_acquirer_id: id_type::ProfileAcquirerId,
     },
     ThreeDsDecisionRule,
+    SubscriptionPlan {
+        plan_id: String,
+    },
+    Subscription {
+        subscription_id: String,
+    },
+    Invoice {
+        invoice_id: String,
+    },
+    PayoutBatch {
+        batch_id: String,
+    },
 }
 
 impl ApiEventMetric for serde_json::Value {}
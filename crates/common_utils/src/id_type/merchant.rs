@@ -9,7 +9,7 @@ use crate::{
     date_time,
     errors::{CustomResult, ValidationError},
     generate_id_with_default_len,
-    id_type::{AlphaNumericId, LengthId},
+    id_type::{self, AlphaNumericId, LengthId},
     new_type::MerchantName,
     types::keymanager,
 };
@@ -168,11 +168,25 @@ impl MerchantId {
         format!("payment_config_id_{}", self.get_string_repr())
     }
 
+    /// get_fraud_risk_engine_config_key
+    pub fn get_fraud_risk_engine_config_key(&self, profile_id: &id_type::ProfileId) -> String {
+        format!(
+            "fraud_risk_engine_{}_{}",
+            self.get_string_repr(),
+            profile_id.get_string_repr()
+        )
+    }
+
     /// get_payment_method_surcharge_routing_id
     pub fn get_payment_method_surcharge_routing_id(&self) -> String {
         format!("payment_method_surcharge_id_{}", self.get_string_repr())
     }
 
+    /// get_rate_limit_overrides_key
+    pub fn get_rate_limit_overrides_key(&self) -> String {
+        format!("rate_limit_overrides_{}", self.get_string_repr())
+    }
+
     /// get_webhook_config_disabled_events_key
     pub fn get_webhook_config_disabled_events_key(&self, connector_id: &str) -> String {
         format!(
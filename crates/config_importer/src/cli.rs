@@ -26,6 +26,24 @@ pub(crate) struct Args {
     /// Prefix to be used for each environment variable in the generated output.
     #[arg(short, long, default_value = "ROUTER")]
     pub(crate) prefix: String,
+
+    /// Dotted TOML key paths (such as `secrets.admin_api_key`) whose values are secrets. A path
+    /// referring to a table marks every key within the table as a secret.
+    ///
+    /// Secrets are written to the `Secret` manifest in the `kubernetes-manifest` format and to the
+    /// `secrets` section in the `helm-values` format.
+    #[arg(short, long, value_name = "PATH", value_delimiter = ',')]
+    pub(crate) secret_path: Vec<String>,
+
+    /// Name of the generated Kubernetes `ConfigMap` and `Secret`. Defaults to the prefix in
+    /// lowercase.
+    #[arg(long, value_name = "NAME")]
+    pub(crate) resource_name: Option<String>,
+
+    /// Rebuild a TOML configuration file from environment variables in the dotenv format, instead
+    /// of converting a TOML configuration file to environment variables.
+    #[arg(short, long)]
+    pub(crate) reverse: bool,
 }
 
 /// The output format to convert environment variables to.
@@ -40,4 +58,44 @@ pub(crate) enum OutputFormat {
     /// }
     /// ```
     KubernetesJson,
+
+    /// Writes each environment variable on a separate line in the dotenv format.
+    ///
+    /// ```shell
+    /// ENVIRONMENT="PRODUCTION"
+    /// ```
+    Dotenv,
+
+    /// Writes the environment variables as Helm `values.yaml` entries, with the secrets in a
+    /// separate section.
+    ///
+    /// ```yaml
+    /// env:
+    ///   ENVIRONMENT: "PRODUCTION"
+    /// secrets:
+    ///   ADMIN_API_KEY: "test_admin"
+    /// ```
+    HelmValues,
+
+    /// Writes the environment variables as the `environment` section of a Docker Compose
+    /// service.
+    ///
+    /// ```yaml
+    /// environment:
+    ///   ENVIRONMENT: "PRODUCTION"
+    /// ```
+    DockerCompose,
+
+    /// Writes a Kubernetes `ConfigMap` manifest containing the environment variables, followed by
+    /// a `Secret` manifest containing the secrets.
+    ///
+    /// ```yaml
+    /// apiVersion: v1
+    /// kind: ConfigMap
+    /// metadata:
+    ///   name: router
+    /// data:
+    ///   ENVIRONMENT: "PRODUCTION"
+    /// ```
+    KubernetesManifest,
 }
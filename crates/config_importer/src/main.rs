@@ -1,4 +1,6 @@
 mod cli;
+mod output;
+mod reverse;
 
 use std::io::{BufWriter, Write};
 
@@ -16,20 +18,11 @@ type EnvironmentVariableMap = indexmap::IndexMap<String, String>;
 fn main() -> anyhow::Result<()> {
     let args = <cli::Args as clap::Parser>::parse();
 
-    // Read input TOML file
-    let toml_contents =
+    // Read input file
+    let input_contents =
         std::fs::read_to_string(args.input_file).context("Failed to read input file")?;
-    let table = toml_contents
-        .parse::<toml::Table>()
-        .context("Failed to parse TOML file contents")?;
-
-    // Parse TOML file contents to a `HashMap` of environment variable name and value pairs
-    let env_vars = table
-        .iter()
-        .flat_map(|(key, value)| process_toml_value(&args.prefix, key, value))
-        .collect::<EnvironmentVariableMap>();
 
-    let writer: BufWriter<Box<dyn Write>> = match args.output_file {
+    let mut writer: BufWriter<Box<dyn Write>> = match args.output_file {
         // Write to file if output file is specified
         Some(file) => BufWriter::new(Box::new(
             std::fs::OpenOptions::new()
@@ -43,6 +36,49 @@ fn main() -> anyhow::Result<()> {
         None => BufWriter::new(Box::new(std::io::stdout().lock())),
     };
 
+    // Rebuild TOML file contents from environment variables if running in reverse
+    if args.reverse {
+        let table = reverse::dotenv_to_toml(&args.prefix, &input_contents)?;
+        let toml_contents =
+            toml::to_string_pretty(&table).context("Failed to serialize TOML file contents")?;
+
+        writer
+            .write_all(toml_contents.as_bytes())
+            .context("Failed to write TOML output")?;
+        return writer.flush().context("Failed to write TOML output");
+    }
+
+    let table = input_contents
+        .parse::<toml::Table>()
+        .context("Failed to parse TOML file contents")?;
+
+    // Parse TOML file contents to a `HashMap` of environment variable name and value pairs
+    let env_vars = table
+        .iter()
+        .flat_map(|(key, value)| process_toml_value(&args.prefix, key, value))
+        .collect::<EnvironmentVariableMap>();
+
+    // Separate the secrets from the rest of the configuration, using the environment variable
+    // names corresponding to the secret key paths
+    let secret_env_var_names = args
+        .secret_path
+        .iter()
+        .map(|path| {
+            format!(
+                "{}{ENV_VAR_SEPARATOR}{}",
+                args.prefix,
+                path.replace('.', ENV_VAR_SEPARATOR)
+            )
+            .to_ascii_uppercase()
+        })
+        .collect::<Vec<_>>();
+    let is_secret = |name: &str| {
+        secret_env_var_names.iter().any(|secret_name| {
+            name.strip_prefix(secret_name.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(ENV_VAR_SEPARATOR))
+        })
+    };
+
     // Write environment variables in specified format
     match args.output_format {
         cli::OutputFormat::KubernetesJson => {
@@ -53,6 +89,28 @@ fn main() -> anyhow::Result<()> {
             serde_json::to_writer_pretty(writer, &k8s_env_vars)
                 .context("Failed to serialize environment variables as JSON")?
         }
+        cli::OutputFormat::Dotenv => output::write_dotenv(writer, &env_vars)?,
+        cli::OutputFormat::HelmValues => {
+            let (secret_env_vars, config_env_vars) = env_vars
+                .into_iter()
+                .partition::<EnvironmentVariableMap, _>(|(name, _)| is_secret(name));
+            output::write_helm_values(writer, &config_env_vars, &secret_env_vars)?
+        }
+        cli::OutputFormat::DockerCompose => output::write_docker_compose(writer, &env_vars)?,
+        cli::OutputFormat::KubernetesManifest => {
+            let resource_name = args
+                .resource_name
+                .unwrap_or_else(|| args.prefix.to_ascii_lowercase().replace('_', "-"));
+            let (secret_env_vars, config_env_vars) = env_vars
+                .into_iter()
+                .partition::<EnvironmentVariableMap, _>(|(name, _)| is_secret(name));
+            output::write_kubernetes_manifest(
+                writer,
+                &resource_name,
+                &config_env_vars,
+                &secret_env_vars,
+            )?
+        }
     }
 
     Ok(())
@@ -0,0 +1,181 @@
+use std::io::Write;
+
+use anyhow::Context;
+
+use crate::EnvironmentVariableMap;
+
+/// The indentation used for nested YAML mappings.
+const YAML_INDENT: &str = "  ";
+
+/// Quotes a string as a YAML double-quoted scalar.
+///
+/// JSON strings are valid YAML double-quoted scalars, so the JSON serializer takes care of
+/// escaping special characters.
+fn yaml_string(value: &str) -> anyhow::Result<String> {
+    serde_json::to_string(value).context("Failed to quote string as YAML scalar")
+}
+
+/// Quotes a mapping key only if it contains characters other than ASCII alphanumerics and
+/// underscores, to keep the generated YAML readable.
+fn yaml_key(key: &str) -> anyhow::Result<String> {
+    if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(key.to_owned())
+    } else {
+        yaml_string(key)
+    }
+}
+
+/// Writes a top-level YAML mapping of environment variables, with each value transformed by
+/// `escape` before being quoted.
+fn write_yaml_mapping(
+    writer: &mut impl Write,
+    name: &str,
+    env_vars: &EnvironmentVariableMap,
+    escape: impl Fn(&str) -> String,
+) -> anyhow::Result<()> {
+    if env_vars.is_empty() {
+        writeln!(writer, "{name}: {{}}")?;
+        return Ok(());
+    }
+
+    writeln!(writer, "{name}:")?;
+    for (key, value) in env_vars {
+        writeln!(
+            writer,
+            "{YAML_INDENT}{}: {}",
+            yaml_key(key)?,
+            yaml_string(&escape(value))?
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Writes the environment variables in the dotenv format, with each value double-quoted.
+pub(crate) fn write_dotenv(
+    mut writer: impl Write,
+    env_vars: &EnvironmentVariableMap,
+) -> anyhow::Result<()> {
+    for (key, value) in env_vars {
+        let value = value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('$', "\\$")
+            .replace('\n', "\\n");
+        writeln!(writer, "{key}=\"{value}\"")?;
+    }
+
+    writer.flush().context("Failed to write dotenv output")
+}
+
+/// Writes the environment variables as Helm values, keeping the secrets in a separate section.
+pub(crate) fn write_helm_values(
+    mut writer: impl Write,
+    config_env_vars: &EnvironmentVariableMap,
+    secret_env_vars: &EnvironmentVariableMap,
+) -> anyhow::Result<()> {
+    write_yaml_mapping(&mut writer, "env", config_env_vars, str::to_owned)?;
+    write_yaml_mapping(&mut writer, "secrets", secret_env_vars, str::to_owned)?;
+
+    writer.flush().context("Failed to write Helm values output")
+}
+
+/// Writes the environment variables as the `environment` section of a Docker Compose service.
+pub(crate) fn write_docker_compose(
+    mut writer: impl Write,
+    env_vars: &EnvironmentVariableMap,
+) -> anyhow::Result<()> {
+    // Docker Compose interpolates variables in values, `$$` is a literal dollar sign
+    write_yaml_mapping(&mut writer, "environment", env_vars, |value| {
+        value.replace('$', "$$")
+    })?;
+
+    writer
+        .flush()
+        .context("Failed to write Docker Compose output")
+}
+
+/// Writes a Kubernetes `ConfigMap` manifest with the configuration, followed by a `Secret`
+/// manifest with the secrets if there are any.
+pub(crate) fn write_kubernetes_manifest(
+    mut writer: impl Write,
+    resource_name: &str,
+    config_env_vars: &EnvironmentVariableMap,
+    secret_env_vars: &EnvironmentVariableMap,
+) -> anyhow::Result<()> {
+    let resource_name = yaml_string(resource_name)?;
+
+    writeln!(writer, "apiVersion: v1")?;
+    writeln!(writer, "kind: ConfigMap")?;
+    writeln!(writer, "metadata:")?;
+    writeln!(writer, "{YAML_INDENT}name: {resource_name}")?;
+    write_yaml_mapping(&mut writer, "data", config_env_vars, str::to_owned)?;
+
+    if !secret_env_vars.is_empty() {
+        writeln!(writer, "---")?;
+        writeln!(writer, "apiVersion: v1")?;
+        writeln!(writer, "kind: Secret")?;
+        writeln!(writer, "metadata:")?;
+        writeln!(writer, "{YAML_INDENT}name: {resource_name}")?;
+        writeln!(writer, "type: Opaque")?;
+        write_yaml_mapping(&mut writer, "stringData", secret_env_vars, str::to_owned)?;
+    }
+
+    writer
+        .flush()
+        .context("Failed to write Kubernetes manifest output")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_env_vars(env_vars: &[(&str, &str)]) -> EnvironmentVariableMap {
+        env_vars
+            .iter()
+            .map(|(name, value)| ((*name).to_owned(), (*value).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn test_dotenv_values_are_quoted() {
+        let mut output = Vec::new();
+        write_dotenv(
+            &mut output,
+            &get_env_vars(&[("ROUTER__SERVER__PORT", "8080")]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "ROUTER__SERVER__PORT=\"8080\"\n"
+        );
+    }
+
+    #[test]
+    fn test_dotenv_special_characters_are_escaped() {
+        let mut output = Vec::new();
+        write_dotenv(
+            &mut output,
+            &get_env_vars(&[("ROUTER__SECRET", "a\\b\"c$d\ne")]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "ROUTER__SECRET=\"a\\\\b\\\"c\\$d\\ne\"\n"
+        );
+    }
+
+    #[test]
+    fn test_docker_compose_dollar_signs_are_escaped() {
+        let mut output = Vec::new();
+        write_docker_compose(&mut output, &get_env_vars(&[("ROUTER__SECRET", "a$b")])).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "environment:\n  ROUTER__SECRET: \"a$$b\"\n"
+        );
+    }
+}
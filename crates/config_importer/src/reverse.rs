@@ -0,0 +1,196 @@
+use anyhow::Context;
+
+use crate::ENV_VAR_SEPARATOR;
+
+/// Parses the contents of a dotenv file into environment variable name and value pairs.
+///
+/// Blank lines, comments and the `export` keyword are ignored. Double-quoted values support the
+/// `\\`, `\"`, `\$` and `\n` escape sequences, single-quoted values are taken literally.
+fn parse_dotenv(contents: &str) -> anyhow::Result<Vec<(String, String)>> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_number, line)| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (name, value) = line
+                .split_once('=')
+                .with_context(|| format!("Missing `=` on line {line_number}"))?;
+            let value = value.trim();
+
+            let value = if let Some(quoted) = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+            {
+                unescape_double_quoted(quoted)
+            } else if let Some(quoted) = value
+                .strip_prefix('\'')
+                .and_then(|value| value.strip_suffix('\''))
+            {
+                quoted.to_owned()
+            } else {
+                value.to_owned()
+            };
+
+            Ok((name.trim().to_owned(), value))
+        })
+        .collect()
+}
+
+fn unescape_double_quoted(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some(escaped @ ('\\' | '"' | '$'))) => {
+                unescaped.push(escaped);
+                chars.next();
+            }
+            (c, _) => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+/// Converts an environment variable value back to a TOML value.
+///
+/// Environment variables do not carry type information, so integers, floats and booleans are
+/// recognized from their textual representation and everything else is kept as a string. Arrays
+/// are flattened into comma-separated strings when converting to environment variables, and are
+/// left as strings here.
+fn parse_toml_value(value: String) -> toml::Value {
+    if let Ok(integer) = value.parse::<i64>() {
+        return toml::Value::Integer(integer);
+    }
+
+    if let Ok(boolean) = value.parse::<bool>() {
+        return toml::Value::Boolean(boolean);
+    }
+
+    let looks_like_float = value.contains('.')
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'));
+    match value.parse::<f64>() {
+        Ok(float) if looks_like_float => toml::Value::Float(float),
+        _ => toml::Value::String(value),
+    }
+}
+
+/// Rebuilds a TOML table from the contents of a dotenv file, using the nesting encoded in the
+/// environment variable names.
+///
+/// Environment variables which do not start with the specified prefix are skipped.
+pub(crate) fn dotenv_to_toml(prefix: &str, contents: &str) -> anyhow::Result<toml::Table> {
+    let prefix = format!("{prefix}{ENV_VAR_SEPARATOR}").to_ascii_uppercase();
+    let mut root = toml::Table::new();
+
+    for (name, value) in parse_dotenv(contents)? {
+        let Some(path) = name.to_ascii_uppercase().strip_prefix(&prefix).map(|path| {
+            path.split(ENV_VAR_SEPARATOR)
+                .map(str::to_ascii_lowercase)
+                .collect::<Vec<_>>()
+        }) else {
+            continue;
+        };
+
+        let Some((key, tables)) = path.split_last() else {
+            continue;
+        };
+
+        let mut table = &mut root;
+        for table_key in tables {
+            table = table
+                .entry(table_key.as_str())
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+                .as_table_mut()
+                .with_context(|| {
+                    format!("`{name}` is nested within `{table_key}`, which has a value")
+                })?;
+        }
+
+        if table.contains_key(key) {
+            anyhow::bail!("`{name}` conflicts with another environment variable");
+        }
+        table.insert(key.to_owned(), parse_toml_value(value));
+    }
+
+    Ok(root)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::{output, process_toml_value, EnvironmentVariableMap};
+
+    #[test]
+    fn test_parse_dotenv() {
+        let contents = "# comment\n\
+            \n\
+            export ROUTER__LOG__LEVEL=debug\n\
+            ROUTER__SECRET=\"a\\\\b\\\"c\\$d\\ne\"\n\
+            ROUTER__LITERAL='a\\nb'\n";
+
+        assert_eq!(
+            parse_dotenv(contents).unwrap(),
+            vec![
+                ("ROUTER__LOG__LEVEL".to_owned(), "debug".to_owned()),
+                ("ROUTER__SECRET".to_owned(), "a\\b\"c$d\ne".to_owned()),
+                ("ROUTER__LITERAL".to_owned(), "a\\nb".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_dotenv_rejects_lines_without_value() {
+        assert!(parse_dotenv("ROUTER__LOG__LEVEL").is_err());
+    }
+
+    #[test]
+    fn test_dotenv_to_toml_skips_other_prefixes() {
+        let table = dotenv_to_toml("router", "ROUTER__PORT=8080\nOTHER__PORT=9090\n").unwrap();
+
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.get("port"), Some(&toml::Value::Integer(8080)));
+    }
+
+    #[test]
+    fn test_dotenv_to_toml_rejects_conflicting_variables() {
+        assert!(dotenv_to_toml("router", "ROUTER__SERVER=a\nROUTER__SERVER__PORT=8080\n").is_err());
+    }
+
+    #[test]
+    fn test_toml_to_dotenv_round_trip() {
+        let table = r#"
+            [server]
+            host = "0.0.0.0"
+            port = 8080
+            shutdown_timeout = 1.5
+
+            [secrets]
+            admin_api_key = "a\\b\"c$d\ne"
+            enabled = true
+        "#
+        .parse::<toml::Table>()
+        .unwrap();
+
+        let env_vars = table
+            .iter()
+            .flat_map(|(key, value)| process_toml_value("router", key, value))
+            .collect::<EnvironmentVariableMap>();
+        let mut dotenv = Vec::new();
+        output::write_dotenv(&mut dotenv, &env_vars).unwrap();
+
+        let reversed = dotenv_to_toml("router", &String::from_utf8(dotenv).unwrap()).unwrap();
+
+        assert_eq!(reversed, table);
+    }
+}
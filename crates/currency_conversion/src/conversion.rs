@@ -1,10 +1,11 @@
 use common_enums::Currency;
 use rust_decimal::Decimal;
 use rusty_money::Money;
+use time::PrimitiveDateTime;
 
 use crate::{
     error::CurrencyConversionError,
-    types::{currency_match, ExchangeRates},
+    types::{currency_match, ExchangeRates, HistoricalExchangeRates},
 };
 
 pub fn convert(
@@ -26,6 +27,20 @@ pub fn convert(
     }
 }
 
+/// Converts the amount at the exchange rates which were in effect at the given time
+pub fn convert_at(
+    historical_rates: &HistoricalExchangeRates,
+    time: PrimitiveDateTime,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: i64,
+) -> Result<Decimal, CurrencyConversionError> {
+    let ex_rates = historical_rates
+        .rates_at(time)
+        .ok_or(CurrencyConversionError::ExchangeRatesNotAvailable)?;
+    convert(ex_rates, from_currency, to_currency, amount)
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used, clippy::print_stdout)]
@@ -89,4 +104,59 @@ mod tests {
             convert(&sample_rate, convert_from, convert_to, amount).expect("converted_currency");
         println!("The conversion from {amount} {convert_from} to {convert_to} is {res:?}");
     }
+
+    #[test]
+    fn point_in_time_conversion() {
+        use super::*;
+        use crate::types::ExchangeRatesSnapshot;
+
+        let july_2025 = |day: u8| {
+            PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2025, time::Month::July, day).expect("date"),
+                time::Time::MIDNIGHT,
+            )
+        };
+        let inr_rates = |to_factor: i64, from_factor: i64| {
+            let mut conversion: HashMap<Currency, CurrencyFactors> = HashMap::new();
+            conversion.insert(
+                Currency::INR,
+                CurrencyFactors::new(Decimal::new(to_factor, 0), Decimal::new(from_factor, 4)),
+            );
+            ExchangeRates::new(Currency::USD, conversion)
+        };
+        let historical_rates = HistoricalExchangeRates::new(vec![
+            ExchangeRatesSnapshot {
+                captured_at: july_2025(10),
+                rates: inr_rates(85, 118),
+            },
+            ExchangeRatesSnapshot {
+                captured_at: july_2025(2),
+                rates: inr_rates(80, 125),
+            },
+        ]);
+        let convert_on = |day: u8| {
+            convert_at(
+                &historical_rates,
+                july_2025(day),
+                Currency::USD,
+                Currency::INR,
+                100,
+            )
+            .expect("converted_currency")
+        };
+
+        assert_eq!(convert_on(5), Decimal::new(80, 0));
+        assert_eq!(convert_on(10), Decimal::new(85, 0));
+        assert_eq!(convert_on(20), Decimal::new(85, 0));
+        // Times before the earliest snapshot are converted at the rates of the earliest snapshot
+        assert_eq!(convert_on(1), Decimal::new(80, 0));
+        assert!(convert_at(
+            &HistoricalExchangeRates::default(),
+            july_2025(5),
+            Currency::USD,
+            Currency::INR,
+            100,
+        )
+        .is_err());
+    }
 }
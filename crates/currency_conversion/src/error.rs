@@ -5,4 +5,6 @@ pub enum CurrencyConversionError {
     DecimalMultiplicationFailed,
     #[error("Currency not supported: '{0}'")]
     ConversionNotSupported(String),
+    #[error("No exchange rates available")]
+    ExchangeRatesNotAvailable,
 }
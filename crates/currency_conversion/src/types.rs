@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use common_enums::Currency;
 use rust_decimal::Decimal;
 use rusty_money::iso;
+use time::PrimitiveDateTime;
 
 use crate::error::CurrencyConversionError;
 
@@ -75,6 +76,48 @@ impl ExchangeRates {
     }
 }
 
+/// Exchange rates captured at a point in time
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExchangeRatesSnapshot {
+    pub captured_at: PrimitiveDateTime,
+    pub rates: ExchangeRates,
+}
+
+/// Exchange rates captured over a period of time, for converting amounts at the rates which were
+/// in effect at a given time
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct HistoricalExchangeRates {
+    /// Snapshots ordered by the time they were captured at
+    snapshots: Vec<ExchangeRatesSnapshot>,
+}
+
+impl HistoricalExchangeRates {
+    pub fn new(mut snapshots: Vec<ExchangeRatesSnapshot>) -> Self {
+        snapshots.sort_by_key(|snapshot| snapshot.captured_at);
+        Self { snapshots }
+    }
+
+    /// Returns the rates in effect at the given time, which are the rates of the latest snapshot
+    /// captured at or before that time. The earliest snapshot is used for times before any
+    /// snapshot was captured.
+    pub fn rates_at(&self, time: PrimitiveDateTime) -> Option<&ExchangeRates> {
+        let snapshots_before = self
+            .snapshots
+            .partition_point(|snapshot| snapshot.captured_at <= time);
+
+        snapshots_before
+            .checked_sub(1)
+            .and_then(|index| self.snapshots.get(index))
+            .or_else(|| self.snapshots.first())
+            .map(|snapshot| &snapshot.rates)
+    }
+
+    /// Returns the rates of the most recent snapshot
+    pub fn latest_rates(&self) -> Option<&ExchangeRates> {
+        self.snapshots.last().map(|snapshot| &snapshot.rates)
+    }
+}
+
 pub fn currency_match(currency: Currency) -> &'static iso::Currency {
     match currency {
         Currency::AED => iso::AED,
@@ -2,7 +2,7 @@ use diesel::{AsChangeset, AsExpression, Identifiable, Insertable, Queryable, Sel
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::schema::api_keys;
+use crate::{enums as storage_enums, schema::api_keys};
 
 #[derive(
     serde::Serialize, serde::Deserialize, Debug, Clone, Identifiable, Queryable, Selectable,
@@ -18,6 +18,9 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permission_groups: Option<Vec<storage_enums::PermissionGroup>>,
+    pub resources: Option<Vec<storage_enums::Resource>>,
+    pub profile_ids: Option<Vec<common_utils::id_type::ProfileId>>,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +35,9 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permission_groups: Option<Vec<storage_enums::PermissionGroup>>,
+    pub resources: Option<Vec<storage_enums::Resource>>,
+    pub profile_ids: Option<Vec<common_utils::id_type::ProfileId>>,
 }
 
 #[derive(Debug)]
@@ -41,6 +47,9 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        permission_groups: Option<Vec<storage_enums::PermissionGroup>>,
+        resources: Option<Vec<storage_enums::Resource>>,
+        profile_ids: Option<Vec<common_utils::id_type::ProfileId>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -54,6 +63,9 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub permission_groups: Option<Vec<storage_enums::PermissionGroup>>,
+    pub resources: Option<Vec<storage_enums::Resource>>,
+    pub profile_ids: Option<Vec<common_utils::id_type::ProfileId>>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -64,17 +76,26 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                permission_groups,
+                resources,
+                profile_ids,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                permission_groups,
+                resources,
+                profile_ids,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                permission_groups: None,
+                resources: None,
+                profile_ids: None,
             },
         }
     }
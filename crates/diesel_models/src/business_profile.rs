@@ -76,6 +76,8 @@ pub struct Profile {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -188,6 +190,8 @@ pub struct ProfileUpdateInternal {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub previous_payment_response_hash_key: Option<Option<String>>,
+    pub previous_payment_response_hash_key_expires_at: Option<Option<time::PrimitiveDateTime>>,
 }
 
 #[cfg(feature = "v1")]
@@ -241,6 +245,8 @@ impl ProfileUpdateInternal {
             three_ds_decision_rule_algorithm,
             acquirer_config_map,
             merchant_category_code,
+            previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -325,10 +331,24 @@ impl ProfileUpdateInternal {
                 .or(source.three_ds_decision_rule_algorithm),
             acquirer_config_map: acquirer_config_map.or(source.acquirer_config_map),
             merchant_category_code: merchant_category_code.or(source.merchant_category_code),
+            previous_payment_response_hash_key: previous_payment_response_hash_key
+                .unwrap_or(source.previous_payment_response_hash_key),
+            previous_payment_response_hash_key_expires_at:
+                previous_payment_response_hash_key_expires_at
+                    .unwrap_or(source.previous_payment_response_hash_key_expires_at),
         }
     }
 }
 
+// Tracking data by process_tracker
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct WebhookSecretRotationTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub previous_payment_response_hash_key_expires_at: time::PrimitiveDateTime,
+}
+
 /// Note: The order of fields in the struct is important.
 /// This should be in the same order as the fields in the schema.rs file, otherwise the code will
 /// not compile
@@ -387,6 +407,8 @@ pub struct Profile {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
     pub routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
@@ -681,6 +703,9 @@ impl ProfileUpdateInternal {
             three_ds_decision_rule_algorithm: None,
             acquirer_config_map: None,
             merchant_category_code: merchant_category_code.or(source.merchant_category_code),
+            previous_payment_response_hash_key: source.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: source
+                .previous_payment_response_hash_key_expires_at,
         }
     }
 }
@@ -0,0 +1,43 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::embedded_vault;
+
+/// An entry stored in the vault embedded in the application, the data is encrypted with a data key
+/// which is itself encrypted using the configured encryption management service.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, Selectable, PartialEq)]
+#[diesel(table_name = embedded_vault, primary_key(merchant_id, vault_id), check_for_backend(diesel::pg::Pg))]
+pub struct EmbeddedVaultEntry {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub vault_id: String,
+    pub entity_id: String,
+    pub encrypted_data: Vec<u8>,
+    pub encrypted_data_key: Vec<u8>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = embedded_vault)]
+pub struct EmbeddedVaultEntryNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub vault_id: String,
+    pub entity_id: String,
+    pub encrypted_data: Vec<u8>,
+    pub encrypted_data_key: Vec<u8>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+// `expires_at` is always set by the changeset, `None` clears the expiry of the entry
+#[diesel(table_name = embedded_vault, treat_none_as_null = true)]
+pub struct EmbeddedVaultEntryUpdate {
+    pub entity_id: String,
+    pub encrypted_data: Vec<u8>,
+    pub encrypted_data_key: Vec<u8>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
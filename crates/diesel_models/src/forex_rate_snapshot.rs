@@ -0,0 +1,26 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::forex_rate_snapshot};
+
+/// The exchange rates in effect at the time they were captured at, the rates are stored as a map
+/// of currencies to their conversion factors with respect to the base currency.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = forex_rate_snapshot, primary_key(snapshot_id), check_for_backend(diesel::pg::Pg))]
+pub struct ForexRateSnapshot {
+    pub snapshot_id: String,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub captured_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = forex_rate_snapshot)]
+pub struct ForexRateSnapshotNew {
+    pub snapshot_id: String,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub captured_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+}
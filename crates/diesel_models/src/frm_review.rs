@@ -0,0 +1,160 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{frm_review, frm_review_note},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = frm_review)]
+pub struct FrmReviewNew {
+    pub review_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub frm_id: String,
+    pub frm_name: String,
+    pub status: storage_enums::FrmReviewStatus,
+    pub frm_reason: Option<serde_json::Value>,
+    pub frm_score: Option<i32>,
+    pub assigned_to: Option<String>,
+    pub decision: Option<storage_enums::FrmReviewAction>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<PrimitiveDateTime>,
+    pub expires_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// A payment which a fraud check flagged for manual review, along with the outcome of the review.
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = frm_review, primary_key(review_id), check_for_backend(diesel::pg::Pg))]
+pub struct FrmReview {
+    pub review_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub attempt_id: String,
+    pub frm_id: String,
+    pub frm_name: String,
+    pub status: storage_enums::FrmReviewStatus,
+    pub frm_reason: Option<serde_json::Value>,
+    pub frm_score: Option<i32>,
+    pub assigned_to: Option<String>,
+    pub decision: Option<storage_enums::FrmReviewAction>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<PrimitiveDateTime>,
+    pub expires_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum FrmReviewUpdate {
+    AssignmentUpdate {
+        assigned_to: Option<String>,
+    },
+    DecisionUpdate {
+        status: storage_enums::FrmReviewStatus,
+        decision: storage_enums::FrmReviewAction,
+        reviewed_by: Option<String>,
+    },
+    StatusUpdate {
+        status: storage_enums::FrmReviewStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = frm_review)]
+pub struct FrmReviewUpdateInternal {
+    pub status: Option<storage_enums::FrmReviewStatus>,
+    // `None` leaves the assignee unchanged, `Some(None)` unassigns the review
+    pub assigned_to: Option<Option<String>>,
+    pub decision: Option<storage_enums::FrmReviewAction>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<FrmReviewUpdate> for FrmReviewUpdateInternal {
+    fn from(frm_review_update: FrmReviewUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match frm_review_update {
+            FrmReviewUpdate::AssignmentUpdate { assigned_to } => Self {
+                status: None,
+                assigned_to: Some(assigned_to),
+                decision: None,
+                reviewed_by: None,
+                reviewed_at: None,
+                modified_at: now,
+            },
+            FrmReviewUpdate::DecisionUpdate {
+                status,
+                decision,
+                reviewed_by,
+            } => Self {
+                status: Some(status),
+                assigned_to: None,
+                decision: Some(decision),
+                reviewed_by,
+                reviewed_at: Some(now),
+                modified_at: now,
+            },
+            FrmReviewUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                assigned_to: None,
+                decision: None,
+                reviewed_by: None,
+                reviewed_at: None,
+                modified_at: now,
+            },
+        }
+    }
+}
+
+impl FrmReviewUpdateInternal {
+    pub fn apply_changeset(self, source: FrmReview) -> FrmReview {
+        FrmReview {
+            status: self.status.unwrap_or(source.status),
+            assigned_to: self.assigned_to.unwrap_or(source.assigned_to),
+            decision: self.decision.or(source.decision),
+            reviewed_by: self.reviewed_by.or(source.reviewed_by),
+            reviewed_at: self.reviewed_at.or(source.reviewed_at),
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = frm_review_note)]
+pub struct FrmReviewNoteNew {
+    pub note_id: String,
+    pub review_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub user_id: String,
+    pub note: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// A note recorded by a reviewer on a manual review.
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = frm_review_note, primary_key(note_id), check_for_backend(diesel::pg::Pg))]
+pub struct FrmReviewNote {
+    pub note_id: String,
+    pub review_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub user_id: String,
+    pub note: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct FrmReviewExpiryTrackingData {
+    pub review_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -1,4 +1,4 @@
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "v2")]
@@ -15,6 +15,7 @@ use crate::{
     payment_intent::PaymentIntentNew,
     payout_attempt::{PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate},
     payouts::{Payouts, PayoutsNew, PayoutsUpdate},
+    query::generics,
     refund::{Refund, RefundNew, RefundUpdate},
     reverse_lookup::{ReverseLookup, ReverseLookupNew},
     Mandate, MandateNew, MandateUpdateInternal, PaymentIntent, PaymentMethod, PaymentMethodNew,
@@ -183,6 +184,86 @@ impl DBOperation {
     }
 }
 
+/// Collects the values of a single `Insertable` variant, failing if the batch contains values of
+/// any other variant.
+macro_rules! collect_insertables {
+    ($insertables:expr, $variant:ident, $unwrap:expr) => {
+        $insertables
+            .into_iter()
+            .map(|insertable| match insertable {
+                Insertable::$variant(value) => Ok($unwrap(value)),
+                _ => Err(report!(errors::DatabaseError::QueryGenerationFailed))
+                    .attach_printable("A batch insert can only contain values of a single table"),
+            })
+            .collect::<crate::StorageResult<Vec<_>>>()
+    };
+}
+
+impl Insertable {
+    /// Inserts values belonging to the same table using a single multi-row `INSERT` query.
+    ///
+    /// Returns the number of values inserted.
+    pub async fn batch_insert(
+        insertables: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> crate::StorageResult<usize> {
+        let count = insertables.len();
+        let Some(first) = insertables.first() else {
+            return Ok(0);
+        };
+
+        match first {
+            Self::PaymentIntent(_) => {
+                let values =
+                    collect_insertables!(insertables, PaymentIntent, |value: Box<_>| { *value })?;
+                generics::generic_insert::<_, _, PaymentIntent>(conn, values).await?;
+            }
+            Self::PaymentAttempt(_) => {
+                let values =
+                    collect_insertables!(insertables, PaymentAttempt, |value: Box<_>| { *value })?;
+                generics::generic_insert::<_, _, PaymentAttempt>(conn, values).await?;
+            }
+            Self::Refund(_) => {
+                let values = collect_insertables!(insertables, Refund, std::convert::identity)?;
+                generics::generic_insert::<_, _, Refund>(conn, values).await?;
+            }
+            Self::Address(_) => {
+                let values = collect_insertables!(insertables, Address, |value: Box<_>| *value)?;
+                generics::generic_insert::<_, _, Address>(conn, values).await?;
+            }
+            Self::Customer(_) => {
+                let values = collect_insertables!(insertables, Customer, std::convert::identity)?;
+                generics::generic_insert::<_, _, Customer>(conn, values).await?;
+            }
+            Self::ReverseLookUp(_) => {
+                let values =
+                    collect_insertables!(insertables, ReverseLookUp, std::convert::identity)?;
+                ReverseLookupNew::batch_insert(values, conn).await?;
+            }
+            Self::Payouts(_) => {
+                let values = collect_insertables!(insertables, Payouts, std::convert::identity)?;
+                generics::generic_insert::<_, _, Payouts>(conn, values).await?;
+            }
+            Self::PayoutAttempt(_) => {
+                let values =
+                    collect_insertables!(insertables, PayoutAttempt, std::convert::identity)?;
+                generics::generic_insert::<_, _, PayoutAttempt>(conn, values).await?;
+            }
+            Self::PaymentMethod(_) => {
+                let values =
+                    collect_insertables!(insertables, PaymentMethod, std::convert::identity)?;
+                generics::generic_insert::<_, _, PaymentMethod>(conn, values).await?;
+            }
+            Self::Mandate(_) => {
+                let values = collect_insertables!(insertables, Mandate, std::convert::identity)?;
+                generics::generic_insert::<_, _, Mandate>(conn, values).await?;
+            }
+        }
+
+        Ok(count)
+    }
+}
+
 impl TypedSql {
     pub fn to_field_value_pairs(
         &self,
@@ -14,13 +14,16 @@ pub mod callback_mapper;
 pub mod customers;
 pub mod dispute;
 pub mod dynamic_routing_stats;
+pub mod embedded_vault;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 #[allow(unused)]
 pub mod fraud_check;
+pub mod frm_review;
 pub mod generic_link;
 pub mod gsm;
 #[cfg(feature = "kv_store")]
@@ -36,14 +39,17 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
+pub mod recon;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod types;
 pub mod unified_translations;
 
@@ -59,6 +65,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
@@ -0,0 +1,192 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{payout_batch, payout_batch_item},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub merchant_batch_reference_id: Option<String>,
+    pub file_id: String,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_rows: i32,
+    pub created_rows: i32,
+    pub failed_rows: i32,
+    pub result_file_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+/// A batch of payouts created from a file uploaded by the merchant.
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = payout_batch, primary_key(batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub merchant_batch_reference_id: Option<String>,
+    pub file_id: String,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_rows: i32,
+    pub created_rows: i32,
+    pub failed_rows: i32,
+    pub result_file_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchUpdate {
+    CompletionUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        created_rows: i32,
+        failed_rows: i32,
+        result_file_id: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    pub status: Option<storage_enums::PayoutBatchStatus>,
+    pub created_rows: Option<i32>,
+    pub failed_rows: Option<i32>,
+    pub result_file_id: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(payout_batch_update: PayoutBatchUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match payout_batch_update {
+            PayoutBatchUpdate::CompletionUpdate {
+                status,
+                created_rows,
+                failed_rows,
+                result_file_id,
+            } => Self {
+                status: Some(status),
+                created_rows: Some(created_rows),
+                failed_rows: Some(failed_rows),
+                result_file_id,
+                modified_at: now,
+                completed_at: Some(now),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemNew {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub row_number: i32,
+    pub payout_id: common_utils::id_type::PayoutId,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub connector: Option<String>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// The encrypted payout create request of the row
+    pub payout_request: Encryption,
+}
+
+/// The result of a single row of a payout batch.
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = payout_batch_item,
+    primary_key(batch_id, row_number),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PayoutBatchItem {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub row_number: i32,
+    pub payout_id: common_utils::id_type::PayoutId,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub connector: Option<String>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// The encrypted payout create request of the row
+    pub payout_request: Encryption,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchItemUpdate {
+    PayoutCreated {
+        connector: Option<String>,
+        payout_status: storage_enums::PayoutStatus,
+    },
+    PayoutCreationFailed {
+        error_code: String,
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemUpdateInternal {
+    pub status: Option<storage_enums::PayoutBatchItemStatus>,
+    pub connector: Option<String>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchItemUpdate> for PayoutBatchItemUpdateInternal {
+    fn from(payout_batch_item_update: PayoutBatchItemUpdate) -> Self {
+        match payout_batch_item_update {
+            PayoutBatchItemUpdate::PayoutCreated {
+                connector,
+                payout_status,
+            } => Self {
+                status: Some(storage_enums::PayoutBatchItemStatus::Created),
+                connector,
+                payout_status: Some(payout_status),
+                error_code: None,
+                error_message: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            PayoutBatchItemUpdate::PayoutCreationFailed {
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(storage_enums::PayoutBatchItemStatus::Failed),
+                connector: None,
+                payout_status: None,
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PayoutBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The row number of the first row of the chunk
+    pub start_row_number: i32,
+    /// The row number of the last row of the chunk
+    pub end_row_number: i32,
+}
@@ -5,7 +5,11 @@ use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as storage_enums, errors, schema::process_tracker, StorageResult};
+use crate::{
+    enums as storage_enums, errors,
+    schema::{process_tracker, process_tracker_audit},
+    StorageResult,
+};
 
 #[derive(
     Clone,
@@ -40,6 +44,7 @@ pub struct ProcessTracker {
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub updated_at: PrimitiveDateTime,
     pub version: ApiVersion,
+    pub recurrence: Option<ProcessTrackerRecurrence>,
 }
 
 impl ProcessTracker {
@@ -66,6 +71,7 @@ pub struct ProcessTrackerNew {
     pub created_at: PrimitiveDateTime,
     pub updated_at: PrimitiveDateTime,
     pub version: ApiVersion,
+    pub recurrence: Option<ProcessTrackerRecurrence>,
 }
 
 impl ProcessTrackerNew {
@@ -102,8 +108,47 @@ impl ProcessTrackerNew {
             created_at: current_time,
             updated_at: current_time,
             version: api_version,
+            recurrence: None,
         })
     }
+
+    /// Makes the task recurring, so that it is scheduled again according to the recurrence once
+    /// it has finished.
+    pub fn with_recurrence(mut self, recurrence: ProcessTrackerRecurrence) -> Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
+}
+
+/// The schedule of a recurring task.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct ProcessTrackerRecurrence {
+    /// Cron expression with the minute, hour, day of month, month and day of week fields.
+    pub cron_expression: String,
+    /// IANA time zone in which the cron expression is evaluated, such as `Asia/Kolkata`.
+    pub timezone: String,
+    pub missed_run_policy: MissedRunPolicy,
+    /// The occurrence of the schedule which the current run of the task corresponds to.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub occurrence_at: Option<PrimitiveDateTime>,
+}
+
+common_utils::impl_to_sql_from_sql_json!(ProcessTrackerRecurrence);
+
+/// Determines how the occurrences of a recurring task which were missed, for example because the
+/// scheduler was not running, are handled.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MissedRunPolicy {
+    /// Missed occurrences are not run, the task is scheduled for the next upcoming occurrence.
+    #[default]
+    Skip,
+    /// The task is run once for all the missed occurrences.
+    CatchUpOnce,
+    /// The task is run once for every missed occurrence, one after the other.
+    CatchUpAll,
 }
 
 #[derive(Debug)]
@@ -126,6 +171,10 @@ pub enum ProcessTrackerUpdate {
         retry_count: i32,
         schedule_time: PrimitiveDateTime,
     },
+    RecurrenceUpdate {
+        schedule_time: PrimitiveDateTime,
+        recurrence: ProcessTrackerRecurrence,
+    },
 }
 
 #[derive(Debug, Clone, AsChangeset, router_derive::DebugAsDisplay)]
@@ -138,6 +187,7 @@ pub struct ProcessTrackerUpdateInternal {
     business_status: Option<String>,
     status: Option<storage_enums::ProcessTrackerStatus>,
     updated_at: Option<PrimitiveDateTime>,
+    recurrence: Option<ProcessTrackerRecurrence>,
 }
 
 impl Default for ProcessTrackerUpdateInternal {
@@ -150,6 +200,7 @@ impl Default for ProcessTrackerUpdateInternal {
             business_status: Option::default(),
             status: Option::default(),
             updated_at: Some(common_utils::date_time::now()),
+            recurrence: Option::default(),
         }
     }
 }
@@ -173,6 +224,7 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 business_status,
                 status,
                 updated_at,
+                recurrence: None,
             },
             ProcessTrackerUpdate::StatusUpdate {
                 status,
@@ -192,10 +244,75 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 schedule_time: Some(schedule_time),
                 ..Default::default()
             },
+            ProcessTrackerUpdate::RecurrenceUpdate {
+                schedule_time,
+                recurrence,
+            } => Self {
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                business_status: Some(String::from(business_status::PENDING)),
+                retry_count: Some(0),
+                schedule_time: Some(schedule_time),
+                recurrence: Some(recurrence),
+                ..Default::default()
+            },
         }
     }
 }
 
+/// Constraints for listing process tracker tasks, the tasks matching all the specified constraints
+/// are listed.
+#[derive(Clone, Debug, Default)]
+pub struct ProcessTrackerListConstraints {
+    pub runner: Option<ProcessTrackerRunner>,
+    pub status: Option<storage_enums::ProcessTrackerStatus>,
+    pub business_status: Option<String>,
+    /// Tasks having this tag among their tags are listed.
+    pub tag: Option<String>,
+    pub scheduled_after: Option<PrimitiveDateTime>,
+    pub scheduled_before: Option<PrimitiveDateTime>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// A change made to a process tracker task through the process tracker admin APIs.
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = process_tracker_audit,
+    primary_key(audit_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ProcessTrackerAudit {
+    pub audit_id: String,
+    pub process_tracker_id: String,
+    pub action: storage_enums::ProcessTrackerAction,
+    pub previous_status: storage_enums::ProcessTrackerStatus,
+    pub new_status: storage_enums::ProcessTrackerStatus,
+    pub previous_business_status: String,
+    pub new_business_status: String,
+    pub previous_schedule_time: Option<PrimitiveDateTime>,
+    pub new_schedule_time: Option<PrimitiveDateTime>,
+    pub reason: String,
+    pub performed_by: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = process_tracker_audit)]
+pub struct ProcessTrackerAuditNew {
+    pub audit_id: String,
+    pub process_tracker_id: String,
+    pub action: storage_enums::ProcessTrackerAction,
+    pub previous_status: storage_enums::ProcessTrackerStatus,
+    pub new_status: storage_enums::ProcessTrackerStatus,
+    pub previous_business_status: String,
+    pub new_business_status: String,
+    pub previous_schedule_time: Option<PrimitiveDateTime>,
+    pub new_schedule_time: Option<PrimitiveDateTime>,
+    pub reason: String,
+    pub performed_by: String,
+    pub created_at: PrimitiveDateTime,
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
@@ -250,6 +367,18 @@ pub mod business_status {
     /// Business status set for newly created tasks.
     pub const PENDING: &str = "Pending";
 
+    /// The task was cancelled before its completion.
+    /// A task that reaches this status should not be retried (rescheduled for execution) later.
+    pub const CANCELLED: &str = "CANCELLED";
+
+    /// The next occurrence of a recurring task could not be determined from its recurrence.
+    /// A task that reaches this status is not scheduled again.
+    pub const INVALID_RECURRENCE: &str = "INVALID_RECURRENCE";
+
+    /// The task was paused through the process tracker admin APIs, and is not picked by the
+    /// producer until it is run or rescheduled.
+    pub const PAUSED: &str = "PAUSED";
+
     /// For the PCR Workflow
     ///
     /// This status indicates the completion of a execute task
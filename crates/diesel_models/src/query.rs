@@ -15,9 +15,12 @@ pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
 pub mod dynamic_routing_stats;
+pub mod embedded_vault;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
+pub mod frm_review;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
@@ -32,13 +35,16 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
+pub mod recon;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
 pub mod unified_translations;
@@ -47,3 +53,4 @@ pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
 mod utils;
+pub mod webhook_endpoint;
@@ -130,6 +130,23 @@ impl Address {
         }
     }
 
+    pub async fn find_by_merchant_id_address_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        address_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::address_id.eq_any(address_ids)),
+            None,
+            None,
+            Some(dsl::address_id.asc()),
+        )
+        .await
+    }
+
     pub async fn find_optional_by_address_id(
         conn: &PgPooledConn,
         address_id: &str,
@@ -0,0 +1,79 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    embedded_vault::{EmbeddedVaultEntry, EmbeddedVaultEntryNew, EmbeddedVaultEntryUpdate},
+    schema::embedded_vault::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl EmbeddedVaultEntryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<EmbeddedVaultEntry> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl EmbeddedVaultEntry {
+    pub async fn find_by_merchant_id_vault_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::vault_id.eq(vault_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_vault_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+        entry_update: EmbeddedVaultEntryUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::vault_id.eq(vault_id.to_owned())),
+            entry_update,
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_vault_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::vault_id.eq(vault_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn delete_expired_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        now: time::PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::expires_at.le(now)),
+        )
+        .await
+    }
+}
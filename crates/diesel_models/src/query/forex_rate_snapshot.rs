@@ -0,0 +1,51 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    forex_rate_snapshot::{ForexRateSnapshot, ForexRateSnapshotNew},
+    schema::forex_rate_snapshot::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ForexRateSnapshotNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ForexRateSnapshot> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ForexRateSnapshot {
+    /// Returns the latest snapshot captured at or before the given time, which holds the rates in
+    /// effect at that time.
+    pub async fn find_latest_captured_at_or_before(
+        conn: &PgPooledConn,
+        time: PrimitiveDateTime,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::captured_at.le(time),
+            Some(1),
+            None,
+            Some(dsl::captured_at.desc()),
+        )
+        .await
+        .map(|snapshots| snapshots.into_iter().next())
+    }
+
+    pub async fn list_captured_between(
+        conn: &PgPooledConn,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::captured_at
+                .gt(start_time)
+                .and(dsl::captured_at.le(end_time)),
+            None,
+            None,
+            Some(dsl::captured_at.asc()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,147 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    frm_review::{
+        FrmReview, FrmReviewNew, FrmReviewNote, FrmReviewNoteNew, FrmReviewUpdate,
+        FrmReviewUpdateInternal,
+    },
+    schema::{frm_review::dsl, frm_review_note::dsl as note_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl FrmReviewNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<FrmReview> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl FrmReview {
+    pub async fn find_by_merchant_id_review_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::review_id.eq(review_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_optional_by_merchant_id_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_profile_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: storage_enums::FrmReviewStatus,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::profile_id.eq(profile_id.to_owned()))
+                .and(dsl::status.eq(status)),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_review_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: FrmReviewUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::review_id.eq(review_id.to_owned())),
+            FrmReviewUpdateInternal::from(frm_review_update),
+        )
+        .await
+    }
+
+    /// Updates the review only if it is still pending, so that a review is resolved exactly once
+    /// even when reviewers and the expiry task act on it concurrently.
+    pub async fn update_pending_by_merchant_id_review_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: FrmReviewUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::review_id.eq(review_id.to_owned()))
+                .and(dsl::status.eq(storage_enums::FrmReviewStatus::Pending)),
+            FrmReviewUpdateInternal::from(frm_review_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => Err(error
+                    .attach_printable("Pending review with the given review ID does not exist")),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl FrmReviewNoteNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<FrmReviewNote> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl FrmReviewNote {
+    pub async fn list_by_merchant_id_review_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            note_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(note_dsl::review_id.eq(review_id.to_owned())),
+            None,
+            None,
+            Some(note_dsl::created_at.asc()),
+        )
+        .await
+    }
+}
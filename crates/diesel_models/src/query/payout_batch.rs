@@ -0,0 +1,192 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    payout_batch::{
+        PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate,
+        PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdate,
+        PayoutBatchUpdateInternal,
+    },
+    schema::{payout_batch::dsl, payout_batch_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match profile_id {
+            Some(profile_id) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::profile_id.eq(profile_id.to_owned())),
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id.eq(merchant_id.to_owned()),
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Updates the batch only if it is still being processed, so that a batch is completed only
+    /// once even if the tasks processing its last chunks complete concurrently.
+    pub async fn update_processing_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned()))
+                .and(dsl::status.eq(storage_enums::PayoutBatchStatus::Processing)),
+            PayoutBatchUpdateInternal::from(payout_batch_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => Err(error.attach_printable(
+                    "Payout batch with the given batch ID is not being processed",
+                )),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl PayoutBatchItemNew {
+    pub async fn batch_insert(
+        payout_batch_items: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PayoutBatchItem>(conn, payout_batch_items).await?;
+        Ok(())
+    }
+}
+
+impl PayoutBatchItem {
+    pub async fn list_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        status: Option<storage_enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = item_dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(item_dsl::batch_id.eq(batch_id.to_owned()));
+
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(item_dsl::status.eq(status)),
+                    limit,
+                    offset,
+                    Some(item_dsl::row_number.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    limit,
+                    offset,
+                    Some(item_dsl::row_number.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn list_by_merchant_id_batch_id_row_numbers(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        start_row_number: i32,
+        end_row_number: i32,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(item_dsl::batch_id.eq(batch_id.to_owned()))
+                .and(item_dsl::row_number.between(start_row_number, end_row_number)),
+            None,
+            None,
+            Some(item_dsl::row_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_batch_id_row_number(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        row_number: i32,
+        payout_batch_item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(item_dsl::batch_id.eq(batch_id.to_owned()))
+                .and(item_dsl::row_number.eq(row_number)),
+            PayoutBatchItemUpdateInternal::from(payout_batch_item_update),
+        )
+        .await
+    }
+}
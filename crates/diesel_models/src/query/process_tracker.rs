@@ -1,14 +1,24 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use router_env::{instrument, tracing};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    PgArrayExpressionMethods, QueryDsl, Table,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
 use time::PrimitiveDateTime;
 
-use super::generics;
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
 use crate::{
     enums, errors,
     process_tracker::{
-        ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate, ProcessTrackerUpdateInternal,
+        business_status, ProcessTracker, ProcessTrackerAudit, ProcessTrackerAuditNew,
+        ProcessTrackerListConstraints, ProcessTrackerNew, ProcessTrackerUpdate,
+        ProcessTrackerUpdateInternal,
     },
-    schema::process_tracker::dsl,
+    schema::{process_tracker::dsl, process_tracker_audit::dsl as audit_dsl},
     PgPooledConn, StorageResult,
 };
 
@@ -55,6 +65,71 @@ impl ProcessTracker {
         .await
     }
 
+    /// Updates the process only if it has not been modified since it was read, so that a process
+    /// being picked by the producer or the consumer at the same time is not overwritten. Returns
+    /// `None` if the process was modified in the meantime.
+    #[instrument(skip(conn))]
+    pub async fn update_if_unmodified(
+        self,
+        conn: &PgPooledConn,
+        process: ProcessTrackerUpdate,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id
+                .eq(self.id)
+                .and(dsl::status.eq(self.status))
+                .and(dsl::updated_at.eq(self.updated_at)),
+            ProcessTrackerUpdateInternal::from(process),
+        )
+        .await
+        .map(|mut processes| processes.pop())
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_constraints(
+        conn: &PgPooledConn,
+        constraints: ProcessTrackerListConstraints,
+        version: enums::ApiVersion,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::version.eq(version))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(runner) = constraints.runner {
+            query = query.filter(dsl::runner.eq(runner.to_string()));
+        }
+        if let Some(status) = constraints.status {
+            query = query.filter(dsl::status.eq(status));
+        }
+        if let Some(business_status) = constraints.business_status {
+            query = query.filter(dsl::business_status.eq(business_status));
+        }
+        if let Some(tag) = constraints.tag {
+            query = query.filter(dsl::tag.contains(vec![tag]));
+        }
+        if let Some(scheduled_after) = constraints.scheduled_after {
+            query = query.filter(dsl::schedule_time.ge(scheduled_after));
+        }
+        if let Some(scheduled_before) = constraints.scheduled_before {
+            query = query.filter(dsl::schedule_time.le(scheduled_before));
+        }
+        if let Some(limit) = constraints.limit {
+            query = query.limit(limit);
+        }
+        if let Some(offset) = constraints.offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering processes by constraints")
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_process_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<Option<Self>> {
         generics::generic_find_by_id_optional::<<Self as HasTable>::Table, _, _>(
@@ -91,6 +166,37 @@ impl ProcessTracker {
         .await
     }
 
+    /// Finds the finished recurring processes, which are to be scheduled for their next
+    /// occurrence. Processes which were cancelled or revoked are not scheduled again.
+    #[instrument(skip(conn))]
+    pub async fn find_recurring_processes_to_reschedule(
+        conn: &PgPooledConn,
+        limit: Option<i64>,
+        version: enums::ApiVersion,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::status
+                .eq(enums::ProcessTrackerStatus::Finish)
+                .and(dsl::recurrence.is_not_null())
+                .and(dsl::business_status.ne_all(vec![
+                    business_status::CANCELLED.to_owned(),
+                    business_status::REVOKED.to_owned(),
+                    business_status::INVALID_RECURRENCE.to_owned(),
+                ]))
+                .and(dsl::version.eq(version)),
+            limit,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_to_clean(
         conn: &PgPooledConn,
@@ -139,3 +245,27 @@ impl ProcessTracker {
         .await
     }
 }
+
+impl ProcessTrackerAuditNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ProcessTrackerAudit> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ProcessTrackerAudit {
+    #[instrument(skip(conn))]
+    pub async fn list_by_process_tracker_id(
+        conn: &PgPooledConn,
+        process_tracker_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            audit_dsl::process_tracker_id.eq(process_tracker_id.to_owned()),
+            None,
+            None,
+            Some(audit_dsl::created_at.desc()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,124 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    recon::{
+        ReconJob, ReconJobNew, ReconJobUpdate, ReconJobUpdateInternal, ReconMismatch,
+        ReconMismatchNew,
+    },
+    schema::{recon_job::dsl, recon_mismatch::dsl as mismatch_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl ReconJobNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReconJob> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReconJob {
+    pub async fn find_by_merchant_id_job_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::job_id.eq(job_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_job_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        recon_job_update: ReconJobUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::job_id.eq(job_id.to_owned())),
+            ReconJobUpdateInternal::from(recon_job_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => {
+                    Err(error.attach_printable("Recon job with the given job ID does not exist"))
+                }
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl ReconMismatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReconMismatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReconMismatch {
+    pub async fn list_by_merchant_id_job_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        mismatch_type: Option<storage_enums::ReconMismatchType>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = mismatch_dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(mismatch_dsl::job_id.eq(job_id.to_owned()));
+
+        match mismatch_type {
+            Some(mismatch_type) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(mismatch_dsl::mismatch_type.eq(mismatch_type)),
+                    limit,
+                    offset,
+                    Some(mismatch_dsl::line_number.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    limit,
+                    offset,
+                    Some(mismatch_dsl::line_number.asc()),
+                )
+                .await
+            }
+        }
+    }
+}
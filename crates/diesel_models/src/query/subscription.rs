@@ -0,0 +1,372 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    schema::{
+        subscription::dsl, subscription_invoice::dsl as invoice_dsl,
+        subscription_plan::dsl as plan_dsl, subscription_price::dsl as price_dsl,
+    },
+    subscription::{
+        Subscription, SubscriptionInvoice, SubscriptionInvoiceNew, SubscriptionInvoiceUpdate,
+        SubscriptionInvoiceUpdateInternal, SubscriptionNew, SubscriptionPlan, SubscriptionPlanNew,
+        SubscriptionPlanUpdate, SubscriptionPlanUpdateInternal, SubscriptionPrice,
+        SubscriptionPriceNew, SubscriptionUpdate, SubscriptionUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl SubscriptionPlanNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionPlan> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionPlan {
+    pub async fn find_by_merchant_id_plan_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(plan_dsl::plan_id.eq(plan_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        is_active: Option<bool>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = plan_dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(plan_dsl::profile_id.eq(profile_id.to_owned()));
+
+        match is_active {
+            Some(is_active) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(plan_dsl::is_active.eq(is_active)),
+                    Some(limit),
+                    Some(offset),
+                    Some(plan_dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    Some(limit),
+                    Some(offset),
+                    Some(plan_dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn update_by_merchant_id_plan_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        subscription_plan_update: SubscriptionPlanUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            plan_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(plan_dsl::plan_id.eq(plan_id.to_owned())),
+            SubscriptionPlanUpdateInternal::from(subscription_plan_update),
+        )
+        .await
+    }
+}
+
+impl SubscriptionPriceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionPrice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionPrice {
+    pub async fn find_by_merchant_id_price_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        price_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            price_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(price_dsl::price_id.eq(price_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_plan_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            price_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(price_dsl::plan_id.eq(plan_id.to_owned())),
+            None,
+            None,
+            Some(price_dsl::created_at.asc()),
+        )
+        .await
+    }
+}
+
+impl SubscriptionNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Subscription> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Subscription {
+    pub async fn find_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        status: Option<storage_enums::SubscriptionStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(dsl::profile_id.eq(profile_id.to_owned()));
+
+        match (customer_id, status) {
+            (Some(customer_id), Some(status)) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate
+                        .and(dsl::customer_id.eq(customer_id.to_owned()))
+                        .and(dsl::status.eq(status)),
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            (Some(customer_id), None) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(dsl::customer_id.eq(customer_id.to_owned())),
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            (None, Some(status)) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(dsl::status.eq(status)),
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            (None, None) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn update_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: SubscriptionUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned())),
+            SubscriptionUpdateInternal::from(subscription_update),
+        )
+        .await
+    }
+
+    /// Updates the subscription only if it is not canceled yet, so that a canceled subscription is
+    /// not renewed by a billing task which ran concurrently with the cancellation.
+    pub async fn update_uncanceled_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: SubscriptionUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::subscription_id.eq(subscription_id.to_owned()))
+                .and(dsl::status.ne(storage_enums::SubscriptionStatus::Canceled)),
+            SubscriptionUpdateInternal::from(subscription_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NotFound => Err(error.attach_printable(
+                    "Uncanceled subscription with the given subscription ID does not exist",
+                )),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl SubscriptionInvoiceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionInvoice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionInvoice {
+    pub async fn find_by_merchant_id_invoice_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            invoice_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(invoice_dsl::invoice_id.eq(invoice_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_subscription_id_period_start(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        billing_reason: storage_enums::InvoiceBillingReason,
+        period_start: time::PrimitiveDateTime,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            invoice_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(invoice_dsl::subscription_id.eq(subscription_id.to_owned()))
+                .and(invoice_dsl::billing_reason.eq(billing_reason))
+                .and(invoice_dsl::period_start.eq(period_start)),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            invoice_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(invoice_dsl::subscription_id.eq(subscription_id.to_owned())),
+            Some(limit),
+            Some(offset),
+            Some(invoice_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_invoice_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: SubscriptionInvoiceUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            invoice_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(invoice_dsl::invoice_id.eq(invoice_id.to_owned())),
+            SubscriptionInvoiceUpdateInternal::from(subscription_invoice_update),
+        )
+        .await
+    }
+
+    /// Voids the invoices of the subscription which are still awaiting payment.
+    pub async fn void_unpaid_by_merchant_id_subscription_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            invoice_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(invoice_dsl::subscription_id.eq(subscription_id.to_owned()))
+                .and(invoice_dsl::status.eq_any(vec![
+                    storage_enums::InvoiceStatus::Open,
+                    storage_enums::InvoiceStatus::PaymentFailed,
+                ])),
+            SubscriptionInvoiceUpdateInternal::from(SubscriptionInvoiceUpdate::StatusUpdate {
+                status: storage_enums::InvoiceStatus::Void,
+            }),
+        )
+        .await
+    }
+}
@@ -0,0 +1,148 @@
+use common_utils::types::MinorUnit;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{recon_job, recon_mismatch},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_job)]
+pub struct ReconJobNew {
+    pub job_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub file_id: String,
+    pub connector: String,
+    pub status: storage_enums::ReconJobStatus,
+    pub total_rows: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = recon_job, primary_key(job_id), check_for_backend(diesel::pg::Pg))]
+pub struct ReconJob {
+    pub job_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub file_id: String,
+    pub connector: String,
+    pub status: storage_enums::ReconJobStatus,
+    pub total_rows: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ReconJobUpdate {
+    StatusUpdate {
+        status: storage_enums::ReconJobStatus,
+    },
+    ResultUpdate {
+        total_rows: i32,
+        matched_count: i32,
+        mismatched_count: i32,
+    },
+    ErrorUpdate {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_job)]
+pub struct ReconJobUpdateInternal {
+    pub status: Option<storage_enums::ReconJobStatus>,
+    pub total_rows: Option<i32>,
+    pub matched_count: Option<i32>,
+    pub mismatched_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<ReconJobUpdate> for ReconJobUpdateInternal {
+    fn from(recon_job_update: ReconJobUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match recon_job_update {
+            ReconJobUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at,
+                ..Default::default()
+            },
+            ReconJobUpdate::ResultUpdate {
+                total_rows,
+                matched_count,
+                mismatched_count,
+            } => Self {
+                status: Some(storage_enums::ReconJobStatus::Completed),
+                total_rows: Some(total_rows),
+                matched_count: Some(matched_count),
+                mismatched_count: Some(mismatched_count),
+                modified_at,
+                ..Default::default()
+            },
+            ReconJobUpdate::ErrorUpdate { error_message } => Self {
+                status: Some(storage_enums::ReconJobStatus::Failed),
+                error_message: Some(error_message),
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_mismatch)]
+pub struct ReconMismatchNew {
+    pub mismatch_id: String,
+    pub job_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub line_number: i32,
+    pub transaction_type: storage_enums::ReconTransactionType,
+    pub mismatch_type: storage_enums::ReconMismatchType,
+    pub connector_transaction_id: String,
+    pub settlement_amount: MinorUnit,
+    pub settlement_currency: storage_enums::Currency,
+    pub settlement_status: storage_enums::ReconSettlementStatus,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub transaction_amount: Option<MinorUnit>,
+    pub transaction_currency: Option<storage_enums::Currency>,
+    pub transaction_status: Option<storage_enums::ReconSettlementStatus>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = recon_mismatch, primary_key(mismatch_id), check_for_backend(diesel::pg::Pg))]
+pub struct ReconMismatch {
+    pub mismatch_id: String,
+    pub job_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub line_number: i32,
+    pub transaction_type: storage_enums::ReconTransactionType,
+    pub mismatch_type: storage_enums::ReconMismatchType,
+    pub connector_transaction_id: String,
+    pub settlement_amount: MinorUnit,
+    pub settlement_currency: storage_enums::Currency,
+    pub settlement_status: storage_enums::ReconSettlementStatus,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub transaction_amount: Option<MinorUnit>,
+    pub transaction_currency: Option<storage_enums::Currency>,
+    pub transaction_status: Option<storage_enums::ReconSettlementStatus>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReconJobTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub job_id: String,
+}
@@ -54,6 +54,9 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        permission_groups -> Nullable<Array<Nullable<Text>>>,
+        resources -> Nullable<Array<Nullable<Text>>>,
+        profile_ids -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -234,6 +237,9 @@ diesel::table! {
         acquirer_config_map -> Nullable<Jsonb>,
         #[max_length = 16]
         merchant_category_code -> Nullable<Varchar>,
+        #[max_length = 255]
+        previous_payment_response_hash_key -> Nullable<Varchar>,
+        previous_payment_response_hash_key_expires_at -> Nullable<Timestamp>,
         #[max_length = 64]
         routing_algorithm_id -> Nullable<Varchar>,
         order_fulfillment_time -> Nullable<Int8>,
@@ -475,6 +481,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    embedded_vault (merchant_id, vault_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        vault_id -> Varchar,
+        #[max_length = 64]
+        entity_id -> Varchar,
+        encrypted_data -> Bytea,
+        encrypted_data_key -> Bytea,
+        expires_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -494,7 +519,7 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 255]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
@@ -503,6 +528,8 @@ diesel::table! {
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
         is_overall_delivery_successful -> Nullable<Bool>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -535,6 +562,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    forex_rate_snapshot (snapshot_id) {
+        #[max_length = 64]
+        snapshot_id -> Varchar,
+        base_currency -> Currency,
+        rates -> Jsonb,
+        captured_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -568,6 +609,60 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    frm_review (review_id) {
+        #[max_length = 64]
+        review_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        attempt_id -> Varchar,
+        #[max_length = 64]
+        frm_id -> Varchar,
+        #[max_length = 64]
+        frm_name -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        frm_reason -> Nullable<Jsonb>,
+        frm_score -> Nullable<Int4>,
+        #[max_length = 64]
+        assigned_to -> Nullable<Varchar>,
+        #[max_length = 32]
+        decision -> Nullable<Varchar>,
+        #[max_length = 64]
+        reviewed_by -> Nullable<Varchar>,
+        reviewed_at -> Nullable<Timestamp>,
+        expires_at -> Timestamp,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    frm_review_note (note_id) {
+        #[max_length = 64]
+        note_id -> Varchar,
+        #[max_length = 64]
+        review_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        user_id -> Varchar,
+        note -> Text,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1133,6 +1228,60 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        merchant_batch_reference_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        file_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        total_rows -> Int4,
+        created_rows -> Int4,
+        failed_rows -> Int4,
+        #[max_length = 64]
+        result_file_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch_item (batch_id, row_number) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        row_number -> Int4,
+        #[max_length = 64]
+        payout_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        payout_status -> Nullable<PayoutStatus>,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        payout_request -> Bytea,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1201,6 +1350,93 @@ diesel::table! {
         created_at -> Timestamp,
         updated_at -> Timestamp,
         version -> ApiVersion,
+        recurrence -> Nullable<Jsonb>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    process_tracker_audit (audit_id) {
+        #[max_length = 64]
+        audit_id -> Varchar,
+        #[max_length = 127]
+        process_tracker_id -> Varchar,
+        #[max_length = 32]
+        action -> Varchar,
+        previous_status -> ProcessTrackerStatus,
+        new_status -> ProcessTrackerStatus,
+        #[max_length = 255]
+        previous_business_status -> Varchar,
+        #[max_length = 255]
+        new_business_status -> Varchar,
+        previous_schedule_time -> Nullable<Timestamp>,
+        new_schedule_time -> Nullable<Timestamp>,
+        reason -> Text,
+        #[max_length = 255]
+        performed_by -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_job (job_id) {
+        #[max_length = 64]
+        job_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        file_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        total_rows -> Int4,
+        matched_count -> Int4,
+        mismatched_count -> Int4,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_mismatch (mismatch_id) {
+        #[max_length = 64]
+        mismatch_id -> Varchar,
+        #[max_length = 64]
+        job_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        line_number -> Int4,
+        #[max_length = 32]
+        transaction_type -> Varchar,
+        #[max_length = 32]
+        mismatch_type -> Varchar,
+        #[max_length = 128]
+        connector_transaction_id -> Varchar,
+        settlement_amount -> Int8,
+        settlement_currency -> Currency,
+        #[max_length = 32]
+        settlement_status -> Varchar,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        attempt_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        transaction_amount -> Nullable<Int8>,
+        transaction_currency -> Nullable<Currency>,
+        #[max_length = 32]
+        transaction_status -> Nullable<Varchar>,
+        created_at -> Timestamp,
     }
 }
 
@@ -1365,6 +1601,119 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription (subscription_id) {
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        price_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        mandate_id -> Varchar,
+        current_period_start -> Timestamp,
+        current_period_end -> Timestamp,
+        trial_end -> Nullable<Timestamp>,
+        billing_cycle_anchor -> Timestamp,
+        cancel_at_period_end -> Bool,
+        canceled_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        latest_invoice_id -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_invoice (invoice_id) {
+        #[max_length = 64]
+        invoice_id -> Varchar,
+        #[max_length = 64]
+        subscription_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 32]
+        billing_reason -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        period_start -> Timestamp,
+        period_end -> Timestamp,
+        line_items -> Jsonb,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        attempt_count -> Int4,
+        next_payment_attempt_at -> Nullable<Timestamp>,
+        paid_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_plan (plan_id) {
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        is_active -> Bool,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_price (price_id) {
+        #[max_length = 64]
+        price_id -> Varchar,
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 32]
+        billing_interval -> Varchar,
+        interval_count -> Int4,
+        trial_period_days -> Nullable<Int4>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1530,6 +1879,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 255]
+        secret -> Varchar,
+        enabled_event_classes -> Nullable<Array<Nullable<EventClass>>>,
+        enabled_event_types -> Nullable<Array<Nullable<EventType>>>,
+        is_enabled -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -1546,9 +1919,13 @@ diesel::allow_tables_to_appear_in_same_query!(
     dashboard_metadata,
     dispute,
     dynamic_routing_stats,
+    embedded_vault,
     events,
     file_metadata,
+    forex_rate_snapshot,
     fraud_check,
+    frm_review,
+    frm_review_note,
     gateway_status_map,
     generic_link,
     incremental_authorization,
@@ -1563,13 +1940,22 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_link,
     payment_methods,
     payout_attempt,
+    payout_batch,
+    payout_batch_item,
     payouts,
     process_tracker,
+    process_tracker_audit,
+    recon_job,
+    recon_mismatch,
     refund,
     relay,
     reverse_lookup,
     roles,
     routing_algorithm,
+    subscription,
+    subscription_invoice,
+    subscription_plan,
+    subscription_price,
     themes,
     tokenization,
     unified_translations,
@@ -1577,4 +1963,5 @@ diesel::allow_tables_to_appear_in_same_query!(
     user_key_store,
     user_roles,
     users,
+    webhook_endpoint,
 );
@@ -0,0 +1,379 @@
+use common_types::subscriptions::InvoiceLineItems;
+use common_utils::{pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{subscription, subscription_invoice, subscription_plan, subscription_price},
+};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanNew {
+    pub plan_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// A product which customers can subscribe to, through one of its prices.
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = subscription_plan, primary_key(plan_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionPlan {
+    pub plan_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    pub description: Option<String>,
+    pub is_active: bool,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionPlanUpdate {
+    Update {
+        name: Option<String>,
+        description: Option<String>,
+        is_active: Option<bool>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_plan)]
+pub struct SubscriptionPlanUpdateInternal {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub is_active: Option<bool>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionPlanUpdate> for SubscriptionPlanUpdateInternal {
+    fn from(subscription_plan_update: SubscriptionPlanUpdate) -> Self {
+        match subscription_plan_update {
+            SubscriptionPlanUpdate::Update {
+                name,
+                description,
+                is_active,
+                metadata,
+            } => Self {
+                name,
+                description,
+                is_active,
+                metadata,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_price)]
+pub struct SubscriptionPriceNew {
+    pub price_id: String,
+    pub plan_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::BillingInterval,
+    pub interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The amount and billing interval at which customers are charged for a plan.
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = subscription_price, primary_key(price_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionPrice {
+    pub price_id: String,
+    pub plan_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub billing_interval: storage_enums::BillingInterval,
+    pub interval_count: i32,
+    pub trial_period_days: Option<i32>,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionNew {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub plan_id: String,
+    pub price_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub mandate_id: String,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    /// The start of the first billing cycle of the current price, from which the end of every
+    /// billing cycle is computed
+    pub billing_cycle_anchor: PrimitiveDateTime,
+    pub cancel_at_period_end: bool,
+    pub canceled_at: Option<PrimitiveDateTime>,
+    pub latest_invoice_id: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// A customer subscribed to a price, charged through a mandate on each billing cycle.
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = subscription, primary_key(subscription_id), check_for_backend(diesel::pg::Pg))]
+pub struct Subscription {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub plan_id: String,
+    pub price_id: String,
+    pub status: storage_enums::SubscriptionStatus,
+    pub mandate_id: String,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    /// The start of the first billing cycle of the current price, from which the end of every
+    /// billing cycle is computed
+    pub billing_cycle_anchor: PrimitiveDateTime,
+    pub cancel_at_period_end: bool,
+    pub canceled_at: Option<PrimitiveDateTime>,
+    pub latest_invoice_id: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionUpdate {
+    GeneralUpdate {
+        mandate_id: Option<String>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+    PriceUpdate {
+        plan_id: String,
+        price_id: String,
+        billing_cycle_anchor: PrimitiveDateTime,
+    },
+    PeriodUpdate {
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+    },
+    StatusUpdate {
+        status: storage_enums::SubscriptionStatus,
+    },
+    LatestInvoiceUpdate {
+        latest_invoice_id: String,
+    },
+    CancelAtPeriodEndUpdate {
+        cancel_at_period_end: bool,
+    },
+    CancellationUpdate,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription)]
+pub struct SubscriptionUpdateInternal {
+    pub plan_id: Option<String>,
+    pub price_id: Option<String>,
+    pub status: Option<storage_enums::SubscriptionStatus>,
+    pub mandate_id: Option<String>,
+    pub current_period_start: Option<PrimitiveDateTime>,
+    pub current_period_end: Option<PrimitiveDateTime>,
+    pub billing_cycle_anchor: Option<PrimitiveDateTime>,
+    pub cancel_at_period_end: Option<bool>,
+    pub canceled_at: Option<PrimitiveDateTime>,
+    pub latest_invoice_id: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<SubscriptionUpdate> for SubscriptionUpdateInternal {
+    fn from(subscription_update: SubscriptionUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match subscription_update {
+            SubscriptionUpdate::GeneralUpdate {
+                mandate_id,
+                metadata,
+            } => Self {
+                mandate_id,
+                metadata,
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            SubscriptionUpdate::PriceUpdate {
+                plan_id,
+                price_id,
+                billing_cycle_anchor,
+            } => Self {
+                plan_id: Some(plan_id),
+                price_id: Some(price_id),
+                billing_cycle_anchor: Some(billing_cycle_anchor),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            SubscriptionUpdate::PeriodUpdate {
+                current_period_start,
+                current_period_end,
+            } => Self {
+                current_period_start: Some(current_period_start),
+                current_period_end: Some(current_period_end),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            SubscriptionUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            SubscriptionUpdate::LatestInvoiceUpdate { latest_invoice_id } => Self {
+                latest_invoice_id: Some(latest_invoice_id),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            SubscriptionUpdate::CancelAtPeriodEndUpdate {
+                cancel_at_period_end,
+            } => Self {
+                cancel_at_period_end: Some(cancel_at_period_end),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+            SubscriptionUpdate::CancellationUpdate => Self {
+                status: Some(storage_enums::SubscriptionStatus::Canceled),
+                canceled_at: Some(now),
+                modified_at: Some(now),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceNew {
+    pub invoice_id: String,
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub status: storage_enums::InvoiceStatus,
+    pub billing_reason: storage_enums::InvoiceBillingReason,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub period_start: PrimitiveDateTime,
+    pub period_end: PrimitiveDateTime,
+    pub line_items: InvoiceLineItems,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_count: i32,
+    pub next_payment_attempt_at: Option<PrimitiveDateTime>,
+    pub paid_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// An amount due by the customer of a subscription, for a period of the subscription.
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = subscription_invoice, primary_key(invoice_id), check_for_backend(diesel::pg::Pg))]
+pub struct SubscriptionInvoice {
+    pub invoice_id: String,
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub status: storage_enums::InvoiceStatus,
+    pub billing_reason: storage_enums::InvoiceBillingReason,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub period_start: PrimitiveDateTime,
+    pub period_end: PrimitiveDateTime,
+    pub line_items: InvoiceLineItems,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_count: i32,
+    pub next_payment_attempt_at: Option<PrimitiveDateTime>,
+    pub paid_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum SubscriptionInvoiceUpdate {
+    PaymentUpdate {
+        status: storage_enums::InvoiceStatus,
+        payment_id: Option<common_utils::id_type::PaymentId>,
+        attempt_count: i32,
+        next_payment_attempt_at: Option<PrimitiveDateTime>,
+    },
+    StatusUpdate {
+        status: storage_enums::InvoiceStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = subscription_invoice)]
+pub struct SubscriptionInvoiceUpdateInternal {
+    pub status: storage_enums::InvoiceStatus,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_count: Option<i32>,
+    // `None` leaves the next payment attempt unchanged, `Some(None)` clears it
+    pub next_payment_attempt_at: Option<Option<PrimitiveDateTime>>,
+    pub paid_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<SubscriptionInvoiceUpdate> for SubscriptionInvoiceUpdateInternal {
+    fn from(subscription_invoice_update: SubscriptionInvoiceUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match subscription_invoice_update {
+            SubscriptionInvoiceUpdate::PaymentUpdate {
+                status,
+                payment_id,
+                attempt_count,
+                next_payment_attempt_at,
+            } => Self {
+                status,
+                payment_id,
+                attempt_count: Some(attempt_count),
+                next_payment_attempt_at: Some(next_payment_attempt_at),
+                paid_at: (status == storage_enums::InvoiceStatus::Paid).then_some(now),
+                modified_at: now,
+            },
+            SubscriptionInvoiceUpdate::StatusUpdate { status } => Self {
+                status,
+                payment_id: None,
+                attempt_count: None,
+                next_payment_attempt_at: Some(None),
+                paid_at: (status == storage_enums::InvoiceStatus::Paid).then_some(now),
+                modified_at: now,
+            },
+        }
+    }
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SubscriptionBillingTrackingData {
+    pub subscription_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The end of the period at which the task was scheduled, so that a task scheduled before the
+    /// subscription was renewed or updated does not bill it twice
+    pub period_end: PrimitiveDateTime,
+    /// The invoice whose payment is retried, if the task retries the payment of an invoice
+    pub invoice_id: Option<String>,
+}
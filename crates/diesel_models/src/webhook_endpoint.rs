@@ -0,0 +1,154 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::webhook_endpoint};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: Secret<String>,
+    pub description: Option<String>,
+    pub secret: Secret<String>,
+    pub enabled_event_classes: Option<Vec<storage_enums::EventClass>>,
+    pub enabled_event_types: Option<Vec<storage_enums::EventType>>,
+    pub is_enabled: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = webhook_endpoint, primary_key(endpoint_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub url: Secret<String>,
+    pub description: Option<String>,
+    pub secret: Secret<String>,
+    pub enabled_event_classes: Option<Vec<storage_enums::EventClass>>,
+    pub enabled_event_types: Option<Vec<storage_enums::EventType>>,
+    pub is_enabled: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl WebhookEndpoint {
+    /// Checks whether an event of the specified class and type must be delivered to this endpoint.
+    ///
+    /// Disabled endpoints do not receive any events. Endpoints without event class or event type
+    /// filters receive events of all classes or types respectively.
+    pub fn is_event_enabled(
+        &self,
+        event_class: storage_enums::EventClass,
+        event_type: storage_enums::EventType,
+    ) -> bool {
+        let is_event_class_enabled = self
+            .enabled_event_classes
+            .as_ref()
+            .map_or(true, |event_classes| event_classes.contains(&event_class));
+        let is_event_type_enabled = self
+            .enabled_event_types
+            .as_ref()
+            .map_or(true, |event_types| event_types.contains(&event_type));
+
+        self.is_enabled && is_event_class_enabled && is_event_type_enabled
+    }
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<Secret<String>>,
+        description: Option<String>,
+        enabled_event_classes: Option<Vec<storage_enums::EventClass>>,
+        enabled_event_types: Option<Vec<storage_enums::EventType>>,
+        is_enabled: Option<bool>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    pub url: Option<Secret<String>>,
+    pub description: Option<String>,
+    pub enabled_event_classes: Option<Vec<storage_enums::EventClass>>,
+    pub enabled_event_types: Option<Vec<storage_enums::EventType>>,
+    pub is_enabled: Option<bool>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(webhook_endpoint_update: WebhookEndpointUpdate) -> Self {
+        match webhook_endpoint_update {
+            WebhookEndpointUpdate::Update {
+                url,
+                description,
+                enabled_event_classes,
+                enabled_event_types,
+                is_enabled,
+            } => Self {
+                url,
+                description,
+                enabled_event_classes,
+                enabled_event_types,
+                is_enabled,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::id_type;
+
+    use super::*;
+
+    fn webhook_endpoint(
+        enabled_event_classes: Option<Vec<storage_enums::EventClass>>,
+        enabled_event_types: Option<Vec<storage_enums::EventType>>,
+        is_enabled: bool,
+    ) -> WebhookEndpoint {
+        let now = common_utils::date_time::now();
+        WebhookEndpoint {
+            endpoint_id: "whe_test".to_string(),
+            merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap(),
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("profile_1")).unwrap(),
+            url: Secret::new("https://example.com/webhooks".to_string()),
+            description: None,
+            secret: Secret::new("secret".to_string()),
+            enabled_event_classes,
+            enabled_event_types,
+            is_enabled,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_event_filters() {
+        use storage_enums::{EventClass, EventType};
+
+        let unfiltered = webhook_endpoint(None, None, true);
+        assert!(unfiltered.is_event_enabled(EventClass::Refunds, EventType::RefundSucceeded));
+
+        let filtered = webhook_endpoint(
+            Some(vec![EventClass::Payments]),
+            Some(vec![EventType::PaymentSucceeded]),
+            true,
+        );
+        assert!(filtered.is_event_enabled(EventClass::Payments, EventType::PaymentSucceeded));
+        assert!(!filtered.is_event_enabled(EventClass::Payments, EventType::PaymentFailed));
+        assert!(!filtered.is_event_enabled(EventClass::Refunds, EventType::RefundSucceeded));
+
+        let disabled = webhook_endpoint(None, None, false);
+        assert!(!disabled.is_event_enabled(EventClass::Payments, EventType::PaymentSucceeded));
+    }
+}
@@ -0,0 +1,631 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    errors::{self, DeadLetterError},
+    metrics,
+    query::ExecuteQuery,
+    services::{self, Store},
+    utils, Settings, StreamData,
+};
+
+/// Header carrying the API key required by the dead letter endpoints.
+const API_KEY_HEADER: &str = "api-key";
+
+/// Time for which the number of attempts of a failed entry is remembered, in seconds.
+const QUERY_ATTEMPTS_TTL: i64 = 24 * 60 * 60;
+
+/// Default number of entries returned when listing a dead letter stream.
+const DEFAULT_LIST_LIMIT: u64 = 20;
+
+/// Maximum number of entries returned when listing a dead letter stream.
+const MAX_LIST_LIMIT: u64 = 100;
+
+// Fields added to the original stream entry when it is moved to the dead letter stream. They are
+// prefixed to avoid clashing with the fields of the original entry.
+const SOURCE_ENTRY_ID_FIELD: &str = "dead_letter_source_entry_id";
+const ERROR_FIELD: &str = "dead_letter_error";
+const ATTEMPTS_FIELD: &str = "dead_letter_attempts";
+const FAILED_AT_FIELD: &str = "dead_letter_failed_at";
+
+/// A drainer stream entry which could not be written to the database, along with the reason.
+#[derive(Debug, Clone)]
+pub struct DeadLetterEntry {
+    /// The ID of the entry in the drainer stream.
+    pub source_entry_id: String,
+    /// The fields of the entry in the drainer stream, including the original `pushed_at`.
+    pub fields: HashMap<String, String>,
+    /// The error returned by the last attempt.
+    pub error: String,
+    /// The number of attempts made before giving up.
+    pub attempts: usize,
+    /// Unix timestamp of the last attempt.
+    pub failed_at: i64,
+}
+
+impl DeadLetterEntry {
+    fn into_field_value_pairs(self) -> Vec<(String, String)> {
+        self.fields
+            .into_iter()
+            .chain([
+                (SOURCE_ENTRY_ID_FIELD.to_owned(), self.source_entry_id),
+                (ERROR_FIELD.to_owned(), self.error),
+                (ATTEMPTS_FIELD.to_owned(), self.attempts.to_string()),
+                (FAILED_AT_FIELD.to_owned(), self.failed_at.to_string()),
+            ])
+            .collect()
+    }
+
+    fn from_field_value_pairs(mut fields: HashMap<String, String>) -> Self {
+        let source_entry_id = fields.remove(SOURCE_ENTRY_ID_FIELD).unwrap_or_default();
+        let error = fields.remove(ERROR_FIELD).unwrap_or_default();
+        let attempts = fields
+            .remove(ATTEMPTS_FIELD)
+            .and_then(|attempts| attempts.parse().ok())
+            .unwrap_or_default();
+        let failed_at = fields
+            .remove(FAILED_AT_FIELD)
+            .and_then(|failed_at| failed_at.parse().ok())
+            .unwrap_or_default();
+
+        Self {
+            source_entry_id,
+            fields,
+            error,
+            attempts,
+            failed_at,
+        }
+    }
+}
+
+impl Store {
+    #[inline(always)]
+    pub(crate) fn get_dead_letter_stream_name(&self, stream_index: u8) -> String {
+        // Example: {shard_5}_drainer_dead_letter_stream
+        format!(
+            "{{shard_{stream_index}}}_{}",
+            self.config.dead_letter_stream_name
+        )
+    }
+
+    #[inline(always)]
+    fn get_query_attempts_key(stream_name: &str) -> String {
+        format!("{stream_name}_query_attempts")
+    }
+
+    /// Records a failed attempt to execute the query of a stream entry, and moves the entry to
+    /// the dead letter stream once it has run out of attempts.
+    ///
+    /// Returns `true` if the entry was moved to the dead letter stream, in which case it can be
+    /// removed from the drainer stream.
+    pub(crate) async fn handle_failed_entry(
+        &self,
+        stream_name: &str,
+        dead_letter_stream_name: &str,
+        entry_id: &str,
+        fields: HashMap<String, String>,
+        error: String,
+    ) -> errors::DrainerResult<bool> {
+        let attempts_key = Self::get_query_attempts_key(stream_name);
+        let attempts = self
+            .redis_conn
+            .increment_fields_in_hash(&attempts_key.as_str().into(), &[(entry_id, 1)])
+            .await
+            .map_err(errors::DrainerError::from)?
+            .first()
+            .copied()
+            .unwrap_or(1);
+        self.redis_conn
+            .set_expiry(&attempts_key.as_str().into(), QUERY_ATTEMPTS_TTL)
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        if !has_exhausted_query_attempts(attempts, self.config.max_query_attempts) {
+            logger::warn!(
+                entry_id,
+                attempts,
+                "Query will be retried in the next cycle"
+            );
+            return Ok(false);
+        }
+
+        self.push_to_dead_letter_stream(
+            dead_letter_stream_name,
+            DeadLetterEntry {
+                source_entry_id: entry_id.to_owned(),
+                fields,
+                error,
+                attempts,
+                failed_at: common_utils::date_time::now_unix_timestamp(),
+            },
+        )
+        .await?;
+        self.clear_query_attempts(stream_name, vec![entry_id.to_owned()])
+            .await;
+
+        Ok(true)
+    }
+
+    /// Forgets the failed attempts of stream entries once they have been removed from the stream,
+    /// either because their query succeeded or because they were moved to the dead letter stream.
+    ///
+    /// Failures are only logged, since the attempts expire on their own.
+    pub(crate) async fn clear_query_attempts(&self, stream_name: &str, entry_ids: Vec<String>) {
+        if entry_ids.is_empty() {
+            return;
+        }
+
+        let attempts_key = Self::get_query_attempts_key(stream_name);
+        if let Err(error) = self
+            .redis_conn
+            .delete_fields_from_hash(&attempts_key.as_str().into(), entry_ids)
+            .await
+        {
+            logger::error!(operation = "clear_query_attempts", ?error);
+        }
+    }
+
+    pub(crate) async fn push_to_dead_letter_stream(
+        &self,
+        dead_letter_stream_name: &str,
+        entry: DeadLetterEntry,
+    ) -> errors::DrainerResult<()> {
+        logger::error!(
+            source_entry_id = %entry.source_entry_id,
+            attempts = entry.attempts,
+            error = %entry.error,
+            "Moving entry to the dead letter stream"
+        );
+
+        self.redis_conn
+            .stream_append_entry(
+                &dead_letter_stream_name.into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                entry.into_field_value_pairs(),
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        metrics::ENTRIES_MOVED_TO_DEAD_LETTER_STREAM.add(
+            1,
+            router_env::metric_attributes!(("stream", dead_letter_stream_name.to_owned())),
+        );
+
+        Ok(())
+    }
+
+    /// Reads the entries of a dead letter stream which come after the specified entry ID.
+    async fn read_dead_letter_entries(
+        &self,
+        dead_letter_stream_name: &str,
+        after_entry_id: &str,
+        limit: u64,
+    ) -> errors::DrainerResult<Vec<(String, DeadLetterEntry)>> {
+        let stream_read = match self
+            .redis_conn
+            .stream_read_entries(dead_letter_stream_name, after_entry_id, Some(limit))
+            .await
+        {
+            Ok(stream_read) => stream_read,
+            Err(error) => {
+                return match error.current_context() {
+                    redis::errors::RedisError::StreamEmptyOrNotAvailable => Ok(Vec::new()),
+                    _ => Err(errors::DrainerError::from(error).into()),
+                }
+            }
+        };
+
+        let entries = utils::parse_stream_entries(
+            &stream_read,
+            self.redis_conn.add_prefix(dead_letter_stream_name).as_str(),
+        )?;
+
+        Ok(entries
+            .iter()
+            .map(|(entry_id, fields)| {
+                (
+                    entry_id.clone(),
+                    DeadLetterEntry::from_field_value_pairs(fields.clone()),
+                )
+            })
+            .collect())
+    }
+
+    async fn find_dead_letter_entry(
+        &self,
+        dead_letter_stream_name: &str,
+        entry_id: &str,
+    ) -> errors::DrainerResult<Option<DeadLetterEntry>> {
+        let entries = self
+            .redis_conn
+            .stream_read_range(&dead_letter_stream_name.into(), entry_id, entry_id, Some(1))
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(entries
+            .into_iter()
+            .next()
+            .map(|(_, fields)| DeadLetterEntry::from_field_value_pairs(fields)))
+    }
+
+    async fn delete_dead_letter_entry(
+        &self,
+        dead_letter_stream_name: &str,
+        entry_id: &str,
+    ) -> errors::DrainerResult<bool> {
+        let deleted_count = self
+            .redis_conn
+            .stream_delete_entries(&dead_letter_stream_name.into(), entry_id)
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(deleted_count > 0)
+    }
+}
+
+pub struct DeadLetter;
+
+impl DeadLetter {
+    pub fn server(conf: Settings, stores: HashMap<id_type::TenantId, Arc<Store>>) -> Scope {
+        web::scope("dead_letter")
+            .app_data(web::Data::new(conf))
+            .app_data(web::Data::new(stores))
+            .service(web::resource("/{tenant_id}/{shard}").route(web::get().to(list_entries)))
+            .service(
+                web::resource("/{tenant_id}/{shard}/{entry_id}")
+                    .route(web::delete().to(discard_entry)),
+            )
+            .service(
+                web::resource("/{tenant_id}/{shard}/{entry_id}/retry")
+                    .route(web::post().to(retry_entry)),
+            )
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct DeadLetterListQuery {
+    /// Only entries after this entry ID are listed.
+    pub cursor: Option<String>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterEntryResponse {
+    pub id: String,
+    pub source_entry_id: String,
+    pub request_id: Option<String>,
+    pub global_id: Option<String>,
+    pub operation: Option<String>,
+    pub table: Option<String>,
+    pub pushed_at: Option<String>,
+    pub failed_at: i64,
+    pub attempts: usize,
+    pub error: String,
+}
+
+impl DeadLetterEntryResponse {
+    fn new(id: String, entry: DeadLetterEntry) -> Self {
+        // The query itself is not included in the response, since it may contain sensitive data
+        let (operation, table) = StreamData::from_hashmap(entry.fields.clone())
+            .map(|data| {
+                (
+                    Some(data.typed_sql.operation().to_owned()),
+                    Some(data.typed_sql.table().to_owned()),
+                )
+            })
+            .unwrap_or_default();
+
+        Self {
+            id,
+            source_entry_id: entry.source_entry_id,
+            request_id: entry.fields.get("request_id").cloned(),
+            global_id: entry.fields.get("global_id").cloned(),
+            operation,
+            table,
+            pushed_at: entry.fields.get("pushed_at").cloned(),
+            failed_at: entry.failed_at,
+            attempts: entry.attempts,
+            error: entry.error,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterListResponse {
+    pub entries: Vec<DeadLetterEntryResponse>,
+    /// Cursor to be used to list the next page of entries, if there are any.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterEntryActionResponse {
+    pub id: String,
+    pub status: DeadLetterEntryStatus,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadLetterEntryStatus {
+    Retried,
+    Discarded,
+}
+
+/// Whether an entry has been attempted as many times as allowed, and should be moved to the dead
+/// letter stream.
+fn has_exhausted_query_attempts(attempts: usize, max_query_attempts: u8) -> bool {
+    attempts >= usize::from(max_query_attempts)
+}
+
+/// Compares the API key in constant time, so that the admin API key cannot be guessed from the
+/// response times.
+fn is_valid_api_key(api_key: Option<&str>, admin_api_key: &str) -> bool {
+    match api_key {
+        Some(api_key) if !admin_api_key.is_empty() => {
+            masking::StrongSecret::<String>::new(api_key.to_owned())
+                == masking::StrongSecret::<String>::new(admin_api_key.to_owned())
+        }
+        _ => false,
+    }
+}
+
+/// Whether the request carries the admin API key, which is required by the operational
+/// endpoints of the drainer.
+pub(crate) fn has_admin_api_key(conf: &Settings, req: &HttpRequest) -> bool {
+    let admin_api_key = conf.secrets.get_inner().admin_api_key.peek();
+    let api_key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|api_key| api_key.to_str().ok());
+
+    is_valid_api_key(api_key, admin_api_key)
+}
+
+fn authenticate(conf: &Settings, req: &HttpRequest) -> Result<(), DeadLetterError> {
+    if has_admin_api_key(conf, req) {
+        Ok(())
+    } else {
+        Err(DeadLetterError::Unauthorized)
+    }
+}
+
+fn get_store_and_stream_name(
+    conf: &Settings,
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: &id_type::TenantId,
+    shard: u8,
+) -> Result<(Arc<Store>, String), DeadLetterError> {
+    if shard >= conf.drainer.num_partitions {
+        return Err(DeadLetterError::ShardNotFound(shard));
+    }
+
+    let store = stores
+        .get(tenant_id)
+        .ok_or_else(|| DeadLetterError::TenantNotFound(tenant_id.get_string_repr().to_owned()))?;
+    let dead_letter_stream_name = store.get_dead_letter_stream_name(shard);
+
+    Ok((store.clone(), dead_letter_stream_name))
+}
+
+fn respond<T: serde::Serialize>(
+    result: Result<T, error_stack::Report<DeadLetterError>>,
+) -> HttpResponse {
+    match result.and_then(|response| {
+        serde_json::to_string(&response).change_context(DeadLetterError::InternalError)
+    }) {
+        Ok(response) => services::http_response_json(response),
+        Err(error) => services::log_and_return_error_response_with_status(error),
+    }
+}
+
+async fn list_entries_core(
+    conf: &Settings,
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: id_type::TenantId,
+    shard: u8,
+    query: DeadLetterListQuery,
+) -> Result<DeadLetterListResponse, error_stack::Report<DeadLetterError>> {
+    let (store, dead_letter_stream_name) =
+        get_store_and_stream_name(conf, stores, &tenant_id, shard)?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_LIST_LIMIT)
+        .clamp(1, MAX_LIST_LIMIT);
+
+    // "0-0" id gives first entry
+    let entries = store
+        .read_dead_letter_entries(
+            &dead_letter_stream_name,
+            query.cursor.as_deref().unwrap_or("0-0"),
+            limit,
+        )
+        .await
+        .change_context(DeadLetterError::InternalError)?;
+
+    let next_cursor = (u64::try_from(entries.len()).unwrap_or(u64::MAX) >= limit)
+        .then(|| entries.last().map(|(entry_id, _)| entry_id.clone()))
+        .flatten();
+
+    Ok(DeadLetterListResponse {
+        entries: entries
+            .into_iter()
+            .map(|(entry_id, entry)| DeadLetterEntryResponse::new(entry_id, entry))
+            .collect(),
+        next_cursor,
+    })
+}
+
+async fn retry_entry_core(
+    conf: &Settings,
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: id_type::TenantId,
+    shard: u8,
+    entry_id: String,
+) -> Result<DeadLetterEntryActionResponse, error_stack::Report<DeadLetterError>> {
+    let (store, dead_letter_stream_name) =
+        get_store_and_stream_name(conf, stores, &tenant_id, shard)?;
+
+    let entry = store
+        .find_dead_letter_entry(&dead_letter_stream_name, &entry_id)
+        .await
+        .change_context(DeadLetterError::InternalError)?
+        .ok_or_else(|| report!(DeadLetterError::EntryNotFound(entry_id.clone())))?;
+
+    let data = StreamData::from_hashmap(entry.fields).map_err(|error| {
+        let message = format!("{error:?}");
+        error.change_context(DeadLetterError::InvalidEntry { message })
+    })?;
+
+    match data.typed_sql.execute_query(&store, data.pushed_at).await {
+        Ok(_) => {}
+        // The row has already been written, so there is nothing left to retry
+        Err(error)
+            if matches!(
+                error.current_context(),
+                diesel_models::errors::DatabaseError::UniqueViolation
+            ) => {}
+        Err(error) => {
+            let message = error.current_context().to_string();
+            return Err(error.change_context(DeadLetterError::RetryFailed { message }));
+        }
+    }
+
+    metrics::DEAD_LETTER_ENTRIES_RETRIED.add(1, &[]);
+    store
+        .delete_dead_letter_entry(&dead_letter_stream_name, &entry_id)
+        .await
+        .change_context(DeadLetterError::InternalError)?;
+
+    Ok(DeadLetterEntryActionResponse {
+        id: entry_id,
+        status: DeadLetterEntryStatus::Retried,
+    })
+}
+
+async fn discard_entry_core(
+    conf: &Settings,
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    tenant_id: id_type::TenantId,
+    shard: u8,
+    entry_id: String,
+) -> Result<DeadLetterEntryActionResponse, error_stack::Report<DeadLetterError>> {
+    let (store, dead_letter_stream_name) =
+        get_store_and_stream_name(conf, stores, &tenant_id, shard)?;
+
+    let deleted = store
+        .delete_dead_letter_entry(&dead_letter_stream_name, &entry_id)
+        .await
+        .change_context(DeadLetterError::InternalError)?;
+    if !deleted {
+        return Err(report!(DeadLetterError::EntryNotFound(entry_id)));
+    }
+
+    logger::info!(entry_id, "Discarded dead letter entry");
+    metrics::DEAD_LETTER_ENTRIES_DISCARDED.add(1, &[]);
+
+    Ok(DeadLetterEntryActionResponse {
+        id: entry_id,
+        status: DeadLetterEntryStatus::Discarded,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn list_entries(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8)>,
+    query: web::Query<DeadLetterListQuery>,
+) -> HttpResponse {
+    let (tenant_id, shard) = path.into_inner();
+    if let Err(error) = authenticate(&conf, &req) {
+        return respond::<()>(Err(report!(error)));
+    }
+
+    respond(list_entries_core(&conf, &stores, tenant_id, shard, query.into_inner()).await)
+}
+
+#[instrument(skip_all)]
+pub async fn retry_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8, String)>,
+) -> HttpResponse {
+    let (tenant_id, shard, entry_id) = path.into_inner();
+    if let Err(error) = authenticate(&conf, &req) {
+        return respond::<()>(Err(report!(error)));
+    }
+
+    respond(retry_entry_core(&conf, &stores, tenant_id, shard, entry_id).await)
+}
+
+#[instrument(skip_all)]
+pub async fn discard_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<(id_type::TenantId, u8, String)>,
+) -> HttpResponse {
+    let (tenant_id, shard, entry_id) = path.into_inner();
+    if let Err(error) = authenticate(&conf, &req) {
+        return respond::<()>(Err(report!(error)));
+    }
+
+    respond(discard_entry_core(&conf, &stores, tenant_id, shard, entry_id).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dead_letter_entry_field_round_trip() {
+        let entry = DeadLetterEntry {
+            source_entry_id: "1700000000000-0".to_owned(),
+            fields: HashMap::from([
+                ("request_id".to_owned(), "req_123".to_owned()),
+                ("pushed_at".to_owned(), "1700000000".to_owned()),
+            ]),
+            error: "DatabaseError".to_owned(),
+            attempts: 3,
+            failed_at: 1_700_000_100,
+        };
+
+        let fields = entry
+            .clone()
+            .into_field_value_pairs()
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        let parsed = DeadLetterEntry::from_field_value_pairs(fields);
+
+        assert_eq!(parsed.source_entry_id, entry.source_entry_id);
+        assert_eq!(parsed.fields, entry.fields);
+        assert_eq!(parsed.error, entry.error);
+        assert_eq!(parsed.attempts, entry.attempts);
+        assert_eq!(parsed.failed_at, entry.failed_at);
+    }
+
+    #[test]
+    fn test_query_attempts_are_exhausted_at_max_attempts() {
+        assert!(!has_exhausted_query_attempts(1, 3));
+        assert!(!has_exhausted_query_attempts(2, 3));
+        assert!(has_exhausted_query_attempts(3, 3));
+        assert!(has_exhausted_query_attempts(4, 3));
+    }
+
+    #[test]
+    fn test_api_key_validation() {
+        assert!(is_valid_api_key(Some("admin_key"), "admin_key"));
+        assert!(!is_valid_api_key(Some("admin_kez"), "admin_key"));
+        assert!(!is_valid_api_key(Some("admin"), "admin_key"));
+        assert!(!is_valid_api_key(None, "admin_key"));
+        // An unset admin API key must not grant access
+        assert!(!is_valid_api_key(Some(""), ""));
+    }
+}
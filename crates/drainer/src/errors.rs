@@ -27,6 +27,34 @@ pub enum HealthCheckError {
     RedisError { message: String },
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum DeadLetterError {
+    #[error("API key is missing or invalid")]
+    Unauthorized,
+    #[error("Tenant `{0}` does not exist")]
+    TenantNotFound(String),
+    #[error("Shard `{0}` does not exist")]
+    ShardNotFound(u8),
+    #[error("Dead letter entry `{0}` does not exist")]
+    EntryNotFound(String),
+    #[error("Dead letter entry cannot be parsed: {message}")]
+    InvalidEntry { message: String },
+    #[error("Retrying the query failed with error: {message}")]
+    RetryFailed { message: String },
+    #[error("Something went wrong")]
+    InternalError,
+}
+
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum LagError {
+    #[error("API key is missing or invalid")]
+    Unauthorized,
+    #[error("Tenant `{0}` does not exist")]
+    TenantNotFound(String),
+    #[error("Something went wrong")]
+    InternalError,
+}
+
 impl From<std::io::Error> for DrainerError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err)
@@ -56,3 +84,32 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for DeadLetterError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound(_) | Self::ShardNotFound(_) | Self::EntryNotFound(_) => {
+                StatusCode::NOT_FOUND
+            }
+            Self::InvalidEntry { .. } | Self::RetryFailed { .. } => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+            Self::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl actix_web::ResponseError for LagError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound(_) => StatusCode::NOT_FOUND,
+            Self::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
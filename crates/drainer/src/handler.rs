@@ -11,8 +11,10 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    dead_letter::DeadLetterEntry,
+    errors, instrument, lag, logger, metrics,
+    query::{self, ExecuteQuery},
+    tracing, utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -33,6 +35,7 @@ impl std::ops::Deref for Handler {
 pub struct HandlerInner {
     shutdown_interval: Duration,
     loop_interval: Duration,
+    lag_report_interval: Duration,
     active_tasks: Arc<atomic::AtomicU64>,
     conf: DrainerSettings,
     stores: HashMap<id_type::TenantId, Arc<Store>>,
@@ -46,6 +49,7 @@ impl Handler {
     ) -> Self {
         let shutdown_interval = Duration::from_millis(conf.shutdown_interval.into());
         let loop_interval = Duration::from_millis(conf.loop_interval.into());
+        let lag_report_interval = Duration::from_millis(conf.lag_report_interval.into());
 
         let active_tasks = Arc::new(atomic::AtomicU64::new(0));
 
@@ -54,6 +58,7 @@ impl Handler {
         let handler = HandlerInner {
             shutdown_interval,
             loop_interval,
+            lag_report_interval,
             active_tasks,
             conf,
             stores,
@@ -100,6 +105,22 @@ impl Handler {
         Ok(())
     }
 
+    /// Periodically records the backlog of the streams of every tenant as metrics.
+    pub fn spawn_lag_reporter(&self) {
+        let handler = self.clone();
+        let _task_handle = tokio::spawn(
+            async move {
+                while handler.running.load(atomic::Ordering::SeqCst) {
+                    for (tenant_id, store) in handler.stores.iter() {
+                        lag::record_lag_metrics(tenant_id, store).await;
+                    }
+                    time::sleep(handler.lag_report_interval).await;
+                }
+            }
+            .in_current_span(),
+        );
+    }
+
     pub(crate) async fn shutdown_listener(&self, mut rx: mpsc::Receiver<()>) {
         while let Some(_c) = rx.recv().await {
             logger::info!("Awaiting shutdown!");
@@ -180,11 +201,13 @@ async fn drainer_handler(
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
     let stream_name = store.get_drainer_stream_name(stream_index);
+    let dead_letter_stream_name = store.get_dead_letter_stream_name(stream_index);
 
     let drainer_result = Box::pin(drainer(
         store.clone(),
         max_read_count,
         stream_name.as_str(),
+        dead_letter_stream_name.as_str(),
         jobs_picked,
     ))
     .await;
@@ -205,13 +228,11 @@ async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
     stream_name: &str,
+    dead_letter_stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
     let stream_read = match store.read_from_stream(stream_name, max_read_count).await {
-        Ok(result) => {
-            jobs_picked.fetch_add(1, atomic::Ordering::SeqCst);
-            result
-        }
+        Ok(result) => result,
         Err(error) => {
             if let errors::DrainerError::RedisError(redis_err) = error.current_context() {
                 if let redis_interface::errors::RedisError::StreamEmptyOrNotAvailable =
@@ -235,47 +256,42 @@ async fn drainer(
     )?;
     let read_count = entries.len();
 
+    // Hold a partially filled batch in the stream until its oldest entry is due to be flushed
+    let batch_size = usize::try_from(store.config.batch_size).unwrap_or(usize::MAX);
+    let oldest_entry_age = entries
+        .first()
+        .and_then(|(entry_id, _)| utils::get_stream_entry_age_millis(entry_id));
+    if read_count < batch_size
+        && oldest_entry_age.is_some_and(|age| age < u64::from(store.config.flush_interval))
+    {
+        metrics::BATCH_FLUSH_DEFERRED.add(
+            1,
+            router_env::metric_attributes!(("stream", stream_name.to_owned())),
+        );
+        return Ok(());
+    }
+
+    jobs_picked.fetch_add(1, atomic::Ordering::SeqCst);
+
     metrics::JOBS_PICKED_PER_STREAM.add(
         u64::try_from(read_count).unwrap_or(u64::MIN),
         router_env::metric_attributes!(("stream", stream_name.to_owned())),
     );
 
     let session_id = common_utils::generate_id_with_default_len("drainer_session");
+    tracing::Span::current().record("session_id", &session_id);
 
     let mut last_processed_id = String::new();
 
-    for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
-            Ok(data) => data,
-            Err(err) => {
-                logger::error!(operation = "deserialization", err=?err);
-                metrics::STREAM_PARSE_FAIL.add(
-                    1,
-                    router_env::metric_attributes!(("operation", "deserialization")),
-                );
+    for batch in entries.chunks(batch_size) {
+        let (batch_last_processed_id, batch_completed) =
+            drain_batch(&store, stream_name, dead_letter_stream_name, batch).await;
 
-                // break from the loop in case of a deser error
-                break;
-            }
-        };
-
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
-        tracing::Span::current().record("session_id", &session_id);
-
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
-            Ok(_) => {
-                last_processed_id = entry_id;
-            }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
-                }
-                // break from the loop in case of an error in query
-                _ => break,
-            },
+        if let Some(entry_id) = batch_last_processed_id {
+            last_processed_id = entry_id;
+        }
+        if !batch_completed {
+            break;
         }
     }
 
@@ -283,6 +299,9 @@ async fn drainer(
         let entries_trimmed = store
             .trim_from_stream(stream_name, &last_processed_id)
             .await?;
+        if let Err(error) = store.record_last_drained_at(stream_name).await {
+            logger::error!(operation = "record_last_drained_at", ?error);
+        }
         if read_count != entries_trimmed {
             logger::error!(
                 read_entries = %read_count,
@@ -297,3 +316,277 @@ async fn drainer(
 
     Ok(())
 }
+
+/// Executes the queries of a batch of stream entries within a single transaction, falling back to
+/// executing them one at a time if the transaction fails.
+///
+/// Returns the ID of the last entry which can be removed from the stream, and whether the whole
+/// batch was processed. The entries following an unprocessed entry are left in the stream, so
+/// that the order of the queries is preserved.
+async fn drain_batch(
+    store: &Arc<Store>,
+    stream_name: &str,
+    dead_letter_stream_name: &str,
+    batch: &[(String, HashMap<String, String>)],
+) -> (Option<String>, bool) {
+    let mut processable_entries = Vec::with_capacity(batch.len());
+    let mut operations = Vec::with_capacity(batch.len());
+    let mut batch_completed = true;
+
+    for (entry_id, entry) in batch {
+        match StreamData::from_hashmap(entry.clone()) {
+            Ok(data) => {
+                operations.push((data.typed_sql, data.pushed_at));
+                processable_entries.push((entry_id, entry, true));
+            }
+            Err(err) => {
+                if dead_letter_unparsable_entry(
+                    store,
+                    dead_letter_stream_name,
+                    entry_id,
+                    entry,
+                    err,
+                )
+                .await
+                {
+                    processable_entries.push((entry_id, entry, false));
+                } else {
+                    // Retry the entry and the ones following it in the next cycle
+                    batch_completed = false;
+                    break;
+                }
+            }
+        }
+    }
+
+    let last_entry_id = processable_entries
+        .last()
+        .map(|(entry_id, _, _)| (*entry_id).clone());
+
+    if operations.is_empty() {
+        return (last_entry_id, batch_completed);
+    }
+
+    let error = match query::execute_batch(store, operations).await {
+        Ok(()) => {
+            // Entries of the batch may have failed individually in previous cycles
+            store
+                .clear_query_attempts(
+                    stream_name,
+                    processable_entries
+                        .iter()
+                        .filter(|(_, _, is_parsed)| *is_parsed)
+                        .map(|(entry_id, _, _)| (*entry_id).clone())
+                        .collect(),
+                )
+                .await;
+            return (last_entry_id, batch_completed);
+        }
+        Err(error) => error,
+    };
+
+    // The database being unreachable is not a problem with the entries themselves, so retry the
+    // batch in the next cycle, except for the leading entries which were moved to the dead letter
+    // stream
+    if !should_execute_entries_individually(error.current_context()) {
+        let last_dead_lettered_id = get_last_leading_unparsed_entry_id(&processable_entries);
+        return (last_dead_lettered_id, false);
+    }
+
+    // A failed batch is rolled back entirely, execute its entries one at a time so that the
+    // failing entries can be handled individually
+    logger::warn!(
+        ?error,
+        "Falling back to executing the batch one entry at a time"
+    );
+
+    let mut last_processed_id = None;
+    for (entry_id, entry, is_parsed) in processable_entries {
+        // Entries which could not be deserialized have already been moved to the dead letter
+        // stream
+        if is_parsed
+            && !execute_entry(store, stream_name, dead_letter_stream_name, entry_id, entry).await
+        {
+            return (last_processed_id, false);
+        }
+        last_processed_id = Some(entry_id.clone());
+    }
+
+    (last_processed_id, batch_completed)
+}
+
+/// Returns `true` if the entries of a failed batch should be executed one at a time, and `false`
+/// if the batch should be retried as a whole in the next cycle.
+fn should_execute_entries_individually(error: &diesel_models::errors::DatabaseError) -> bool {
+    !matches!(
+        error,
+        diesel_models::errors::DatabaseError::DatabaseConnectionError
+    )
+}
+
+/// Returns the ID of the last entry of the run of unparsable entries at the start of the batch,
+/// which have already been moved to the dead letter stream.
+fn get_last_leading_unparsed_entry_id(
+    processable_entries: &[(&String, &HashMap<String, String>, bool)],
+) -> Option<String> {
+    processable_entries
+        .iter()
+        .take_while(|(_, _, is_parsed)| !is_parsed)
+        .last()
+        .map(|(entry_id, _, _)| (*entry_id).clone())
+}
+
+/// Moves an entry which cannot be deserialized to the dead letter stream, since it will never
+/// succeed.
+///
+/// Returns `true` if the entry can be removed from the stream.
+async fn dead_letter_unparsable_entry(
+    store: &Arc<Store>,
+    dead_letter_stream_name: &str,
+    entry_id: &str,
+    entry: &HashMap<String, String>,
+    err: error_stack::Report<common_utils::errors::ParsingError>,
+) -> bool {
+    logger::error!(operation = "deserialization", err=?err);
+    metrics::STREAM_PARSE_FAIL.add(
+        1,
+        router_env::metric_attributes!(("operation", "deserialization")),
+    );
+
+    let dead_letter_entry = DeadLetterEntry {
+        source_entry_id: entry_id.to_owned(),
+        fields: entry.clone(),
+        error: format!("{err:?}"),
+        attempts: 1,
+        failed_at: common_utils::date_time::now_unix_timestamp(),
+    };
+    match store
+        .push_to_dead_letter_stream(dead_letter_stream_name, dead_letter_entry)
+        .await
+    {
+        Ok(()) => true,
+        Err(error) => {
+            logger::error!(operation = "dead_letter", ?error);
+            false
+        }
+    }
+}
+
+/// Executes the query of a single stream entry.
+///
+/// Returns `true` if the entry can be removed from the stream.
+async fn execute_entry(
+    store: &Arc<Store>,
+    stream_name: &str,
+    dead_letter_stream_name: &str,
+    entry_id: &str,
+    entry: &HashMap<String, String>,
+) -> bool {
+    let data = match StreamData::from_hashmap(entry.clone()) {
+        Ok(data) => data,
+        Err(err) => {
+            return dead_letter_unparsable_entry(
+                store,
+                dead_letter_stream_name,
+                entry_id,
+                entry,
+                err,
+            )
+            .await
+        }
+    };
+
+    tracing::Span::current().record("request_id", data.request_id);
+    tracing::Span::current().record("global_id", data.global_id);
+
+    match data.typed_sql.execute_query(store, data.pushed_at).await {
+        Ok(_) => {
+            store
+                .clear_query_attempts(stream_name, vec![entry_id.to_owned()])
+                .await;
+            true
+        }
+        Err(err) => match err.current_context() {
+            // In case of Uniqueviolation we can't really do anything to fix it so just clear
+            // it from the stream
+            diesel_models::errors::DatabaseError::UniqueViolation => {
+                store
+                    .clear_query_attempts(stream_name, vec![entry_id.to_owned()])
+                    .await;
+                true
+            }
+            // The database being unreachable is not a problem with the entry itself, so
+            // break from the loop and retry in the next cycle
+            diesel_models::errors::DatabaseError::DatabaseConnectionError => false,
+            // Retry the entry in the next cycle until it runs out of attempts, after which it
+            // is moved to the dead letter stream
+            _ => {
+                match store
+                    .handle_failed_entry(
+                        stream_name,
+                        dead_letter_stream_name,
+                        entry_id,
+                        entry.clone(),
+                        format!("{err:?}"),
+                    )
+                    .await
+                {
+                    Ok(is_dead_lettered) => is_dead_lettered,
+                    Err(error) => {
+                        logger::error!(operation = "dead_letter", ?error);
+                        false
+                    }
+                }
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel_models::errors::DatabaseError;
+
+    use super::*;
+
+    #[test]
+    fn test_batch_is_retried_when_database_is_unreachable() {
+        assert!(!should_execute_entries_individually(
+            &DatabaseError::DatabaseConnectionError
+        ));
+    }
+
+    #[test]
+    fn test_batch_falls_back_to_single_execution_on_query_errors() {
+        assert!(should_execute_entries_individually(
+            &DatabaseError::UniqueViolation
+        ));
+        assert!(should_execute_entries_individually(
+            &DatabaseError::NotFound
+        ));
+        assert!(should_execute_entries_individually(
+            &DatabaseError::QueryGenerationFailed
+        ));
+        assert!(should_execute_entries_individually(&DatabaseError::Others));
+    }
+
+    #[test]
+    fn test_last_leading_unparsed_entry_id() {
+        let ids = ["1-0", "2-0", "3-0", "4-0"].map(String::from);
+        let entry = HashMap::new();
+
+        let entries = [
+            (&ids[0], &entry, false),
+            (&ids[1], &entry, false),
+            (&ids[2], &entry, true),
+            (&ids[3], &entry, false),
+        ];
+        assert_eq!(
+            get_last_leading_unparsed_entry_id(&entries),
+            Some("2-0".to_owned())
+        );
+
+        let entries = [(&ids[0], &entry, true), (&ids[1], &entry, false)];
+        assert_eq!(get_last_leading_unparsed_entry_id(&entries), None);
+        assert_eq!(get_last_leading_unparsed_entry_id(&[]), None);
+    }
+}
@@ -0,0 +1,251 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    dead_letter,
+    errors::{self, LagError},
+    metrics,
+    services::{self, Store},
+    utils, Settings,
+};
+
+/// Time for which the time of the last successful drain of a stream is remembered, in seconds.
+const LAST_DRAINED_AT_TTL: i64 = 7 * 24 * 60 * 60;
+
+/// The backlog of a drainer stream, used to detect the drainer falling behind.
+#[derive(Debug, serde::Serialize)]
+pub struct StreamLag {
+    pub shard: u8,
+    pub stream_name: String,
+    /// Number of entries waiting to be written to the database.
+    pub length: usize,
+    /// ID of the oldest entry waiting to be written to the database.
+    pub oldest_entry_id: Option<String>,
+    /// Time elapsed since the oldest entry was added to the stream, in milliseconds.
+    pub oldest_entry_age_ms: Option<u64>,
+    /// The drainer instance currently draining the stream, if any.
+    pub lock_holder: Option<String>,
+    /// Unix timestamp of the last time entries were removed from the stream after being written
+    /// to the database.
+    pub last_drained_at: Option<i64>,
+    /// Number of entries in the dead letter stream of the shard.
+    pub dead_letter_length: usize,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct TenantLag {
+    pub streams: Vec<StreamLag>,
+    /// Total number of entries waiting to be written to the database across all streams.
+    pub total_length: usize,
+    /// Age of the oldest entry waiting to be written to the database across all streams, in
+    /// milliseconds.
+    pub max_oldest_entry_age_ms: Option<u64>,
+}
+
+impl Store {
+    #[inline(always)]
+    fn get_last_drained_at_key(stream_name: &str) -> String {
+        format!("{stream_name}_last_drained_at")
+    }
+
+    /// Records the current time as the time of the last successful drain of a stream.
+    pub(crate) async fn record_last_drained_at(
+        &self,
+        stream_name: &str,
+    ) -> errors::DrainerResult<()> {
+        self.redis_conn
+            .set_key_with_expiry(
+                &Self::get_last_drained_at_key(stream_name).as_str().into(),
+                common_utils::date_time::now_unix_timestamp(),
+                LAST_DRAINED_AT_TTL,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(())
+    }
+
+    async fn get_stream_length(&self, stream_name: &str) -> errors::DrainerResult<usize> {
+        Ok(self
+            .redis_conn
+            .stream_get_length(&stream_name.into())
+            .await
+            .map_err(errors::DrainerError::from)?)
+    }
+
+    async fn get_optional_key(&self, key: &str) -> errors::DrainerResult<Option<String>> {
+        Ok(self
+            .redis_conn
+            .get_key::<Option<String>>(&key.into())
+            .await
+            .map_err(errors::DrainerError::from)?)
+    }
+
+    pub(crate) async fn get_stream_lag(
+        &self,
+        stream_index: u8,
+    ) -> errors::DrainerResult<StreamLag> {
+        let stream_name = self.get_drainer_stream_name(stream_index);
+
+        let length = self.get_stream_length(&stream_name).await?;
+        let oldest_entry_id = self
+            .redis_conn
+            .stream_read_range(&stream_name.as_str().into(), "-", "+", Some(1))
+            .await
+            .map_err(errors::DrainerError::from)?
+            .into_iter()
+            .next()
+            .map(|(entry_id, _)| entry_id);
+        let oldest_entry_age_ms = oldest_entry_id
+            .as_deref()
+            .and_then(utils::get_stream_entry_age_millis);
+
+        let lock_holder = self
+            .get_optional_key(&self.get_stream_key_flag(stream_index))
+            .await?;
+        let last_drained_at = self
+            .get_optional_key(&Self::get_last_drained_at_key(&stream_name))
+            .await?
+            .and_then(|last_drained_at| last_drained_at.parse().ok());
+
+        let dead_letter_length = self
+            .get_stream_length(&self.get_dead_letter_stream_name(stream_index))
+            .await?;
+
+        Ok(StreamLag {
+            shard: stream_index,
+            stream_name,
+            length,
+            oldest_entry_id,
+            oldest_entry_age_ms,
+            lock_holder,
+            last_drained_at,
+            dead_letter_length,
+        })
+    }
+
+    pub(crate) async fn get_tenant_lag(&self) -> errors::DrainerResult<TenantLag> {
+        let mut streams = Vec::with_capacity(usize::from(self.config.drainer_num_partitions));
+        for stream_index in 0..self.config.drainer_num_partitions {
+            streams.push(self.get_stream_lag(stream_index).await?);
+        }
+
+        Ok(TenantLag {
+            total_length: streams.iter().map(|stream| stream.length).sum(),
+            max_oldest_entry_age_ms: streams
+                .iter()
+                .filter_map(|stream| stream.oldest_entry_age_ms)
+                .max(),
+            streams,
+        })
+    }
+}
+
+/// Records the backlog of every stream of a tenant as metrics, so that alerts can be raised on
+/// the replication lag from Redis to the database.
+pub(crate) async fn record_lag_metrics(tenant_id: &id_type::TenantId, store: &Store) {
+    let tenant_lag = match store.get_tenant_lag().await {
+        Ok(tenant_lag) => tenant_lag,
+        Err(error) => {
+            logger::error!(operation = "lag_report", ?error);
+            return;
+        }
+    };
+    let now = common_utils::date_time::now_unix_timestamp();
+
+    for stream in tenant_lag.streams {
+        let tags = router_env::metric_attributes!(
+            ("tenant", tenant_id.get_string_repr().to_owned()),
+            ("stream", stream.stream_name),
+        );
+
+        metrics::STREAM_LENGTH.record(u64::try_from(stream.length).unwrap_or(u64::MAX), tags);
+        metrics::STREAM_OLDEST_ENTRY_AGE.record(stream.oldest_entry_age_ms.unwrap_or(0), tags);
+        metrics::STREAM_LOCKED.record(u64::from(stream.lock_holder.is_some()), tags);
+        metrics::DEAD_LETTER_STREAM_LENGTH.record(
+            u64::try_from(stream.dead_letter_length).unwrap_or(u64::MAX),
+            tags,
+        );
+        if let Some(last_drained_at) = stream.last_drained_at {
+            metrics::SECONDS_SINCE_LAST_DRAIN
+                .record(u64::try_from(now - last_drained_at).unwrap_or(0), tags);
+        }
+    }
+}
+
+pub struct Lag;
+
+impl Lag {
+    pub fn server(conf: Settings, stores: HashMap<id_type::TenantId, Arc<Store>>) -> Scope {
+        web::scope("lag")
+            .app_data(web::Data::new(conf))
+            .app_data(web::Data::new(stores))
+            .service(web::resource("").route(web::get().to(all_tenants_lag)))
+            .service(web::resource("/{tenant_id}").route(web::get().to(tenant_lag)))
+    }
+}
+
+fn respond<T: serde::Serialize>(result: Result<T, error_stack::Report<LagError>>) -> HttpResponse {
+    match result.and_then(|response| {
+        serde_json::to_string(&response).change_context(LagError::InternalError)
+    }) {
+        Ok(response) => services::http_response_json(response),
+        Err(error) => services::log_and_return_error_response_with_status(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn all_tenants_lag(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+) -> HttpResponse {
+    logger::info!("Drainer lag was called");
+    if !dead_letter::has_admin_api_key(&conf, &req) {
+        return respond::<TenantLag>(Err(LagError::Unauthorized.into()));
+    }
+
+    let mut lag = HashMap::new();
+    for (tenant_id, store) in stores.iter() {
+        match store
+            .get_tenant_lag()
+            .await
+            .change_context(LagError::InternalError)
+        {
+            Ok(tenant_lag) => {
+                lag.insert(tenant_id.get_string_repr().to_owned(), tenant_lag);
+            }
+            Err(error) => return respond::<TenantLag>(Err(error)),
+        }
+    }
+
+    respond(Ok(lag))
+}
+
+#[instrument(skip_all)]
+pub async fn tenant_lag(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<id_type::TenantId, Arc<Store>>>,
+    path: web::Path<id_type::TenantId>,
+) -> HttpResponse {
+    let tenant_id = path.into_inner();
+    logger::info!(tenant_id = %tenant_id.get_string_repr(), "Drainer tenant lag was called");
+    if !dead_letter::has_admin_api_key(&conf, &req) {
+        return respond::<TenantLag>(Err(LagError::Unauthorized.into()));
+    }
+
+    let result = match stores.get(&tenant_id) {
+        Some(store) => store
+            .get_tenant_lag()
+            .await
+            .change_context(LagError::InternalError),
+        None => Err(LagError::TenantNotFound(tenant_id.get_string_repr().to_owned()).into()),
+    };
+
+    respond(result)
+}
@@ -1,7 +1,9 @@
 mod connection;
+mod dead_letter;
 pub mod errors;
 mod handler;
 mod health_check;
+mod lag;
 pub mod logger;
 pub(crate) mod metrics;
 mod query;
@@ -50,6 +52,7 @@ pub async fn start_drainer(
     tokio::task::spawn(async move { handler_clone.shutdown_listener(rx).await });
 
     drainer_handler.spawn_error_handlers(tx)?;
+    drainer_handler.spawn_lag_reporter();
     drainer_handler.spawn().await?;
 
     handle.close();
@@ -66,7 +69,13 @@ pub async fn start_web_server(
 ) -> Result<Server, errors::DrainerError> {
     let server = conf.server.clone();
     let web_server = actix_web::HttpServer::new(move || {
-        actix_web::App::new().service(health_check::Health::server(conf.clone(), stores.clone()))
+        actix_web::App::new()
+            .service(health_check::Health::server(conf.clone(), stores.clone()))
+            .service(dead_letter::DeadLetter::server(
+                conf.clone(),
+                stores.clone(),
+            ))
+            .service(lag::Lag::server(conf.clone(), stores.clone()))
     })
     .bind((server.host.as_str(), server.port))?
     .run();
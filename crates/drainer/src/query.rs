@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
+use async_bb8_diesel::AsyncConnection;
 use common_utils::errors::CustomResult;
 use diesel_models::errors::DatabaseError;
+use error_stack::report;
 
 use crate::{kv, logger, metrics, pg_connection, services::Store};
 
@@ -48,6 +50,106 @@ impl ExecuteQuery for kv::DBOperation {
     }
 }
 
+/// A step of a batch, executed as a single query
+enum BatchStep {
+    Insert(Vec<kv::Insertable>),
+    Update(kv::DBOperation),
+}
+
+/// Groups runs of consecutive inserts into the same table, so that they can be executed as a
+/// single multi-row insert. Updates are kept as they are, and the order of the operations in the
+/// stream is preserved.
+fn group_operations(operations: Vec<kv::DBOperation>) -> Vec<BatchStep> {
+    let mut steps: Vec<BatchStep> = Vec::new();
+
+    for operation in operations {
+        match operation {
+            kv::DBOperation::Insert { insertable } => match steps.last_mut() {
+                Some(BatchStep::Insert(run))
+                    if run.first().is_some_and(|first| {
+                        std::mem::discriminant(first) == std::mem::discriminant(&*insertable)
+                    }) =>
+                {
+                    run.push(*insertable)
+                }
+                _ => steps.push(BatchStep::Insert(vec![*insertable])),
+            },
+            update @ kv::DBOperation::Update { .. } => steps.push(BatchStep::Update(update)),
+        }
+    }
+
+    steps
+}
+
+/// Executes the operations of a batch of stream entries within a single transaction.
+///
+/// Each element of the batch is an operation along with the time at which it was pushed to the
+/// stream. Either all operations are applied, or none of them are.
+pub async fn execute_batch(
+    store: &Arc<Store>,
+    batch: Vec<(kv::DBOperation, i64)>,
+) -> CustomResult<(), DatabaseError> {
+    let conn = pg_connection(&store.master_pool).await;
+
+    let mut entries = Vec::with_capacity(batch.len());
+    let mut operations = Vec::with_capacity(batch.len());
+    for (operation, pushed_at) in batch {
+        entries.push((operation.operation(), operation.table(), pushed_at));
+        operations.push(operation);
+    }
+    let batch_size = entries.len();
+    let steps = group_operations(operations);
+    let query_count = steps.len();
+
+    let (result, execution_time) = Box::pin(common_utils::date_time::time_it(|| {
+        conn.transaction_async(|conn| async move {
+            for step in steps {
+                match step {
+                    BatchStep::Insert(insertables) => {
+                        kv::Insertable::batch_insert(insertables, &conn)
+                            .await
+                            .map(|_| ())
+                    }
+                    BatchStep::Update(operation) => operation.execute(&conn).await.map(|_| ()),
+                }
+                .map_err(|error| {
+                    // The transaction can only be rolled back with an error which can be
+                    // converted from a `diesel::result::Error`, which `error_stack::Report` cannot,
+                    // so the report is logged here and only its context is returned
+                    logger::error!(?error, "Query in a batch failed");
+                    *error.current_context()
+                })?;
+            }
+
+            Ok::<_, DatabaseError>(())
+        })
+    }))
+    .await;
+
+    metrics::BATCH_EXECUTION_TIME.record(execution_time, &[]);
+
+    match result {
+        Ok(()) => {
+            logger::info!(batch_size, query_count, "Executed batch");
+            metrics::SUCCESSFUL_BATCH_EXECUTION.add(1, &[]);
+
+            for (operation, table, pushed_at) in entries {
+                let tags =
+                    router_env::metric_attributes!(("operation", operation), ("table", table));
+                push_drainer_delay(pushed_at, operation, table, tags);
+                metrics::SUCCESSFUL_QUERY_EXECUTION.add(1, tags);
+            }
+
+            Ok(())
+        }
+        Err(err) => {
+            logger::error!(batch_size, query_count, ?err, "Failed to execute batch");
+            metrics::ERRORS_WHILE_BATCH_EXECUTION.add(1, &[]);
+            Err(report!(err))
+        }
+    }
+}
+
 #[inline(always)]
 fn push_drainer_delay(
     pushed_at: i64,
@@ -71,3 +173,190 @@ fn push_drainer_delay(
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use diesel_models::{
+        address::{Address, AddressNew, AddressUpdateInternal},
+        reverse_lookup::ReverseLookupNew,
+    };
+
+    use super::*;
+
+    fn reverse_lookup_insert(lookup_id: &str) -> kv::DBOperation {
+        kv::DBOperation::Insert {
+            insertable: Box::new(kv::Insertable::ReverseLookUp(ReverseLookupNew {
+                lookup_id: lookup_id.to_owned(),
+                pk_id: "pk".to_owned(),
+                sk_id: "sk".to_owned(),
+                source: "payment_attempt".to_owned(),
+                updated_by: "postgres_only".to_owned(),
+            })),
+        }
+    }
+
+    fn address_insert(address_id: &str) -> kv::DBOperation {
+        let now = common_utils::date_time::now();
+        kv::DBOperation::Insert {
+            insertable: Box::new(kv::Insertable::Address(Box::new(AddressNew {
+                address_id: address_id.to_owned(),
+                city: None,
+                country: None,
+                line1: None,
+                line2: None,
+                line3: None,
+                state: None,
+                zip: None,
+                first_name: None,
+                last_name: None,
+                phone_number: None,
+                country_code: None,
+                customer_id: None,
+                merchant_id: common_utils::id_type::MerchantId::get_irrelevant_merchant_id(),
+                payment_id: None,
+                created_at: now,
+                modified_at: now,
+                updated_by: "postgres_only".to_owned(),
+                email: None,
+            }))),
+        }
+    }
+
+    fn address_update(address_id: &str) -> kv::DBOperation {
+        let now = common_utils::date_time::now();
+        kv::DBOperation::Update {
+            updatable: Box::new(kv::Updateable::AddressUpdate(Box::new(
+                kv::AddressUpdateMems {
+                    orig: Address {
+                        address_id: address_id.to_owned(),
+                        city: None,
+                        country: None,
+                        line1: None,
+                        line2: None,
+                        line3: None,
+                        state: None,
+                        zip: None,
+                        first_name: None,
+                        last_name: None,
+                        phone_number: None,
+                        country_code: None,
+                        created_at: now,
+                        modified_at: now,
+                        customer_id: None,
+                        merchant_id: common_utils::id_type::MerchantId::get_irrelevant_merchant_id(
+                        ),
+                        payment_id: None,
+                        updated_by: "postgres_only".to_owned(),
+                        email: None,
+                    },
+                    update_data: AddressUpdateInternal {
+                        city: Some("Bangalore".to_owned()),
+                        country: None,
+                        line1: None,
+                        line2: None,
+                        line3: None,
+                        state: None,
+                        zip: None,
+                        first_name: None,
+                        last_name: None,
+                        phone_number: None,
+                        country_code: None,
+                        modified_at: now,
+                        updated_by: "postgres_only".to_owned(),
+                        email: None,
+                    },
+                },
+            ))),
+        }
+    }
+
+    fn get_insertable_id(insertable: &kv::Insertable) -> String {
+        match insertable {
+            kv::Insertable::ReverseLookUp(lookup) => lookup.lookup_id.clone(),
+            kv::Insertable::Address(address) => address.address_id.clone(),
+            _ => String::new(),
+        }
+    }
+
+    /// Describes each step by its kind along with the IDs of the rows it affects
+    fn describe(steps: &[BatchStep]) -> Vec<(&'static str, Vec<String>)> {
+        steps
+            .iter()
+            .map(|step| match step {
+                BatchStep::Insert(run) => ("insert", run.iter().map(get_insertable_id).collect()),
+                BatchStep::Update(kv::DBOperation::Update { updatable }) => match &**updatable {
+                    kv::Updateable::AddressUpdate(update) => {
+                        ("update", vec![update.orig.address_id.clone()])
+                    }
+                    _ => ("update", Vec::new()),
+                },
+                BatchStep::Update(kv::DBOperation::Insert { .. }) => ("invalid", Vec::new()),
+            })
+            .collect()
+    }
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| (*id).to_owned()).collect()
+    }
+
+    #[test]
+    fn test_consecutive_inserts_into_same_table_are_grouped() {
+        let steps = group_operations(vec![
+            reverse_lookup_insert("lookup_1"),
+            reverse_lookup_insert("lookup_2"),
+            reverse_lookup_insert("lookup_3"),
+        ]);
+
+        assert_eq!(
+            describe(&steps),
+            vec![("insert", ids(&["lookup_1", "lookup_2", "lookup_3"]))]
+        );
+    }
+
+    #[test]
+    fn test_inserts_into_different_tables_are_not_grouped() {
+        let steps = group_operations(vec![
+            reverse_lookup_insert("lookup_1"),
+            address_insert("address_1"),
+            address_insert("address_2"),
+            reverse_lookup_insert("lookup_2"),
+        ]);
+
+        assert_eq!(
+            describe(&steps),
+            vec![
+                ("insert", ids(&["lookup_1"])),
+                ("insert", ids(&["address_1", "address_2"])),
+                ("insert", ids(&["lookup_2"])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_updates_split_runs_of_inserts_and_preserve_order() {
+        let steps = group_operations(vec![
+            address_insert("address_1"),
+            address_update("address_1"),
+            address_insert("address_2"),
+            address_insert("address_3"),
+            address_update("address_2"),
+            address_update("address_3"),
+        ]);
+
+        assert_eq!(
+            describe(&steps),
+            vec![
+                ("insert", ids(&["address_1"])),
+                ("update", ids(&["address_1"])),
+                ("insert", ids(&["address_2", "address_3"])),
+                ("update", ids(&["address_2"])),
+                ("update", ids(&["address_3"])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_batch_has_no_steps() {
+        assert!(group_operations(Vec::new()).is_empty());
+    }
+}
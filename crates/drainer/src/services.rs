@@ -22,6 +22,10 @@ pub struct Store {
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub dead_letter_stream_name: String,
+    pub max_query_attempts: u8,
+    pub batch_size: u64,
+    pub flush_interval: u32,
 }
 
 impl Store {
@@ -45,6 +49,10 @@ impl Store {
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                dead_letter_stream_name: config.drainer.dead_letter_stream_name.clone(),
+                max_query_attempts: config.drainer.max_query_attempts,
+                batch_size: config.drainer.batch_size,
+                flush_interval: config.drainer.flush_interval,
             },
             request_id: None,
         }
@@ -65,6 +73,20 @@ where
         .body(body)
 }
 
+pub fn log_and_return_error_response_with_status<T>(error: Report<T>) -> HttpResponse
+where
+    T: error_stack::Context + ResponseError,
+{
+    logger::error!(?error);
+    let body = serde_json::json!({
+        "message": error.to_string()
+    })
+    .to_string();
+    HttpResponse::build(error.current_context().status_code())
+        .content_type(mime::APPLICATION_JSON)
+        .body(body)
+}
+
 pub fn http_response_json<T: body::MessageBody + 'static>(response: T) -> HttpResponse {
     HttpResponse::Ok()
         .content_type(mime::APPLICATION_JSON)
@@ -68,6 +68,7 @@ impl AppState {
 pub struct Settings<S: SecretState> {
     pub server: Server,
     pub master_database: SecretStateContainer<Database, S>,
+    pub secrets: SecretStateContainer<Secrets, S>,
     pub redis: redis::RedisSettings,
     pub log: Log,
     pub drainer: DrainerSettings,
@@ -76,6 +77,13 @@ pub struct Settings<S: SecretState> {
     pub multitenancy: Multitenancy,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Secrets {
+    /// API key required by the admin endpoints of the drainer, which are disabled if it is empty
+    pub admin_api_key: Secret<String>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Database {
@@ -114,6 +122,11 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub dead_letter_stream_name: String,
+    pub max_query_attempts: u8,
+    pub batch_size: u64,
+    pub flush_interval: u32,      // in milliseconds
+    pub lag_report_interval: u32, // in milliseconds
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -224,6 +237,11 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
+            max_query_attempts: 5,
+            batch_size: 100,
+            flush_interval: 500,        // in milliseconds
+            lag_report_interval: 30000, // in milliseconds
         }
     }
 }
@@ -270,10 +288,42 @@ impl Database {
 
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.max_query_attempts == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max query attempts must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.batch_size == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer batch size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.batch_size > self.max_read_count, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer batch size must not be greater than the max read count".into(),
+            ))
+        })?;
+
+        when(self.lag_report_interval == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer lag report interval must be greater than zero".into(),
+            ))
         })
     }
 }
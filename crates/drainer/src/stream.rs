@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::LazyLock};
 
 use redis_interface as redis;
 use router_env::{logger, tracing};
@@ -8,6 +8,12 @@ use crate::{errors, metrics, Store};
 pub type StreamEntries = Vec<(String, HashMap<String, String>)>;
 pub type StreamReadResult = HashMap<String, StreamEntries>;
 
+/// Identifies this drainer instance as the holder of the stream locks it acquires.
+static DRAINER_INSTANCE_ID: LazyLock<String> = LazyLock::new(|| {
+    let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "unknown".to_owned());
+    format!("{host}:{}", std::process::id())
+});
+
 impl Store {
     #[inline(always)]
     pub fn drainer_stream(&self, shard_key: &str) -> String {
@@ -31,7 +37,11 @@ impl Store {
 
         match self
             .redis_conn
-            .set_key_if_not_exists_with_expiry(&stream_key_flag.as_str().into(), true, None)
+            .set_key_if_not_exists_with_expiry(
+                &stream_key_flag.as_str().into(),
+                DRAINER_INSTANCE_ID.as_str(),
+                None,
+            )
             .await
         {
             Ok(resp) => resp == redis::types::SetnxReply::KeySet,
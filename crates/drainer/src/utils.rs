@@ -20,6 +20,18 @@ pub fn parse_stream_entries<'a>(
     })
 }
 
+/// Returns the time elapsed since an entry was added to a stream, using the millisecond timestamp
+/// which forms the first part of the entry ID.
+pub(crate) fn get_stream_entry_age_millis(entry_id: &str) -> Option<u64> {
+    let added_at = entry_id.split_once('-')?.0.parse::<u64>().ok()?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?
+        .as_millis();
+
+    u64::try_from(now).ok()?.checked_sub(added_at)
+}
+
 pub(crate) fn deserialize_i64<'de, D>(deserializer: D) -> Result<i64, D::Error>
 where
     D: serde::Deserializer<'de>,
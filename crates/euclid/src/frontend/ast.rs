@@ -1,6 +1,8 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+#[cfg(feature = "ast_parser")]
+pub mod printer;
 
 use common_enums::RoutableConnectors;
 use common_utils::types::MinorUnit;
@@ -2,10 +2,82 @@ use common_utils::types::MinorUnit;
 use nom::{
     branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
 };
+use serde::Serialize;
 
 use crate::{frontend::ast, types::DummyOutput};
 pub type ParseResult<T, U> = nom::IResult<T, U, error::VerboseError<T>>;
 
+/// A failure to parse a program, located in the source text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, thiserror::Error)]
+#[error("line {line}, column {column}: {message}")]
+pub struct ParseError {
+    /// The line of the failure, starting from 1
+    pub line: usize,
+    /// The column of the failure in characters, starting from 1
+    pub column: usize,
+    /// What went wrong at the failure location
+    pub message: String,
+    /// The grammar rules that were being parsed at the failure location, outermost first
+    pub context: Vec<&'static str>,
+}
+
+impl ParseError {
+    fn new(source: &str, remaining: &str, message: String, context: Vec<&'static str>) -> Self {
+        let offset = source.len().saturating_sub(remaining.len());
+        let consumed = source.get(..offset).unwrap_or(source);
+        let current_line = consumed.rsplit('\n').next().unwrap_or(consumed);
+
+        Self {
+            line: consumed.matches('\n').count() + 1,
+            column: current_line.chars().count() + 1,
+            message,
+            context,
+        }
+    }
+
+    fn from_verbose_error(source: &str, err: error::VerboseError<&str>) -> Self {
+        // The entry which made the most progress through the source is the most precise one
+        let remaining = err
+            .errors
+            .iter()
+            .map(|(remaining, _)| *remaining)
+            .min_by_key(|remaining| remaining.len())
+            .unwrap_or(source);
+
+        let expected = err
+            .errors
+            .iter()
+            .find_map(|(_, kind)| match kind {
+                error::VerboseErrorKind::Char(c) => Some(format!("expected '{c}'")),
+                error::VerboseErrorKind::Nom(error::ErrorKind::Eof) => {
+                    Some("expected end of input".to_string())
+                }
+                error::VerboseErrorKind::Nom(_) => Some("invalid syntax".to_string()),
+                error::VerboseErrorKind::Context(_) => None,
+            })
+            .unwrap_or_else(|| "invalid syntax".to_string());
+
+        let found = remaining
+            .split_whitespace()
+            .next()
+            .map(|token| format!("found `{}`", token.chars().take(20).collect::<String>()))
+            .unwrap_or_else(|| "found end of input".to_string());
+
+        let mut context = err
+            .errors
+            .iter()
+            .rev()
+            .filter_map(|(_, kind)| match kind {
+                error::VerboseErrorKind::Context(context) => Some(*context),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        context.dedup();
+
+        Self::new(source, remaining, format!("{expected}, {found}"), context)
+    }
+}
+
 pub enum EuclidError {
     InvalidPercentage(String),
     InvalidConnector(String),
@@ -443,3 +515,63 @@ pub fn program<O: EuclidParsable + 'static>(input: &str) -> ParseResult<&str, as
         ),
     )(input)
 }
+
+/// Parses a complete program, reporting the location of the failure if the source is invalid
+pub fn parse_program<O: EuclidParsable + 'static>(
+    source: &str,
+) -> Result<ast::Program<O>, ParseError> {
+    let (remaining, program) = sequence::terminated(program::<O>, pchar::multispace0)(source)
+        .map_err(|err| match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                ParseError::from_verbose_error(source, err)
+            }
+            nom::Err::Incomplete(_) => ParseError::new(
+                source,
+                "",
+                "unexpected end of input".to_string(),
+                Vec::new(),
+            ),
+        })?;
+
+    if remaining.is_empty() {
+        Ok(program)
+    } else {
+        // The rules are parsed until the first one that fails, parse it again to report why
+        Err(match rule::<O>(remaining) {
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+                ParseError::from_verbose_error(source, err)
+            }
+            _ => ParseError::new(
+                source,
+                remaining,
+                "expected a rule or end of input".to_string(),
+                Vec::new(),
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_parse_program_reports_error_location() {
+        let program_str = r#"default: ["stripe"]
+
+rule_1: ["adyen"]
+{
+    payment_method = card & amount >= 
+}
+"#;
+
+        let err = parse_program::<DummyOutput>(program_str)
+            .map(|_| ())
+            .expect_err("Program should be invalid");
+
+        assert_eq!((err.line, err.column), (6, 1));
+        assert!(err.context.contains(&"rule"));
+    }
+}
@@ -0,0 +1,322 @@
+//! Renders a program into the textual DSL accepted by [`super::parser`]
+//!
+//! The output is canonical: printing a parsed program and parsing it again yields the same rules.
+//! The `metadata` maps of the program and of its comparisons have no textual representation, so
+//! they are not printed and are lost when a program is converted to text.
+//!
+//! Programs which the grammar cannot express, such as a comparison against a metadata key after
+//! the first comparison of a condition, fail to print instead of producing invalid text.
+
+use common_utils::types::MinorUnit;
+
+use crate::{frontend::ast, types::DummyOutput};
+
+const INDENT: &str = "    ";
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PrintError {
+    #[error("'{0}' cannot be written as an identifier")]
+    InvalidIdentifier(String),
+    #[error("'{0}' cannot be written as a string")]
+    InvalidString(String),
+    #[error("negative numbers are not supported: {0}")]
+    NegativeNumber(i64),
+    #[error("'{0}' is not a valid operator in a number comparison")]
+    InvalidNumberComparison(String),
+    #[error("empty {0} cannot be written")]
+    Empty(&'static str),
+    #[error("a metadata comparison can only be the first comparison of a condition")]
+    MisplacedMetadataComparison,
+    #[error("volume split percentage {0} cannot be written, it must be below 100")]
+    InvalidPercentage(u8),
+}
+
+pub trait EuclidPrintable: Sized {
+    fn print_output(&self) -> Result<String, PrintError>;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> Result<String, PrintError> {
+        if self.outputs.is_empty() {
+            return Err(PrintError::Empty("output"));
+        }
+
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| {
+                if output.contains('"') {
+                    Err(PrintError::InvalidString(output.clone()))
+                } else {
+                    Ok(format!("\"{output}\""))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(format!("[{}]", outputs.join(", ")))
+    }
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+pub fn identifier(value: &str) -> Result<String, PrintError> {
+    if is_identifier(value) {
+        Ok(value.to_string())
+    } else {
+        Err(PrintError::InvalidIdentifier(value.to_string()))
+    }
+}
+
+pub fn string_str(value: &str) -> Result<String, PrintError> {
+    if value.is_empty() || value.contains('"') {
+        Err(PrintError::InvalidString(value.to_string()))
+    } else {
+        Ok(format!("\"{value}\""))
+    }
+}
+
+fn number(value: MinorUnit) -> Result<String, PrintError> {
+    let value = value.get_amount_as_i64();
+    if value < 0 {
+        Err(PrintError::NegativeNumber(value))
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+fn array<T>(
+    values: &[T],
+    print: impl Fn(&T) -> Result<String, PrintError>,
+) -> Result<String, PrintError> {
+    if values.is_empty() {
+        return Err(PrintError::Empty("array"));
+    }
+
+    let values = values.iter().map(print).collect::<Result<Vec<_>, _>>()?;
+    Ok(format!("({})", values.join(", ")))
+}
+
+pub fn comparison_type(comparison_type: &ast::ComparisonType) -> &'static str {
+    match comparison_type {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+fn number_comparison(number_comparison: &ast::NumberComparison) -> Result<String, PrintError> {
+    let operator = comparison_type(&number_comparison.comparison_type);
+    if matches!(
+        number_comparison.comparison_type,
+        ast::ComparisonType::Equal | ast::ComparisonType::NotEqual
+    ) {
+        return Err(PrintError::InvalidNumberComparison(operator.to_string()));
+    }
+
+    Ok(format!("{operator}{}", number(number_comparison.number)?))
+}
+
+pub fn value_type(value: &ast::ValueType) -> Result<String, PrintError> {
+    match value {
+        ast::ValueType::Number(value) => number(*value),
+        ast::ValueType::EnumVariant(variant) => identifier(variant),
+        ast::ValueType::StrValue(value) => string_str(value),
+        ast::ValueType::NumberArray(values) => array(values, |value| number(*value)),
+        ast::ValueType::EnumVariantArray(variants) => {
+            array(variants, |variant| identifier(variant))
+        }
+        ast::ValueType::NumberComparisonArray(comparisons) => array(comparisons, number_comparison),
+        ast::ValueType::MetadataVariant(metadata) => Err(PrintError::InvalidString(format!(
+            "{}: {}",
+            metadata.key, metadata.value
+        ))),
+    }
+}
+
+pub fn comparison(comparison: &ast::Comparison) -> Result<String, PrintError> {
+    let operator = comparison_type(&comparison.comparison);
+
+    match &comparison.value {
+        ast::ValueType::MetadataVariant(metadata) if comparison.lhs == "metadata" => Ok(format!(
+            "{} {operator} {}",
+            string_str(&metadata.key)?,
+            string_str(&metadata.value)?
+        )),
+        value => {
+            let lhs_is_valid = !comparison.lhs.is_empty()
+                && comparison
+                    .lhs
+                    .chars()
+                    .all(|c| c.is_ascii_alphabetic() || c == '.' || c == '_');
+            if !lhs_is_valid {
+                return Err(PrintError::InvalidIdentifier(comparison.lhs.clone()));
+            }
+
+            Ok(format!(
+                "{} {operator} {}",
+                comparison.lhs,
+                value_type(value)?
+            ))
+        }
+    }
+}
+
+fn if_statement(
+    statement: &ast::IfStatement,
+    depth: usize,
+    out: &mut String,
+) -> Result<(), PrintError> {
+    if statement.condition.is_empty() {
+        return Err(PrintError::Empty("condition"));
+    }
+
+    // The grammar only accepts a comparison against a metadata key as the first comparison
+    if statement
+        .condition
+        .iter()
+        .skip(1)
+        .any(|comparison| matches!(comparison.value, ast::ValueType::MetadataVariant(_)))
+    {
+        return Err(PrintError::MisplacedMetadataComparison);
+    }
+
+    let indent = INDENT.repeat(depth);
+    let condition = statement
+        .condition
+        .iter()
+        .map(comparison)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    out.push_str(&indent);
+    out.push_str(&condition.join(" & "));
+
+    if let Some(nested) = &statement.nested {
+        out.push_str(" {\n");
+        for nested_statement in nested {
+            if_statement(nested_statement, depth + 1, out)?;
+        }
+        out.push_str(&indent);
+        out.push('}');
+    }
+    out.push('\n');
+
+    Ok(())
+}
+
+pub fn rule<O: EuclidPrintable>(rule: &ast::Rule<O>) -> Result<String, PrintError> {
+    let name = identifier(&rule.name)?;
+    if rule.statements.is_empty() {
+        return Err(PrintError::Empty("rule"));
+    }
+
+    let mut out = format!("{name}: {}\n{{\n", rule.connector_selection.print_output()?);
+    for statement in &rule.statements {
+        if_statement(statement, 1, &mut out)?;
+    }
+    out.push_str("}\n");
+
+    Ok(out)
+}
+
+pub fn program<O: EuclidPrintable>(program: &ast::Program<O>) -> Result<String, PrintError> {
+    if program.rules.is_empty() {
+        return Err(PrintError::Empty("program"));
+    }
+
+    let mut out = format!("default: {}\n", program.default_selection.print_output()?);
+    for program_rule in &program.rules {
+        out.push('\n');
+        out.push_str(&rule(program_rule)?);
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    #[test]
+    fn test_program_round_trip() {
+        let program_str = r#"
+        default: ["stripe",    "adyen"]
+
+        rule_1: ["stripe"]
+        {
+            pm = card & amount >= 500 {
+                "tier" = "gold" & card_type = (credit, debit)
+                amount = (>=100, <200)
+            }
+            currency /= (USD, EUR) & billing_name = "name"
+        }
+
+        rule_2: ["adyen"] { amount = (100, 200) }
+        "#;
+
+        let parsed =
+            parser::parse_program::<DummyOutput>(program_str).expect("Program should parse");
+        let printed = program(&parsed).expect("Program should print");
+        let reparsed =
+            parser::parse_program::<DummyOutput>(&printed).expect("Printed program should parse");
+
+        assert_eq!(program(&reparsed).expect("Program should print"), printed);
+        assert_eq!(reparsed.rules.len(), 2);
+        assert!(printed.starts_with("default: [\"stripe\", \"adyen\"]\n"));
+    }
+
+    #[test]
+    fn test_unprintable_program() {
+        let program = ast::Program {
+            default_selection: DummyOutput {
+                outputs: vec!["stripe".to_string()],
+            },
+            rules: vec![ast::Rule {
+                name: "rule 1".to_string(),
+                connector_selection: DummyOutput {
+                    outputs: vec!["adyen".to_string()],
+                },
+                statements: Vec::new(),
+            }],
+            metadata: std::collections::HashMap::new(),
+        };
+
+        assert_eq!(
+            super::program(&program),
+            Err(PrintError::InvalidIdentifier("rule 1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_misplaced_metadata_comparison_is_not_printed() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"] { "tier" = "gold" & pm = card }
+        "#;
+
+        let mut parsed =
+            parser::parse_program::<DummyOutput>(program_str).expect("Program should parse");
+        parsed
+            .rules
+            .iter_mut()
+            .flat_map(|rule| rule.statements.iter_mut())
+            .for_each(|statement| statement.condition.reverse());
+
+        assert_eq!(
+            program(&parsed),
+            Err(PrintError::MisplacedMetadataComparison)
+        );
+    }
+}
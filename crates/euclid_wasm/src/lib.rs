@@ -34,12 +34,13 @@ use wasm_bindgen::prelude::*;
 use crate::utils::JsResultExt;
 type JsResult = Result<JsValue, JsValue>;
 use api_models::payment_methods::CountryCodeWithName;
-#[cfg(feature = "payouts")]
-use common_enums::PayoutStatus;
 use common_enums::{
-    CountryAlpha2, DisputeStatus, EventClass, EventType, IntentStatus, MandateStatus,
-    MerchantCategoryCode, MerchantCategoryCodeWithName, RefundStatus,
+    CountryAlpha2, DisputeStatus, EventClass, EventType, IntentStatus, InvoiceStatus,
+    MandateStatus, MerchantCategoryCode, MerchantCategoryCodeWithName, RefundStatus,
+    SubscriptionStatus,
 };
+#[cfg(feature = "payouts")]
+use common_enums::{PayoutBatchStatus, PayoutStatus};
 use strum::IntoEnumIterator;
 
 struct SeedData {
@@ -514,5 +515,20 @@ pub fn get_valid_webhook_status(key: &str) -> JsResult {
                 .collect();
             Ok(serde_wasm_bindgen::to_value(&statuses)?)
         }
+        EventClass::Subscriptions => {
+            let statuses: Vec<SubscriptionStatus> = SubscriptionStatus::iter().collect();
+            Ok(serde_wasm_bindgen::to_value(&statuses)?)
+        }
+        EventClass::Invoices => {
+            let statuses: Vec<InvoiceStatus> = InvoiceStatus::iter().collect();
+            Ok(serde_wasm_bindgen::to_value(&statuses)?)
+        }
+        #[cfg(feature = "payouts")]
+        EventClass::PayoutBatches => {
+            let statuses: Vec<PayoutBatchStatus> = PayoutBatchStatus::iter()
+                .filter(|status| Into::<Option<EventType>>::into(*status).is_some())
+                .collect();
+            Ok(serde_wasm_bindgen::to_value(&statuses)?)
+        }
     }
 }
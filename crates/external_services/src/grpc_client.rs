@@ -17,6 +17,8 @@ use health_check_client::HealthCheckClient;
 #[cfg(feature = "dynamic_routing")]
 use hyper_util::client::legacy::connect::HttpConnector;
 #[cfg(feature = "dynamic_routing")]
+use redis_interface::RedisConnectionPool;
+#[cfg(feature = "dynamic_routing")]
 use router_env::logger;
 use serde;
 #[cfg(feature = "dynamic_routing")]
@@ -57,8 +59,13 @@ impl GrpcClientSettings {
     ///
     /// This function will panic if it fails to establish a connection with the gRPC server.
     /// This function will be called at service startup.
+    ///
+    /// The redis connection is used by the embedded dynamic routing engine, if it is configured.
     #[allow(clippy::expect_used)]
-    pub async fn get_grpc_client_interface(&self) -> Arc<GrpcClients> {
+    pub async fn get_grpc_client_interface(
+        &self,
+        #[cfg(feature = "dynamic_routing")] redis_conn: Option<Arc<RedisConnectionPool>>,
+    ) -> Arc<GrpcClients> {
         #[cfg(feature = "dynamic_routing")]
         let client =
             hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
@@ -69,7 +76,7 @@ impl GrpcClientSettings {
         let dynamic_routing_connection = self
             .dynamic_routing_client
             .clone()
-            .get_dynamic_routing_connection(client.clone())
+            .get_dynamic_routing_connection(client.clone(), redis_conn)
             .await
             .expect("Failed to establish a connection with the Dynamic Routing Server");
 
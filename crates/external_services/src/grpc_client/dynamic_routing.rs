@@ -1,9 +1,12 @@
 /// Module for Contract based routing
 pub mod contract_routing_client;
+/// Embedded Dynamic Routing Engine backed by Redis
+pub mod embedded_client;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use common_utils::errors::CustomResult;
+use redis_interface::RedisConnectionPool;
 use router_env::logger;
 use serde;
 /// Elimination Routing Client Interface Implementation
@@ -12,8 +15,9 @@ pub mod elimination_based_client;
 pub mod success_rate_client;
 
 pub use contract_routing_client::ContractScoreCalculatorClient;
-pub use elimination_based_client::EliminationAnalyserClient;
-pub use success_rate_client::SuccessRateCalculatorClient;
+pub use elimination_based_client::{EliminationAnalyserClient, EliminationBasedRouting};
+pub use embedded_client::EmbeddedDynamicRoutingClient;
+pub use success_rate_client::{SuccessBasedDynamicRouting, SuccessRateCalculatorClient};
 
 use super::Client;
 /// Result type for Dynamic Routing
@@ -47,11 +51,11 @@ pub enum DynamicRoutingError {
 #[derive(Debug, Clone)]
 pub struct RoutingStrategy {
     /// success rate service for Dynamic Routing
-    pub success_rate_client: Option<SuccessRateCalculatorClient<Client>>,
+    pub success_rate_client: Option<Box<dyn SuccessBasedDynamicRouting>>,
     /// contract based routing service for Dynamic Routing
     pub contract_based_client: Option<ContractScoreCalculatorClient<Client>>,
     /// elimination service for Dynamic Routing
-    pub elimination_based_client: Option<EliminationAnalyserClient<Client>>,
+    pub elimination_based_client: Option<Box<dyn EliminationBasedRouting>>,
 }
 
 /// Contains the Dynamic Routing Client Config
@@ -67,31 +71,72 @@ pub enum DynamicRoutingClientConfig {
         /// Service name
         service: String,
     },
+    /// If dynamic routing should be performed in-process, with the windows and buckets kept in Redis
+    Embedded {
+        /// Configs for the embedded dynamic routing engine
+        embedded: EmbeddedDynamicRoutingConfig,
+    },
     #[default]
     /// If the dynamic routing client config has been disabled
     Disabled,
 }
 
+/// Configs for the embedded dynamic routing engine
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct EmbeddedDynamicRoutingConfig {
+    /// Time in seconds after which an untouched success rate window or elimination bucket expires
+    pub ttl_in_seconds: i64,
+}
+
+impl Default for EmbeddedDynamicRoutingConfig {
+    fn default() -> Self {
+        Self {
+            // 7 days
+            ttl_in_seconds: 604800,
+        }
+    }
+}
+
 impl DynamicRoutingClientConfig {
     /// establish connection with the server
     pub async fn get_dynamic_routing_connection(
         self,
         client: Client,
+        redis_conn: Option<Arc<RedisConnectionPool>>,
     ) -> Result<RoutingStrategy, Box<dyn std::error::Error>> {
-        let (success_rate_client, contract_based_client, elimination_based_client) = match self {
+        let (success_rate_client, contract_based_client, elimination_based_client): (
+            Option<Box<dyn SuccessBasedDynamicRouting>>,
+            Option<ContractScoreCalculatorClient<Client>>,
+            Option<Box<dyn EliminationBasedRouting>>,
+        ) = match self {
             Self::Enabled { host, port, .. } => {
                 let uri = format!("http://{host}:{port}").parse::<tonic::transport::Uri>()?;
                 logger::info!("Connection established with dynamic routing gRPC Server");
                 (
-                    Some(SuccessRateCalculatorClient::with_origin(
+                    Some(Box::new(SuccessRateCalculatorClient::with_origin(
                         client.clone(),
                         uri.clone(),
-                    )),
+                    ))),
                     Some(ContractScoreCalculatorClient::with_origin(
                         client.clone(),
                         uri.clone(),
                     )),
-                    Some(EliminationAnalyserClient::with_origin(client, uri)),
+                    Some(Box::new(EliminationAnalyserClient::with_origin(
+                        client, uri,
+                    ))),
+                )
+            }
+            Self::Embedded { embedded } => {
+                let redis_conn = redis_conn.ok_or(
+                    "Redis connection is required for the embedded dynamic routing engine",
+                )?;
+                let embedded_client = EmbeddedDynamicRoutingClient::new(redis_conn, embedded);
+                logger::info!("Embedded dynamic routing engine initialized");
+                (
+                    Some(Box::new(embedded_client.clone())),
+                    None,
+                    Some(Box::new(embedded_client)),
                 )
             }
             Self::Disabled => (None, None, None),
@@ -0,0 +1,937 @@
+use std::sync::Arc;
+
+use api_models::routing::{
+    EliminationAnalyserConfig as EliminationConfig, EliminationRoutingConfig,
+    RoutableConnectorChoice, RoutableConnectorChoiceWithBucketName,
+    RoutableConnectorChoiceWithStatus, SuccessBasedRoutingConfig,
+};
+use common_utils::{date_time, ext_traits::OptionExt, transformers::ForeignTryFrom};
+use error_stack::ResultExt;
+use rand::{seq::SliceRandom, Rng};
+use redis_interface::RedisConnectionPool;
+use router_env::{instrument, logger, tracing};
+
+use super::{
+    elimination_based_client::{
+        elimination_rate::{
+            update_elimination_bucket_response, BucketInformation, EliminationInformation,
+            LabelWithStatus as LabelWithEliminationStatus,
+        },
+        EliminationBasedRouting, EliminationBucketConfig, EliminationResponse,
+        InvalidateBucketResponse, UpdateEliminationBucketResponse,
+    },
+    success_rate_client::{
+        success_rate::{update_success_rate_window_response, LabelWithScore, RoutingApproach},
+        CalGlobalSuccessRateResponse, CalSuccessRateConfig, CalSuccessRateResponse,
+        InvalidateWindowsResponse, ProtoSpecificityLevel, SuccessBasedDynamicRouting,
+        UpdateSuccessRateWindowConfig, UpdateSuccessRateWindowResponse,
+    },
+    DynamicRoutingError, DynamicRoutingResult, EmbeddedDynamicRoutingConfig,
+};
+use crate::grpc_client::GrpcHeaders;
+
+/// Id under which the windows and buckets shared by all the entities of a tenant are stored
+const GLOBAL_ENTITY_ID: &str = "global";
+
+/// Closes the current block into the aggregates list once it is full or has outlived its duration
+///
+/// KEYS: current block, aggregates
+/// ARGV: status, max_total_count, block_duration_in_secs, max_aggregates_size, now, ttl
+const UPDATE_SUCCESS_RATE_WINDOW_SCRIPT: &str = r#"
+local status = tonumber(ARGV[1])
+local max_total_count = tonumber(ARGV[2])
+local block_duration = tonumber(ARGV[3])
+local max_aggregates_size = tonumber(ARGV[4])
+local now = tonumber(ARGV[5])
+local ttl = tonumber(ARGV[6])
+
+local block = redis.call('HMGET', KEYS[1], 'success', 'total', 'started_at')
+local success = tonumber(block[1]) or 0
+local total = tonumber(block[2]) or 0
+local started_at = tonumber(block[3]) or now
+
+if total > 0 and block_duration > 0 and now - started_at >= block_duration then
+    redis.call('LPUSH', KEYS[2], success .. ':' .. total)
+    success, total, started_at = 0, 0, now
+end
+
+total = total + 1
+if status == 1 then
+    success = success + 1
+end
+
+if total >= max_total_count then
+    redis.call('LPUSH', KEYS[2], success .. ':' .. total)
+    redis.call('DEL', KEYS[1])
+else
+    redis.call('HSET', KEYS[1], 'success', success, 'total', total, 'started_at', started_at)
+    redis.call('EXPIRE', KEYS[1], ttl)
+end
+
+redis.call('LTRIM', KEYS[2], 0, max_aggregates_size - 1)
+redis.call('EXPIRE', KEYS[2], ttl)
+"#;
+
+/// Leaks the bucket for the time elapsed since the last leak and adds the reported failure to it
+///
+/// KEYS: bucket, bucket names
+/// ARGV: bucket_name, bucket_size, bucket_leak_interval_in_secs, now, ttl
+const UPDATE_ELIMINATION_BUCKET_SCRIPT: &str = r#"
+local bucket_size = tonumber(ARGV[2])
+local leak_interval = tonumber(ARGV[3])
+local now = tonumber(ARGV[4])
+local ttl = tonumber(ARGV[5])
+
+local bucket = redis.call('HMGET', KEYS[1], 'level', 'last_leaked_at')
+local level = tonumber(bucket[1]) or 0
+local last_leaked_at = tonumber(bucket[2]) or now
+
+if leak_interval > 0 then
+    local leaked = math.floor((now - last_leaked_at) / leak_interval)
+    if leaked > 0 then
+        level = math.max(level - leaked, 0)
+        last_leaked_at = last_leaked_at + leaked * leak_interval
+    end
+end
+
+if level == 0 then
+    last_leaked_at = now
+end
+level = math.min(level + 1, bucket_size)
+
+redis.call('HSET', KEYS[1], 'level', level, 'last_leaked_at', last_leaked_at)
+redis.call('EXPIRE', KEYS[1], ttl)
+redis.call('SADD', KEYS[2], ARGV[1])
+redis.call('EXPIRE', KEYS[2], ttl)
+"#;
+
+/// Returns the names of the buckets of a label which are still full after leaking
+///
+/// KEYS: buckets
+/// ARGV: bucket_size, bucket_leak_interval_in_secs, now, followed by the name of each bucket
+const FETCH_ELIMINATED_BUCKETS_SCRIPT: &str = r#"
+local bucket_size = tonumber(ARGV[1])
+local leak_interval = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+
+local eliminated = {}
+for index, bucket_key in ipairs(KEYS) do
+    local bucket = redis.call('HMGET', bucket_key, 'level', 'last_leaked_at')
+    local level = tonumber(bucket[1]) or 0
+    local last_leaked_at = tonumber(bucket[2]) or now
+    if leak_interval > 0 then
+        level = level - math.floor((now - last_leaked_at) / leak_interval)
+    end
+    if level >= bucket_size then
+        table.insert(eliminated, ARGV[index + 3])
+    end
+end
+return eliminated
+"#;
+
+/// Dynamic routing engine which runs in-process and keeps the success rate windows and the
+/// elimination buckets in Redis
+#[derive(Clone)]
+pub struct EmbeddedDynamicRoutingClient {
+    redis_conn: Arc<RedisConnectionPool>,
+    config: EmbeddedDynamicRoutingConfig,
+}
+
+impl std::fmt::Debug for EmbeddedDynamicRoutingClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmbeddedDynamicRoutingClient")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The kind of state stored for an entity
+#[derive(Debug, Clone, Copy)]
+enum RoutingStateKind {
+    SuccessRate,
+    Elimination,
+}
+
+impl std::fmt::Display for RoutingStateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SuccessRate => write!(f, "success_rate"),
+            Self::Elimination => write!(f, "elimination"),
+        }
+    }
+}
+
+impl EmbeddedDynamicRoutingClient {
+    /// Create a new embedded dynamic routing engine on top of the given redis connection
+    pub fn new(redis_conn: Arc<RedisConnectionPool>, config: EmbeddedDynamicRoutingConfig) -> Self {
+        Self { redis_conn, config }
+    }
+
+    /// Prefix of all the keys of an entity, the entity is used as the hash tag so that all
+    /// the keys of a script land in the same slot
+    fn get_entity_key_prefix(tenant_id: &str, id: &str, kind: RoutingStateKind) -> String {
+        format!("dynamic_routing:{{{tenant_id}:{id}}}:{kind}")
+    }
+
+    fn get_label_key_prefix(
+        tenant_id: &str,
+        id: &str,
+        kind: RoutingStateKind,
+        params: &str,
+        label: &str,
+    ) -> String {
+        format!(
+            "{}:{params}:{label}",
+            Self::get_entity_key_prefix(tenant_id, id, kind)
+        )
+    }
+
+    async fn update_success_rate_window(
+        &self,
+        label_key_prefix: &str,
+        status: bool,
+        config: &UpdateSuccessRateWindowConfig,
+        max_total_count: u64,
+        now: i64,
+    ) -> DynamicRoutingResult<()> {
+        let block_duration_in_secs = get_block_duration_in_secs(config);
+
+        self.redis_conn
+            .evaluate_redis_script::<_, ()>(
+                UPDATE_SUCCESS_RATE_WINDOW_SCRIPT,
+                vec![
+                    self.redis_conn
+                        .add_prefix(&format!("{label_key_prefix}:current_block")),
+                    self.redis_conn
+                        .add_prefix(&format!("{label_key_prefix}:aggregates")),
+                ],
+                vec![
+                    u8::from(status).to_string(),
+                    max_total_count.to_string(),
+                    block_duration_in_secs.to_string(),
+                    config.max_aggregates_size.to_string(),
+                    now.to_string(),
+                    self.config.ttl_in_seconds.to_string(),
+                ],
+            )
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to update the success rate window".to_string(),
+            ))
+    }
+
+    /// Computes the success rate of a label over its aggregated blocks, falling back to the
+    /// default success rate until enough blocks have been aggregated
+    async fn fetch_success_rate(
+        &self,
+        label_key_prefix: &str,
+        min_aggregates_size: u32,
+        default_success_rate: f64,
+    ) -> DynamicRoutingResult<f64> {
+        let aggregates = self
+            .redis_conn
+            .get_list_elements(&format!("{label_key_prefix}:aggregates").into(), 0, -1)
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to fetch the success rate window".to_string(),
+            ))?;
+
+        Ok(get_success_rate_from_aggregates(
+            &aggregates,
+            min_aggregates_size,
+            default_success_rate,
+        ))
+    }
+
+    async fn update_elimination_bucket(
+        &self,
+        label_key_prefix: &str,
+        bucket_name: &str,
+        config: &EliminationBucketConfig,
+        now: i64,
+    ) -> DynamicRoutingResult<()> {
+        self.redis_conn
+            .evaluate_redis_script::<_, ()>(
+                UPDATE_ELIMINATION_BUCKET_SCRIPT,
+                vec![
+                    self.redis_conn
+                        .add_prefix(&format!("{label_key_prefix}:bucket:{bucket_name}")),
+                    self.redis_conn
+                        .add_prefix(&format!("{label_key_prefix}:buckets")),
+                ],
+                vec![
+                    bucket_name.to_string(),
+                    config.bucket_size.to_string(),
+                    config.bucket_leak_interval_in_secs.to_string(),
+                    now.to_string(),
+                    self.config.ttl_in_seconds.to_string(),
+                ],
+            )
+            .await
+            .change_context(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Failed to update the elimination bucket".to_string(),
+            ))
+    }
+
+    async fn fetch_bucket_information(
+        &self,
+        label_key_prefix: &str,
+        config: &EliminationBucketConfig,
+        now: i64,
+    ) -> DynamicRoutingResult<BucketInformation> {
+        let bucket_names = self
+            .redis_conn
+            .get_set_members(&format!("{label_key_prefix}:buckets").into())
+            .await
+            .change_context(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Failed to fetch the elimination bucket names".to_string(),
+            ))?;
+        if bucket_names.is_empty() {
+            return Ok(BucketInformation {
+                is_eliminated: false,
+                bucket_name: Vec::new(),
+            });
+        }
+
+        // The bucket keys share the hash tag of the entity, so that they land in the same slot
+        let bucket_keys: Vec<String> = bucket_names
+            .iter()
+            .map(|bucket_name| {
+                self.redis_conn
+                    .add_prefix(&format!("{label_key_prefix}:bucket:{bucket_name}"))
+            })
+            .collect();
+        let args: Vec<String> = [
+            config.bucket_size.to_string(),
+            config.bucket_leak_interval_in_secs.to_string(),
+            now.to_string(),
+        ]
+        .into_iter()
+        .chain(bucket_names)
+        .collect();
+
+        let bucket_name = self
+            .redis_conn
+            .evaluate_redis_script::<_, Vec<String>>(
+                FETCH_ELIMINATED_BUCKETS_SCRIPT,
+                bucket_keys,
+                args,
+            )
+            .await
+            .change_context(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Failed to fetch the elimination buckets".to_string(),
+            ))?;
+
+        Ok(BucketInformation {
+            is_eliminated: !bucket_name.is_empty(),
+            bucket_name,
+        })
+    }
+
+    /// Deletes every key stored for the entity
+    async fn invalidate_entity_keys(
+        &self,
+        tenant_id: &str,
+        id: &str,
+        kind: RoutingStateKind,
+    ) -> error_stack::Result<(), redis_interface::errors::RedisError> {
+        let pattern = format!("{}:*", Self::get_entity_key_prefix(tenant_id, id, kind));
+        let key_prefix = self.redis_conn.add_prefix("");
+
+        let keys = self
+            .redis_conn
+            .scan(&pattern.into(), None, None)
+            .await?
+            .into_iter()
+            .map(|key| {
+                key.strip_prefix(&key_prefix)
+                    .map(ToString::to_string)
+                    .unwrap_or(key)
+                    .into()
+            })
+            .collect::<Vec<_>>();
+
+        self.redis_conn.delete_multiple_keys(&keys).await?;
+
+        Ok(())
+    }
+}
+
+/// Duration after which the current block of a success rate window is closed, zero if the
+/// block is only closed once it is full
+fn get_block_duration_in_secs(config: &UpdateSuccessRateWindowConfig) -> u64 {
+    config
+        .current_block_threshold
+        .as_ref()
+        .and_then(|threshold| threshold.duration_in_mins)
+        .map(|duration_in_mins| duration_in_mins.saturating_mul(60))
+        .unwrap_or_default()
+}
+
+/// Computes the success rate over the aggregated blocks of a window, each stored as
+/// `success:total`, falling back to the default success rate until enough blocks have been
+/// aggregated
+fn get_success_rate_from_aggregates(
+    aggregates: &[String],
+    min_aggregates_size: u32,
+    default_success_rate: f64,
+) -> f64 {
+    let (success, total) = aggregates
+        .iter()
+        .filter_map(|aggregate| {
+            let (success, total) = aggregate.split_once(':')?;
+            Some((success.parse::<u32>().ok()?, total.parse::<u32>().ok()?))
+        })
+        .fold(
+            (0_u32, 0_u32),
+            |(acc_success, acc_total), (success, total)| {
+                (
+                    acc_success.saturating_add(success),
+                    acc_total.saturating_add(total),
+                )
+            },
+        );
+
+    let has_enough_aggregates = u32::try_from(aggregates.len())
+        .map(|aggregates_size| aggregates_size >= min_aggregates_size)
+        .unwrap_or(true);
+
+    if has_enough_aggregates && total > 0 {
+        f64::from(success) / f64::from(total) * 100.0
+    } else {
+        default_success_rate
+    }
+}
+
+fn get_success_rate_config(
+    success_rate_based_config: SuccessBasedRoutingConfig,
+) -> DynamicRoutingResult<api_models::routing::SuccessBasedRoutingConfigBody> {
+    success_rate_based_config
+        .config
+        .or(SuccessBasedRoutingConfig::default().config)
+        .get_required_value("config")
+        .change_context(DynamicRoutingError::MissingRequiredField {
+            field: "config".to_string(),
+        })
+}
+
+fn get_elimination_config(
+    configs: Option<EliminationConfig>,
+) -> DynamicRoutingResult<EliminationBucketConfig> {
+    configs
+        .or(EliminationRoutingConfig::default().elimination_analyser_config)
+        .get_required_value("elimination_analyser_config")
+        .change_context(DynamicRoutingError::MissingRequiredField {
+            field: "elimination_analyser_config".to_string(),
+        })
+        .and_then(EliminationBucketConfig::foreign_try_from)
+}
+
+/// Orders the labels for the next payment, the labels are either shuffled to explore or ranked
+/// by their score to exploit
+fn rank_labels_with_score(
+    mut labels_with_score: Vec<LabelWithScore>,
+    config: &CalSuccessRateConfig,
+) -> CalSuccessRateResponse {
+    let mut rng = rand::thread_rng();
+    let should_explore = config
+        .exploration_percent
+        .is_some_and(|exploration_percent| rng.gen::<f64>() * 100.0 < exploration_percent);
+
+    let routing_approach = if should_explore {
+        labels_with_score.shuffle(&mut rng);
+        RoutingApproach::Exploration
+    } else {
+        if config.shuffle_on_tie_during_exploitation.unwrap_or(false) {
+            labels_with_score.shuffle(&mut rng);
+        }
+        labels_with_score.sort_by(|a, b| b.score.total_cmp(&a.score));
+        RoutingApproach::Exploitation
+    };
+
+    CalSuccessRateResponse {
+        labels_with_score,
+        routing_approach: routing_approach.into(),
+    }
+}
+
+#[async_trait::async_trait]
+impl SuccessBasedDynamicRouting for EmbeddedDynamicRoutingClient {
+    #[instrument(skip_all)]
+    async fn calculate_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalSuccessRateResponse> {
+        let config = CalSuccessRateConfig::foreign_try_from(get_success_rate_config(
+            success_rate_based_config,
+        )?)?;
+
+        let is_global = config.specificity_level == Some(ProtoSpecificityLevel::Global.into());
+
+        let mut labels_with_score = Vec::with_capacity(label_input.len());
+        for conn_choice in label_input {
+            let label_key_prefix = if is_global {
+                Self::get_label_key_prefix(
+                    &headers.tenant_id,
+                    GLOBAL_ENTITY_ID,
+                    RoutingStateKind::SuccessRate,
+                    &params,
+                    &conn_choice.connector.to_string(),
+                )
+            } else {
+                Self::get_label_key_prefix(
+                    &headers.tenant_id,
+                    &id,
+                    RoutingStateKind::SuccessRate,
+                    &params,
+                    &conn_choice.to_string(),
+                )
+            };
+
+            let score = self
+                .fetch_success_rate(
+                    &label_key_prefix,
+                    config.min_aggregates_size,
+                    config.default_success_rate,
+                )
+                .await?;
+
+            labels_with_score.push(LabelWithScore {
+                score,
+                label: conn_choice.to_string(),
+            });
+        }
+
+        let response = rank_labels_with_score(labels_with_score, &config);
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoiceWithStatus>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateSuccessRateWindowResponse> {
+        let config = UpdateSuccessRateWindowConfig::foreign_try_from(get_success_rate_config(
+            success_rate_based_config,
+        )?)?;
+
+        let max_total_count = config
+            .current_block_threshold
+            .as_ref()
+            .map(|threshold| threshold.max_total_count)
+            .get_required_value("current_block_threshold")
+            .change_context(DynamicRoutingError::MissingRequiredField {
+                field: "current_block_threshold".to_string(),
+            })?;
+
+        let now = date_time::now_unix_timestamp();
+
+        for conn_choice in label_input {
+            let entity_label_key_prefix = Self::get_label_key_prefix(
+                &headers.tenant_id,
+                &id,
+                RoutingStateKind::SuccessRate,
+                &params,
+                &conn_choice.routable_connector_choice.to_string(),
+            );
+            self.update_success_rate_window(
+                &entity_label_key_prefix,
+                conn_choice.status,
+                &config,
+                max_total_count,
+                now,
+            )
+            .await?;
+
+            let global_label_key_prefix = Self::get_label_key_prefix(
+                &headers.tenant_id,
+                GLOBAL_ENTITY_ID,
+                RoutingStateKind::SuccessRate,
+                &params,
+                &conn_choice.routable_connector_choice.connector.to_string(),
+            );
+            self.update_success_rate_window(
+                &global_label_key_prefix,
+                conn_choice.status,
+                &config,
+                max_total_count,
+                now,
+            )
+            .await?;
+        }
+
+        let response = UpdateSuccessRateWindowResponse {
+            status: update_success_rate_window_response::UpdationStatus::WindowUpdationSucceeded
+                .into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_success_rate_routing_keys(
+        &self,
+        id: String,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateWindowsResponse> {
+        self.invalidate_entity_keys(&headers.tenant_id, &id, RoutingStateKind::SuccessRate)
+            .await
+            .change_context(DynamicRoutingError::SuccessRateBasedRoutingFailure(
+                "Failed to invalidate the success rate routing keys".to_string(),
+            ))?;
+
+        let response = InvalidateWindowsResponse::default();
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn calculate_entity_and_global_success_rate(
+        &self,
+        id: String,
+        success_rate_based_config: SuccessBasedRoutingConfig,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<CalGlobalSuccessRateResponse> {
+        let config = CalSuccessRateConfig::foreign_try_from(get_success_rate_config(
+            success_rate_based_config,
+        )?)?;
+
+        let mut entity_scores_with_labels = Vec::with_capacity(label_input.len());
+        let mut global_scores_with_labels = Vec::with_capacity(label_input.len());
+        for conn_choice in label_input {
+            let entity_label = conn_choice.to_string();
+            let score = self
+                .fetch_success_rate(
+                    &Self::get_label_key_prefix(
+                        &headers.tenant_id,
+                        &id,
+                        RoutingStateKind::SuccessRate,
+                        &params,
+                        &entity_label,
+                    ),
+                    config.min_aggregates_size,
+                    config.default_success_rate,
+                )
+                .await?;
+            entity_scores_with_labels.push(LabelWithScore {
+                score,
+                label: entity_label,
+            });
+
+            let global_label = conn_choice.connector.to_string();
+            let score = self
+                .fetch_success_rate(
+                    &Self::get_label_key_prefix(
+                        &headers.tenant_id,
+                        GLOBAL_ENTITY_ID,
+                        RoutingStateKind::SuccessRate,
+                        &params,
+                        &global_label,
+                    ),
+                    config.min_aggregates_size,
+                    config.default_success_rate,
+                )
+                .await?;
+            global_scores_with_labels.push(LabelWithScore {
+                score,
+                label: global_label,
+            });
+        }
+
+        entity_scores_with_labels.sort_by(|a, b| b.score.total_cmp(&a.score));
+        global_scores_with_labels.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let response = CalGlobalSuccessRateResponse {
+            entity_scores_with_labels,
+            global_scores_with_labels,
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+}
+
+#[async_trait::async_trait]
+impl EliminationBasedRouting for EmbeddedDynamicRoutingClient {
+    #[instrument(skip_all)]
+    async fn perform_elimination_routing(
+        &self,
+        id: String,
+        params: String,
+        label_input: Vec<RoutableConnectorChoice>,
+        configs: Option<EliminationConfig>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<EliminationResponse> {
+        let config = get_elimination_config(configs)?;
+        let now = date_time::now_unix_timestamp();
+
+        let mut labels_with_status = Vec::with_capacity(label_input.len());
+        for conn_choice in label_input {
+            let label = conn_choice.to_string();
+
+            let entity = self
+                .fetch_bucket_information(
+                    &Self::get_label_key_prefix(
+                        &headers.tenant_id,
+                        &id,
+                        RoutingStateKind::Elimination,
+                        &params,
+                        &label,
+                    ),
+                    &config,
+                    now,
+                )
+                .await?;
+
+            let global = self
+                .fetch_bucket_information(
+                    &Self::get_label_key_prefix(
+                        &headers.tenant_id,
+                        GLOBAL_ENTITY_ID,
+                        RoutingStateKind::Elimination,
+                        &params,
+                        &conn_choice.connector.to_string(),
+                    ),
+                    &config,
+                    now,
+                )
+                .await?;
+
+            labels_with_status.push(LabelWithEliminationStatus {
+                label,
+                elimination_information: Some(EliminationInformation {
+                    entity: Some(entity),
+                    global: Some(global),
+                }),
+            });
+        }
+
+        let response = EliminationResponse { labels_with_status };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_elimination_bucket_config(
+        &self,
+        id: String,
+        params: String,
+        report: Vec<RoutableConnectorChoiceWithBucketName>,
+        configs: Option<EliminationConfig>,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<UpdateEliminationBucketResponse> {
+        let config = get_elimination_config(configs)?;
+        let now = date_time::now_unix_timestamp();
+
+        for conn_choice_with_bucket in report {
+            let conn_choice = conn_choice_with_bucket.routable_connector_choice;
+
+            self.update_elimination_bucket(
+                &Self::get_label_key_prefix(
+                    &headers.tenant_id,
+                    &id,
+                    RoutingStateKind::Elimination,
+                    &params,
+                    &conn_choice.to_string(),
+                ),
+                &conn_choice_with_bucket.bucket_name,
+                &config,
+                now,
+            )
+            .await?;
+
+            self.update_elimination_bucket(
+                &Self::get_label_key_prefix(
+                    &headers.tenant_id,
+                    GLOBAL_ENTITY_ID,
+                    RoutingStateKind::Elimination,
+                    &params,
+                    &conn_choice.connector.to_string(),
+                ),
+                &conn_choice_with_bucket.bucket_name,
+                &config,
+                now,
+            )
+            .await?;
+        }
+
+        let response = UpdateEliminationBucketResponse {
+            status: update_elimination_bucket_response::UpdationStatus::BucketUpdationSucceeded
+                .into(),
+        };
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+
+    #[instrument(skip_all)]
+    async fn invalidate_elimination_bucket(
+        &self,
+        id: String,
+        headers: GrpcHeaders,
+    ) -> DynamicRoutingResult<InvalidateBucketResponse> {
+        self.invalidate_entity_keys(&headers.tenant_id, &id, RoutingStateKind::Elimination)
+            .await
+            .change_context(DynamicRoutingError::EliminationRateRoutingFailure(
+                "Failed to invalidate the elimination bucket".to_string(),
+            ))?;
+
+        let response = InvalidateBucketResponse::default();
+
+        logger::info!(dynamic_routing_response=?response);
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grpc_client::dynamic_routing::success_rate_client::DynamicCurrentThreshold;
+
+    fn get_aggregates(aggregates: &[&str]) -> Vec<String> {
+        aggregates.iter().map(ToString::to_string).collect()
+    }
+
+    fn get_label_with_score(label: &str, score: f64) -> LabelWithScore {
+        LabelWithScore {
+            score,
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_success_rate_from_aggregates() {
+        let aggregates = get_aggregates(&["8:10", "2:10"]);
+
+        assert_eq!(
+            get_success_rate_from_aggregates(&aggregates, 2, 100.0),
+            50.0
+        );
+    }
+
+    #[test]
+    fn test_success_rate_defaults_until_enough_aggregates() {
+        let aggregates = get_aggregates(&["8:10", "2:10"]);
+
+        assert_eq!(
+            get_success_rate_from_aggregates(&aggregates, 3, 100.0),
+            100.0
+        );
+        assert_eq!(get_success_rate_from_aggregates(&[], 0, 100.0), 100.0);
+    }
+
+    #[test]
+    fn test_success_rate_ignores_malformed_aggregates() {
+        let aggregates = get_aggregates(&["3:4", "invalid", "1:x"]);
+
+        assert_eq!(
+            get_success_rate_from_aggregates(&aggregates, 1, 100.0),
+            75.0
+        );
+    }
+
+    #[test]
+    fn test_block_duration_in_secs() {
+        let config = |duration_in_mins| UpdateSuccessRateWindowConfig {
+            max_aggregates_size: 5,
+            current_block_threshold: Some(DynamicCurrentThreshold {
+                duration_in_mins,
+                max_total_count: 10,
+            }),
+        };
+
+        assert_eq!(get_block_duration_in_secs(&config(Some(5))), 300);
+        assert_eq!(get_block_duration_in_secs(&config(None)), 0);
+        assert_eq!(
+            get_block_duration_in_secs(&UpdateSuccessRateWindowConfig::default()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_labels_ranked_by_score_during_exploitation() {
+        let labels_with_score = vec![
+            get_label_with_score("stripe", 50.0),
+            get_label_with_score("adyen", 90.0),
+            get_label_with_score("checkout", 70.0),
+        ];
+        let config = CalSuccessRateConfig {
+            exploration_percent: Some(0.0),
+            ..Default::default()
+        };
+
+        let response = rank_labels_with_score(labels_with_score, &config);
+
+        let labels = response
+            .labels_with_score
+            .iter()
+            .map(|label_with_score| label_with_score.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["adyen", "checkout", "stripe"]);
+        assert_eq!(
+            response.routing_approach,
+            i32::from(RoutingApproach::Exploitation)
+        );
+    }
+
+    #[test]
+    fn test_labels_shuffled_during_exploration() {
+        let labels_with_score = vec![
+            get_label_with_score("stripe", 50.0),
+            get_label_with_score("adyen", 90.0),
+        ];
+        let config = CalSuccessRateConfig {
+            exploration_percent: Some(100.0),
+            ..Default::default()
+        };
+
+        let response = rank_labels_with_score(labels_with_score, &config);
+
+        assert_eq!(response.labels_with_score.len(), 2);
+        assert_eq!(
+            response.routing_approach,
+            i32::from(RoutingApproach::Exploration)
+        );
+    }
+
+    #[test]
+    fn test_label_keys_share_the_entity_hash_tag() {
+        let entity_key_prefix = EmbeddedDynamicRoutingClient::get_entity_key_prefix(
+            "public",
+            "profile_123",
+            RoutingStateKind::SuccessRate,
+        );
+        let label_key_prefix = EmbeddedDynamicRoutingClient::get_label_key_prefix(
+            "public",
+            "profile_123",
+            RoutingStateKind::SuccessRate,
+            "card",
+            "stripe",
+        );
+
+        assert!(entity_key_prefix.starts_with("dynamic_routing:{public:profile_123}:"));
+        assert_eq!(label_key_prefix, format!("{entity_key_prefix}:card:stripe"));
+    }
+}
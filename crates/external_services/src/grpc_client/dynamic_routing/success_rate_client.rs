@@ -1,3 +1,5 @@
+use std::fmt::Debug;
+
 use api_models::routing::{
     CurrentBlockThreshold, RoutableConnectorChoice, RoutableConnectorChoiceWithStatus,
     SuccessBasedRoutingConfig, SuccessBasedRoutingConfigBody, SuccessRateSpecificityLevel,
@@ -28,7 +30,7 @@ use super::{Client, DynamicRoutingError, DynamicRoutingResult};
 use crate::grpc_client::{self, GrpcHeaders};
 /// The trait Success Based Dynamic Routing would have the functions required to support the calculation and updation window
 #[async_trait::async_trait]
-pub trait SuccessBasedDynamicRouting: dyn_clone::DynClone + Send + Sync {
+pub trait SuccessBasedDynamicRouting: dyn_clone::DynClone + Debug + Send + Sync {
     /// To calculate the success rate for the list of chosen connectors
     async fn calculate_success_rate(
         &self,
@@ -64,6 +66,8 @@ pub trait SuccessBasedDynamicRouting: dyn_clone::DynClone + Send + Sync {
     ) -> DynamicRoutingResult<CalGlobalSuccessRateResponse>;
 }
 
+dyn_clone::clone_trait_object!(SuccessBasedDynamicRouting);
+
 #[async_trait::async_trait]
 impl SuccessBasedDynamicRouting for SuccessRateCalculatorClient<Client> {
     #[instrument(skip_all)]
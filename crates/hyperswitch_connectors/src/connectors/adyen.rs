@@ -2215,6 +2215,9 @@ impl FileUpload for Adyen {
                     })?
                 }
             }
+            FilePurpose::PayoutBatch => Err(errors::ConnectorError::FileValidationFailed {
+                reason: "payout batch files are not uploaded to the connector".to_owned(),
+            })?,
         }
         Ok(())
     }
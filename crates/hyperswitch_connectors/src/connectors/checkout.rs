@@ -951,6 +951,9 @@ impl FileUpload for Checkout {
                     })?
                 }
             }
+            FilePurpose::PayoutBatch => Err(errors::ConnectorError::FileValidationFailed {
+                reason: "payout batch files are not uploaded to the connector".to_owned(),
+            })?,
         }
         Ok(())
     }
@@ -1686,6 +1686,9 @@ impl FileUpload for Stripe {
                     })?
                 }
             }
+            FilePurpose::PayoutBatch => Err(ConnectorError::FileValidationFailed {
+                reason: "payout batch files are not uploaded to the connector".to_owned(),
+            })?,
         }
         Ok(())
     }
@@ -82,6 +82,8 @@ pub struct Profile {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -197,16 +199,39 @@ impl From<ProfileSetter> for Profile {
             three_ds_decision_rule_algorithm: None, // three_ds_decision_rule_algorithm is not yet created during profile creation
             acquirer_config_map: None,
             merchant_category_code: value.merchant_category_code,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
         }
     }
 }
 
+/// Whether the previous payment response hash key is still within its overlap period at `now`.
+#[cfg(feature = "v1")]
+fn is_previous_payment_response_hash_key_active(
+    expires_at: Option<time::PrimitiveDateTime>,
+    now: time::PrimitiveDateTime,
+) -> bool {
+    expires_at.is_some_and(|expires_at| expires_at > now)
+}
+
 impl Profile {
     #[cfg(feature = "v1")]
     pub fn get_id(&self) -> &common_utils::id_type::ProfileId {
         &self.profile_id
     }
 
+    /// Returns the previous payment response hash key, if its overlap period following a key
+    /// rotation has not ended yet.
+    #[cfg(feature = "v1")]
+    pub fn get_active_previous_payment_response_hash_key(&self) -> Option<&String> {
+        is_previous_payment_response_hash_key_active(
+            self.previous_payment_response_hash_key_expires_at,
+            date_time::now(),
+        )
+        .then_some(self.previous_payment_response_hash_key.as_ref())
+        .flatten()
+    }
+
     #[cfg(feature = "v2")]
     pub fn get_id(&self) -> &common_utils::id_type::ProfileId {
         &self.id
@@ -287,6 +312,12 @@ pub enum ProfileUpdate {
     AcquirerConfigMapUpdate {
         acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     },
+    PaymentResponseHashKeyRotationUpdate {
+        payment_response_hash_key: String,
+        previous_payment_response_hash_key: Option<String>,
+        previous_payment_response_hash_key_expires_at: Option<time::PrimitiveDateTime>,
+    },
+    PreviousPaymentResponseHashKeyRetirementUpdate,
 }
 
 #[cfg(feature = "v1")]
@@ -389,6 +420,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     three_ds_decision_rule_algorithm: None,
                     acquirer_config_map: None,
                     merchant_category_code,
+                    previous_payment_response_hash_key: None,
+                    previous_payment_response_hash_key_expires_at: None,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -443,6 +476,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -494,6 +529,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -545,6 +582,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -596,6 +635,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -647,6 +688,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -698,6 +741,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
             },
             ProfileUpdate::AcquirerConfigMapUpdate {
                 acquirer_config_map,
@@ -749,6 +794,116 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map,
                 merchant_category_code: None,
+                previous_payment_response_hash_key: None,
+                previous_payment_response_hash_key_expires_at: None,
+            },
+            ProfileUpdate::PaymentResponseHashKeyRotationUpdate {
+                payment_response_hash_key,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+            } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: Some(payment_response_hash_key),
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                is_iframe_redirection_enabled: None,
+                is_pre_network_tokenization_enabled: None,
+                three_ds_decision_rule_algorithm: None,
+                acquirer_config_map: None,
+                merchant_category_code: None,
+                previous_payment_response_hash_key: Some(previous_payment_response_hash_key),
+                previous_payment_response_hash_key_expires_at: Some(
+                    previous_payment_response_hash_key_expires_at,
+                ),
+            },
+            ProfileUpdate::PreviousPaymentResponseHashKeyRetirementUpdate => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_debit_routing_enabled: None,
+                merchant_business_country: None,
+                is_iframe_redirection_enabled: None,
+                is_pre_network_tokenization_enabled: None,
+                three_ds_decision_rule_algorithm: None,
+                acquirer_config_map: None,
+                merchant_category_code: None,
+                previous_payment_response_hash_key: Some(None),
+                previous_payment_response_hash_key_expires_at: Some(None),
             },
         }
     }
@@ -820,6 +975,9 @@ impl super::behaviour::Conversion for Profile {
             three_ds_decision_rule_algorithm: self.three_ds_decision_rule_algorithm,
             acquirer_config_map: self.acquirer_config_map,
             merchant_category_code: self.merchant_category_code,
+            previous_payment_response_hash_key: self.previous_payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: self
+                .previous_payment_response_hash_key_expires_at,
         })
     }
 
@@ -917,6 +1075,9 @@ impl super::behaviour::Conversion for Profile {
                 three_ds_decision_rule_algorithm: item.three_ds_decision_rule_algorithm,
                 acquirer_config_map: item.acquirer_config_map,
                 merchant_category_code: item.merchant_category_code,
+                previous_payment_response_hash_key: item.previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at: item
+                    .previous_payment_response_hash_key_expires_at,
             })
         }
         .await
@@ -2031,6 +2192,8 @@ impl super::behaviour::Conversion for Profile {
             three_ds_decision_rule_algorithm: None,
             acquirer_config_map: None,
             merchant_category_code: self.merchant_category_code,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
         })
     }
 
@@ -2197,3 +2360,66 @@ impl super::behaviour::Conversion for Profile {
         })
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_previous_payment_response_hash_key_is_active_until_expiry() {
+        let now = date_time::now();
+
+        assert!(is_previous_payment_response_hash_key_active(
+            Some(now.saturating_add(time::Duration::seconds(60))),
+            now
+        ));
+        assert!(!is_previous_payment_response_hash_key_active(
+            Some(now),
+            now
+        ));
+        assert!(!is_previous_payment_response_hash_key_active(
+            Some(now.saturating_sub(time::Duration::seconds(60))),
+            now
+        ));
+        assert!(!is_previous_payment_response_hash_key_active(None, now));
+    }
+
+    #[test]
+    fn test_payment_response_hash_key_rotation_update() {
+        let expires_at = date_time::now().saturating_add(time::Duration::seconds(3600));
+        let update =
+            ProfileUpdateInternal::from(ProfileUpdate::PaymentResponseHashKeyRotationUpdate {
+                payment_response_hash_key: "new_secret".to_string(),
+                previous_payment_response_hash_key: Some("old_secret".to_string()),
+                previous_payment_response_hash_key_expires_at: Some(expires_at),
+            });
+
+        assert_eq!(
+            update.payment_response_hash_key,
+            Some("new_secret".to_string())
+        );
+        assert_eq!(
+            update.previous_payment_response_hash_key,
+            Some(Some("old_secret".to_string()))
+        );
+        assert_eq!(
+            update.previous_payment_response_hash_key_expires_at,
+            Some(Some(expires_at))
+        );
+    }
+
+    #[test]
+    fn test_previous_payment_response_hash_key_retirement_update() {
+        let update = ProfileUpdateInternal::from(
+            ProfileUpdate::PreviousPaymentResponseHashKeyRetirementUpdate,
+        );
+
+        // The current key is retained, while the previous key and its expiry are cleared
+        assert_eq!(update.payment_response_hash_key, None);
+        assert_eq!(update.previous_payment_response_hash_key, Some(None));
+        assert_eq!(
+            update.previous_payment_response_hash_key_expires_at,
+            Some(None)
+        );
+    }
+}
@@ -47,7 +47,7 @@ impl Relay {
             connector_id: relay_request.connector_id.clone(),
             profile_id: profile_id.clone(),
             merchant_id: merchant_id.clone(),
-            relay_type: common_enums::RelayType::Refund,
+            relay_type: relay_request.relay_type,
             request_data: relay_request.data.clone().map(From::from),
             status: common_enums::RelayStatus::Created,
             connector_reference_id: None,
@@ -64,12 +64,22 @@ impl From<api_models::relay::RelayData> for RelayData {
     fn from(relay: api_models::relay::RelayData) -> Self {
         match relay {
             api_models::relay::RelayData::Refund(relay_refund_request) => {
-                Self::Refund(RelayRefundData {
-                    amount: relay_refund_request.amount,
-                    currency: relay_refund_request.currency,
-                    reason: relay_refund_request.reason,
-                })
+                Self::Refund(RelayRefundData::from(relay_refund_request))
+            }
+            api_models::relay::RelayData::Capture(relay_capture_request) => {
+                Self::Capture(RelayCaptureData::from(relay_capture_request))
+            }
+            api_models::relay::RelayData::Void(relay_void_request) => {
+                Self::Void(RelayVoidData::from(relay_void_request))
             }
+            api_models::relay::RelayData::PaymentSync(relay_payment_sync_request) => {
+                Self::PaymentSync(RelayPaymentSyncData::from(relay_payment_sync_request))
+            }
+            api_models::relay::RelayData::IncrementalAuthorization(
+                relay_incremental_authorization_request,
+            ) => Self::IncrementalAuthorization(RelayIncrementalAuthorizationData::from(
+                relay_incremental_authorization_request,
+            )),
         }
     }
 }
@@ -84,6 +94,48 @@ impl From<api_models::relay::RelayRefundRequestData> for RelayRefundData {
     }
 }
 
+impl From<api_models::relay::RelayCaptureRequestData> for RelayCaptureData {
+    fn from(relay: api_models::relay::RelayCaptureRequestData) -> Self {
+        Self {
+            amount_to_capture: relay.amount_to_capture,
+            payment_amount: relay.payment_amount,
+            currency: relay.currency,
+        }
+    }
+}
+
+impl From<api_models::relay::RelayVoidRequestData> for RelayVoidData {
+    fn from(relay: api_models::relay::RelayVoidRequestData) -> Self {
+        Self {
+            amount: relay.amount,
+            currency: relay.currency,
+            cancellation_reason: relay.cancellation_reason,
+        }
+    }
+}
+
+impl From<api_models::relay::RelayPaymentSyncRequestData> for RelayPaymentSyncData {
+    fn from(relay: api_models::relay::RelayPaymentSyncRequestData) -> Self {
+        Self {
+            amount: relay.amount,
+            currency: relay.currency,
+        }
+    }
+}
+
+impl From<api_models::relay::RelayIncrementalAuthorizationRequestData>
+    for RelayIncrementalAuthorizationData
+{
+    fn from(relay: api_models::relay::RelayIncrementalAuthorizationRequestData) -> Self {
+        Self {
+            total_amount: relay.total_amount,
+            additional_amount: relay.additional_amount,
+            currency: relay.currency,
+            reason: relay.reason,
+        }
+    }
+}
+
 impl RelayUpdate {
     pub fn from(
         response: Result<router_response_types::RefundsResponseData, ErrorResponse>,
@@ -100,6 +152,87 @@ impl RelayUpdate {
             },
         }
     }
+
+    pub fn from_payments_response(
+        response: Result<router_response_types::PaymentsResponseData, ErrorResponse>,
+        relay_type: enums::RelayType,
+        attempt_status: enums::AttemptStatus,
+    ) -> Self {
+        let status = get_relay_status_from_attempt_status(relay_type, attempt_status);
+
+        match response {
+            Err(error) => Self::ErrorUpdate {
+                error_code: error.code,
+                error_message: error.reason.unwrap_or(error.message),
+                status: common_enums::RelayStatus::Failure,
+            },
+            Ok(router_response_types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                status: enums::AuthorizationStatus::Failure,
+                error_code: Some(error_code),
+                error_message,
+                ..
+            }) => Self::ErrorUpdate {
+                error_code,
+                error_message: error_message.unwrap_or_default(),
+                status: common_enums::RelayStatus::Failure,
+            },
+            Ok(router_response_types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                status,
+                connector_authorization_id,
+                ..
+            }) => Self::StatusUpdate {
+                connector_reference_id: connector_authorization_id,
+                status: common_enums::RelayStatus::from(status),
+            },
+            Ok(router_response_types::PaymentsResponseData::TransactionResponse {
+                resource_id,
+                ..
+            }) => Self::StatusUpdate {
+                connector_reference_id: resource_id.get_connector_transaction_id().ok(),
+                status,
+            },
+            Ok(_) => Self::StatusUpdate {
+                connector_reference_id: None,
+                status,
+            },
+        }
+    }
+}
+
+/// Whether the status of a payment indicates that the relayed operation has succeeded depends on
+/// the operation, for instance an authorized payment is expected after a payment sync, but not
+/// after a capture.
+fn get_relay_status_from_attempt_status(
+    relay_type: enums::RelayType,
+    attempt_status: enums::AttemptStatus,
+) -> enums::RelayStatus {
+    match (relay_type, attempt_status) {
+        (
+            enums::RelayType::Capture | enums::RelayType::PaymentSync,
+            enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::PartialChargedAndChargeable,
+        )
+        | (enums::RelayType::Void | enums::RelayType::PaymentSync, enums::AttemptStatus::Voided)
+        | (
+            enums::RelayType::PaymentSync | enums::RelayType::IncrementalAuthorization,
+            enums::AttemptStatus::Authorized,
+        )
+        | (enums::RelayType::PaymentSync, enums::AttemptStatus::AutoRefunded) => {
+            enums::RelayStatus::Success
+        }
+        (
+            _,
+            enums::AttemptStatus::AuthenticationFailed
+            | enums::AttemptStatus::RouterDeclined
+            | enums::AttemptStatus::AuthorizationFailed
+            | enums::AttemptStatus::CaptureFailed
+            | enums::AttemptStatus::VoidFailed
+            | enums::AttemptStatus::Failure
+            | enums::AttemptStatus::IntegrityFailure,
+        ) => enums::RelayStatus::Failure,
+        _ => enums::RelayStatus::Pending,
+    }
 }
 
 impl From<RelayData> for api_models::relay::RelayData {
@@ -112,6 +245,37 @@ impl From<RelayData> for api_models::relay::RelayData {
                     reason: relay_refund_request.reason,
                 })
             }
+            RelayData::Capture(relay_capture_request) => {
+                Self::Capture(api_models::relay::RelayCaptureRequestData {
+                    amount_to_capture: relay_capture_request.amount_to_capture,
+                    payment_amount: relay_capture_request.payment_amount,
+                    currency: relay_capture_request.currency,
+                })
+            }
+            RelayData::Void(relay_void_request) => {
+                Self::Void(api_models::relay::RelayVoidRequestData {
+                    amount: relay_void_request.amount,
+                    currency: relay_void_request.currency,
+                    cancellation_reason: relay_void_request.cancellation_reason,
+                })
+            }
+            RelayData::PaymentSync(relay_payment_sync_request) => {
+                Self::PaymentSync(api_models::relay::RelayPaymentSyncRequestData {
+                    amount: relay_payment_sync_request.amount,
+                    currency: relay_payment_sync_request.currency,
+                })
+            }
+            RelayData::IncrementalAuthorization(relay_incremental_authorization_request) => {
+                Self::IncrementalAuthorization(
+                    api_models::relay::RelayIncrementalAuthorizationRequestData {
+                        total_amount: relay_incremental_authorization_request.total_amount,
+                        additional_amount: relay_incremental_authorization_request
+                            .additional_amount,
+                        currency: relay_incremental_authorization_request.currency,
+                        reason: relay_incremental_authorization_request.reason,
+                    },
+                )
+            }
         }
     }
 }
@@ -128,15 +292,7 @@ impl From<Relay> for api_models::relay::RelayResponse {
                 },
             );
 
-        let data = value.request_data.map(|relay_data| match relay_data {
-            RelayData::Refund(relay_refund_request) => {
-                api_models::relay::RelayData::Refund(api_models::relay::RelayRefundRequestData {
-                    amount: relay_refund_request.amount,
-                    currency: relay_refund_request.currency,
-                    reason: relay_refund_request.reason,
-                })
-            }
-        });
+        let data = value.request_data.map(api_models::relay::RelayData::from);
         Self {
             id: value.id,
             status: value.status,
@@ -155,6 +311,29 @@ impl From<Relay> for api_models::relay::RelayResponse {
 #[serde(rename_all = "snake_case", untagged)]
 pub enum RelayData {
     Refund(RelayRefundData),
+    Capture(RelayCaptureData),
+    Void(RelayVoidData),
+    PaymentSync(RelayPaymentSyncData),
+    IncrementalAuthorization(RelayIncrementalAuthorizationData),
+}
+
+impl RelayData {
+    /// Relay data is stored untagged, so the relay type is required to determine the variant of
+    /// the stored data.
+    fn from_value(
+        relay_type: enums::RelayType,
+        value: serde_json::Value,
+    ) -> Result<Self, serde_json::Error> {
+        match relay_type {
+            enums::RelayType::Refund => serde_json::from_value(value).map(Self::Refund),
+            enums::RelayType::Capture => serde_json::from_value(value).map(Self::Capture),
+            enums::RelayType::Void => serde_json::from_value(value).map(Self::Void),
+            enums::RelayType::PaymentSync => serde_json::from_value(value).map(Self::PaymentSync),
+            enums::RelayType::IncrementalAuthorization => {
+                serde_json::from_value(value).map(Self::IncrementalAuthorization)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -164,6 +343,34 @@ pub struct RelayRefundData {
     pub reason: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RelayCaptureData {
+    pub amount_to_capture: MinorUnit,
+    pub payment_amount: MinorUnit,
+    pub currency: enums::Currency,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RelayVoidData {
+    pub amount: Option<MinorUnit>,
+    pub currency: Option<enums::Currency>,
+    pub cancellation_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RelayPaymentSyncData {
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RelayIncrementalAuthorizationData {
+    pub total_amount: MinorUnit,
+    pub additional_amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug)]
 pub enum RelayUpdate {
     ErrorUpdate {
@@ -249,11 +456,11 @@ impl super::behaviour::Conversion for Relay {
             connector_id: item.connector_id,
             profile_id: item.profile_id,
             merchant_id: item.merchant_id,
-            relay_type: enums::RelayType::Refund,
+            relay_type: item.relay_type,
             request_data: item
                 .request_data
                 .map(|data| {
-                    serde_json::from_value(data.expose()).change_context(
+                    RelayData::from_value(item.relay_type, data.expose()).change_context(
                         ValidationError::InvalidValue {
                             message: "Failed while decrypting business profile data".to_string(),
                         },
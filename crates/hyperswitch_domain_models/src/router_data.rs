@@ -109,6 +109,9 @@ pub struct RouterData<Flow, Request, Response> {
     /// Indicates whether the payment ID was provided by the merchant (true),
     /// or generated internally by Hyperswitch (false)
     pub is_payment_id_from_merchant: Option<bool>,
+
+    /// The merchant connector account through which the connector is called
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
 }
 
 // Different patterns of authentication.
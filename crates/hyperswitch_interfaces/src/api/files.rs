@@ -18,6 +18,8 @@ use crate::{
 pub enum FilePurpose {
     /// DisputeEvidence
     DisputeEvidence,
+    /// PayoutBatch
+    PayoutBatch,
 }
 
 /// trait UploadFile
@@ -86,6 +86,7 @@ fn get_default_router_data<F, Req, Resp>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     }
 }
 
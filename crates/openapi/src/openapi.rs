@@ -66,6 +66,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Webhook Endpoint", description = "Create and manage webhook endpoints"),
         (name = "Authentication", description = "Create and manage authentication")
     ),
     // The paths will be displayed in the same order as they are registered here
@@ -149,6 +150,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::profile::profile_retrieve,
         routes::profile::profile_update,
         routes::profile::profile_delete,
+        routes::profile::profile_rotate_webhook_secret,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
@@ -158,6 +160,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
+        routes::routing::routing_simulate_algorithm,
+        routes::routing::routing_parse_dsl,
+        routes::routing::routing_retrieve_dsl,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
         routes::routing::routing_update_default_config,
@@ -200,6 +205,16 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::create_webhook_replay,
+        routes::webhook_events::retrieve_webhook_replay,
+        routes::webhook_events::cancel_webhook_replay,
+
+        // Routes for webhook endpoints
+        routes::webhook_endpoints::webhook_endpoint_create,
+        routes::webhook_endpoints::webhook_endpoint_retrieve,
+        routes::webhook_endpoints::webhook_endpoint_list,
+        routes::webhook_endpoints::webhook_endpoint_update,
+        routes::webhook_endpoints::webhook_endpoint_delete,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -378,6 +393,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::ProfileCreate,
         api_models::admin::ProfileResponse,
+        api_models::admin::WebhookSecretRotationRequest,
+        api_models::admin::WebhookSecretRotationResponse,
         api_models::admin::BusinessPaymentLinkConfig,
         api_models::admin::PaymentLinkBackgroundImageConfig,
         api_models::admin::PaymentLinkConfigRequest,
@@ -596,6 +613,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::RelayType,
         api_models::relay::RelayData,
         api_models::relay::RelayRefundRequestData,
+        api_models::relay::RelayCaptureRequestData,
+        api_models::relay::RelayVoidRequestData,
+        api_models::relay::RelayPaymentSyncRequestData,
+        api_models::relay::RelayIncrementalAuthorizationRequestData,
         api_models::enums::RelayStatus,
         api_models::relay::RelayError,
         api_models::payments::AmountFilter,
@@ -672,6 +693,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::RoutingSimulationConnectorDistribution,
+        api_models::routing::RoutingDslParseRequest,
+        api_models::routing::RoutingDslParseResponse,
+        api_models::routing::RoutingDslResponse,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::DynamicRoutingFeatures,
@@ -728,6 +755,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_events::WebhookReplayRequest,
+        api_models::webhook_events::WebhookReplayResponse,
+        api_models::webhook_events::WebhookReplayStatus,
+        api_models::webhook_endpoints::WebhookEndpointCreateRequest,
+        api_models::webhook_endpoints::WebhookEndpointUpdateRequest,
+        api_models::webhook_endpoints::WebhookEndpointResponse,
+        api_models::webhook_endpoints::WebhookEndpointDeleteResponse,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
@@ -90,6 +90,41 @@ pub async fn profile_update() {}
 )]
 pub async fn profile_retrieve() {}
 
+#[cfg(feature = "v1")]
+/// Profile - Rotate Webhook Secret
+///
+/// Rotate the secret used for signing outgoing webhooks of the *profile*. Outgoing webhooks are
+/// signed using both the new and previous secrets until the overlap period ends, with the
+/// signature generated using the previous secret sent in the `X-Webhook-Signature-512-Previous`
+/// header.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/rotate_webhook_secret",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = WebhookSecretRotationRequest,
+        examples(
+            (
+                "Rotate webhook secret with an overlap period of 1 hour" = (
+                    value = json!({
+                        "overlap_duration_in_seconds" : 3600
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Webhook Secret Rotated", body = WebhookSecretRotationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Rotate the Webhook Secret of a Profile",
+    security(("api_key" = []))
+)]
+pub async fn profile_rotate_webhook_secret() {}
+
 // ******************************************** Common profile routes ******************************************** //
 
 /// Profile - Delete
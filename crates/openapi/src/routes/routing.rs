@@ -86,6 +86,70 @@ pub async fn routing_link_config() {}
 )]
 pub async fn routing_retrieve_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Simulate
+///
+/// Replay the historical payments of a profile against a draft routing algorithm and compare the resulting connector distribution with what actually happened
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Successfully simulated the routing algorithm", body = RoutingSimulationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate_algorithm() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Parse DSL
+///
+/// Parse a routing program written in the routing DSL into a routing algorithm
+#[utoipa::path(
+    post,
+    path = "/routing/dsl/parse",
+    request_body = RoutingDslParseRequest,
+    responses(
+        (status = 200, description = "Successfully parsed the routing program", body = RoutingDslParseResponse),
+        (status = 400, description = "The routing program is invalid"),
+        (status = 500, description = "Internal server error"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Parse a routing DSL program",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_parse_dsl() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve as DSL
+///
+/// Retrieve an advanced routing algorithm written in the routing DSL
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_algorithm_id}/dsl",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Successfully rendered the routing config", body = RoutingDslResponse),
+        (status = 400, description = "The routing config is not an advanced routing algorithm"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve a routing config as DSL",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_dsl() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Retrieve
 ///
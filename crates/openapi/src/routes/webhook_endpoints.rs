@@ -0,0 +1,128 @@
+/// Webhook Endpoint - Create
+///
+/// Create a webhook endpoint for a Profile. Outgoing webhooks for the Profile are delivered to all
+/// enabled webhook endpoints whose event filters match the event.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = WebhookEndpointCreateRequest,
+        examples(
+            (
+                "Create a webhook endpoint for successful and failed payments" = (
+                    value = json!({
+                        "url": "https://example.com/webhooks",
+                        "enabled_event_classes": ["payments"],
+                        "enabled_event_types": ["payment_succeeded", "payment_failed"]
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint created", body = WebhookEndpointResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Create a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_create() {}
+
+/// Webhook Endpoint - Retrieve
+///
+/// Retrieve a webhook endpoint of a Profile.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint retrieved", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Retrieve a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_retrieve() {}
+
+/// Webhook Endpoint - List
+///
+/// List all webhook endpoints of a Profile.
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoints retrieved", body = Vec<WebhookEndpointResponse>)
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "List Webhook Endpoints",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_list() {}
+
+/// Webhook Endpoint - Update
+///
+/// Update a webhook endpoint of a Profile.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    request_body(
+        content = WebhookEndpointUpdateRequest,
+        examples(
+            (
+                "Disable a webhook endpoint" = (
+                    value = json!({
+                        "is_enabled": false
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint updated", body = WebhookEndpointResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Update a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_update() {}
+
+/// Webhook Endpoint - Delete
+///
+/// Delete a webhook endpoint of a Profile.
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_endpoints/{endpoint_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        ("endpoint_id" = String, Path, description = "The unique identifier for the webhook endpoint")
+    ),
+    responses(
+        (status = 200, description = "Webhook Endpoint deleted", body = WebhookEndpointDeleteResponse),
+        (status = 404, description = "Webhook Endpoint not found")
+    ),
+    tag = "Webhook Endpoint",
+    operation_id = "Delete a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn webhook_endpoint_delete() {}
@@ -115,3 +115,70 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Bulk Replay
+///
+/// Replay the delivery of Events matching the specified constraints. The Events are enqueued for
+/// delivery in batches, and the progress of the replay can be tracked using the returned
+/// `replay_id`.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/replay",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body(
+        content = WebhookReplayRequest,
+        description = "The constraints of the Events to be replayed",
+    ),
+    responses(
+        (status = 200, description = "Replay of Events created successfully", body = WebhookReplayResponse),
+        (status = 404, description = "No Events match the specified constraints"),
+    ),
+    tag = "Event",
+    operation_id = "Replay Events in bulk",
+    security(("admin_api_key" = []))
+)]
+pub fn create_webhook_replay() {}
+
+/// Events - Retrieve Bulk Replay
+///
+/// Retrieve the progress of a replay of Events.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/replay/{replay_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("replay_id" = String, Path, description = "The unique identifier for the replay"),
+    ),
+    responses(
+        (status = 200, description = "Replay of Events retrieved successfully", body = WebhookReplayResponse),
+        (status = 404, description = "Replay not found"),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve a replay of Events",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_replay() {}
+
+/// Events - Cancel Bulk Replay
+///
+/// Cancel a replay of Events. Events which have already been enqueued for delivery are not
+/// affected.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/replay/{replay_id}/cancel",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+        ("replay_id" = String, Path, description = "The unique identifier for the replay"),
+    ),
+    responses(
+        (status = 200, description = "Replay of Events cancelled successfully", body = WebhookReplayResponse),
+        (status = 404, description = "Replay not found"),
+        (status = 412, description = "Replay has already finished"),
+    ),
+    tag = "Event",
+    operation_id = "Cancel a replay of Events",
+    security(("admin_api_key" = []))
+)]
+pub fn cancel_webhook_replay() {}
@@ -466,6 +466,18 @@ impl super::RedisConnectionPool {
         Ok(values_after_increment)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_fields_from_hash(
+        &self,
+        key: &RedisKey,
+        fields: Vec<String>,
+    ) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .hdel(key.tenant_aware_key(self), fields)
+            .await
+            .change_context(errors::RedisError::DeleteFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn hscan(
         &self,
@@ -647,6 +659,17 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetAddMembersFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_set_members(
+        &self,
+        key: &RedisKey,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        self.pool
+            .smembers(key.tenant_aware_key(self))
+            .await
+            .change_context(errors::RedisError::GetSetMembersFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -679,6 +702,21 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::StreamDeleteFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &RedisKey,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<(String, std::collections::HashMap<String, String>)>, errors::RedisError>
+    {
+        self.pool
+            .xrange_values(stream.tenant_aware_key(self), start, end, count)
+            .await
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_trim_entries<C>(
         &self,
@@ -50,6 +50,8 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to add members to set in Redis")]
     SetAddMembersFailed,
+    #[error("Failed to get members of set in Redis")]
+    GetSetMembersFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
     #[error("The requested value was not found in Redis")]
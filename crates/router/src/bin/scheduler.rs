@@ -324,6 +324,75 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::WebhookSecretRotationWorkflow => Ok(Box::new(
+                    workflows::webhook_secret_rotation::WebhookSecretRotationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::WebhookReplayWorkflow => {
+                    Ok(Box::new(workflows::webhook_replay::WebhookReplayWorkflow))
+                }
+                storage::ProcessTrackerRunner::ReconWorkflow => {
+                    #[cfg(feature = "recon")]
+                    {
+                        Ok(Box::new(workflows::recon::ReconWorkflow))
+                    }
+
+                    #[cfg(not(feature = "recon"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run recon workflow when recon feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::ForexRateSnapshotWorkflow => Ok(Box::new(
+                    workflows::forex_rate_snapshot::ForexRateSnapshotWorkflow,
+                )),
+                storage::ProcessTrackerRunner::FrmReviewExpiryWorkflow => {
+                    #[cfg(all(feature = "frm", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::frm_review_expiry::FrmReviewExpiryWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(all(feature = "frm", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run FRM review expiry workflow when frm is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::SubscriptionBillingWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::subscription_billing::SubscriptionBillingWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run subscription billing workflow when v1 is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(all(feature = "payouts", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+
+                    #[cfg(not(all(feature = "payouts", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
             }
         };
 
@@ -372,6 +441,19 @@ async fn start_scheduler(
         .scheduler
         .clone()
         .ok_or(ProcessTrackerError::ConfigurationError)?;
+
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Producer) {
+        for tenant in state.conf.multitenancy.get_tenant_ids() {
+            let session_state = Arc::new(state.clone()).get_session_state(&tenant, None, || {
+                error_stack::report!(ProcessTrackerError::TenantNotFound)
+            })?;
+            router::core::currency::add_forex_rate_snapshot_task(&session_state)
+                .await
+                .change_context(ProcessTrackerError::EApiErrorResponse)
+                .attach_printable("Failed to schedule the forex rate snapshot task")?;
+        }
+    }
+
     scheduler::start_process_tracker(
         state,
         scheduler_flow,
@@ -259,6 +259,8 @@ pub enum StripeErrorCode {
     PaymentLinkNotFound,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Resource Busy. Please try again later")]
     LockTimeout,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly")]
+    RateLimitExceeded,
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "Merchant connector account is configured with invalid {config}")]
     InvalidConnectorConfiguration { config: String },
     #[error(error_type = StripeErrorType::HyperswitchError, code = "HE_01", message = "Failed to convert currency to minor unit")]
@@ -600,6 +602,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::FlowNotSupported { .. } => Self::InternalServerError,
             errors::ApiErrorResponse::MandatePaymentDataMismatch { .. } => Self::PlatformBadRequest,
             errors::ApiErrorResponse::MaxFieldLengthViolated { .. } => Self::PlatformBadRequest,
+            errors::ApiErrorResponse::RateLimitExceeded => Self::RateLimitExceeded,
             errors::ApiErrorResponse::PaymentUnexpectedState {
                 current_flow,
                 field_name,
@@ -787,6 +790,7 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::RateLimitExceeded => StatusCode::TOO_MANY_REQUESTS,
             Self::ProfileAcquirerNotFound => StatusCode::NOT_FOUND,
         }
     }
@@ -854,6 +858,11 @@ impl ErrorSwitch<StripeErrorCode> for CustomersErrorResponse {
             Self::MandateActive => SC::MandateActive,
             Self::CustomerNotFound => SC::CustomerNotFound,
             Self::CustomerAlreadyExists => SC::DuplicateCustomer,
+            Self::ResourceBusy => SC::LockTimeout,
+            Self::GenericDuplicateError { message } => SC::GenericDuplicateError {
+                message: message.clone(),
+            },
+            Self::RateLimitExceeded => SC::RateLimitExceeded,
         }
     }
 }
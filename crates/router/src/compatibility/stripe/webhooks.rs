@@ -1,5 +1,7 @@
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
+#[cfg(feature = "v1")]
+use api_models::subscriptions;
 use api_models::{
     enums::{Currency, DisputeStatus, MandateStatus},
     webhooks::{self as api},
@@ -79,6 +81,16 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
             signature.into(),
         ))
     }
+
+    fn add_previous_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+    ) {
+        header.push((
+            headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE_PREVIOUS.to_string(),
+            signature.into(),
+        ))
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -90,6 +102,12 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    #[cfg(feature = "v1")]
+    Subscription(Box<subscriptions::SubscriptionResponse>),
+    #[cfg(feature = "v1")]
+    Invoice(Box<subscriptions::InvoiceResponse>),
+    #[cfg(feature = "payouts")]
+    PayoutBatch(Box<payout_models::PayoutBatchResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -299,6 +317,16 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::SubscriptionTrialing => "customer.subscription.created",
+        api_models::enums::EventType::SubscriptionActive
+        | api_models::enums::EventType::SubscriptionPastDue => "customer.subscription.updated",
+        api_models::enums::EventType::SubscriptionCanceled => "customer.subscription.deleted",
+        api_models::enums::EventType::InvoiceCreated => "invoice.created",
+        api_models::enums::EventType::InvoicePaid => "invoice.paid",
+        api_models::enums::EventType::InvoicePaymentFailed => "invoice.payment_failed",
+        api_models::enums::EventType::InvoiceUncollectible => "invoice.marked_uncollectible",
+        api_models::enums::EventType::InvoiceVoided => "invoice.voided",
+        api_models::enums::EventType::PayoutBatchCompleted => "payout_batch.completed",
     }
 }
 
@@ -341,6 +369,16 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            #[cfg(feature = "v1")]
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                Self::Subscription(subscription)
+            }
+            #[cfg(feature = "v1")]
+            api::OutgoingWebhookContent::InvoiceDetails(invoice) => Self::Invoice(invoice),
+            #[cfg(feature = "payouts")]
+            api::OutgoingWebhookContent::PayoutBatchDetails(payout_batch) => {
+                Self::PayoutBatch(payout_batch)
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use common_utils::id_type;
 
@@ -62,6 +62,7 @@ impl Default for super::settings::Locker {
             //Time to live for storage entries in locker
             ttl_for_storage_in_secs: 60 * 60 * 24 * 365 * 7,
             decryption_scheme: Default::default(),
+            vault_backend: Default::default(),
         }
     }
 }
@@ -101,6 +102,83 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::WebhookReplaySettings {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            batch_interval_in_seconds: 60,
+        }
+    }
+}
+
+impl Default for super::settings::IdempotencySettings {
+    fn default() -> Self {
+        Self {
+            // 24 hours
+            ttl_in_seconds: 24 * 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_in_seconds: 60,
+            default_limit: 1000,
+            client_ip_limit: 100,
+            failed_authentication_limit: 20,
+            trusted_proxies: HashSet::new(),
+            route_groups: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::CircuitBreakerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_in_seconds: 60,
+            minimum_requests: 20,
+            error_rate_threshold_percentage: 50,
+            latency_threshold_in_milliseconds: 10000,
+            open_duration_in_seconds: 30,
+            half_open_max_requests: 5,
+        }
+    }
+}
+
+impl Default for super::settings::SubscriptionSettings {
+    fn default() -> Self {
+        Self {
+            payment_retry_interval_in_hours: 24,
+            max_payment_attempts: 4,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl Default for super::settings::PayoutBatchSettings {
+    fn default() -> Self {
+        Self {
+            max_rows: 10000,
+            chunk_size: 100,
+            default_connector_concurrency: 5,
+            connector_concurrency: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(feature = "frm")]
+impl Default for super::settings::FrmManualReviewSettings {
+    fn default() -> Self {
+        Self {
+            expiry_in_hours: 72,
+            default_action: common_enums::FrmReviewAction::Reject,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
@@ -298,6 +298,22 @@ impl SecretsHandler for settings::UserAuthMethodSettings {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::EmbeddedVault {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let embedded_vault = value.get_inner();
+
+        let fingerprint_secret = secret_management_client
+            .get_secret(embedded_vault.fingerprint_secret.clone())
+            .await?;
+
+        Ok(value.transition_state(|_| Self { fingerprint_secret }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::NetworkTokenizationService {
     async fn convert_to_raw_secret(
@@ -450,6 +466,16 @@ pub(crate) async fn fetch_raw_secrets(
         })
         .await;
 
+    #[allow(clippy::expect_used)]
+    let embedded_vault = conf
+        .embedded_vault
+        .async_map(|embedded_vault| async {
+            settings::EmbeddedVault::convert_to_raw_secret(embedded_vault, secret_management_client)
+                .await
+                .expect("Failed to decrypt embedded vault configs")
+        })
+        .await;
+
     Settings {
         server: conf.server,
         master_database,
@@ -505,6 +531,10 @@ pub(crate) async fn fetch_raw_secrets(
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        idempotency: conf.idempotency,
+        rate_limit: conf.rate_limit,
+        circuit_breaker: conf.circuit_breaker,
+        subscriptions: conf.subscriptions,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
@@ -535,6 +565,7 @@ pub(crate) async fn fetch_raw_secrets(
         network_tokenization_supported_card_networks: conf
             .network_tokenization_supported_card_networks,
         network_tokenization_service,
+        embedded_vault,
         network_tokenization_supported_connectors: conf.network_tokenization_supported_connectors,
         theme: conf.theme,
         platform: conf.platform,
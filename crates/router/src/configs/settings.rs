@@ -123,6 +123,10 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub idempotency: IdempotencySettings,
+    pub rate_limit: RateLimitSettings,
+    pub circuit_breaker: CircuitBreakerSettings,
+    pub subscriptions: SubscriptionSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -150,6 +154,7 @@ pub struct Settings<S: SecretState> {
     pub cell_information: CellInformation,
     pub network_tokenization_supported_card_networks: NetworkTokenizationSupportedCardNetworks,
     pub network_tokenization_service: Option<SecretStateContainer<NetworkTokenizationService, S>>,
+    pub embedded_vault: Option<SecretStateContainer<EmbeddedVault, S>>,
     pub network_tokenization_supported_connectors: NetworkTokenizationSupportedConnectors,
     pub theme: ThemeSettings,
     pub platform: Platform,
@@ -382,6 +387,18 @@ pub struct UnmaskedHeaders {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Frm {
     pub enabled: bool,
+    #[serde(default)]
+    pub manual_review: FrmManualReviewSettings,
+}
+
+#[cfg(feature = "frm")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FrmManualReviewSettings {
+    /// Number of hours a payment can stay in the manual review queue before it expires
+    pub expiry_in_hours: u32,
+    /// Action taken on the payment when its review expires
+    pub default_action: enums::FrmReviewAction,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -472,6 +489,9 @@ pub struct ForexApi {
     pub data_expiration_delay_in_seconds: u32,
     pub redis_lock_timeout_in_seconds: u32,
     pub redis_ttl_in_seconds: u32,
+    /// Cron expression, evaluated in UTC, on which the forex rates are persisted as a snapshot
+    /// for converting amounts at historical rates. Snapshots are not taken if not configured.
+    pub snapshot_schedule: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -727,6 +747,25 @@ pub struct Locker {
     pub locker_enabled: bool,
     pub ttl_for_storage_in_secs: i64,
     pub decryption_scheme: DecryptionScheme,
+    pub vault_backend: VaultBackend,
+}
+
+/// The backend used to store payment method data in the vault
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultBackend {
+    /// The external locker service reachable at the configured locker host
+    #[default]
+    External,
+    /// The vault embedded in the application, backed by the application database. Only supported
+    /// with the v2 API.
+    Embedded,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct EmbeddedVault {
+    /// The secret used for computing the fingerprints of the data stored in the embedded vault
+    pub fingerprint_secret: Secret<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -833,6 +872,16 @@ pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
     pub redis_lock_expiry_seconds: u32,
+    pub replay: WebhookReplaySettings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookReplaySettings {
+    /// Maximum number of events to be enqueued for delivery in a single batch
+    pub batch_size: u32,
+    /// Interval between consecutive batches of a replay
+    pub batch_interval_in_seconds: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -970,6 +1019,7 @@ impl Settings<SecuredSecret> {
                     .list_separator(",")
                     .with_list_parse_key("log.telemetry.route_to_trace")
                     .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("rate_limit.trusted_proxies")
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("connectors.supported.wallets")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
@@ -1036,6 +1086,10 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.idempotency.validate()?;
+        self.rate_limit.validate()?;
+        self.circuit_breaker.validate()?;
+        self.subscriptions.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -1058,6 +1112,29 @@ impl Settings<SecuredSecret> {
             .map(|x| x.get_inner().validate())
             .transpose()?;
 
+        self.embedded_vault
+            .as_ref()
+            .map(|x| x.get_inner().validate())
+            .transpose()?;
+
+        // The embedded vault only serves the vaulting flows of the v2 API
+        #[cfg(not(feature = "v2"))]
+        common_utils::fp_utils::when(self.locker.vault_backend == VaultBackend::Embedded, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "the embedded vault backend is only supported with the v2 API".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(
+            self.locker.vault_backend == VaultBackend::Embedded && self.embedded_vault.is_none(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "embedded_vault must be configured when the embedded vault backend is used"
+                        .into(),
+                ))
+            },
+        )?;
+
         self.paze_decrypt_keys
             .as_ref()
             .map(|x| x.get_inner().validate())
@@ -1069,6 +1146,7 @@ impl Settings<SecuredSecret> {
             .transpose()?;
 
         self.key_manager.get_inner().validate()?;
+        self.forex_api.get_inner().validate()?;
         #[cfg(feature = "email")]
         self.email
             .validate()
@@ -1083,6 +1161,12 @@ impl Settings<SecuredSecret> {
 
         self.open_router.validate()?;
 
+        #[cfg(feature = "frm")]
+        self.frm.validate()?;
+
+        #[cfg(feature = "payouts")]
+        self.payouts.validate()?;
+
         Ok(())
     }
 }
@@ -1105,6 +1189,90 @@ pub struct Payouts {
     pub payout_eligibility: bool,
     #[serde(default)]
     pub required_fields: PayoutRequiredFields,
+    #[serde(default)]
+    pub batch: PayoutBatchSettings,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PayoutBatchSettings {
+    /// Maximum number of rows accepted in a single payout batch file
+    pub max_rows: u32,
+    /// Number of rows of a batch which are processed by a single process tracker task
+    pub chunk_size: u32,
+    /// Number of payouts of a merchant created concurrently through a connector which is not
+    /// listed in `connector_concurrency`, across all the payout batch tasks
+    pub default_connector_concurrency: u32,
+    /// Number of payouts of a merchant created concurrently through each connector, across all the
+    /// payout batch tasks
+    pub connector_concurrency: HashMap<enums::PayoutConnectors, u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IdempotencySettings {
+    /// Number of seconds for which the response of a request made with an `Idempotency-Key` is
+    /// stored, and replayed on retries with the same key
+    pub ttl_in_seconds: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    /// Length of the sliding window over which the requests are counted
+    pub window_in_seconds: u32,
+    /// Number of requests allowed per window for the flows which are not part of any route group
+    pub default_limit: u32,
+    /// Number of requests allowed per window for a client IP address, applied to requests which
+    /// are not made on behalf of a merchant
+    pub client_ip_limit: u32,
+    /// Number of failed authentications allowed per window for a client IP address, after which
+    /// the requests from the address are rejected before being authenticated
+    pub failed_authentication_limit: u32,
+    /// Addresses of the proxies in front of the application, whose `X-Forwarded-For` header is
+    /// used to determine the IP address of the client. The header is ignored for requests received
+    /// from any other address.
+    pub trusted_proxies: HashSet<std::net::IpAddr>,
+    /// Groups of flows sharing a limit, keyed by the name of the group. The limit of a group can
+    /// be overridden per merchant through the `rate_limit_overrides_{merchant_id}` config.
+    pub route_groups: HashMap<String, RateLimitRouteGroup>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RateLimitRouteGroup {
+    pub flows: HashSet<String>,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CircuitBreakerSettings {
+    pub enabled: bool,
+    /// Length of the window over which the outcomes of the connector calls are counted
+    pub window_in_seconds: u32,
+    /// Number of connector calls in a window below which the circuit is never opened
+    pub minimum_requests: u32,
+    /// Percentage of failed connector calls in a window at which the circuit is opened
+    pub error_rate_threshold_percentage: u8,
+    /// Latency above which a connector call is counted as failed, even if it succeeded
+    pub latency_threshold_in_milliseconds: u32,
+    /// Number of seconds for which an open circuit rejects the connector calls, before letting
+    /// trial calls through
+    pub open_duration_in_seconds: u32,
+    /// Number of successful trial calls after which a half-open circuit is closed
+    pub half_open_max_requests: u32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionSettings {
+    /// Number of hours after which the payment of a failed invoice is retried
+    pub payment_retry_interval_in_hours: u32,
+    /// Number of payment attempts after which an unpaid invoice is marked uncollectible and the
+    /// subscription is canceled
+    pub max_payment_attempts: u32,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -181,6 +181,153 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::IdempotencySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.ttl_in_seconds.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency ttl_in_seconds must not be empty or 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.window_in_seconds.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit window_in_seconds must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(self.default_limit.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit default_limit must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(self.client_ip_limit.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit client_ip_limit must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(
+            self.failed_authentication_limit.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "rate limit failed_authentication_limit must not be empty or 0".into(),
+                ))
+            },
+        )?;
+
+        let mut grouped_flows = std::collections::HashSet::new();
+        self.route_groups
+            .iter()
+            .try_for_each(|(group_name, route_group)| {
+                when(
+                    route_group.flows.is_empty() || route_group.limit.is_default_or_empty(),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "rate limit route group `{group_name}` must have at least one flow and a non-zero limit"
+                        )))
+                    },
+                )?;
+
+                route_group.flows.iter().try_for_each(|flow| {
+                    when(!grouped_flows.insert(flow), || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "rate limit flow `{flow}` must not be part of more than one route group"
+                        )))
+                    })
+                })
+            })
+    }
+}
+
+impl super::settings::CircuitBreakerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.window_in_seconds.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker window_in_seconds must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(self.minimum_requests.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker minimum_requests must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(
+            self.error_rate_threshold_percentage.is_default_or_empty()
+                || self.error_rate_threshold_percentage > 100,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "circuit breaker error_rate_threshold_percentage must be between 1 and 100"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.latency_threshold_in_milliseconds.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "circuit breaker latency_threshold_in_milliseconds must not be empty or 0"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(self.open_duration_in_seconds.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker open_duration_in_seconds must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(self.half_open_max_requests.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker half_open_max_requests must not be empty or 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SubscriptionSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.payment_retry_interval_in_hours.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "subscriptions payment_retry_interval_in_hours must not be empty or 0".into(),
+                ))
+            },
+        )?;
+
+        when(self.max_payment_attempts.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "subscriptions max_payment_attempts must not be empty or 0".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::WebhooksSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -189,7 +336,73 @@ impl super::settings::WebhooksSettings {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "redis_lock_expiry_seconds must not be empty or 0".into(),
             ))
-        })
+        })?;
+
+        when(self.replay.batch_size.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhooks replay batch_size must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(
+            self.replay.batch_interval_in_seconds.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "webhooks replay batch_interval_in_seconds must not be empty or 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl super::settings::Payouts {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.batch.max_rows.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payouts batch max_rows must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(self.batch.chunk_size.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payouts batch chunk_size must not be empty or 0".into(),
+            ))
+        })?;
+
+        when(
+            self.batch
+                .default_connector_concurrency
+                .is_default_or_empty()
+                || self
+                    .batch
+                    .connector_concurrency
+                    .values()
+                    .any(|concurrency| concurrency.is_default_or_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "payouts batch connector concurrency must not be empty or 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+#[cfg(feature = "frm")]
+impl super::settings::Frm {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.manual_review.expiry_in_hours.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "frm manual_review expiry_in_hours must not be empty or 0".into(),
+                ))
+            },
+        )
     }
 }
 
@@ -218,6 +431,40 @@ impl super::settings::CellInformation {
     }
 }
 
+impl super::settings::EmbeddedVault {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.fingerprint_secret.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "embedded vault fingerprint_secret must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ForexApi {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.snapshot_schedule
+            .as_deref()
+            .map(|snapshot_schedule| {
+                scheduler::recurrence::RecurringSchedule::new(
+                    snapshot_schedule,
+                    "UTC",
+                    scheduler::recurrence::MissedRunPolicy::Skip,
+                )
+            })
+            .transpose()
+            .map_err(|error| {
+                ApplicationError::InvalidConfigurationValueError(format!(
+                    "Invalid forex rate snapshot schedule: {error}"
+                ))
+            })?;
+
+        Ok(())
+    }
+}
+
 impl super::settings::NetworkTokenizationService {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
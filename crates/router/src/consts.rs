@@ -27,6 +27,9 @@ pub(crate) const ALPHABETS: [char; 62] = [
 pub const REQUEST_TIME_OUT: u64 = 30;
 pub const REQUEST_TIMEOUT_ERROR_CODE: &str = "TIMEOUT";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE: &str = "Connector did not respond in specified time";
+pub const CIRCUIT_BREAKER_OPEN_ERROR_CODE: &str = "CIRCUIT_OPEN";
+pub const CIRCUIT_BREAKER_OPEN_ERROR_MESSAGE: &str =
+    "Connector calls are suspended as the connector is failing, please retry later";
 pub const REQUEST_TIMEOUT_PAYMENT_NOT_FOUND: &str = "Timed out ,payment not found";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC: &str =
     "This Payment has been moved to failed as there is no response from the connector";
@@ -37,6 +40,14 @@ pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
 /// Payment intent default client secret expiry (in seconds)
 pub const DEFAULT_SESSION_EXPIRY: i64 = 15 * 60;
 
+/// Default duration (in seconds) for which the previous webhook secret of a profile remains active
+/// after a rotation
+pub const DEFAULT_WEBHOOK_SECRET_ROTATION_OVERLAP_IN_SECS: i64 = 60 * 60 * 24; // 1 day
+
+/// Maximum duration (in seconds) for which the previous webhook secret of a profile can remain
+/// active after a rotation
+pub const MAX_WEBHOOK_SECRET_ROTATION_OVERLAP_IN_SECS: i64 = 60 * 60 * 24 * 7; // 7 days
+
 /// The length of a merchant fingerprint secret
 pub const FINGERPRINT_SECRET_LENGTH: usize = 64;
 
@@ -9,6 +9,8 @@ pub mod blocklist;
 pub mod cache;
 pub mod card_testing_guard;
 pub mod cards_info;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod circuit_breaker;
 pub mod conditional_config;
 pub mod configs;
 #[cfg(feature = "olap")]
@@ -26,6 +28,7 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
@@ -39,6 +42,8 @@ pub mod payout_link;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+#[cfg(feature = "olap")]
+pub mod process_tracker;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
@@ -49,6 +54,8 @@ pub mod refunds_v2;
 #[cfg(feature = "v1")]
 pub mod debit_routing;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod subscriptions;
 pub mod surcharge_decision_config;
 pub mod three_ds_decision_rule;
 #[cfg(feature = "olap")]
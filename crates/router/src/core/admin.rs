@@ -4432,6 +4432,167 @@ pub async fn connector_agnostic_mit_toggle(
     ))
 }
 
+#[cfg(feature = "v1")]
+const WEBHOOK_SECRET_ROTATION_TAG: &str = "WEBHOOK_SECRET";
+#[cfg(feature = "v1")]
+const WEBHOOK_SECRET_ROTATION_TASK: &str = "WEBHOOK_SECRET_ROTATION";
+
+#[cfg(feature = "v1")]
+pub async fn rotate_profile_webhook_secret(
+    state: SessionState,
+    profile_id: &id_type::ProfileId,
+    key_store: domain::MerchantKeyStore,
+    request: admin_types::WebhookSecretRotationRequest,
+) -> RouterResponse<admin_types::WebhookSecretRotationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let overlap_duration_in_seconds = request
+        .overlap_duration_in_seconds
+        .map(i64::from)
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SECRET_ROTATION_OVERLAP_IN_SECS);
+    fp_utils::when(
+        overlap_duration_in_seconds > consts::MAX_WEBHOOK_SECRET_ROTATION_OVERLAP_IN_SECS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`overlap_duration_in_seconds` must not exceed {} seconds",
+                    consts::MAX_WEBHOOK_SECRET_ROTATION_OVERLAP_IN_SECS
+                ),
+            })
+        },
+    )?;
+
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            &key_store.merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let (previous_payment_response_hash_key, previous_secret_expires_at) =
+        get_previous_webhook_secret(
+            business_profile.payment_response_hash_key.clone(),
+            overlap_duration_in_seconds,
+            date_time::now(),
+        );
+
+    // The task is added before updating the profile, so that the previous secret is never left
+    // without a task to retire it. The task is a no-op if the profile update fails.
+    if let Some(previous_secret_expires_at) = previous_secret_expires_at {
+        add_webhook_secret_rotation_task(
+            db,
+            &key_store.merchant_id,
+            profile_id,
+            previous_secret_expires_at,
+        )
+        .await?;
+    }
+
+    let payment_response_hash_key =
+        common_utils::crypto::generate_cryptographically_secure_random_string(64);
+    let profile_update = domain::ProfileUpdate::PaymentResponseHashKeyRotationUpdate {
+        payment_response_hash_key: payment_response_hash_key.clone(),
+        previous_payment_response_hash_key,
+        previous_payment_response_hash_key_expires_at: previous_secret_expires_at,
+    };
+
+    db.update_profile_by_profile_id(
+        key_manager_state,
+        &key_store,
+        business_profile,
+        profile_update,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::WebhookSecretRotationResponse {
+            profile_id: profile_id.to_owned(),
+            payment_response_hash_key,
+            previous_secret_expires_at,
+        },
+    ))
+}
+
+/// The current secret remains active for the overlap period, unless no overlap was requested.
+/// Returns the secret to be retained along with the time at which it expires.
+#[cfg(feature = "v1")]
+fn get_previous_webhook_secret(
+    current_secret: Option<String>,
+    overlap_duration_in_seconds: i64,
+    now: time::PrimitiveDateTime,
+) -> (Option<String>, Option<time::PrimitiveDateTime>) {
+    let previous_secret = current_secret.filter(|_| overlap_duration_in_seconds > 0);
+    let previous_secret_expires_at = previous_secret
+        .as_ref()
+        .map(|_| now.saturating_add(time::Duration::seconds(overlap_duration_in_seconds)));
+
+    (previous_secret, previous_secret_expires_at)
+}
+
+#[cfg(feature = "v1")]
+async fn add_webhook_secret_rotation_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    previous_secret_expires_at: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::WebhookSecretRotationWorkflow;
+    let tracking_data = storage::WebhookSecretRotationTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        profile_id: profile_id.to_owned(),
+        previous_payment_response_hash_key_expires_at: previous_secret_expires_at,
+    };
+
+    // A profile may be rotated multiple times, hence the expiry time is included in the ID
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        WEBHOOK_SECRET_ROTATION_TASK,
+        &format!(
+            "{}_{}",
+            profile_id.get_string_repr(),
+            previous_secret_expires_at.assume_utc().unix_timestamp()
+        ),
+        merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        WEBHOOK_SECRET_ROTATION_TASK,
+        runner,
+        [WEBHOOK_SECRET_ROTATION_TAG],
+        tracking_data,
+        None,
+        previous_secret_expires_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct webhook secret rotation process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting webhook secret rotation task to process_tracker: {:?}",
+                profile_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "WebhookSecretRotation")),
+    );
+
+    Ok(())
+}
+
 pub async fn transfer_key_store_to_key_manager(
     state: SessionState,
     req: admin_types::MerchantKeyTransferRequest,
@@ -4793,3 +4954,32 @@ impl From<&types::MerchantAccountData> for pm_auth_types::RecipientCreateRequest
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_previous_webhook_secret_retained_for_overlap_period() {
+        let now = date_time::now();
+        let (previous_secret, expires_at) =
+            get_previous_webhook_secret(Some("old_secret".to_string()), 3600, now);
+
+        assert_eq!(previous_secret, Some("old_secret".to_string()));
+        assert_eq!(
+            expires_at,
+            Some(now.saturating_add(time::Duration::seconds(3600)))
+        );
+    }
+
+    #[test]
+    fn test_previous_webhook_secret_dropped_without_overlap() {
+        let now = date_time::now();
+
+        assert_eq!(
+            get_previous_webhook_secret(Some("old_secret".to_string()), 0, now),
+            (None, None)
+        );
+        assert_eq!(get_previous_webhook_secret(None, 3600, now), (None, None));
+    }
+}
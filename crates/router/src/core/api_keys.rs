@@ -1,4 +1,4 @@
-use common_utils::date_time;
+use common_utils::{date_time, fp_utils};
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
 use error_stack::{report, ResultExt};
@@ -119,6 +119,15 @@ pub async fn create_api_key(
 
     let merchant_id = key_store.merchant_id.clone();
 
+    validate_api_key_restrictions(
+        &state,
+        &key_store,
+        api_key.permission_groups.as_ref(),
+        api_key.resources.as_ref(),
+        api_key.profile_ids.as_ref(),
+    )
+    .await?;
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -131,6 +140,9 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        permission_groups: api_key.permission_groups,
+        resources: api_key.resources,
+        profile_ids: api_key.profile_ids,
     };
 
     let api_key = store
@@ -183,6 +195,47 @@ pub async fn create_api_key(
     ))
 }
 
+/// Validates the permission groups, resources and profiles an API key is being restricted to.
+async fn validate_api_key_restrictions(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    permission_groups: Option<&Vec<common_enums::PermissionGroup>>,
+    resources: Option<&Vec<common_enums::Resource>>,
+    profile_ids: Option<&Vec<common_utils::id_type::ProfileId>>,
+) -> errors::RouterResult<()> {
+    for (field_name, is_empty) in [
+        (
+            "permission_groups",
+            permission_groups.is_some_and(Vec::is_empty),
+        ),
+        ("resources", resources.is_some_and(Vec::is_empty)),
+        ("profile_ids", profile_ids.is_some_and(Vec::is_empty)),
+    ] {
+        fp_utils::when(is_empty, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`{field_name}` must not be empty when provided"),
+            })
+        })?;
+    }
+
+    for profile_id in profile_ids.into_iter().flatten() {
+        state
+            .store
+            .find_business_profile_by_merchant_id_profile_id(
+                &state.into(),
+                key_store,
+                &key_store.merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
+    Ok(())
+}
+
 // Add api_key_expiry task to the process_tracker table.
 // Construct ProcessTrackerNew struct with all required fields, and schedule the first email.
 // After first email has been sent, update the schedule_time based on retry_count in execute_workflow().
@@ -276,6 +329,24 @@ pub async fn update_api_key(
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            &(&state).into(),
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    validate_api_key_restrictions(
+        &state,
+        &key_store,
+        api_key.permission_groups.as_ref(),
+        api_key.resources.as_ref(),
+        api_key.profile_ids.as_ref(),
+    )
+    .await?;
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
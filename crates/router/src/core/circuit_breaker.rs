@@ -0,0 +1,108 @@
+use api_models::circuit_breaker as circuit_breaker_api_types;
+use common_utils::id_type;
+use router_env::{instrument, tracing};
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    services::{self, api::circuit_breaker},
+    types::domain,
+    SessionState,
+};
+
+async fn build_circuit_breaker_response(
+    state: &SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> RouterResult<circuit_breaker_api_types::CircuitBreakerResponse> {
+    let merchant_connector_id = merchant_connector_account.get_id();
+    let status = circuit_breaker::get_status(
+        state,
+        &merchant_connector_account.merchant_id,
+        &merchant_connector_id,
+    )
+    .await?;
+
+    Ok(circuit_breaker_api_types::CircuitBreakerResponse {
+        merchant_connector_id,
+        connector_name: merchant_connector_account.connector_name.clone(),
+        state: status.state,
+        total_requests: status.total_requests,
+        failed_requests: status.failed_requests,
+        opened_at: status
+            .opened_at
+            .and_then(|opened_at| OffsetDateTime::from_unix_timestamp(opened_at).ok())
+            .map(|opened_at| PrimitiveDateTime::new(opened_at.date(), opened_at.time())),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn list_circuit_breakers(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<circuit_breaker_api_types::CircuitBreakerListResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_accounts = store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            &merchant_id,
+            false,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+
+    let mut data = Vec::with_capacity(merchant_connector_accounts.len());
+    for merchant_connector_account in &merchant_connector_accounts {
+        data.push(build_circuit_breaker_response(&state, merchant_connector_account).await?);
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        circuit_breaker_api_types::CircuitBreakerListResponse { data },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn reset_circuit_breaker(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<circuit_breaker_api_types::CircuitBreakerResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_account = store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            &merchant_id,
+            &merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    circuit_breaker::reset(&state, &merchant_id, &merchant_connector_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        build_circuit_breaker_response(&state, &merchant_connector_account).await?,
+    ))
+}
@@ -1,17 +1,27 @@
+use std::collections::HashMap;
+
 use analytics::errors::AnalyticsError;
-use common_utils::errors::CustomResult;
-use currency_conversion::types::ExchangeRates;
+use api_models::{analytics::TimeRange, enums};
+use common_utils::{date_time, errors::CustomResult, ext_traits::ValueExt};
+use currency_conversion::types::{
+    CurrencyFactors, ExchangeRates, ExchangeRatesSnapshot, HistoricalExchangeRates,
+};
 use error_stack::ResultExt;
 use router_env::logger;
+use scheduler::recurrence::{MissedRunPolicy, ProcessTrackerRecurrence, RecurringSchedule};
 
 use crate::{
     consts::DEFAULT_ANALYTICS_FOREX_RETRY_ATTEMPTS,
-    core::errors::ApiErrorResponse,
+    core::errors::{ApiErrorResponse, RouterResult},
     services::ApplicationResponse,
+    types::storage,
     utils::currency::{self, convert_currency, get_forex_rates, ForexError as ForexCacheError},
     SessionState,
 };
 
+const FOREX_RATE_SNAPSHOT_TASK: &str = "FOREX_RATE_SNAPSHOT";
+const FOREX_RATE_SNAPSHOT_TAG: &str = "FOREX";
+
 pub async fn retrieve_forex(
     state: SessionState,
 ) -> CustomResult<ApplicationResponse<currency::FxExchangeRatesCacheEntry>, ApiErrorResponse> {
@@ -92,3 +102,252 @@ pub async fn get_forex_exchange_rates(
         }
     }
 }
+
+/// Returns the exchange rates in effect over the time range, built from the snapshots persisted
+/// during the time range along with the latest rates. Only the latest rates are used if the
+/// snapshots cannot be retrieved.
+pub async fn get_historical_forex_exchange_rates(
+    state: SessionState,
+    time_range: Option<TimeRange>,
+) -> CustomResult<HistoricalExchangeRates, AnalyticsError> {
+    let latest_rates = get_forex_exchange_rates(state.clone()).await?;
+    let now = date_time::now();
+
+    let mut snapshots = match time_range {
+        Some(time_range) => get_forex_rate_snapshots(&state, time_range, now)
+            .await
+            .inspect_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch forex rate snapshots, falling back to the latest rates"
+                )
+            })
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+    snapshots.push(ExchangeRatesSnapshot {
+        captured_at: now,
+        rates: latest_rates,
+    });
+
+    Ok(HistoricalExchangeRates::new(snapshots))
+}
+
+async fn get_forex_rate_snapshots(
+    state: &SessionState,
+    time_range: TimeRange,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<Vec<ExchangeRatesSnapshot>> {
+    let db = &*state.store;
+
+    // The rates in effect at the start of the time range were captured at or before it
+    let snapshot_at_start = db
+        .find_latest_forex_rate_snapshot_captured_at_or_before(time_range.start_time)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch forex rate snapshot")?;
+    let snapshots_in_range = db
+        .list_forex_rate_snapshots_captured_between(
+            time_range.start_time,
+            time_range.end_time.unwrap_or(now),
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list forex rate snapshots")?;
+
+    snapshot_at_start
+        .into_iter()
+        .chain(snapshots_in_range)
+        .map(|snapshot| {
+            let conversion: HashMap<enums::Currency, CurrencyFactors> = snapshot
+                .rates
+                .parse_value("ForexRateSnapshotRates")
+                .change_context(ApiErrorResponse::InternalServerError)?;
+
+            Ok(ExchangeRatesSnapshot {
+                captured_at: snapshot.captured_at,
+                rates: ExchangeRates::new(snapshot.base_currency, conversion),
+            })
+        })
+        .collect()
+}
+
+/// Schedules the recurring task which persists the forex rates as snapshots, if a snapshot
+/// schedule is configured. The schedule of the task is updated if it was scheduled with a
+/// different schedule before.
+pub async fn add_forex_rate_snapshot_task(state: &SessionState) -> RouterResult<()> {
+    let Some(snapshot_schedule) = state
+        .conf
+        .forex_api
+        .get_inner()
+        .snapshot_schedule
+        .as_deref()
+    else {
+        return Ok(());
+    };
+
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::ForexRateSnapshotWorkflow;
+    let process_tracker_id = format!("{runner}_{FOREX_RATE_SNAPSHOT_TASK}");
+
+    let recurring_schedule =
+        RecurringSchedule::new(snapshot_schedule, "UTC", MissedRunPolicy::Skip)
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid forex rate snapshot schedule")?;
+    let schedule_time = recurring_schedule
+        .next_occurrence_after(date_time::now())
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to determine the first forex rate snapshot time")?;
+    let recurrence = recurring_schedule.to_recurrence(schedule_time);
+
+    if let Some(process) = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch forex rate snapshot task")?
+    {
+        return update_forex_rate_snapshot_schedule(state, process, schedule_time, recurrence)
+            .await;
+    }
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        FOREX_RATE_SNAPSHOT_TASK,
+        runner,
+        [FOREX_RATE_SNAPSHOT_TAG],
+        serde_json::json!({}),
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct forex rate snapshot process tracker task")?
+    .with_recurrence(recurrence);
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The task was scheduled by another producer in the meantime
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::info!("Forex rate snapshot task has already been scheduled");
+            Ok(())
+        }
+        Err(error) => Err(error)
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while inserting forex rate snapshot task to process_tracker"),
+    }
+}
+
+/// Reschedules an existing forex rate snapshot task if its schedule differs from the configured
+/// schedule.
+async fn update_forex_rate_snapshot_schedule(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    schedule_time: time::PrimitiveDateTime,
+    recurrence: ProcessTrackerRecurrence,
+) -> RouterResult<()> {
+    if !is_forex_rate_snapshot_schedule_changed(&process, &recurrence) {
+        return Ok(());
+    }
+
+    // Tasks being executed are rescheduled by the consumer once they finish, and tasks which are
+    // paused or finished are not run until they are changed through the process tracker APIs
+    if !matches!(
+        process.status,
+        storage::enums::ProcessTrackerStatus::New | storage::enums::ProcessTrackerStatus::Pending
+    ) {
+        logger::warn!(
+            process_tracker_id = %process.id,
+            status = ?process.status,
+            "Forex rate snapshot schedule has changed, but the task cannot be rescheduled"
+        );
+        return Ok(());
+    }
+
+    let updated_process = state
+        .store
+        .update_process_if_unmodified(
+            process,
+            storage::ProcessTrackerUpdate::RecurrenceUpdate {
+                schedule_time,
+                recurrence,
+            },
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the schedule of the forex rate snapshot task")?;
+
+    match updated_process {
+        Some(_) => logger::info!("Forex rate snapshot task was rescheduled"),
+        // The task was picked or rescheduled by another producer in the meantime
+        None => logger::warn!("Forex rate snapshot task was modified while it was rescheduled"),
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the task was scheduled with a schedule other than the given recurrence.
+fn is_forex_rate_snapshot_schedule_changed(
+    process: &storage::ProcessTracker,
+    recurrence: &ProcessTrackerRecurrence,
+) -> bool {
+    process
+        .recurrence
+        .as_ref()
+        .map_or(true, |current_recurrence| {
+            current_recurrence.cron_expression != recurrence.cron_expression
+                || current_recurrence.timezone != recurrence.timezone
+                || current_recurrence.missed_run_policy != recurrence.missed_run_policy
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn snapshot_task(recurrence: Option<ProcessTrackerRecurrence>) -> storage::ProcessTracker {
+        let now = date_time::now();
+        storage::ProcessTracker {
+            id: "FOREX_RATE_SNAPSHOT_WORKFLOW_FOREX_RATE_SNAPSHOT".to_string(),
+            name: Some(FOREX_RATE_SNAPSHOT_TASK.to_string()),
+            tag: vec![FOREX_RATE_SNAPSHOT_TAG.to_string()],
+            runner: Some(storage::ProcessTrackerRunner::ForexRateSnapshotWorkflow.to_string()),
+            retry_count: 0,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: serde_json::json!({}),
+            business_status: storage::business_status::PENDING.to_string(),
+            status: storage::enums::ProcessTrackerStatus::New,
+            event: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            version: common_types::consts::API_VERSION,
+            recurrence,
+        }
+    }
+
+    fn recurrence(cron_expression: &str) -> ProcessTrackerRecurrence {
+        RecurringSchedule::new(cron_expression, "UTC", MissedRunPolicy::Skip)
+            .unwrap()
+            .to_recurrence(date_time::now())
+    }
+
+    #[test]
+    fn test_forex_rate_snapshot_schedule_changed() {
+        let daily = recurrence("0 0 * * *");
+        let hourly = recurrence("0 * * * *");
+
+        // The occurrence the task corresponds to changes with every run of the task
+        let task = snapshot_task(Some(ProcessTrackerRecurrence {
+            occurrence_at: None,
+            ..daily.clone()
+        }));
+        assert!(!is_forex_rate_snapshot_schedule_changed(&task, &daily));
+        assert!(is_forex_rate_snapshot_schedule_changed(&task, &hourly));
+        assert!(is_forex_rate_snapshot_schedule_changed(
+            &snapshot_task(None),
+            &daily
+        ));
+    }
+}
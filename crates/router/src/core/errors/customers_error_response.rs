@@ -16,6 +16,15 @@ pub enum CustomersErrorResponse {
 
     #[error("Customer with the given customer id already exists")]
     CustomerAlreadyExists,
+
+    #[error("Resource is busy. Please try again later.")]
+    ResourceBusy,
+
+    #[error("{message}")]
+    GenericDuplicateError { message: String },
+
+    #[error("Too many requests. Please retry after some time.")]
+    RateLimitExceeded,
 }
 
 impl actix_web::ResponseError for CustomersErrorResponse {
@@ -34,6 +34,21 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for CustomersError
                 "Customer with the given `customer_id` already exists",
                 None,
             )),
+            Self::ResourceBusy => AER::Unprocessable(ApiError::new(
+                "HE",
+                0,
+                "Resource is busy. Please try again later.",
+                None,
+            )),
+            Self::GenericDuplicateError { message } => {
+                AER::BadRequest(ApiError::new("IR", 38, message, None))
+            }
+            Self::RateLimitExceeded => AER::TooManyRequests(ApiError::new(
+                "IR",
+                48,
+                "Too many requests. Please retry after some time.",
+                None,
+            )),
         }
     }
 }
@@ -62,6 +77,11 @@ impl ErrorSwitch<CustomersErrorResponse> for ApiErrorResponse {
             Self::InternalServerError => CER::InternalServerError,
             Self::MandateActive => CER::MandateActive,
             Self::CustomerNotFound => CER::CustomerNotFound,
+            Self::ResourceBusy => CER::ResourceBusy,
+            Self::GenericDuplicateError { message } => CER::GenericDuplicateError {
+                message: message.clone(),
+            },
+            Self::RateLimitExceeded => CER::RateLimitExceeded,
             _ => CER::InternalServerError,
         }
     }
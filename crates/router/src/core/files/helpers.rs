@@ -27,6 +27,7 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("payout_batch") => Some(api::FilePurpose::PayoutBatch),
         _ => None,
     }
 }
@@ -81,6 +82,17 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        api::FilePurpose::PayoutBatch => {
+            let file_type = create_file_request.file_type.essence_str();
+            if file_type != mime::TEXT_CSV.essence_str()
+                && file_type != mime::APPLICATION_JSON.essence_str()
+            {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type does not match CSV or JSON format".to_string(),
+                })?
+            }
+            Ok(())
+        }
     }
 }
 
@@ -378,5 +390,18 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        api::FilePurpose::PayoutBatch => {
+            state
+                .file_storage_client
+                .upload_file(&file_key, create_file_request.file.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
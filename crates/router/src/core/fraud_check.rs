@@ -44,7 +44,11 @@ use crate::{
     utils::ValueExt,
 };
 pub mod flows;
+#[cfg(feature = "v1")]
+pub mod manual_review;
 pub mod operation;
+#[cfg(feature = "v1")]
+pub mod risk_engine;
 pub mod types;
 
 #[cfg(feature = "v2")]
@@ -92,15 +96,21 @@ where
     // To construct connector flow specific api
     dyn Connector: services::api::ConnectorIntegration<F, Req, frm_types::FraudCheckResponseData>,
 {
-    let merchant_connector_account = payments::construct_profile_id_and_get_mca(
-        state,
-        merchant_context,
-        payment_data,
-        &frm_data.connector_details.connector_name,
-        None,
-        false,
-    )
-    .await?;
+    let is_risk_engine =
+        frm_data.connector_details.connector_name == risk_engine::RISK_ENGINE_PROVIDER;
+    let merchant_connector_account = if is_risk_engine {
+        risk_engine::get_merchant_connector_account()
+    } else {
+        payments::construct_profile_id_and_get_mca(
+            state,
+            merchant_context,
+            payment_data,
+            &frm_data.connector_details.connector_name,
+            None,
+            false,
+        )
+        .await?
+    };
 
     frm_data
         .payment_attempt
@@ -130,6 +140,17 @@ where
         frm_data.fraud_check.last_step = FraudCheckLastStep::TransactionOrRecordRefund
     }
 
+    if is_risk_engine {
+        return risk_engine::execute_risk_engine::<F, Req, D, OperationData>(
+            state,
+            payment_data,
+            frm_data,
+            customer,
+            router_data,
+        )
+        .await;
+    }
+
     let connector =
         FraudCheckConnectorData::get_connector_by_name(&frm_data.connector_details.connector_name)?;
     let router_data_res = router_data
@@ -164,6 +185,93 @@ where
     todo!()
 }
 
+/// Filters the FRM configs by the connector and payment method of the payment attempt, and
+/// returns whether the fraud check is enabled for the payment along with the matched config.
+#[cfg(feature = "v1")]
+pub fn get_frm_configs_object<F, D>(
+    frm_configs_struct: &[api_models::admin::FrmConfigs],
+    payment_data: &D,
+) -> RouterResult<(bool, FrmConfigsObject)>
+where
+    F: Send + Clone,
+    D: payments::OperationSessionGetters<F> + Send + Sync + Clone,
+{
+    let mut is_frm_connector_enabled = false;
+    let mut is_frm_pm_enabled = false;
+    let connector = payment_data.get_payment_attempt().connector.clone();
+    let filtered_frm_config = frm_configs_struct
+        .iter()
+        .filter(|frm_config| match (&connector, &frm_config.gateway) {
+            (Some(current_connector), Some(configured_connector)) => {
+                let is_enabled = *current_connector == configured_connector.to_string();
+                if is_enabled {
+                    is_frm_connector_enabled = true;
+                }
+                is_enabled
+            }
+            (None, _) | (_, None) => true,
+        })
+        .collect::<Vec<_>>();
+    let filtered_payment_methods = filtered_frm_config
+        .iter()
+        .map(|frm_config| {
+            let filtered_frm_config_by_pm = frm_config
+                .payment_methods
+                .iter()
+                .filter(|frm_config_pm| {
+                    match (
+                        payment_data.get_payment_attempt().payment_method,
+                        frm_config_pm.payment_method,
+                    ) {
+                        (Some(current_pm), Some(configured_connector_pm)) => {
+                            let is_enabled =
+                                current_pm.to_string() == configured_connector_pm.to_string();
+                            if is_enabled {
+                                is_frm_pm_enabled = true;
+                            }
+                            is_enabled
+                        }
+                        (None, _) | (_, None) => true,
+                    }
+                })
+                .collect::<Vec<_>>();
+            filtered_frm_config_by_pm
+        })
+        .collect::<Vec<_>>()
+        .concat();
+    let is_frm_enabled = is_frm_connector_enabled && is_frm_pm_enabled;
+    logger::debug!(
+        "is_frm_connector_enabled {:?}, is_frm_pm_enabled:  {:?}, is_frm_enabled :{:?}",
+        is_frm_connector_enabled,
+        is_frm_pm_enabled,
+        is_frm_enabled
+    );
+    // filtered_frm_config...
+    // Panic Safety: we are first checking if the object is present... only if present, we try to fetch index 0
+    let frm_configs_object = FrmConfigsObject {
+        frm_enabled_gateway: filtered_frm_config
+            .first()
+            .and_then(|c| c.gateway),
+        frm_enabled_pm: filtered_payment_methods
+            .first()
+            .and_then(|pm| pm.payment_method),
+        // flow type should be consumed from payment_method.flow. To provide backward compatibility, if we don't find it there, we consume it from payment_method.payment_method_types[0].flow_type.
+        frm_preferred_flow_type: filtered_payment_methods
+            .first()
+            .and_then(|pm| pm.flow.clone())
+            .or(filtered_payment_methods.first().and_then(|pm| {
+                pm.payment_method_types.as_ref().and_then(|pmt| {
+                    pmt.first().map(|pmts| pmts.flow.clone())
+                })
+            }))
+            .ok_or(errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name: "frm_configs".to_string(),
+                    expected_format: r#"[{ "gateway": "stripe", "payment_methods": [{ "payment_method": "card","flow": "post"}]}]"#.to_string(),
+            })?,
+    };
+    Ok((is_frm_enabled, frm_configs_object))
+}
+
 #[cfg(feature = "v1")]
 pub async fn should_call_frm<F, D>(
     merchant_context: &domain::MerchantContext,
@@ -206,6 +314,40 @@ where
                 .attach_printable("profile_id is not set in payment_intent")?
                 .clone();
 
+            if frm_routing_algorithm_struct.data == risk_engine::RISK_ENGINE_PROVIDER {
+                let risk_engine_record = risk_engine::find_risk_engine_record(
+                    state,
+                    merchant_context.get_merchant_account().get_id(),
+                    &profile_id,
+                )
+                .await?;
+                return match risk_engine_record {
+                    Some(record) => {
+                        let (is_frm_enabled, frm_configs_object) = get_frm_configs_object::<F, _>(
+                            &record.config.frm_configs,
+                            payment_data,
+                        )?;
+                        logger::debug!(
+                            "frm_routing_configs: {:?} {:?} {:?} {:?}",
+                            frm_routing_algorithm_struct,
+                            profile_id,
+                            frm_configs_object,
+                            is_frm_enabled
+                        );
+                        Ok((
+                            is_frm_enabled,
+                            Some(frm_routing_algorithm_struct),
+                            Some(profile_id),
+                            Some(frm_configs_object),
+                        ))
+                    }
+                    None => {
+                        logger::error!("Cannot find fraud risk engine config for the profile");
+                        Ok((false, None, None, None))
+                    }
+                };
+            }
+
             #[cfg(feature = "v1")]
             let merchant_connector_account_from_db_option = db
                 .find_merchant_connector_account_by_profile_id_connector_name(
@@ -272,83 +414,8 @@ where
                                 })
                                 .collect::<Result<Vec<_>, _>>()?;
 
-                            let mut is_frm_connector_enabled = false;
-                            let mut is_frm_pm_enabled = false;
-                            let connector = payment_data.get_payment_attempt().connector.clone();
-                            let filtered_frm_config = frm_configs_struct
-                                .iter()
-                                .filter(|frm_config| match (&connector, &frm_config.gateway) {
-                                    (Some(current_connector), Some(configured_connector)) => {
-                                        let is_enabled =
-                                            *current_connector == configured_connector.to_string();
-                                        if is_enabled {
-                                            is_frm_connector_enabled = true;
-                                        }
-                                        is_enabled
-                                    }
-                                    (None, _) | (_, None) => true,
-                                })
-                                .collect::<Vec<_>>();
-                            let filtered_payment_methods = filtered_frm_config
-                                .iter()
-                                .map(|frm_config| {
-                                    let filtered_frm_config_by_pm = frm_config
-                                        .payment_methods
-                                        .iter()
-                                        .filter(|frm_config_pm| {
-                                            match (
-                                                payment_data.get_payment_attempt().payment_method,
-                                                frm_config_pm.payment_method,
-                                            ) {
-                                                (
-                                                    Some(current_pm),
-                                                    Some(configured_connector_pm),
-                                                ) => {
-                                                    let is_enabled = current_pm.to_string()
-                                                        == configured_connector_pm.to_string();
-                                                    if is_enabled {
-                                                        is_frm_pm_enabled = true;
-                                                    }
-                                                    is_enabled
-                                                }
-                                                (None, _) | (_, None) => true,
-                                            }
-                                        })
-                                        .collect::<Vec<_>>();
-                                    filtered_frm_config_by_pm
-                                })
-                                .collect::<Vec<_>>()
-                                .concat();
-                            let is_frm_enabled = is_frm_connector_enabled && is_frm_pm_enabled;
-                            logger::debug!(
-                                "is_frm_connector_enabled {:?}, is_frm_pm_enabled:  {:?}, is_frm_enabled :{:?}",
-                                is_frm_connector_enabled,
-                                is_frm_pm_enabled,
-                                is_frm_enabled
-                            );
-                            // filtered_frm_config...
-                            // Panic Safety: we are first checking if the object is present... only if present, we try to fetch index 0
-                            let frm_configs_object = FrmConfigsObject {
-                                frm_enabled_gateway: filtered_frm_config
-                                    .first()
-                                    .and_then(|c| c.gateway),
-                                frm_enabled_pm: filtered_payment_methods
-                                    .first()
-                                    .and_then(|pm| pm.payment_method),
-                                // flow type should be consumed from payment_method.flow. To provide backward compatibility, if we don't find it there, we consume it from payment_method.payment_method_types[0].flow_type.
-                                frm_preferred_flow_type: filtered_payment_methods
-                                    .first()
-                                    .and_then(|pm| pm.flow.clone())
-                                    .or(filtered_payment_methods.first().and_then(|pm| {
-                                        pm.payment_method_types.as_ref().and_then(|pmt| {
-                                            pmt.first().map(|pmts| pmts.flow.clone())
-                                        })
-                                    }))
-                                    .ok_or(errors::ApiErrorResponse::InvalidDataFormat {
-                                            field_name: "frm_configs".to_string(),
-                                            expected_format: r#"[{ "gateway": "stripe", "payment_methods": [{ "payment_method": "card","flow": "post"}]}]"#.to_string(),
-                                    })?,
-                            };
+                            let (is_frm_enabled, frm_configs_object) =
+                                get_frm_configs_object::<F, _>(&frm_configs_struct, payment_data)?;
                             logger::debug!(
                                 "frm_routing_configs: {:?} {:?} {:?} {:?}",
                                 frm_routing_algorithm_struct,
@@ -651,6 +718,14 @@ where
                         frm_router_data.to_owned(),
                     )
                     .await?;
+                #[cfg(feature = "v1")]
+                if matches!(
+                    updated_frm_data.fraud_check.frm_status,
+                    FraudCheckStatus::ManualReview
+                ) {
+                    manual_review::create_frm_review(state, merchant_context, &updated_frm_data)
+                        .await?;
+                }
                 return Ok(Some(updated_frm_data));
             }
         }
@@ -842,6 +917,12 @@ pub async fn make_fulfillment_api_call(
         )
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    if fraud_check.frm_name == risk_engine::RISK_ENGINE_PROVIDER {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Fulfillment is not supported by the fraud risk engine".to_string(),
+        }
+        .into());
+    }
     let connector_data = FraudCheckConnectorData::get_connector_by_name(&fraud_check.frm_name)?;
     let connector_integration: services::BoxedFrmConnectorIntegrationInterface<
         Fulfillment,
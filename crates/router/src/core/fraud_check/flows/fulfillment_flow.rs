@@ -134,6 +134,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -0,0 +1,553 @@
+use api_models::{
+    frm_review::{
+        FrmReviewAssignRequest, FrmReviewDecisionRequest, FrmReviewListConstraints,
+        FrmReviewListResponse, FrmReviewNoteRequest, FrmReviewNoteResponse, FrmReviewResponse,
+    },
+    payments as payment_types,
+};
+use common_enums::{FrmReviewAction, FrmReviewStatus, IntentStatus};
+use common_utils::{date_time, fp_utils};
+use diesel_models::enums::{UserRoleVersion, UserStatus};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::{instrument, logger, tracing};
+
+use super::types::FrmData;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    routes::{app::ReqState, SessionState},
+    services::{self, ApplicationResponse},
+    types::{api, domain, storage, transformers::ForeignFrom},
+    utils::OptionExt,
+};
+
+const FRM_REVIEW_EXPIRY_TASK: &str = "FRM_REVIEW_EXPIRY";
+const FRM_REVIEW_TAG: &str = "FRM_REVIEW";
+
+const DEFAULT_LIST_LIMIT: u32 = 20;
+const MAX_LIST_LIMIT: u32 = 100;
+
+/// Adds the payment flagged for manual review by the fraud check to the review queue, and
+/// schedules the task which takes the default action on the payment once the review expires.
+#[instrument(skip_all)]
+pub async fn create_frm_review(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    frm_data: &FrmData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let fraud_check = &frm_data.fraud_check;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    if db
+        .find_frm_review_by_merchant_id_attempt_id(merchant_id, &fraud_check.attempt_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch manual review for the payment attempt")?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let profile_id = frm_data
+        .payment_intent
+        .profile_id
+        .clone()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?;
+
+    let now = date_time::now();
+    let expires_at = now.saturating_add(time::Duration::hours(i64::from(
+        state.conf.frm.manual_review.expiry_in_hours,
+    )));
+
+    let frm_review_new = storage::FrmReviewNew {
+        review_id: common_utils::generate_id(consts::ID_LENGTH, "frr"),
+        merchant_id: merchant_id.clone(),
+        profile_id,
+        payment_id: fraud_check.payment_id.clone(),
+        attempt_id: fraud_check.attempt_id.clone(),
+        frm_id: fraud_check.frm_id.clone(),
+        frm_name: fraud_check.frm_name.clone(),
+        status: FrmReviewStatus::Pending,
+        frm_reason: fraud_check.frm_reason.clone(),
+        frm_score: fraud_check.frm_score,
+        assigned_to: None,
+        decision: None,
+        reviewed_by: None,
+        reviewed_at: None,
+        expires_at,
+        created_at: now,
+        modified_at: now,
+    };
+    let expiry_task = get_frm_review_expiry_task(&frm_review_new)?;
+
+    let frm_review = db
+        .insert_frm_review_with_expiry_task(frm_review_new, expiry_task)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert manual review along with its expiry task")?;
+
+    logger::info!(review_id = %frm_review.review_id, "Payment added to the manual review queue");
+    Ok(())
+}
+
+fn get_frm_review_expiry_task(
+    frm_review: &storage::FrmReviewNew,
+) -> RouterResult<storage::ProcessTrackerNew> {
+    let runner = storage::ProcessTrackerRunner::FrmReviewExpiryWorkflow;
+    let process_tracker_id = format!("{runner}_{FRM_REVIEW_EXPIRY_TASK}_{}", frm_review.review_id);
+    let tracking_data = storage::FrmReviewExpiryTrackingData {
+        review_id: frm_review.review_id.clone(),
+        merchant_id: frm_review.merchant_id.clone(),
+    };
+
+    storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        FRM_REVIEW_EXPIRY_TASK,
+        runner,
+        [FRM_REVIEW_TAG],
+        tracking_data,
+        None,
+        frm_review.expires_at,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct manual review expiry process tracker task")
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn list_frm_reviews(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    constraints: FrmReviewListConstraints,
+) -> RouterResponse<FrmReviewListResponse> {
+    let profile_id = profile_id.get_required_value("profile_id")?;
+    let limit = get_list_limit(constraints.limit)?;
+
+    let frm_reviews = state
+        .store
+        .list_frm_reviews_by_merchant_id_profile_id_status(
+            merchant_context.get_merchant_account().get_id(),
+            &profile_id,
+            constraints.status.unwrap_or_default(),
+            i64::from(limit),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list manual reviews")?;
+
+    let data: Vec<_> = frm_reviews
+        .into_iter()
+        .map(FrmReviewResponse::foreign_from)
+        .collect();
+
+    Ok(ApplicationResponse::Json(FrmReviewListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn retrieve_frm_review(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    review_id: String,
+) -> RouterResponse<FrmReviewResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let frm_review = find_frm_review(&state, merchant_id, profile_id, &review_id).await?;
+
+    let notes = state
+        .store
+        .list_frm_review_notes_by_merchant_id_review_id(merchant_id, &review_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list manual review notes")?
+        .into_iter()
+        .map(FrmReviewNoteResponse::foreign_from)
+        .collect();
+
+    let mut response = FrmReviewResponse::foreign_from(frm_review);
+    response.notes = Some(notes);
+    Ok(ApplicationResponse::Json(response))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn assign_frm_review(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    review_id: String,
+    request: FrmReviewAssignRequest,
+) -> RouterResponse<FrmReviewResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let frm_review = find_frm_review(&state, merchant_id, profile_id, &review_id).await?;
+    validate_review_is_pending(&frm_review)?;
+
+    if let Some(user_id) = &request.user_id {
+        validate_user_has_access_to_review(&state, &merchant_context, &frm_review, user_id).await?;
+    }
+
+    let frm_review = state
+        .store
+        .update_pending_frm_review_by_merchant_id_review_id(
+            merchant_id,
+            &review_id,
+            storage::FrmReviewUpdate::AssignmentUpdate {
+                assigned_to: request.user_id,
+            },
+        )
+        .await
+        .to_not_found_response(review_not_pending_error())?;
+
+    Ok(ApplicationResponse::Json(FrmReviewResponse::foreign_from(
+        frm_review,
+    )))
+}
+
+/// Approves or rejects the payment under review, by running the payments approve or reject flow,
+/// and resolves the review with the decision of the reviewer.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip(state, req_state, merchant_context))]
+pub async fn decide_frm_review(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    user_id: String,
+    review_id: String,
+    action: FrmReviewAction,
+    request: FrmReviewDecisionRequest,
+) -> RouterResponse<FrmReviewResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let frm_review = find_frm_review(&state, &merchant_id, profile_id.clone(), &review_id).await?;
+    validate_review_is_pending(&frm_review)?;
+
+    apply_frm_review_action(
+        &state,
+        req_state,
+        merchant_context,
+        profile_id,
+        frm_review.payment_id,
+        action,
+    )
+    .await?;
+
+    let status = match action {
+        FrmReviewAction::Approve => FrmReviewStatus::Approved,
+        FrmReviewAction::Reject => FrmReviewStatus::Rejected,
+    };
+    let frm_review = state
+        .store
+        .update_pending_frm_review_by_merchant_id_review_id(
+            &merchant_id,
+            &review_id,
+            storage::FrmReviewUpdate::DecisionUpdate {
+                status,
+                decision: action,
+                reviewed_by: Some(user_id.clone()),
+            },
+        )
+        .await
+        .to_not_found_response(review_not_pending_error())?;
+
+    if let Some(note) = request.note {
+        insert_frm_review_note(&state, &frm_review, user_id, note).await?;
+    }
+
+    Ok(ApplicationResponse::Json(FrmReviewResponse::foreign_from(
+        frm_review,
+    )))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn add_frm_review_note(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    user_id: String,
+    review_id: String,
+    request: FrmReviewNoteRequest,
+) -> RouterResponse<FrmReviewNoteResponse> {
+    let frm_review = find_frm_review(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        profile_id,
+        &review_id,
+    )
+    .await?;
+
+    let frm_review_note =
+        insert_frm_review_note(&state, &frm_review, user_id, request.note).await?;
+
+    Ok(ApplicationResponse::Json(
+        FrmReviewNoteResponse::foreign_from(frm_review_note),
+    ))
+}
+
+/// Takes the configured default action on the payment under review once the review expires. If
+/// the payment was already approved or rejected outside the review queue, the review is only
+/// marked as expired.
+#[instrument(skip(state, merchant_context))]
+pub async fn expire_frm_review(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    review_id: &str,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let frm_review = db
+        .find_frm_review_by_merchant_id_review_id(&merchant_id, review_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch manual review")?;
+
+    if frm_review.status != FrmReviewStatus::Pending {
+        return Ok(());
+    }
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &frm_review.payment_id,
+            &merchant_id,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment under manual review")?;
+
+    let frm_review_update = if payment_intent.status == IntentStatus::RequiresMerchantAction {
+        let default_action = state.conf.frm.manual_review.default_action;
+        apply_frm_review_action(
+            state,
+            state.get_req_state(),
+            merchant_context,
+            Some(frm_review.profile_id),
+            frm_review.payment_id,
+            default_action,
+        )
+        .await?;
+
+        storage::FrmReviewUpdate::DecisionUpdate {
+            status: FrmReviewStatus::Expired,
+            decision: default_action,
+            reviewed_by: None,
+        }
+    } else {
+        logger::info!(
+            review_id,
+            "Payment under manual review was already resolved outside the review queue"
+        );
+        storage::FrmReviewUpdate::StatusUpdate {
+            status: FrmReviewStatus::Expired,
+        }
+    };
+
+    db.update_pending_frm_review_by_merchant_id_review_id(
+        &merchant_id,
+        review_id,
+        frm_review_update,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to mark manual review as expired")?;
+
+    Ok(())
+}
+
+async fn apply_frm_review_action(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    payment_id: common_utils::id_type::PaymentId,
+    action: FrmReviewAction,
+) -> RouterResult<()> {
+    match action {
+        FrmReviewAction::Approve => {
+            payments::payments_core::<
+                api::Capture,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api::Capture>,
+            >(
+                state.clone(),
+                req_state,
+                merchant_context,
+                profile_id,
+                payments::PaymentApprove,
+                payment_types::PaymentsCaptureRequest {
+                    payment_id,
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+            )
+            .await?;
+        }
+        FrmReviewAction::Reject => {
+            payments::payments_core::<
+                api::Void,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api::Void>,
+            >(
+                state.clone(),
+                req_state,
+                merchant_context,
+                profile_id,
+                payments::PaymentReject,
+                payment_types::PaymentsCancelRequest {
+                    payment_id,
+                    cancellation_reason: Some("Rejected by merchant".to_string()),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn find_frm_review(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    review_id: &str,
+) -> RouterResult<storage::FrmReview> {
+    let frm_review = state
+        .store
+        .find_frm_review_by_merchant_id_review_id(merchant_id, review_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Manual review not found".to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &frm_review)?;
+
+    Ok(frm_review)
+}
+
+async fn insert_frm_review_note(
+    state: &SessionState,
+    frm_review: &storage::FrmReview,
+    user_id: String,
+    note: String,
+) -> RouterResult<storage::FrmReviewNote> {
+    state
+        .store
+        .insert_frm_review_note(storage::FrmReviewNoteNew {
+            note_id: common_utils::generate_id(consts::ID_LENGTH, "frn"),
+            review_id: frm_review.review_id.clone(),
+            merchant_id: frm_review.merchant_id.clone(),
+            user_id,
+            note,
+            created_at: date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert manual review note")
+}
+
+/// Validates that the user has an active role in the lineage of the review, at the organization,
+/// merchant or profile level, so that a review cannot be assigned to a user of another merchant
+async fn validate_user_has_access_to_review(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    frm_review: &storage::FrmReview,
+    user_id: &str,
+) -> RouterResult<()> {
+    for version in [UserRoleVersion::V2, UserRoleVersion::V1] {
+        match state
+            .global_store
+            .find_user_role_by_user_id_and_lineage(
+                user_id,
+                &state.tenant.tenant_id,
+                merchant_context.get_merchant_account().get_org_id(),
+                &frm_review.merchant_id,
+                &frm_review.profile_id,
+                version,
+            )
+            .await
+        {
+            Ok(user_role) if user_role.status == UserStatus::Active => return Ok(()),
+            Ok(_) => {}
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the role of the user"))
+            }
+        }
+    }
+
+    Err(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "User not found".to_string(),
+    }
+    .into())
+}
+
+fn validate_review_is_pending(frm_review: &storage::FrmReview) -> RouterResult<()> {
+    fp_utils::when(frm_review.status != FrmReviewStatus::Pending, || {
+        Err(review_not_pending_error().into())
+    })
+}
+
+fn review_not_pending_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::PreconditionFailed {
+        message: "The review has already been resolved".to_string(),
+    }
+}
+
+fn get_list_limit(limit: Option<u32>) -> RouterResult<u32> {
+    let limit = limit.unwrap_or(DEFAULT_LIST_LIMIT);
+    fp_utils::when(limit == 0 || limit > MAX_LIST_LIMIT, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be between 1 and {MAX_LIST_LIMIT}"),
+        })
+    })?;
+
+    Ok(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_list_limit_defaults_when_not_provided() {
+        assert_eq!(get_list_limit(None).unwrap(), DEFAULT_LIST_LIMIT);
+    }
+
+    #[test]
+    fn test_list_limit_rejects_out_of_range_values() {
+        assert_eq!(
+            get_list_limit(Some(MAX_LIST_LIMIT)).unwrap(),
+            MAX_LIST_LIMIT
+        );
+        assert!(get_list_limit(Some(0)).is_err());
+        assert!(get_list_limit(Some(MAX_LIST_LIMIT + 1)).is_err());
+    }
+}
@@ -0,0 +1,740 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
+
+use api_models::{
+    fraud_risk_engine::{AmountAnomalyConfig, FraudRiskEngineConfig, FraudRiskEngineRecord},
+    payments::{AdditionalCardInfo, AdditionalPaymentData},
+};
+use common_enums::{Country, CountryAlpha2};
+use common_types::fraud_risk_engine::{FraudRiskDecision, FraudRiskRule};
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    date_time,
+    ext_traits::{Encode, StringExt},
+    fp_utils,
+};
+use error_stack::ResultExt;
+use euclid::{
+    backend::{self, inputs as dsl_inputs, EuclidBackend},
+    frontend::ast::lowering,
+};
+use masking::{ExposeInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+
+use super::types::FrmData;
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, helpers, routing::make_dsl_input},
+        routing as core_routing,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain, fraud_check as frm_types,
+        storage::{self, enums::FraudCheckStatus},
+        ResponseId, RouterData,
+    },
+    utils::ValueExt,
+};
+
+/// The name of the internal FRM provider, to be set as the `data` of the FRM routing algorithm of
+/// the merchant account.
+pub const RISK_ENGINE_PROVIDER: &str = "risk_engine";
+
+const BIN_COUNTRY_MISMATCH_SIGNAL: &str = "bin_country_mismatch";
+const AMOUNT_ANOMALY_SIGNAL: &str = "amount_anomaly";
+const BLOCKLIST_HIT_SIGNAL: &str = "blocklist_hit";
+
+#[instrument(skip(state))]
+pub async fn upsert_risk_engine_config(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    request: FraudRiskEngineConfig,
+) -> RouterResponse<FraudRiskEngineRecord> {
+    let db = state.store.as_ref();
+    validate_profile(&state, &key_store, &profile_id).await?;
+    validate_risk_engine_config(&request)?;
+
+    let key = key_store
+        .merchant_id
+        .get_fraud_risk_engine_config_key(&profile_id);
+    let existing_record =
+        find_risk_engine_record(&state, &key_store.merchant_id, &profile_id).await?;
+
+    let now = date_time::now_unix_timestamp();
+    let record = FraudRiskEngineRecord {
+        profile_id,
+        config: request,
+        created_at: existing_record
+            .as_ref()
+            .map_or(now, |existing_record| existing_record.created_at),
+        modified_at: now,
+    };
+    let serialized_record = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the fraud risk engine config")?;
+
+    if existing_record.is_some() {
+        db.update_config_by_key(
+            &key,
+            storage::ConfigUpdate::Update {
+                config: Some(serialized_record),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the fraud risk engine config")?;
+    } else {
+        db.insert_config(storage::ConfigNew {
+            key,
+            config: serialized_record,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the fraud risk engine config")?;
+    }
+
+    Ok(ApplicationResponse::Json(record))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_risk_engine_config(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<FraudRiskEngineRecord> {
+    validate_profile(&state, &key_store, &profile_id).await?;
+
+    let record = find_risk_engine_record(&state, &key_store.merchant_id, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Fraud risk engine config does not exist for the business profile".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(record))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_risk_engine_config(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<()> {
+    validate_profile(&state, &key_store, &profile_id).await?;
+
+    state
+        .store
+        .delete_config_by_key(
+            &key_store
+                .merchant_id
+                .get_fraud_risk_engine_config_key(&profile_id),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Fraud risk engine config does not exist for the business profile".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Fetches the fraud risk engine config of the business profile, if one has been configured.
+pub async fn find_risk_engine_record(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<Option<FraudRiskEngineRecord>> {
+    let key = merchant_id.get_fraud_risk_engine_config_key(profile_id);
+    match state.store.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("FraudRiskEngineRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the fraud risk engine config")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the fraud risk engine config")),
+    }
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            &key_store.merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+        .map(|_| ())
+}
+
+fn validate_risk_engine_config(config: &FraudRiskEngineConfig) -> RouterResult<()> {
+    lowering::lower_program(config.program.clone()).change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid fraud risk engine rules".to_string(),
+        },
+    )?;
+
+    fp_utils::when(config.frm_configs.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "frm_configs must not be empty".to_string(),
+        })
+    })?;
+
+    for velocity_limit in &config.velocity_limits {
+        fp_utils::when(
+            velocity_limit.window_in_seconds == 0 || velocity_limit.max_payments == 0,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "window_in_seconds and max_payments of velocity limits must be \
+                              greater than zero"
+                        .to_string(),
+                })
+            },
+        )?;
+    }
+
+    if let Some(amount_anomaly) = config.amount_anomaly.as_ref() {
+        fp_utils::when(
+            amount_anomaly.window_in_seconds == 0 || amount_anomaly.max_amount_multiplier == 0,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "window_in_seconds and max_amount_multiplier of amount_anomaly \
+                              must be greater than zero"
+                        .to_string(),
+                })
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The connector account details used to construct the router data of the risk engine, which
+/// does not need any credentials.
+pub fn get_merchant_connector_account() -> helpers::MerchantConnectorAccountType {
+    helpers::MerchantConnectorAccountType::CacheVal(api_models::admin::MerchantConnectorDetails {
+        connector_account_details: Secret::new(serde_json::json!({ "auth_type": "NoKey" })),
+        metadata: None,
+    })
+}
+
+/// The outcome of evaluating the risk engine rules for a payment.
+#[derive(Debug, serde::Serialize)]
+pub struct RiskAssessment {
+    pub rule_name: Option<String>,
+    pub decision: FraudRiskDecision,
+    pub signals: BTreeMap<&'static str, bool>,
+}
+
+impl RiskAssessment {
+    pub fn get_frm_status(&self) -> FraudCheckStatus {
+        match self.decision {
+            FraudRiskDecision::Accept => FraudCheckStatus::Legit,
+            FraudRiskDecision::Review => FraudCheckStatus::ManualReview,
+            FraudRiskDecision::Decline => FraudCheckStatus::Fraud,
+        }
+    }
+
+    /// The score of the payment is the percentage of the computed signals that were raised.
+    pub fn get_score(&self) -> Option<i32> {
+        let raised_signals = self.signals.values().filter(|raised| **raised).count();
+        (raised_signals * 100)
+            .checked_div(self.signals.len())
+            .and_then(|score| i32::try_from(score).ok())
+    }
+}
+
+/// Evaluates the risk engine rules for the payment and sets the outcome as the response of the
+/// router data, in place of calling an FRM connector.
+#[instrument(skip_all)]
+pub async fn execute_risk_engine<F, Req, D, OperationData>(
+    state: &SessionState,
+    payment_data: &OperationData,
+    frm_data: &FrmData,
+    customer: &Option<domain::Customer>,
+    mut router_data: RouterData<F, Req, frm_types::FraudCheckResponseData>,
+) -> RouterResult<RouterData<F, Req, frm_types::FraudCheckResponseData>>
+where
+    OperationData: payments::OperationSessionGetters<D> + Send + Sync + Clone,
+{
+    // Only transactions are scored, the returns recorded after a refund have nothing to evaluate
+    if !matches!(
+        router_data.response,
+        Ok(frm_types::FraudCheckResponseData::TransactionResponse { .. })
+    ) {
+        return Ok(router_data);
+    }
+
+    let assessment = assess_payment(state, payment_data, frm_data, customer).await?;
+    logger::debug!(fraud_risk_assessment=?assessment);
+
+    router_data.response = Ok(frm_types::FraudCheckResponseData::TransactionResponse {
+        resource_id: ResponseId::NoResponseId,
+        status: assessment.get_frm_status(),
+        connector_metadata: None,
+        reason: Some(
+            assessment
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the fraud risk assessment")?,
+        ),
+        score: assessment.get_score(),
+    });
+
+    Ok(router_data)
+}
+
+async fn assess_payment<D, OperationData>(
+    state: &SessionState,
+    payment_data: &OperationData,
+    frm_data: &FrmData,
+    customer: &Option<domain::Customer>,
+) -> RouterResult<RiskAssessment>
+where
+    OperationData: payments::OperationSessionGetters<D> + Send + Sync + Clone,
+{
+    let merchant_id = frm_data.merchant_account.get_id();
+    let profile_id = &frm_data.connector_details.profile_id;
+    let record = find_risk_engine_record(state, merchant_id, profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Fraud risk engine is not configured for the business profile".to_string(),
+        })?;
+
+    let additional_card_info = get_additional_card_info(frm_data);
+    let signals = compute_signals(
+        state,
+        &record.config,
+        frm_data,
+        customer,
+        additional_card_info.as_ref(),
+    )
+    .await;
+
+    let payment_dsl_input = core_routing::PaymentsDslInput::new(
+        payment_data.get_setup_mandate(),
+        payment_data.get_payment_attempt(),
+        payment_data.get_payment_intent(),
+        payment_data.get_payment_method_data(),
+        payment_data.get_address(),
+        payment_data.get_recurring_details(),
+        payment_data.get_currency(),
+    );
+    let mut backend_input = make_dsl_input(&payment_dsl_input)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error constructing DSL input")?;
+
+    backend_input.issuer_data = Some(dsl_inputs::IssuerDataInput {
+        name: additional_card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_issuer.clone()),
+        country: additional_card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_issuing_country.as_deref())
+            .and_then(resolve_country),
+    });
+    backend_input
+        .metadata
+        .get_or_insert_with(Default::default)
+        .extend(
+            signals
+                .iter()
+                .map(|(signal, raised)| ((*signal).to_string(), raised.to_string())),
+        );
+
+    let interpreter =
+        backend::VirInterpreterBackend::<FraudRiskRule>::with_program(record.config.program)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error initializing DSL interpreter backend")?;
+    let output = interpreter
+        .execute(backend_input)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error executing fraud risk engine rules")?;
+
+    Ok(RiskAssessment {
+        rule_name: output.rule_name.clone(),
+        decision: output.get_output().get_decision(),
+        signals,
+    })
+}
+
+fn get_additional_card_info(frm_data: &FrmData) -> Option<AdditionalCardInfo> {
+    frm_data
+        .payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            AdditionalPaymentData::Card(card_info) => Some(*card_info),
+            _ => None,
+        })
+}
+
+/// Computes the risk signals of the payment. A signal is left out when the data needed to compute
+/// it is not available, so rules on it never match.
+async fn compute_signals(
+    state: &SessionState,
+    config: &FraudRiskEngineConfig,
+    frm_data: &FrmData,
+    customer: &Option<domain::Customer>,
+    additional_card_info: Option<&AdditionalCardInfo>,
+) -> BTreeMap<&'static str, bool> {
+    let mut signals = BTreeMap::new();
+
+    for velocity_limit in &config.velocity_limits {
+        let Some(dimension_value) =
+            get_dimension_value(velocity_limit.dimension, frm_data, customer)
+        else {
+            continue;
+        };
+        match count_payments_in_window(
+            state,
+            frm_data,
+            &format!("{:?}", velocity_limit.dimension),
+            &dimension_value,
+            velocity_limit.window_in_seconds,
+        )
+        .await
+        {
+            Ok(payment_count) => {
+                let is_exceeded = payment_count > u64::from(velocity_limit.max_payments);
+                *signals
+                    .entry(velocity_limit.dimension.get_signal_key())
+                    .or_default() |= is_exceeded;
+            }
+            Err(error) => logger::error!(?error, "Failed to compute the velocity signal"),
+        }
+    }
+
+    if let Some(amount_anomaly) = config.amount_anomaly.as_ref() {
+        if let Some(customer_id) = frm_data.payment_intent.customer_id.as_ref() {
+            match get_previous_amounts(
+                state,
+                frm_data,
+                customer_id.get_string_repr(),
+                amount_anomaly.window_in_seconds,
+            )
+            .await
+            {
+                Ok(previous_amounts) => {
+                    let amount = frm_data
+                        .payment_attempt
+                        .net_amount
+                        .get_total_amount()
+                        .get_amount_as_i64();
+                    signals.insert(
+                        AMOUNT_ANOMALY_SIGNAL,
+                        is_amount_anomalous(amount, &previous_amounts, amount_anomaly),
+                    );
+                }
+                Err(error) => logger::error!(?error, "Failed to compute the amount anomaly signal"),
+            }
+        }
+    }
+
+    let issuing_country = additional_card_info
+        .and_then(|card_info| card_info.card_issuing_country.as_deref())
+        .and_then(resolve_country);
+    let billing_country = frm_data
+        .address
+        .get_payment_method_billing()
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country);
+    if let (Some(issuing_country), Some(billing_country)) = (issuing_country, billing_country) {
+        signals.insert(
+            BIN_COUNTRY_MISMATCH_SIGNAL,
+            issuing_country != Country::from_alpha2(billing_country),
+        );
+    }
+
+    let blocklist_candidates = [
+        frm_data.payment_attempt.fingerprint_id.as_ref(),
+        additional_card_info.and_then(|card_info| card_info.card_isin.as_ref()),
+        additional_card_info.and_then(|card_info| card_info.card_extended_bin.as_ref()),
+    ];
+    let mut is_blocklist_hit = false;
+    for candidate in blocklist_candidates.into_iter().flatten() {
+        match state
+            .store
+            .find_blocklist_entry_by_merchant_id_fingerprint_id(
+                frm_data.merchant_account.get_id(),
+                candidate,
+            )
+            .await
+        {
+            Ok(_) => {
+                is_blocklist_hit = true;
+                break;
+            }
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => logger::error!(?error, "Failed to look up the blocklist"),
+        }
+    }
+    signals.insert(BLOCKLIST_HIT_SIGNAL, is_blocklist_hit);
+
+    signals
+}
+
+fn get_dimension_value(
+    dimension: api_models::fraud_risk_engine::VelocityDimension,
+    frm_data: &FrmData,
+    customer: &Option<domain::Customer>,
+) -> Option<String> {
+    use api_models::fraud_risk_engine::VelocityDimension;
+
+    use crate::connector::utils::PaymentsAttemptData;
+
+    match dimension {
+        VelocityDimension::CardFingerprint => frm_data.payment_attempt.fingerprint_id.clone(),
+        VelocityDimension::Customer => frm_data
+            .payment_intent
+            .customer_id
+            .as_ref()
+            .map(|customer_id| customer_id.get_string_repr().to_owned()),
+        VelocityDimension::IpAddress => frm_data
+            .payment_attempt
+            .get_browser_info()
+            .ok()
+            .and_then(|browser_info| browser_info.ip_address)
+            .map(|ip_address| ip_address.to_string()),
+        VelocityDimension::Email => customer
+            .as_ref()
+            .and_then(|customer| customer.email.clone())
+            .map(|email| email.into_inner().expose().to_lowercase()),
+    }
+}
+
+/// Hashes the value of a dimension, so that it is not stored in redis in plain text.
+fn hash_dimension_value(value: &str) -> RouterResult<String> {
+    Sha256
+        .generate_digest(value.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the risk engine dimension value")
+}
+
+/// Records the payment attempt against the value of the dimension and returns the number of
+/// payment attempts recorded against it within the current time window.
+async fn count_payments_in_window(
+    state: &SessionState,
+    frm_data: &FrmData,
+    dimension: &str,
+    dimension_value: &str,
+    window_in_seconds: u32,
+) -> RouterResult<u64> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let window_start = get_window_start(date_time::now_unix_timestamp(), window_in_seconds);
+    let key = format!(
+        "risk_engine_velocity_{}_{}_{}_{}_{}",
+        frm_data.merchant_account.get_id().get_string_repr(),
+        frm_data.connector_details.profile_id.get_string_repr(),
+        dimension,
+        hash_dimension_value(dimension_value)?,
+        window_start
+    );
+
+    redis_conn
+        .set_hash_field_if_not_exist(
+            &key.as_str().into(),
+            &frm_data.payment_attempt.attempt_id,
+            "1",
+            Some(window_in_seconds),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the payment for the velocity signal")?;
+
+    let recorded_payments = redis_conn
+        .get_hash_fields::<HashMap<String, String>>(&key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the payments for the velocity signal")?;
+
+    Ok(u64::try_from(recorded_payments.len()).unwrap_or(u64::MAX))
+}
+
+/// Returns the time window the given unix timestamp falls in, payments are counted in fixed windows
+/// so that the keys of past windows expire, instead of being kept alive by every new payment.
+fn get_window_start(now: i64, window_in_seconds: u32) -> i64 {
+    now / i64::from(window_in_seconds)
+}
+
+/// Records the amount of the payment attempt against the customer and returns the amounts of the
+/// other payment attempts of the customer seen within the current time window.
+async fn get_previous_amounts(
+    state: &SessionState,
+    frm_data: &FrmData,
+    customer_id: &str,
+    window_in_seconds: u32,
+) -> RouterResult<Vec<i64>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let window_start = get_window_start(date_time::now_unix_timestamp(), window_in_seconds);
+    let key = format!(
+        "risk_engine_amounts_{}_{}_{}_{}",
+        frm_data.merchant_account.get_id().get_string_repr(),
+        frm_data.connector_details.profile_id.get_string_repr(),
+        hash_dimension_value(customer_id)?,
+        window_start
+    );
+
+    let recorded_amounts = redis_conn
+        .get_hash_fields::<HashMap<String, String>>(&key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the previous amounts of the customer")?;
+
+    let amount = frm_data
+        .payment_attempt
+        .net_amount
+        .get_total_amount()
+        .get_amount_as_i64();
+    redis_conn
+        .set_hash_field_if_not_exist(
+            &key.as_str().into(),
+            &frm_data.payment_attempt.attempt_id,
+            amount.to_string(),
+            Some(window_in_seconds),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the amount of the payment")?;
+
+    Ok(recorded_amounts
+        .into_iter()
+        .filter(|(attempt_id, _)| *attempt_id != frm_data.payment_attempt.attempt_id)
+        .filter_map(|(_, amount)| amount.parse::<i64>().ok())
+        .collect())
+}
+
+/// Checks whether the amount exceeds the average of the previous amounts by more than the
+/// configured multiplier, once there are enough previous payments to compare against.
+fn is_amount_anomalous(
+    amount: i64,
+    previous_amounts: &[i64],
+    config: &AmountAnomalyConfig,
+) -> bool {
+    let payment_count = previous_amounts.len();
+    if payment_count == 0
+        || payment_count < usize::try_from(config.min_payments).unwrap_or(usize::MAX)
+    {
+        return false;
+    }
+
+    let total_amount: i128 = previous_amounts.iter().copied().map(i128::from).sum();
+    let payment_count = i128::try_from(payment_count).unwrap_or(i128::MAX);
+
+    // amount > (total_amount / payment_count) * multiplier, without losing precision
+    i128::from(amount) * payment_count > total_amount * i128::from(config.max_amount_multiplier)
+}
+
+/// Resolves the issuing country of a card, which may be given as an alpha-2 code or as the name
+/// of the country, such as `UNITED STATES OF AMERICA`.
+fn resolve_country(country: &str) -> Option<Country> {
+    let country = country.trim();
+    if let Ok(country_code) = CountryAlpha2::from_str(&country.to_uppercase()) {
+        return Some(Country::from_alpha2(country_code));
+    }
+
+    let normalize = |name: &str| {
+        name.chars()
+            .filter(|character| character.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let country_name = normalize(country);
+    Country::iter().find(|variant| normalize(&variant.to_string()) == country_name)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_resolve_country() {
+        assert_eq!(resolve_country("US"), Some(Country::UnitedStatesOfAmerica));
+        assert_eq!(resolve_country(" in "), Some(Country::India));
+        assert_eq!(
+            resolve_country("UNITED STATES OF AMERICA"),
+            Some(Country::UnitedStatesOfAmerica)
+        );
+        assert_eq!(
+            resolve_country("United Kingdom"),
+            Some(Country::UnitedKingdom)
+        );
+        assert_eq!(resolve_country("Atlantis"), None);
+    }
+
+    #[test]
+    fn test_is_amount_anomalous() {
+        let config = AmountAnomalyConfig {
+            window_in_seconds: 3600,
+            min_payments: 3,
+            max_amount_multiplier: 5,
+        };
+
+        // Not enough previous payments to compare against
+        assert!(!is_amount_anomalous(100_000, &[100, 100], &config));
+        // Within five times the average of 100
+        assert!(!is_amount_anomalous(500, &[50, 100, 150], &config));
+        assert!(is_amount_anomalous(501, &[50, 100, 150], &config));
+    }
+
+    #[test]
+    fn test_get_window_start() {
+        // Payments within the same hour share a window, regardless of when the last one was made
+        assert_eq!(get_window_start(7_200, 3600), 2);
+        assert_eq!(get_window_start(10_799, 3600), 2);
+        assert_eq!(get_window_start(10_800, 3600), 3);
+    }
+
+    #[test]
+    fn test_risk_assessment_status_and_score() {
+        let assessment = RiskAssessment {
+            rule_name: Some("velocity".to_string()),
+            decision: FraudRiskDecision::Review,
+            signals: BTreeMap::from([(BLOCKLIST_HIT_SIGNAL, false), (AMOUNT_ANOMALY_SIGNAL, true)]),
+        };
+        assert_eq!(assessment.get_frm_status(), FraudCheckStatus::ManualReview);
+        assert_eq!(assessment.get_score(), Some(50));
+
+        let assessment = RiskAssessment {
+            rule_name: None,
+            decision: FraudRiskDecision::Accept,
+            signals: BTreeMap::new(),
+        };
+        assert_eq!(assessment.get_frm_status(), FraudCheckStatus::Legit);
+        assert_eq!(assessment.get_score(), None);
+    }
+}
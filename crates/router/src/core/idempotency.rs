@@ -0,0 +1,407 @@
+use std::future::Future;
+
+use actix_web::http::header::HeaderMap;
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    errors::{CustomResult, ErrorSwitch},
+    events::{ApiEventMetric, ApiEventsType},
+    fp_utils,
+    pii::SecretSerdeValue,
+};
+use error_stack::ResultExt;
+use masking::{Maskable, Secret};
+use router_env::{instrument, logger, tracing, Flow};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    api_locking::{LockAction, LockingInput},
+    errors::{self, RouterResponse, RouterResult},
+};
+use crate::{
+    headers,
+    routes::{lock_utils, SessionState},
+    services::{authentication as auth, ApplicationResponse},
+};
+
+pub const IDEMPOTENCY_PREFIX: &str = "IDEMPOTENCY";
+
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// The `Idempotency-Key` sent with a request, along with the fingerprint of the request it was
+/// sent with.
+#[derive(Clone, Debug)]
+pub struct IdempotencyContext {
+    key: String,
+    flow: Flow,
+    request_fingerprint: String,
+}
+
+/// The response of a request made with an `Idempotency-Key`, stored against the key.
+#[derive(Debug, Serialize, Deserialize)]
+struct IdempotencyRecord {
+    flow: String,
+    request_fingerprint: String,
+    response: SecretSerdeValue,
+}
+
+/// The response of an API which supports idempotent requests. A retry with the same
+/// `Idempotency-Key` gets back the stored response of the original request.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum IdempotentResponse<R> {
+    Original(R),
+    // The stored response is masked as a whole in API events, since the masking information of
+    // its fields is lost once it is serialized
+    Replayed(SecretSerdeValue),
+}
+
+impl<R: ApiEventMetric> ApiEventMetric for IdempotentResponse<R> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        match self {
+            Self::Original(response) => response.get_api_event_type(),
+            // Falls back to the event type of the request
+            Self::Replayed(_) => None,
+        }
+    }
+}
+
+/// The error type of an API which supports idempotent requests, into which the errors raised while
+/// looking up or storing the idempotent response are converted.
+pub trait IdempotencyError: error_stack::Context {
+    fn from_api_error(error: &errors::ApiErrorResponse) -> Self;
+}
+
+impl IdempotencyError for errors::ApiErrorResponse {
+    fn from_api_error(error: &errors::ApiErrorResponse) -> Self {
+        error.clone()
+    }
+}
+
+impl IdempotencyError for errors::CustomersErrorResponse {
+    fn from_api_error(error: &errors::ApiErrorResponse) -> Self {
+        error.switch()
+    }
+}
+
+impl IdempotencyContext {
+    /// Reads the `Idempotency-Key` header of the request, if any. The fingerprint is computed from
+    /// the request body as sent by the client, so this must be called before any identifiers are
+    /// generated for the request.
+    pub fn from_request<T: Serialize>(
+        request_headers: &HeaderMap,
+        flow: Flow,
+        payload: &T,
+    ) -> RouterResult<Option<Self>> {
+        let Some(key) =
+            auth::get_header_value_by_key(headers::IDEMPOTENCY_KEY.to_string(), request_headers)?
+        else {
+            return Ok(None);
+        };
+
+        let key = key.trim();
+        fp_utils::when(
+            key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LENGTH,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "`{}` header must be between 1 and {MAX_IDEMPOTENCY_KEY_LENGTH} characters long",
+                        headers::IDEMPOTENCY_KEY
+                    ),
+                })
+            },
+        )?;
+
+        let request_fingerprint = serde_json::to_vec(payload)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the request for the idempotency fingerprint")
+            .and_then(|request| {
+                Sha256
+                    .generate_digest(&request)
+                    .map(hex::encode)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to compute the idempotency fingerprint")
+            })?;
+
+        Ok(Some(Self {
+            key: key.to_string(),
+            flow,
+            request_fingerprint,
+        }))
+    }
+
+    fn get_redis_key(&self, merchant_id: &common_utils::id_type::MerchantId) -> String {
+        format!(
+            "{IDEMPOTENCY_PREFIX}_{}_{}",
+            merchant_id.get_string_repr(),
+            self.key
+        )
+    }
+
+    /// Concurrent requests with the same key are serialized, so that a retry sent while the
+    /// original request is in flight waits for, and replays, its response.
+    fn get_lock_action(&self) -> LockAction {
+        LockAction::Hold {
+            input: LockingInput {
+                unique_locking_key: format!("{IDEMPOTENCY_PREFIX}_{}", self.key),
+                api_identifier: lock_utils::ApiIdentifier::from(self.flow.clone()),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+/// Runs the request, unless a request was already made with the same `Idempotency-Key`, in which
+/// case the stored response of that request is replayed. A request with the same key but a
+/// different body or API is rejected. Only successful responses are stored, so that a request
+/// which failed can be retried with the same key.
+#[instrument(skip_all)]
+pub async fn execute_idempotently<R, E, Fut>(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    idempotency_context: Option<IdempotencyContext>,
+    request: Fut,
+) -> CustomResult<ApplicationResponse<IdempotentResponse<R>>, E>
+where
+    R: Serialize,
+    E: IdempotencyError,
+    Fut: Future<Output = CustomResult<ApplicationResponse<R>, E>>,
+{
+    let Some(idempotency_context) = idempotency_context else {
+        return request.await.map(wrap_original_response);
+    };
+
+    let lock_action = idempotency_context.get_lock_action();
+    lock_action
+        .clone()
+        .perform_locking_action(&state, merchant_id.clone())
+        .await
+        .map_err(convert_error)?;
+    let response =
+        execute_with_idempotency_key(&state, &merchant_id, &idempotency_context, request).await;
+    lock_action
+        .free_lock_action(&state, merchant_id)
+        .await
+        .map_err(convert_error)?;
+
+    response
+}
+
+async fn execute_with_idempotency_key<R, E, Fut>(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    idempotency_context: &IdempotencyContext,
+    request: Fut,
+) -> CustomResult<ApplicationResponse<IdempotentResponse<R>>, E>
+where
+    R: Serialize,
+    E: IdempotencyError,
+    Fut: Future<Output = CustomResult<ApplicationResponse<R>, E>>,
+{
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+        .map_err(convert_error)?;
+    let redis_key = idempotency_context.get_redis_key(merchant_id);
+
+    let stored_record = redis_conn
+        .get_and_deserialize_key::<IdempotencyRecord>(
+            &redis_key.as_str().into(),
+            "IdempotencyRecord",
+        )
+        .await;
+    match stored_record {
+        Ok(record) => return replay_response(idempotency_context, record).map_err(convert_error),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                redis_interface::errors::RedisError::NotFound
+            ) => {}
+        Err(error) => {
+            return Err(convert_error(
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the stored idempotent response"),
+            ))
+        }
+    }
+
+    let response = request.await?;
+
+    let response_body = match &response {
+        ApplicationResponse::Json(body) | ApplicationResponse::JsonWithHeaders((body, _)) => {
+            Some(body)
+        }
+        _ => None,
+    };
+    if let Some(response_body) = response_body {
+        let record = serde_json::to_value(response_body).map(|response| IdempotencyRecord {
+            flow: idempotency_context.flow.to_string(),
+            request_fingerprint: idempotency_context.request_fingerprint.clone(),
+            response: Secret::new(response),
+        });
+
+        // The request has already been processed at this point, so a failure to store its
+        // response must not fail the request
+        match record {
+            Ok(record) => redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &redis_key.as_str().into(),
+                    record,
+                    i64::from(state.conf.idempotency.ttl_in_seconds),
+                )
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to store the idempotent response"))
+                .ok(),
+            Err(error) => {
+                logger::error!(?error, "Failed to serialize the idempotent response");
+                None
+            }
+        };
+    }
+
+    Ok(wrap_original_response(response))
+}
+
+fn replay_response<R>(
+    idempotency_context: &IdempotencyContext,
+    record: IdempotencyRecord,
+) -> RouterResponse<IdempotentResponse<R>> {
+    fp_utils::when(
+        record.flow != idempotency_context.flow.to_string()
+            || record.request_fingerprint != idempotency_context.request_fingerprint,
+        || {
+            Err(errors::ApiErrorResponse::GenericDuplicateError {
+                message: format!(
+                    "The `{}` has already been used with a different request",
+                    headers::IDEMPOTENCY_KEY
+                ),
+            })
+        },
+    )?;
+    logger::info!("Replaying the stored response for the idempotency key");
+
+    Ok(ApplicationResponse::JsonWithHeaders((
+        IdempotentResponse::Replayed(record.response),
+        vec![(
+            headers::IDEMPOTENT_REPLAYED.to_string(),
+            Maskable::new_normal("true".to_string()),
+        )],
+    )))
+}
+
+fn convert_error<E: IdempotencyError>(
+    error: error_stack::Report<errors::ApiErrorResponse>,
+) -> error_stack::Report<E> {
+    let converted_error = E::from_api_error(error.current_context());
+    error.change_context(converted_error)
+}
+
+fn wrap_original_response<R>(
+    response: ApplicationResponse<R>,
+) -> ApplicationResponse<IdempotentResponse<R>> {
+    match response {
+        ApplicationResponse::Json(body) => {
+            ApplicationResponse::Json(IdempotentResponse::Original(body))
+        }
+        ApplicationResponse::JsonWithHeaders((body, headers)) => {
+            ApplicationResponse::JsonWithHeaders((IdempotentResponse::Original(body), headers))
+        }
+        ApplicationResponse::StatusOk => ApplicationResponse::StatusOk,
+        ApplicationResponse::TextPlain(text) => ApplicationResponse::TextPlain(text),
+        ApplicationResponse::JsonForRedirection(redirection) => {
+            ApplicationResponse::JsonForRedirection(redirection)
+        }
+        ApplicationResponse::Form(form) => ApplicationResponse::Form(form),
+        ApplicationResponse::PaymentLinkForm(form) => ApplicationResponse::PaymentLinkForm(form),
+        ApplicationResponse::FileData(file_data) => ApplicationResponse::FileData(file_data),
+        ApplicationResponse::GenericLinkForm(form) => ApplicationResponse::GenericLinkForm(form),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    fn headers_with_key(key: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("idempotency-key"),
+            HeaderValue::from_static(key),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_context_is_not_created_without_header() {
+        let context = IdempotencyContext::from_request(
+            &HeaderMap::new(),
+            Flow::PaymentsCreate,
+            &serde_json::json!({"amount": 100}),
+        )
+        .unwrap();
+        assert!(context.is_none());
+    }
+
+    #[test]
+    fn test_fingerprint_depends_on_request_body() {
+        let headers = headers_with_key("order_123");
+        let first = IdempotencyContext::from_request(
+            &headers,
+            Flow::PaymentsCreate,
+            &serde_json::json!({"amount": 100}),
+        )
+        .unwrap()
+        .unwrap();
+        let retry = IdempotencyContext::from_request(
+            &headers,
+            Flow::PaymentsCreate,
+            &serde_json::json!({"amount": 100}),
+        )
+        .unwrap()
+        .unwrap();
+        let conflicting = IdempotencyContext::from_request(
+            &headers,
+            Flow::PaymentsCreate,
+            &serde_json::json!({"amount": 200}),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(first.request_fingerprint, retry.request_fingerprint);
+        assert_ne!(first.request_fingerprint, conflicting.request_fingerprint);
+    }
+
+    #[test]
+    fn test_replay_rejects_conflicting_request() {
+        let context = IdempotencyContext::from_request(
+            &headers_with_key("order_123"),
+            Flow::PaymentsCreate,
+            &serde_json::json!({"amount": 200}),
+        )
+        .unwrap()
+        .unwrap();
+        let record = IdempotencyRecord {
+            flow: Flow::PaymentsCreate.to_string(),
+            request_fingerprint: "fingerprint_of_another_request".to_string(),
+            response: Secret::new(serde_json::json!({"status": "succeeded"})),
+        };
+
+        assert!(replay_response::<serde_json::Value>(&context, record).is_err());
+    }
+
+    #[test]
+    fn test_empty_key_is_rejected() {
+        let result = IdempotencyContext::from_request(
+            &headers_with_key(" "),
+            Flow::PaymentsCreate,
+            &serde_json::json!({}),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -87,6 +87,7 @@ pub async fn construct_mandate_revoke_router_data(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
@@ -1,4 +1,6 @@
 pub mod cards;
+#[cfg(feature = "v2")]
+pub mod embedded_vault;
 pub mod network_tokenization;
 pub mod surcharge_decision_configs;
 #[cfg(feature = "v1")]
@@ -1815,11 +1817,15 @@ pub async fn vault_payment_method_internal(
     let db = &*state.store;
 
     // get fingerprint_id from vault
-    let fingerprint_id_from_vault =
-        vault::get_fingerprint_id_from_vault(state, pmd, customer_id.get_string_repr().to_owned())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to get fingerprint_id from vault")?;
+    let fingerprint_id_from_vault = vault::get_fingerprint_id_from_vault(
+        state,
+        merchant_context.get_merchant_account().get_id(),
+        pmd,
+        customer_id.get_string_repr().to_owned(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get fingerprint_id from vault")?;
 
     // throw back error if payment method is duplicated
     when(
@@ -3315,6 +3321,7 @@ async fn create_single_use_tokenization_flow(
             psd2_sca_exemption_type: None,
             raw_connector_response: None,
             is_payment_id_from_merchant: None,
+            merchant_connector_id: None,
         };
 
     let payment_method_token_response = tokenization::add_token_for_payment_method(
@@ -0,0 +1,425 @@
+//! Vault embedded in the application, which can be used in place of the external locker service
+//! by self-hosted and test deployments.
+//!
+//! Every entry is encrypted with a data key generated for the entry, the data key itself is
+//! encrypted using the configured encryption management service before being stored alongside
+//! the entry. Entries are scoped to the merchant they were stored for. Expired entries are removed
+//! when they are read, and whenever a new entry is stored for the merchant.
+
+use common_utils::{
+    crypto::{self, DecodeMessage, EncodeMessage, SignMessage},
+    ext_traits::{ByteSliceExt, Encode},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_interfaces::encryption_interface::EncryptionManagementInterface;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, CustomResult},
+    routes::SessionState,
+    types::{domain, payment_methods as pm_types, storage},
+};
+
+/// Length of the data keys generated for encrypting the entries stored in the embedded vault
+const DATA_KEY_LENGTH: usize = 32;
+
+/// A vaulting flow which can be served by the embedded vault, the request and response payloads
+/// are the same as those exchanged with the external locker service.
+#[async_trait::async_trait]
+pub trait EmbeddedVaultFlow: pm_types::VaultingInterface {
+    async fn call_embedded_vault(
+        vault: &EmbeddedVault<'_>,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError>;
+}
+
+pub struct EmbeddedVault<'a> {
+    state: &'a SessionState,
+    merchant_id: &'a id_type::MerchantId,
+}
+
+impl<'a> EmbeddedVault<'a> {
+    pub fn new(state: &'a SessionState, merchant_id: &'a id_type::MerchantId) -> Self {
+        Self { state, merchant_id }
+    }
+
+    #[instrument(skip_all)]
+    async fn add(
+        &self,
+        request: pm_types::AddVaultRequest<serde_json::Value>,
+    ) -> CustomResult<pm_types::AddVaultResponse, errors::VaultError> {
+        let db = &*self.state.store;
+        let vault_id = request.vault_id.get_string_repr();
+        let entity_id = request.entity_id.get_string_repr();
+
+        let data = request
+            .data
+            .encode_to_vec()
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode the data to be stored in the embedded vault")?;
+        let (encrypted_data, encrypted_data_key) =
+            encrypt_entry_data(self.state.encryption_client.as_ref(), &data).await?;
+
+        let now = common_utils::date_time::now();
+        let expires_at =
+            (request.ttl > 0).then(|| now.saturating_add(time::Duration::seconds(request.ttl)));
+
+        let existing_entry = db
+            .find_embedded_vault_entry(self.merchant_id, vault_id)
+            .await;
+
+        match existing_entry {
+            Ok(entry) if entry.entity_id != *entity_id => {
+                Err(report!(errors::VaultError::SavePaymentMethodFailed)).attach_printable(
+                    "The vault id is already in use by a different entity in the embedded vault",
+                )?
+            }
+            Ok(_) => {
+                db.update_embedded_vault_entry(
+                    self.merchant_id,
+                    vault_id,
+                    storage::EmbeddedVaultEntryUpdate {
+                        entity_id: entity_id.to_owned(),
+                        encrypted_data,
+                        encrypted_data_key,
+                        expires_at,
+                        modified_at: now,
+                    },
+                )
+                .await
+                .change_context(errors::VaultError::SavePaymentMethodFailed)
+                .attach_printable("Failed to update the entry in the embedded vault")?;
+            }
+            Err(error) if error.current_context().is_db_not_found() => {
+                db.insert_embedded_vault_entry(storage::EmbeddedVaultEntryNew {
+                    merchant_id: self.merchant_id.to_owned(),
+                    vault_id: vault_id.to_owned(),
+                    entity_id: entity_id.to_owned(),
+                    encrypted_data,
+                    encrypted_data_key,
+                    expires_at,
+                    created_at: now,
+                    modified_at: now,
+                })
+                .await
+                .change_context(errors::VaultError::SavePaymentMethodFailed)
+                .attach_printable("Failed to insert the entry in the embedded vault")?;
+            }
+            Err(error) => Err(error)
+                .change_context(errors::VaultError::SavePaymentMethodFailed)
+                .attach_printable("Failed to find the entry in the embedded vault")?,
+        };
+
+        // Expired entries are otherwise only removed when they are read, the expired entries of the
+        // merchant are swept whenever an entry is stored so that they do not pile up
+        let _ = db
+            .delete_expired_embedded_vault_entries(self.merchant_id, now)
+            .await
+            .inspect_err(|error| {
+                logger::error!(?error, "Failed to delete expired embedded vault entries")
+            });
+
+        Ok(pm_types::AddVaultResponse {
+            entity_id: Some(request.entity_id),
+            vault_id: request.vault_id,
+            fingerprint_id: None,
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn retrieve(
+        &self,
+        request: pm_types::VaultRetrieveRequest,
+    ) -> CustomResult<serde_json::Value, errors::VaultError> {
+        let entry = self
+            .find_entry(&request.entity_id, &request.vault_id)
+            .await?;
+
+        let data = decrypt_entry_data(self.state.encryption_client.as_ref(), &entry).await?;
+        let data: serde_json::Value = data
+            .parse_struct("EmbeddedVaultData")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Failed to parse the data stored in the embedded vault")?;
+
+        Ok(serde_json::json!({ "data": data }))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete(
+        &self,
+        request: pm_types::VaultDeleteRequest,
+    ) -> CustomResult<pm_types::VaultDeleteResponse, errors::VaultError> {
+        self.find_entry(&request.entity_id, &request.vault_id)
+            .await
+            .change_context(errors::VaultError::DeleteCardFailed)?;
+
+        self.state
+            .store
+            .delete_embedded_vault_entry(self.merchant_id, request.vault_id.get_string_repr())
+            .await
+            .change_context(errors::VaultError::DeleteCardFailed)
+            .attach_printable("Failed to delete the entry from the embedded vault")?;
+
+        Ok(pm_types::VaultDeleteResponse {
+            entity_id: request.entity_id,
+            vault_id: request.vault_id,
+        })
+    }
+
+    fn fingerprint(
+        &self,
+        request: pm_types::VaultFingerprintRequest,
+    ) -> CustomResult<pm_types::VaultFingerprintResponse, errors::VaultError> {
+        let embedded_vault_config = self
+            .state
+            .conf
+            .embedded_vault
+            .as_ref()
+            .ok_or(errors::VaultError::GenerateFingerprintFailed)
+            .attach_printable("Embedded vault configuration is not present")?
+            .get_inner();
+
+        let fingerprint_id = compute_fingerprint(
+            embedded_vault_config.fingerprint_secret.peek().as_bytes(),
+            self.merchant_id,
+            &request.key,
+            &request.data,
+        )?;
+
+        Ok(pm_types::VaultFingerprintResponse { fingerprint_id })
+    }
+
+    /// Finds the entry stored for the entity, expired entries are removed from the vault and are
+    /// treated as being absent.
+    async fn find_entry(
+        &self,
+        entity_id: &id_type::GlobalCustomerId,
+        vault_id: &domain::VaultId,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::VaultError> {
+        let db = &*self.state.store;
+
+        let entry = db
+            .find_embedded_vault_entry(self.merchant_id, vault_id.get_string_repr())
+            .await
+            .change_context(errors::VaultError::FetchPaymentMethodFailed)
+            .attach_printable("Failed to find the entry in the embedded vault")?;
+
+        if entry.entity_id != *entity_id.get_string_repr() {
+            return Err(
+                report!(errors::VaultError::FetchPaymentMethodFailed).attach_printable(
+                    "The entry in the embedded vault belongs to a different entity",
+                ),
+            );
+        }
+
+        if entry
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= common_utils::date_time::now())
+        {
+            let _ = db
+                .delete_embedded_vault_entry(self.merchant_id, vault_id.get_string_repr())
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to delete expired embedded vault entry")
+                });
+
+            return Err(report!(errors::VaultError::FetchPaymentMethodFailed)
+                .attach_printable("The entry in the embedded vault has expired"));
+        }
+
+        Ok(entry)
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddedVaultFlow for pm_types::AddVault {
+    async fn call_embedded_vault(
+        vault: &EmbeddedVault<'_>,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError> {
+        let request: pm_types::AddVaultRequest<serde_json::Value> = payload
+            .parse_struct("AddVaultRequest")
+            .change_context(errors::VaultError::RequestEncodingFailed)?;
+
+        vault
+            .add(request)
+            .await?
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddedVaultFlow for pm_types::VaultRetrieve {
+    async fn call_embedded_vault(
+        vault: &EmbeddedVault<'_>,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError> {
+        let request: pm_types::VaultRetrieveRequest = payload
+            .parse_struct("VaultRetrieveRequest")
+            .change_context(errors::VaultError::RequestEncodingFailed)?;
+
+        vault
+            .retrieve(request)
+            .await?
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddedVaultFlow for pm_types::VaultDelete {
+    async fn call_embedded_vault(
+        vault: &EmbeddedVault<'_>,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError> {
+        let request: pm_types::VaultDeleteRequest = payload
+            .parse_struct("VaultDeleteRequest")
+            .change_context(errors::VaultError::RequestEncodingFailed)?;
+
+        vault
+            .delete(request)
+            .await?
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddedVaultFlow for pm_types::GetVaultFingerprint {
+    async fn call_embedded_vault(
+        vault: &EmbeddedVault<'_>,
+        payload: &[u8],
+    ) -> CustomResult<String, errors::VaultError> {
+        let request: pm_types::VaultFingerprintRequest = payload
+            .parse_struct("VaultFingerprintRequest")
+            .change_context(errors::VaultError::RequestEncodingFailed)?;
+
+        vault
+            .fingerprint(request)?
+            .encode_to_string_of_json()
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+    }
+}
+
+/// Encrypts the data with a newly generated data key, returns the encrypted data along with the
+/// data key encrypted by the encryption management service.
+async fn encrypt_entry_data(
+    encryption_client: &dyn EncryptionManagementInterface,
+    data: &[u8],
+) -> CustomResult<(Vec<u8>, Vec<u8>), errors::VaultError> {
+    let data_key = crypto::generate_cryptographically_secure_random_bytes::<DATA_KEY_LENGTH>();
+
+    let encrypted_data = crypto::GcmAes256
+        .encode_message(&data_key, data)
+        .change_context(errors::VaultError::RequestEncryptionFailed)
+        .attach_printable("Failed to encrypt the data to be stored in the embedded vault")?;
+
+    let encrypted_data_key = encryption_client
+        .encrypt(&data_key)
+        .await
+        .change_context(errors::VaultError::RequestEncryptionFailed)
+        .attach_printable("Failed to encrypt the data key of the embedded vault entry")?;
+
+    Ok((encrypted_data, encrypted_data_key))
+}
+
+async fn decrypt_entry_data(
+    encryption_client: &dyn EncryptionManagementInterface,
+    entry: &storage::EmbeddedVaultEntry,
+) -> CustomResult<Vec<u8>, errors::VaultError> {
+    let data_key = encryption_client
+        .decrypt(&entry.encrypted_data_key)
+        .await
+        .change_context(errors::VaultError::ResponseDecryptionFailed)
+        .attach_printable("Failed to decrypt the data key of the embedded vault entry")?;
+
+    crypto::GcmAes256
+        .decode_message(&data_key, entry.encrypted_data.clone().into())
+        .change_context(errors::VaultError::ResponseDecryptionFailed)
+        .attach_printable("Failed to decrypt the data stored in the embedded vault")
+}
+
+/// Fingerprints are scoped to the merchant, the same data stored for different merchants results
+/// in different fingerprints.
+fn compute_fingerprint(
+    secret: &[u8],
+    merchant_id: &id_type::MerchantId,
+    key: &str,
+    data: &str,
+) -> CustomResult<String, errors::VaultError> {
+    let message = format!("{}:{key}:{data}", merchant_id.get_string_repr());
+
+    crypto::HmacSha256
+        .sign_message(secret, message.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::VaultError::GenerateFingerprintFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_compute_fingerprint() {
+        let merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+        let other_merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_2")).unwrap();
+
+        let fingerprint =
+            compute_fingerprint(b"secret", &merchant_id, "customer_1", "4242424242424242").unwrap();
+
+        assert_eq!(fingerprint.len(), 64);
+        assert_eq!(
+            fingerprint,
+            compute_fingerprint(b"secret", &merchant_id, "customer_1", "4242424242424242").unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            compute_fingerprint(
+                b"secret",
+                &other_merchant_id,
+                "customer_1",
+                "4242424242424242"
+            )
+            .unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            compute_fingerprint(b"secret", &merchant_id, "customer_2", "4242424242424242").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_entry_data_encryption() {
+        let encryption_client = external_services::no_encryption::core::NoEncryption;
+        let (encrypted_data, encrypted_data_key) =
+            encrypt_entry_data(&encryption_client, b"vault data")
+                .await
+                .unwrap();
+
+        assert_ne!(encrypted_data, b"vault data".to_vec());
+
+        let entry = storage::EmbeddedVaultEntry {
+            merchant_id: id_type::MerchantId::default(),
+            vault_id: "vault_1".to_string(),
+            entity_id: "customer_1".to_string(),
+            encrypted_data,
+            encrypted_data_key,
+            expires_at: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+        };
+
+        assert_eq!(
+            decrypt_entry_data(&encryption_client, &entry)
+                .await
+                .unwrap(),
+            b"vault data".to_vec()
+        );
+    }
+}
@@ -36,7 +36,7 @@ use crate::{
     core::{
         errors::ConnectorErrorExt,
         errors::StorageErrorExt,
-        payment_methods::{transformers as pm_transforms, utils},
+        payment_methods::{embedded_vault, transformers as pm_transforms, utils},
         payments::{self as payments_core, helpers as payment_helpers},
         utils as core_utils,
     },
@@ -1237,11 +1237,18 @@ async fn create_vault_request<R: pm_types::VaultingInterface>(
 
 #[cfg(feature = "v2")]
 #[instrument(skip_all)]
-pub async fn call_to_vault<V: pm_types::VaultingInterface>(
+pub async fn call_to_vault<V: pm_types::VaultingInterface + embedded_vault::EmbeddedVaultFlow>(
     state: &routes::SessionState,
+    merchant_id: &id_type::MerchantId,
     payload: Vec<u8>,
 ) -> CustomResult<String, errors::VaultError> {
     let locker = &state.conf.locker;
+
+    if locker.vault_backend == settings::VaultBackend::Embedded {
+        let vault = embedded_vault::EmbeddedVault::new(state, merchant_id);
+        return V::call_embedded_vault(&vault, &payload).await;
+    }
+
     let jwekey = state.conf.jwekey.get_inner();
 
     let request =
@@ -1272,6 +1279,7 @@ pub async fn call_to_vault<V: pm_types::VaultingInterface>(
 #[instrument(skip_all)]
 pub async fn get_fingerprint_id_from_vault<D: domain::VaultingDataInterface + serde::Serialize>(
     state: &routes::SessionState,
+    merchant_id: &id_type::MerchantId,
     data: &D,
     key: String,
 ) -> CustomResult<String, errors::VaultError> {
@@ -1284,7 +1292,7 @@ pub async fn get_fingerprint_id_from_vault<D: domain::VaultingDataInterface + se
         .change_context(errors::VaultError::RequestEncodingFailed)
         .attach_printable("Failed to encode VaultFingerprintRequest")?;
 
-    let resp = call_to_vault::<pm_types::GetVaultFingerprint>(state, payload)
+    let resp = call_to_vault::<pm_types::GetVaultFingerprint>(state, merchant_id, payload)
         .await
         .change_context(errors::VaultError::VaultAPIError)
         .attach_printable("Call to vault failed")?;
@@ -1317,10 +1325,14 @@ pub async fn add_payment_method_to_vault(
     .change_context(errors::VaultError::RequestEncodingFailed)
     .attach_printable("Failed to encode AddVaultRequest")?;
 
-    let resp = call_to_vault::<pm_types::AddVault>(state, payload)
-        .await
-        .change_context(errors::VaultError::VaultAPIError)
-        .attach_printable("Call to vault failed")?;
+    let resp = call_to_vault::<pm_types::AddVault>(
+        state,
+        merchant_context.get_merchant_account().get_id(),
+        payload,
+    )
+    .await
+    .change_context(errors::VaultError::VaultAPIError)
+    .attach_printable("Call to vault failed")?;
 
     let stored_pm_resp: pm_types::AddVaultResponse = resp
         .parse_struct("AddVaultResponse")
@@ -1346,10 +1358,14 @@ pub async fn retrieve_payment_method_from_vault_internal(
     .change_context(errors::VaultError::RequestEncodingFailed)
     .attach_printable("Failed to encode VaultRetrieveRequest")?;
 
-    let resp = call_to_vault::<pm_types::VaultRetrieve>(state, payload)
-        .await
-        .change_context(errors::VaultError::VaultAPIError)
-        .attach_printable("Call to vault failed")?;
+    let resp = call_to_vault::<pm_types::VaultRetrieve>(
+        state,
+        merchant_context.get_merchant_account().get_id(),
+        payload,
+    )
+    .await
+    .change_context(errors::VaultError::VaultAPIError)
+    .attach_printable("Call to vault failed")?;
 
     let stored_pm_resp: pm_types::VaultRetrieveResponse = resp
         .parse_struct("VaultRetrieveResponse")
@@ -1363,6 +1379,7 @@ pub async fn retrieve_payment_method_from_vault_internal(
 #[instrument(skip_all)]
 pub async fn retrieve_value_from_vault(
     state: &routes::SessionState,
+    merchant_id: &id_type::MerchantId,
     request: pm_types::VaultRetrieveRequest,
 ) -> CustomResult<serde_json::value::Value, errors::VaultError> {
     let payload = request
@@ -1370,7 +1387,7 @@ pub async fn retrieve_value_from_vault(
         .change_context(errors::VaultError::RequestEncodingFailed)
         .attach_printable("Failed to encode VaultRetrieveRequest")?;
 
-    let resp = call_to_vault::<pm_types::VaultRetrieve>(state, payload)
+    let resp = call_to_vault::<pm_types::VaultRetrieve>(state, merchant_id, payload)
         .await
         .change_context(errors::VaultError::VaultAPIError)
         .attach_printable("Call to vault failed")?;
@@ -1721,10 +1738,14 @@ pub async fn delete_payment_method_data_from_vault_internal(
     .change_context(errors::VaultError::RequestEncodingFailed)
     .attach_printable("Failed to encode VaultDeleteRequest")?;
 
-    let resp = call_to_vault::<pm_types::VaultDelete>(state, payload)
-        .await
-        .change_context(errors::VaultError::VaultAPIError)
-        .attach_printable("Call to vault failed")?;
+    let resp = call_to_vault::<pm_types::VaultDelete>(
+        state,
+        merchant_context.get_merchant_account().get_id(),
+        payload,
+    )
+    .await
+    .change_context(errors::VaultError::VaultAPIError)
+    .attach_printable("Call to vault failed")?;
 
     let stored_pm_resp: pm_types::VaultDeleteResponse = resp
         .parse_struct("VaultDeleteResponse")
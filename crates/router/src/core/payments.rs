@@ -8648,6 +8648,13 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    let connectors = services::api::circuit_breaker::filter_connectors_with_open_circuit(
+        state,
+        merchant_context.get_merchant_account().get_id(),
+        connectors,
+    )
+    .await;
+
     // dynamic success based connector selection
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
     let connectors = if let Some(algo) = business_profile.dynamic_routing_algorithm.clone() {
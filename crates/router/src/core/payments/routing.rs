@@ -84,6 +84,29 @@ pub enum CachedAlgorithm {
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
 }
 
+impl CachedAlgorithm {
+    pub fn from_static_routing_algorithm(
+        algorithm: routing_types::StaticRoutingAlgorithm,
+    ) -> RoutingResult<Self> {
+        Ok(match algorithm {
+            routing_types::StaticRoutingAlgorithm::Single(conn) => Self::Single(conn),
+            routing_types::StaticRoutingAlgorithm::Priority(plist) => Self::Priority(plist),
+            routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+            routing_types::StaticRoutingAlgorithm::Advanced(program) => {
+                let interpreter = backend::VirInterpreterBackend::with_program(program)
+                    .change_context(errors::RoutingError::DslBackendInitError)
+                    .attach_printable("Error initializing DSL interpreter backend")?;
+
+                Self::Advanced(interpreter)
+            }
+            routing_types::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
+                Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
+                    .attach_printable("Unsupported algorithm received")?
+            }
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 pub struct SessionFlowRoutingInput<'a> {
     pub state: &'a SessionState,
@@ -635,7 +658,7 @@ pub fn perform_routing_for_single_straight_through_algorithm(
     })
 }
 
-fn execute_dsl_and_get_connector_v1(
+pub fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
@@ -674,26 +697,7 @@ pub async fn refresh_routing_cache_v1(
         algorithm
     };
 
-    let cached_algorithm = match algorithm {
-        routing_types::StaticRoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
-        routing_types::StaticRoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
-        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
-            CachedAlgorithm::VolumeSplit(splits)
-        }
-        routing_types::StaticRoutingAlgorithm::Advanced(program) => {
-            let interpreter = backend::VirInterpreterBackend::with_program(program)
-                .change_context(errors::RoutingError::DslBackendInitError)
-                .attach_printable("Error initializing DSL interpreter backend")?;
-
-            CachedAlgorithm::Advanced(interpreter)
-        }
-        api_models::routing::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
-            Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
-                .attach_printable("Unsupported algorithm received")?
-        }
-    };
-
-    let arc_cached_algorithm = Arc::new(cached_algorithm);
+    let arc_cached_algorithm = Arc::new(CachedAlgorithm::from_static_routing_algorithm(algorithm)?);
 
     ROUTING_CACHE
         .push(
@@ -175,6 +175,7 @@ where
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: payment_data.payment_intent.is_payment_id_from_merchant,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
     Ok(router_data)
 }
@@ -404,6 +405,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: payment_data.payment_intent.is_payment_id_from_merchant,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
@@ -569,6 +571,7 @@ pub async fn construct_payment_router_data_for_capture<'a>(
         authentication_id: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
@@ -697,6 +700,7 @@ pub async fn construct_router_data_for_psync<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
@@ -880,6 +884,7 @@ pub async fn construct_payment_router_data_for_sdk_session<'a>(
         authentication_id: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
@@ -1098,6 +1103,7 @@ pub async fn construct_payment_router_data_for_setup_mandate<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
@@ -1300,6 +1306,7 @@ where
         psd2_sca_exemption_type: payment_data.payment_intent.psd2_sca_exemption_type,
         raw_connector_response: None,
         is_payment_id_from_merchant: payment_data.payment_intent.is_payment_id_from_merchant,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
 
     Ok(router_data)
@@ -1493,6 +1500,7 @@ pub async fn construct_payment_router_data_for_update_metadata<'a>(
         psd2_sca_exemption_type: payment_data.payment_intent.psd2_sca_exemption_type,
         raw_connector_response: None,
         is_payment_id_from_merchant: payment_data.payment_intent.is_payment_id_from_merchant,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
 
     Ok(router_data)
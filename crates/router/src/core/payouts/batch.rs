@@ -0,0 +1,1372 @@
+use std::{collections::HashSet, str::FromStr, time::Duration};
+
+use api_models::{
+    payouts::{
+        PayoutBatchCreateRequest, PayoutBatchCsvRecord, PayoutBatchItemListConstraints,
+        PayoutBatchItemListResponse, PayoutBatchItemResponse, PayoutBatchListConstraints,
+        PayoutBatchListResponse, PayoutBatchResponse, PayoutCreateRequest, PayoutCreateResponse,
+        PayoutRetrieveRequest,
+    },
+    webhooks,
+};
+use common_enums::{
+    EventClass, EventObjectType, EventType, FileUploadProvider, PayoutBatchItemStatus,
+    PayoutBatchStatus, PayoutConnectors,
+};
+use common_utils::{
+    date_time,
+    encryption::Encryption,
+    ext_traits::Encode,
+    fp_utils,
+    id_type::{self, GenerateId},
+    types::{keymanager::KeyManagerState, MinorUnit},
+};
+use error_stack::{report, ResultExt};
+use futures::{
+    future::{join_all, try_join_all},
+    stream, StreamExt,
+};
+use router_env::{instrument, logger, tracing, Instrument};
+
+use crate::{
+    configs::settings::PayoutBatchSettings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as file_helpers,
+        payment_methods::cards,
+        payouts, utils as core_utils, webhooks as webhooks_core,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain, storage},
+    utils::OptionExt,
+};
+
+const PAYOUT_BATCH_TASK: &str = "PAYOUT_BATCH";
+const PAYOUT_BATCH_TAG: &str = "PAYOUT_BATCH";
+const PAYOUT_BATCH_PERMITS_PREFIX: &str = "PAYOUT_BATCH_PERMITS";
+
+const MAX_LIST_LIMIT: u32 = 100;
+/// Maximum number of invalid rows described in the error returned for an invalid batch file
+const MAX_REPORTED_ROW_ERRORS: usize = 10;
+
+/// Duration for which a permit to create a payout through a connector is held at most
+const CONNECTOR_PERMIT_LEASE_IN_SECONDS: i64 = 120;
+const CONNECTOR_PERMIT_RETRY_INTERVAL_IN_MILLISECONDS: u64 = 200;
+/// Number of attempts to acquire the permits of a row before the task is retried later
+const MAX_CONNECTOR_PERMIT_ATTEMPTS: u32 = 600;
+
+/// Acquires a permit from every semaphore, or from none of them if any semaphore has no permit
+/// left. A semaphore is a sorted set of the tokens holding a permit, scored by the time at which
+/// their lease expires, so that the permits which were never released are eventually reclaimed.
+///
+/// KEYS: semaphores of the connectors, sharing the same hash tag
+/// ARGV: current unix timestamp, lease_in_seconds, token, followed by the number of permits of
+///       each semaphore in KEYS
+/// Returns 1 if the permits were acquired, 0 otherwise
+const ACQUIRE_CONNECTOR_PERMITS_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local lease = tonumber(ARGV[2])
+
+for index, key in ipairs(KEYS) do
+    redis.call('ZREMRANGEBYSCORE', key, '-inf', now)
+    if redis.call('ZCARD', key) >= tonumber(ARGV[index + 3]) then
+        return 0
+    end
+end
+
+for _, key in ipairs(KEYS) do
+    redis.call('ZADD', key, now + lease, ARGV[3])
+    redis.call('EXPIRE', key, lease)
+end
+return 1
+"#;
+
+/// Releases the permits held by a token.
+///
+/// KEYS: semaphores of the connectors, sharing the same hash tag
+/// ARGV: token
+/// Returns the number of permits released
+const RELEASE_CONNECTOR_PERMITS_SCRIPT: &str = r#"
+local released = 0
+for _, key in ipairs(KEYS) do
+    released = released + redis.call('ZREM', key, ARGV[1])
+end
+return released
+"#;
+
+/// Creates a payout batch from a file uploaded through the files API. Every row of the file is
+/// validated before the batch is created, and the payouts are then created asynchronously by
+/// process tracker tasks, each processing a chunk of the rows. The payout create request of every
+/// row is stored encrypted with the row, so that the tasks do not need the file anymore.
+#[instrument(skip(state, merchant_context))]
+pub async fn create_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    auth_profile_id: Option<id_type::ProfileId>,
+    request: PayoutBatchCreateRequest,
+) -> RouterResponse<PayoutBatchResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let batch_settings = &state.conf.payouts.batch;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        key_manager_state,
+        None,
+        None,
+        &merchant_context,
+        request.profile_id.as_ref().or(auth_profile_id.as_ref()),
+        db,
+        true,
+    )
+    .await?;
+
+    let file_rows =
+        get_payout_batch_file_rows(&state, &merchant_context, request.file_id.clone()).await?;
+    fp_utils::when(file_rows.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The payout batch file has no rows".to_string(),
+        })
+    })?;
+    fp_utils::when(
+        u32::try_from(file_rows.len())
+            .map_or(true, |total_rows| total_rows > batch_settings.max_rows),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The payout batch file cannot have more than {} rows",
+                    batch_settings.max_rows
+                ),
+            })
+        },
+    )?;
+    let rows = validate_payout_batch_rows(file_rows, merchant_id, &profile_id)?;
+
+    let now = date_time::now();
+    let payout_batch_new = storage::PayoutBatchNew {
+        batch_id: common_utils::generate_id(consts::ID_LENGTH, "pbatch"),
+        merchant_id: merchant_id.clone(),
+        profile_id,
+        merchant_batch_reference_id: request.merchant_batch_reference_id,
+        file_id: request.file_id,
+        status: PayoutBatchStatus::Processing,
+        total_rows: get_row_number(rows.len())?,
+        created_rows: 0,
+        failed_rows: 0,
+        result_file_id: None,
+        created_at: now,
+        modified_at: now,
+        completed_at: None,
+    };
+    core_utils::validate_profile_id_from_auth_layer(auth_profile_id, &payout_batch_new)?;
+
+    let chunk_size = usize::try_from(batch_settings.chunk_size)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid payout batch chunk size")?;
+    let mut chunks = Vec::with_capacity(rows.len().div_ceil(chunk_size));
+    for (chunk_index, chunk) in rows.chunks(chunk_size).enumerate() {
+        let first_row_index = chunk_index * chunk_size;
+        let payout_requests = try_join_all(chunk.iter().map(|row| {
+            encrypt_payout_batch_row_request(key_manager_state, &merchant_context, row)
+        }))
+        .await?;
+        let payout_batch_items = chunk
+            .iter()
+            .zip(payout_requests)
+            .enumerate()
+            .map(|(index, (row, payout_request))| -> RouterResult<_> {
+                Ok(storage::PayoutBatchItemNew {
+                    batch_id: payout_batch_new.batch_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    row_number: get_row_number(first_row_index + index + 1)?,
+                    payout_id: row.payout_id.clone().get_required_value("payout_id")?,
+                    status: PayoutBatchItemStatus::Pending,
+                    connector: get_row_connector(row).map(|connector| connector.to_string()),
+                    payout_status: None,
+                    error_code: None,
+                    error_message: None,
+                    created_at: now,
+                    modified_at: now,
+                    payout_request,
+                })
+            })
+            .collect::<RouterResult<Vec<_>>>()?;
+        let payout_batch_task = get_payout_batch_task(storage::PayoutBatchTrackingData {
+            batch_id: payout_batch_new.batch_id.clone(),
+            merchant_id: merchant_id.clone(),
+            start_row_number: get_row_number(first_row_index + 1)?,
+            end_row_number: get_row_number(first_row_index + chunk.len())?,
+        })?;
+        chunks.push((payout_batch_items, payout_batch_task));
+    }
+
+    let payout_batch = db
+        .insert_payout_batch_with_items(payout_batch_new, chunks)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_unique_violation() {
+                error.change_context(errors::ApiErrorResponse::GenericDuplicateError {
+                    message: "A payout batch was already created with this file_id or \
+                        merchant_batch_reference_id"
+                        .to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to insert payout batch")
+            }
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        get_payout_batch_response(&state, payout_batch).await?,
+    ))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn retrieve_payout_batch(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    batch_id: String,
+) -> RouterResponse<PayoutBatchResponse> {
+    let payout_batch = find_payout_batch(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        profile_id,
+        &batch_id,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        get_payout_batch_response(&state, payout_batch).await?,
+    ))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn list_payout_batches(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    constraints: PayoutBatchListConstraints,
+) -> RouterResponse<PayoutBatchListResponse> {
+    validate_list_limit(constraints.limit)?;
+    let payout_batches = state
+        .store
+        .list_payout_batches_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            profile_id.as_ref(),
+            i64::from(constraints.limit),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout batches")?;
+
+    let data = join_all(
+        payout_batches
+            .into_iter()
+            .map(|payout_batch| get_payout_batch_response(&state, payout_batch)),
+    )
+    .await
+    .into_iter()
+    .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(ApplicationResponse::Json(PayoutBatchListResponse {
+        size: data.len(),
+        data,
+    }))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn list_payout_batch_items(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    batch_id: String,
+    constraints: PayoutBatchItemListConstraints,
+) -> RouterResponse<PayoutBatchItemListResponse> {
+    validate_list_limit(constraints.limit)?;
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let payout_batch = find_payout_batch(&state, merchant_id, profile_id, &batch_id).await?;
+
+    let data = state
+        .store
+        .list_payout_batch_items_by_merchant_id_batch_id(
+            merchant_id,
+            &payout_batch.batch_id,
+            constraints.status,
+            Some(i64::from(constraints.limit)),
+            Some(i64::from(constraints.offset.unwrap_or_default())),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout batch items")?
+        .into_iter()
+        .map(get_payout_batch_item_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(ApplicationResponse::Json(PayoutBatchItemListResponse {
+        batch_id: payout_batch.batch_id,
+        size: data.len(),
+        data,
+    }))
+}
+
+/// Runs the task processing a chunk of the rows of a payout batch. A payout is created for every
+/// row of the chunk which is still pending, with the payouts created concurrently up to the limit
+/// configured for each connector across every task of the merchant. The batch is completed once no
+/// row of the batch is pending anymore.
+#[instrument(skip(state, merchant_context))]
+pub async fn execute_payout_batch_chunk(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    tracking_data: &storage::PayoutBatchTrackingData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payout_batch = db
+        .find_payout_batch_by_merchant_id_batch_id(
+            &tracking_data.merchant_id,
+            &tracking_data.batch_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch")?;
+
+    if payout_batch.status != PayoutBatchStatus::Processing {
+        logger::info!(batch_id = %payout_batch.batch_id, "Payout batch is already completed");
+        return Ok(());
+    }
+
+    let pending_items: Vec<_> = db
+        .list_payout_batch_items_by_merchant_id_batch_id_row_numbers(
+            &tracking_data.merchant_id,
+            &tracking_data.batch_id,
+            tracking_data.start_row_number,
+            tracking_data.end_row_number,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch items")?
+        .into_iter()
+        .filter(|item| item.status == PayoutBatchItemStatus::Pending)
+        .collect();
+
+    if !pending_items.is_empty() {
+        let requests = try_join_all(
+            pending_items
+                .iter()
+                .map(|item| decrypt_payout_batch_row_request(state, &merchant_context, item)),
+        )
+        .await?;
+        let rows: Vec<_> = pending_items.into_iter().zip(requests).collect();
+
+        // The rows which do not specify a connector are routed when their payout is created, and
+        // hold a permit of every payout connector of the profile they could be routed to
+        let profile_connectors = if rows
+            .iter()
+            .any(|(_, request)| get_row_connector(request).is_none())
+        {
+            get_profile_payout_connectors(state, &merchant_context, &payout_batch.profile_id)
+                .await?
+        } else {
+            Vec::new()
+        };
+
+        stream::iter(rows)
+            .map(|(item, request)| {
+                let connectors = get_row_connector(&request)
+                    .map_or_else(|| profile_connectors.clone(), |connector| vec![connector]);
+                process_payout_batch_row(
+                    state,
+                    &merchant_context,
+                    &payout_batch,
+                    item,
+                    request,
+                    connectors,
+                )
+            })
+            .buffer_unordered(get_max_connector_concurrency(&state.conf.payouts.batch))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<RouterResult<Vec<_>>>()?;
+    }
+
+    complete_payout_batch_if_processed(state, &merchant_context, payout_batch).await
+}
+
+async fn process_payout_batch_row(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: &storage::PayoutBatch,
+    item: storage::PayoutBatchItem,
+    request: PayoutCreateRequest,
+    connectors: Vec<PayoutConnectors>,
+) -> RouterResult<()> {
+    let connector_permits =
+        acquire_connector_permits(state, &payout_batch.merchant_id, &connectors).await?;
+    let payout_batch_item_update =
+        create_payout_for_row(state, merchant_context, payout_batch, request).await;
+    release_connector_permits(state, connector_permits).await;
+
+    state
+        .store
+        .update_payout_batch_item_by_merchant_id_batch_id_row_number(
+            &item.merchant_id,
+            &item.batch_id,
+            item.row_number,
+            payout_batch_item_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch item")?;
+
+    Ok(())
+}
+
+/// Permits held by a row of a batch to create its payout through the connectors in `keys`
+struct ConnectorPermits {
+    keys: Vec<String>,
+    token: String,
+}
+
+/// Waits until a permit to create a payout through every one of the connectors is available, so
+/// that the number of payouts of the merchant being created through a connector stays within the
+/// limit configured for the connector across all the payout batch tasks. A permit is held for the
+/// lease duration at most, so that the permits of a task which stopped are eventually released.
+async fn acquire_connector_permits(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connectors: &[PayoutConnectors],
+) -> RouterResult<ConnectorPermits> {
+    let batch_settings = &state.conf.payouts.batch;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let connector_permits = ConnectorPermits {
+        keys: connectors
+            .iter()
+            .map(|connector| {
+                redis_conn.add_prefix(&get_connector_permits_key(merchant_id, *connector))
+            })
+            .collect(),
+        token: common_utils::generate_id(consts::ID_LENGTH, "permit"),
+    };
+    if connector_permits.keys.is_empty() {
+        return Ok(connector_permits);
+    }
+
+    for _ in 0..MAX_CONNECTOR_PERMIT_ATTEMPTS {
+        let arguments = [
+            date_time::now_unix_timestamp().to_string(),
+            CONNECTOR_PERMIT_LEASE_IN_SECONDS.to_string(),
+            connector_permits.token.clone(),
+        ]
+        .into_iter()
+        .chain(
+            connectors
+                .iter()
+                .map(|connector| get_connector_concurrency(batch_settings, *connector).to_string()),
+        )
+        .collect::<Vec<_>>();
+
+        let acquired = redis_conn
+            .evaluate_redis_script::<_, i64>(
+                ACQUIRE_CONNECTOR_PERMITS_SCRIPT,
+                connector_permits.keys.clone(),
+                arguments,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to acquire payout connector permits")?;
+        if acquired == 1 {
+            return Ok(connector_permits);
+        }
+        tokio::time::sleep(Duration::from_millis(
+            CONNECTOR_PERMIT_RETRY_INTERVAL_IN_MILLISECONDS,
+        ))
+        .await;
+    }
+
+    // The task is retried later, with the rows still pending
+    Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable_lazy(|| {
+        format!("Timed out waiting for a permit to create payouts through {connectors:?}")
+    })
+}
+
+/// Releases the permits of a row. The permits which could not be released are released once their
+/// lease expires.
+async fn release_connector_permits(state: &SessionState, connector_permits: ConnectorPermits) {
+    if connector_permits.keys.is_empty() {
+        return;
+    }
+
+    let result = async {
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?
+            .evaluate_redis_script::<_, i64>(
+                RELEASE_CONNECTOR_PERMITS_SCRIPT,
+                connector_permits.keys,
+                vec![connector_permits.token],
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to release payout connector permits")
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to release payout connector permits");
+    }
+}
+
+fn get_connector_permits_key(
+    merchant_id: &id_type::MerchantId,
+    connector: PayoutConnectors,
+) -> String {
+    // The keys of a merchant share the same hash tag, as a row acquires the permits of several
+    // connectors in a single script
+    format!(
+        "{{{PAYOUT_BATCH_PERMITS_PREFIX}:{}}}:{connector}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Lists the payout connectors of the enabled connector accounts of the profile.
+async fn get_profile_payout_connectors(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Vec<PayoutConnectors>> {
+    let connectors = state
+        .store
+        .list_enabled_connector_accounts_by_profile_id(
+            &state.into(),
+            profile_id,
+            merchant_context.get_merchant_key_store(),
+            common_enums::ConnectorType::PayoutProcessor,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts")?
+        .into_iter()
+        .filter_map(|connector_account| {
+            PayoutConnectors::from_str(&connector_account.connector_name).ok()
+        })
+        .collect::<HashSet<_>>();
+
+    Ok(connectors.into_iter().collect())
+}
+
+async fn create_payout_for_row(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: &storage::PayoutBatch,
+    request: PayoutCreateRequest,
+) -> storage::PayoutBatchItemUpdate {
+    let payout_id = request.payout_id.clone();
+    match Box::pin(payouts::payouts_create_core(
+        state.clone(),
+        merchant_context.clone(),
+        request,
+    ))
+    .await
+    {
+        Ok(ApplicationResponse::Json(payout))
+        | Ok(ApplicationResponse::JsonWithHeaders((payout, _))) => get_created_item_update(payout),
+        Ok(_) => get_failed_item_update(&errors::ApiErrorResponse::InternalServerError),
+        Err(error) => {
+            logger::info!(
+                ?error,
+                ?payout_id,
+                "Failed to create payout of payout batch row"
+            );
+            match (error.current_context(), payout_id) {
+                // The payout was created by a previous run of the task which failed before the
+                // row was updated
+                (errors::ApiErrorResponse::DuplicatePayout { .. }, Some(payout_id)) => {
+                    find_payout_of_batch(state, merchant_context, payout_batch, payout_id)
+                        .await
+                        .map_or_else(
+                            || get_failed_item_update(error.current_context()),
+                            get_created_item_update,
+                        )
+                }
+                (api_error, _) => get_failed_item_update(api_error),
+            }
+        }
+    }
+}
+
+/// Finds the payout with the given ID, if it was created for the batch.
+async fn find_payout_of_batch(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: &storage::PayoutBatch,
+    payout_id: id_type::PayoutId,
+) -> Option<PayoutCreateResponse> {
+    let request = PayoutRetrieveRequest {
+        payout_id,
+        force_sync: None,
+        merchant_id: None,
+    };
+    match Box::pin(payouts::payouts_retrieve_core(
+        state.clone(),
+        merchant_context.clone(),
+        Some(payout_batch.profile_id.clone()),
+        request,
+    ))
+    .await
+    {
+        Ok(ApplicationResponse::Json(payout))
+            if payout
+                .created
+                .is_some_and(|created| created >= payout_batch.created_at) =>
+        {
+            Some(payout)
+        }
+        _ => None,
+    }
+}
+
+async fn complete_payout_batch_if_processed(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<()> {
+    let payout_batch_items = state
+        .store
+        .list_payout_batch_items_by_merchant_id_batch_id(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+            None,
+            None,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch items")?;
+
+    if payout_batch_items
+        .iter()
+        .any(|item| item.status == PayoutBatchItemStatus::Pending)
+    {
+        return Ok(());
+    }
+
+    let created_rows = count_items(&payout_batch_items, PayoutBatchItemStatus::Created)?;
+    let failed_rows = count_items(&payout_batch_items, PayoutBatchItemStatus::Failed)?;
+    let result_file_id =
+        upload_payout_batch_result_file(state, &payout_batch, payout_batch_items).await?;
+
+    let payout_batch = match state
+        .store
+        .update_processing_payout_batch_by_merchant_id_batch_id(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+            storage::PayoutBatchUpdate::CompletionUpdate {
+                status: get_payout_batch_status(created_rows, failed_rows),
+                created_rows,
+                failed_rows,
+                result_file_id: Some(result_file_id),
+            },
+        )
+        .await
+    {
+        Ok(payout_batch) => payout_batch,
+        // The batch was completed by the task of another chunk in the meantime
+        Err(error) if error.current_context().is_db_not_found() => {
+            logger::info!(batch_id = %payout_batch.batch_id, "Payout batch is already completed");
+            return Ok(());
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to complete payout batch")?,
+    };
+    logger::info!(
+        batch_id = %payout_batch.batch_id,
+        status = %payout_batch.status,
+        "Payout batch completed"
+    );
+
+    trigger_payout_batch_webhook(state, merchant_context, payout_batch).await;
+
+    Ok(())
+}
+
+/// Uploads a CSV file holding the result of every row of the batch, which can be downloaded
+/// through the files API.
+async fn upload_payout_batch_result_file(
+    state: &SessionState,
+    payout_batch: &storage::PayoutBatch,
+    payout_batch_items: Vec<storage::PayoutBatchItem>,
+) -> RouterResult<String> {
+    let payout_batch_items = payout_batch_items
+        .into_iter()
+        .map(get_payout_batch_item_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+    let file_data = get_payout_batch_result_file_data(&payout_batch_items)?;
+    let file_size = i32::try_from(file_data.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payout batch result file is too large")?;
+
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!("{}/{}", payout_batch.merchant_id.get_string_repr(), file_id);
+    state
+        .file_storage_client
+        .upload_file(&file_key, file_data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload payout batch result file")?;
+
+    state
+        .store
+        .insert_file_metadata(diesel_models::file::FileMetadataNew {
+            file_id: file_id.clone(),
+            merchant_id: payout_batch.merchant_id.clone(),
+            file_name: Some(format!("{}_result.csv", payout_batch.batch_id)),
+            file_size,
+            file_type: mime::TEXT_CSV.to_string(),
+            provider_file_id: Some(file_key),
+            file_upload_provider: Some(FileUploadProvider::Router),
+            available: true,
+            connector_label: None,
+            profile_id: Some(payout_batch.profile_id.clone()),
+            merchant_connector_id: None,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch result file metadata")?;
+
+    Ok(file_id)
+}
+
+fn get_payout_batch_result_file_data(
+    payout_batch_items: &[PayoutBatchItemResponse],
+) -> RouterResult<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for payout_batch_item in payout_batch_items {
+        writer
+            .serialize(payout_batch_item)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write payout batch result file")?;
+    }
+
+    writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write payout batch result file")
+}
+
+fn get_payout_batch_task(
+    tracking_data: storage::PayoutBatchTrackingData,
+) -> RouterResult<storage::ProcessTrackerNew> {
+    let runner = storage::ProcessTrackerRunner::PayoutBatchWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{PAYOUT_BATCH_TASK}_{}_{}",
+        tracking_data.batch_id, tracking_data.start_row_number
+    );
+
+    storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAYOUT_BATCH_TASK,
+        runner,
+        [PAYOUT_BATCH_TAG],
+        tracking_data,
+        None,
+        date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payout batch process tracker task")
+}
+
+async fn trigger_payout_batch_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_batch: storage::PayoutBatch,
+) {
+    let Some(event_type) = Option::<EventType>::from(payout_batch.status) else {
+        logger::warn!("Outgoing webhook not sent because of missing event type status mapping");
+        return;
+    };
+
+    let business_profile = match state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            &payout_batch.profile_id,
+        )
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch business profile for outgoing webhook"
+            );
+            return;
+        }
+    };
+
+    let primary_object_id = payout_batch.batch_id.clone();
+    let primary_object_created_at = payout_batch.created_at;
+    let payout_batch_response = match get_payout_batch_response(state, payout_batch).await {
+        Ok(payout_batch_response) => payout_batch_response,
+        Err(error) => {
+            logger::error!(?error, "Failed to construct payout batch outgoing webhook");
+            return;
+        }
+    };
+
+    let cloned_state = state.clone();
+    let cloned_merchant_context = merchant_context.clone();
+    // This spawns this futures in a background thread, the exception inside this future won't affect
+    // the current thread and the lifecycle of spawn thread is not handled by runtime.
+    // So when server shutdown won't wait for this thread's completion.
+    tokio::spawn(
+        async move {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                cloned_merchant_context,
+                business_profile,
+                event_type,
+                EventClass::PayoutBatches,
+                primary_object_id,
+                EventObjectType::PayoutBatchDetails,
+                webhooks::OutgoingWebhookContent::PayoutBatchDetails(Box::new(
+                    payout_batch_response,
+                )),
+                Some(primary_object_created_at),
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+}
+
+async fn find_payout_batch(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    let payout_batch = state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payout batch not found".to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payout_batch)?;
+
+    Ok(payout_batch)
+}
+
+/// Fetches the rows of a payout batch file. A row which cannot be parsed is returned as the reason
+/// it could not be parsed.
+async fn get_payout_batch_file_rows(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    file_id: String,
+) -> RouterResult<Vec<Result<PayoutCreateRequest, String>>> {
+    let file_info = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        state,
+        Some(file_id),
+        merchant_context,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+    let file_data = file_info
+        .file_data
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("Payout batch file has no data")?;
+    let file_type = file_info
+        .file_type
+        .unwrap_or_default()
+        .parse::<mime::Mime>()
+        .ok();
+
+    parse_payout_batch_file(file_type.as_ref(), &file_data)
+}
+
+fn parse_payout_batch_file(
+    file_type: Option<&mime::Mime>,
+    file_data: &[u8],
+) -> RouterResult<Vec<Result<PayoutCreateRequest, String>>> {
+    match file_type.map(|file_type| file_type.essence_str()) {
+        Some(essence) if essence == mime::TEXT_CSV.essence_str() => {
+            Ok(csv::Reader::from_reader(file_data)
+                .deserialize::<PayoutBatchCsvRecord>()
+                .map(|record| {
+                    record
+                        .map(PayoutCreateRequest::from)
+                        .map_err(|error| error.to_string())
+                })
+                .collect())
+        }
+        Some(essence) if essence == mime::APPLICATION_JSON.essence_str() => {
+            let rows: Vec<serde_json::Value> =
+                serde_json::from_slice(file_data)
+                    .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message:
+                        "The payout batch JSON file must hold an array of payout create requests"
+                            .to_string(),
+                })?;
+            Ok(rows
+                .into_iter()
+                .map(|row| {
+                    serde_json::from_value::<PayoutCreateRequest>(row)
+                        .map_err(|error| error.to_string())
+                })
+                .collect())
+        }
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The file is not a CSV or JSON payout batch file".to_string(),
+        })),
+    }
+}
+
+/// Validates every row of a payout batch file, and builds the payout create request of every row.
+/// A payout ID is assigned to the rows which do not have one, and the payouts of a batch are
+/// confirmed and fulfilled unless the row says otherwise. The error lists the first invalid rows,
+/// if any row is invalid.
+fn validate_payout_batch_rows(
+    file_rows: Vec<Result<PayoutCreateRequest, String>>,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Vec<PayoutCreateRequest>> {
+    let mut payout_ids = HashSet::new();
+    let mut rows = Vec::with_capacity(file_rows.len());
+    let mut row_errors = Vec::new();
+
+    for (index, file_row) in file_rows.into_iter().enumerate() {
+        let row_number = index + 1;
+        let validated_row = file_row.and_then(|row| {
+            validate_payout_batch_row(&row, merchant_id, profile_id)?;
+            match &row.payout_id {
+                Some(payout_id) if !payout_ids.insert(payout_id.clone()) => Err(format!(
+                    "payout_id {} is used by another row",
+                    payout_id.get_string_repr()
+                )),
+                _ => Ok(row),
+            }
+        });
+        match validated_row {
+            Ok(row) => rows.push(row),
+            Err(error) => row_errors.push(format!("row {row_number}: {error}")),
+        }
+    }
+
+    fp_utils::when(!row_errors.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The payout batch file has {} invalid rows: {}",
+                row_errors.len(),
+                row_errors
+                    .iter()
+                    .take(MAX_REPORTED_ROW_ERRORS)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+        })
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| PayoutCreateRequest {
+            payout_id: row
+                .payout_id
+                .or_else(|| Some(id_type::PayoutId::generate())),
+            profile_id: Some(profile_id.clone()),
+            confirm: row.confirm.or(Some(true)),
+            auto_fulfill: row.auto_fulfill.or(Some(true)),
+            ..row
+        })
+        .collect())
+}
+
+fn validate_payout_batch_row(
+    row: &PayoutCreateRequest,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> Result<(), String> {
+    if row.merchant_id.as_ref().is_some_and(|id| id != merchant_id) {
+        return Err("merchant_id does not match the merchant account".to_string());
+    }
+    if row.profile_id.as_ref().is_some_and(|id| id != profile_id) {
+        return Err("profile_id does not match the profile of the batch".to_string());
+    }
+    if !row
+        .amount
+        .map(MinorUnit::from)
+        .is_some_and(|amount| amount.is_greater_than(0))
+    {
+        return Err("amount must be greater than 0".to_string());
+    }
+    if row.currency.is_none() {
+        return Err("currency is required".to_string());
+    }
+    if row.customer_id.is_none()
+        && row.customer.is_none()
+        && row.payout_method_id.is_none()
+        && row.payout_token.is_none()
+    {
+        return Err(
+            "customer_id, customer, payout_method_id or payout_token is required".to_string(),
+        );
+    }
+    if row.payout_method_data.is_some() && row.customer_id.is_none() && row.customer.is_none() {
+        return Err("customer_id is required when payout_method_data is provided".to_string());
+    }
+
+    Ok(())
+}
+
+/// Encrypts the payout create request of a row, to be stored with the row.
+async fn encrypt_payout_batch_row_request(
+    key_manager_state: &KeyManagerState,
+    merchant_context: &domain::MerchantContext,
+    row: &PayoutCreateRequest,
+) -> RouterResult<Encryption> {
+    cards::create_encrypted_data(
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        encode_payout_batch_row_request(row)?,
+    )
+    .await
+    .map(Encryption::from)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt payout batch row request")
+}
+
+/// Encodes the payout create request of a row. The amount is encoded as an integer, as the amount
+/// of a payout create request is only deserialized from an integer.
+fn encode_payout_batch_row_request(row: &PayoutCreateRequest) -> RouterResult<serde_json::Value> {
+    let mut request = row
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode payout batch row request")?;
+    if let Some(request) = request.as_object_mut() {
+        request.insert(
+            "amount".to_string(),
+            serde_json::json!(row.amount.map(MinorUnit::from)),
+        );
+    }
+
+    Ok(request)
+}
+
+async fn decrypt_payout_batch_row_request(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    item: &storage::PayoutBatchItem,
+) -> RouterResult<PayoutCreateRequest> {
+    cards::decrypt_generic_data::<PayoutCreateRequest>(
+        state,
+        Some(item.payout_request.clone()),
+        merchant_context.get_merchant_key_store(),
+    )
+    .await?
+    .get_required_value("payout_request")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt payout batch row request")
+}
+
+fn get_row_connector(row: &PayoutCreateRequest) -> Option<PayoutConnectors> {
+    row.connector
+        .as_ref()
+        .and_then(|connectors| connectors.first())
+        .copied()
+}
+
+fn get_connector_concurrency(
+    batch_settings: &PayoutBatchSettings,
+    connector: PayoutConnectors,
+) -> u32 {
+    batch_settings
+        .connector_concurrency
+        .get(&connector)
+        .copied()
+        .unwrap_or(batch_settings.default_connector_concurrency)
+}
+
+/// Number of rows of a chunk processed concurrently, which is the highest concurrency configured
+/// for a connector, as the rows wait for a permit of their connectors anyway.
+fn get_max_connector_concurrency(batch_settings: &PayoutBatchSettings) -> usize {
+    let concurrency = batch_settings
+        .connector_concurrency
+        .values()
+        .copied()
+        .fold(batch_settings.default_connector_concurrency, u32::max);
+    usize::try_from(concurrency).unwrap_or(1).max(1)
+}
+
+fn get_payout_batch_status(created_rows: i32, failed_rows: i32) -> PayoutBatchStatus {
+    match (created_rows, failed_rows) {
+        (_, 0) => PayoutBatchStatus::Completed,
+        (0, _) => PayoutBatchStatus::Failed,
+        _ => PayoutBatchStatus::PartiallyCompleted,
+    }
+}
+
+fn get_created_item_update(payout: PayoutCreateResponse) -> storage::PayoutBatchItemUpdate {
+    storage::PayoutBatchItemUpdate::PayoutCreated {
+        connector: payout.connector,
+        payout_status: payout.status,
+    }
+}
+
+fn get_failed_item_update(error: &errors::ApiErrorResponse) -> storage::PayoutBatchItemUpdate {
+    storage::PayoutBatchItemUpdate::PayoutCreationFailed {
+        error_code: error.error_code(),
+        error_message: error.error_message(),
+    }
+}
+
+/// Builds the response of a payout batch. The row counts of a batch which is being processed are
+/// computed from its rows, as they are only stored once the batch is completed.
+async fn get_payout_batch_response(
+    state: &SessionState,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<PayoutBatchResponse> {
+    let (created_rows, failed_rows) = match payout_batch.status {
+        PayoutBatchStatus::Processing => {
+            let payout_batch_items = state
+                .store
+                .list_payout_batch_items_by_merchant_id_batch_id(
+                    &payout_batch.merchant_id,
+                    &payout_batch.batch_id,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch payout batch items")?;
+            (
+                count_items(&payout_batch_items, PayoutBatchItemStatus::Created)?,
+                count_items(&payout_batch_items, PayoutBatchItemStatus::Failed)?,
+            )
+        }
+        PayoutBatchStatus::Completed
+        | PayoutBatchStatus::PartiallyCompleted
+        | PayoutBatchStatus::Failed => (payout_batch.created_rows, payout_batch.failed_rows),
+    };
+    let total_rows = get_row_count(payout_batch.total_rows)?;
+    let created_rows = get_row_count(created_rows)?;
+    let failed_rows = get_row_count(failed_rows)?;
+
+    Ok(PayoutBatchResponse {
+        batch_id: payout_batch.batch_id,
+        merchant_id: payout_batch.merchant_id,
+        profile_id: payout_batch.profile_id,
+        merchant_batch_reference_id: payout_batch.merchant_batch_reference_id,
+        file_id: payout_batch.file_id,
+        status: payout_batch.status,
+        total_rows,
+        created_rows,
+        failed_rows,
+        pending_rows: total_rows.saturating_sub(created_rows.saturating_add(failed_rows)),
+        result_file_id: payout_batch.result_file_id,
+        created: payout_batch.created_at,
+        completed_at: payout_batch.completed_at,
+    })
+}
+
+fn get_payout_batch_item_response(
+    item: storage::PayoutBatchItem,
+) -> RouterResult<PayoutBatchItemResponse> {
+    Ok(PayoutBatchItemResponse {
+        row_number: get_row_count(item.row_number)?,
+        status: item.status,
+        payout_id: item.payout_id,
+        payout_status: item.payout_status,
+        connector: item.connector,
+        error_code: item.error_code,
+        error_message: item.error_message,
+    })
+}
+
+fn count_items(
+    payout_batch_items: &[storage::PayoutBatchItem],
+    status: PayoutBatchItemStatus,
+) -> RouterResult<i32> {
+    get_row_number(
+        payout_batch_items
+            .iter()
+            .filter(|item| item.status == status)
+            .count(),
+    )
+}
+
+fn get_row_number(row_number: usize) -> RouterResult<i32> {
+    i32::try_from(row_number)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payout batch has too many rows")
+}
+
+fn get_row_count(row_count: i32) -> RouterResult<u32> {
+    u32::try_from(row_count)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid row count of payout batch")
+}
+
+fn validate_list_limit(limit: u32) -> RouterResult<()> {
+    fp_utils::when(limit == 0 || limit > MAX_LIST_LIMIT, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be between 1 and {MAX_LIST_LIMIT}"),
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_merchant_and_profile_ids() -> (id_type::MerchantId, id_type::ProfileId) {
+        (
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap(),
+            id_type::ProfileId::try_from(std::borrow::Cow::from("pro_1")).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_parse_csv_payout_batch_file() {
+        let file_data = b"payout_id,merchant_order_reference_id,amount,currency,customer_id,payout_type,payout_method_id,payout_token,connector,priority,description\n\
+            ,order_1,1000,USD,cus_1,bank,,,adyen,,seller payout\n\
+            ,order_2,abc,USD,cus_2,bank,,,,,\n";
+        let rows = parse_payout_batch_file(Some(&mime::TEXT_CSV), file_data).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        let row = rows.first().unwrap().as_ref().unwrap();
+        assert_eq!(row.amount.map(MinorUnit::from), Some(MinorUnit::new(1000)));
+        assert_eq!(row.connector, Some(vec![PayoutConnectors::Adyen]));
+        assert_eq!(row.payout_id, None);
+        assert!(rows.get(1).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_parse_json_payout_batch_file() {
+        let file_data =
+            br#"[{"amount": 1000, "currency": "EUR", "customer_id": "cus_1"}, {"amount": 1000, "unknown": true}]"#;
+        let rows = parse_payout_batch_file(Some(&mime::APPLICATION_JSON), file_data).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert!(rows.first().unwrap().is_ok());
+        assert!(rows.get(1).unwrap().is_err());
+        assert!(parse_payout_batch_file(Some(&mime::APPLICATION_JSON), b"{}").is_err());
+        assert!(parse_payout_batch_file(Some(&mime::TEXT_PLAIN), file_data).is_err());
+    }
+
+    #[test]
+    fn test_validate_payout_batch_rows_reports_invalid_rows() {
+        let (merchant_id, profile_id) = get_merchant_and_profile_ids();
+        let payout_id = id_type::PayoutId::generate();
+        let valid_row = PayoutCreateRequest {
+            amount: Some(api_models::payments::Amount::from(MinorUnit::new(1000))),
+            currency: Some(common_enums::Currency::USD),
+            customer_id: Some(
+                id_type::CustomerId::try_from(std::borrow::Cow::from("cus_1")).unwrap(),
+            ),
+            ..Default::default()
+        };
+
+        let rows = validate_payout_batch_rows(
+            vec![Ok(valid_row.clone()), Ok(valid_row.clone())],
+            &merchant_id,
+            &profile_id,
+        )
+        .unwrap();
+        assert!(rows.iter().all(|row| row.payout_id.is_some()));
+        assert_ne!(
+            rows.first().unwrap().payout_id,
+            rows.get(1).unwrap().payout_id
+        );
+
+        let duplicate_row = PayoutCreateRequest {
+            payout_id: Some(payout_id),
+            ..valid_row.clone()
+        };
+        let error = validate_payout_batch_rows(
+            vec![
+                Ok(duplicate_row.clone()),
+                Ok(duplicate_row),
+                Ok(PayoutCreateRequest {
+                    currency: None,
+                    ..valid_row
+                }),
+                Err("invalid amount".to_string()),
+            ],
+            &merchant_id,
+            &profile_id,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message.starts_with("The payout batch file has 3 invalid rows: row 2:")
+        ));
+    }
+
+    #[test]
+    fn test_payout_batch_row_request_round_trip() {
+        let row = PayoutCreateRequest {
+            payout_id: Some(id_type::PayoutId::generate()),
+            amount: Some(api_models::payments::Amount::from(MinorUnit::new(1000))),
+            currency: Some(common_enums::Currency::USD),
+            connector: Some(vec![PayoutConnectors::Adyen]),
+            confirm: Some(true),
+            ..Default::default()
+        };
+
+        let request = serde_json::from_value::<PayoutCreateRequest>(
+            encode_payout_batch_row_request(&row).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(request.payout_id, row.payout_id);
+        assert_eq!(request.amount, row.amount);
+        assert_eq!(request.connector, row.connector);
+        assert_eq!(request.confirm, Some(true));
+    }
+
+    #[test]
+    fn test_connector_concurrency() {
+        let batch_settings = PayoutBatchSettings {
+            max_rows: 10,
+            chunk_size: 10,
+            default_connector_concurrency: 5,
+            connector_concurrency: [(PayoutConnectors::Adyen, 10)].into_iter().collect(),
+        };
+
+        assert_eq!(
+            get_connector_concurrency(&batch_settings, PayoutConnectors::Adyen),
+            10
+        );
+        assert_eq!(
+            get_connector_concurrency(&batch_settings, PayoutConnectors::Wise),
+            5
+        );
+        assert_eq!(get_max_connector_concurrency(&batch_settings), 10);
+    }
+
+    #[test]
+    fn test_payout_batch_status_from_row_counts() {
+        assert_eq!(get_payout_batch_status(10, 0), PayoutBatchStatus::Completed);
+        assert_eq!(get_payout_batch_status(0, 10), PayoutBatchStatus::Failed);
+        assert_eq!(
+            get_payout_batch_status(9, 1),
+            PayoutBatchStatus::PartiallyCompleted
+        );
+    }
+}
@@ -0,0 +1,499 @@
+use std::collections::HashSet;
+
+use api_models::process_tracker as process_tracker_api;
+use common_utils::fp_utils;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::storage::{self, business_status, enums},
+};
+
+const PROCESS_TRACKER_LIST_MAX_LIMIT: i64 = 100;
+
+fn process_tracker_response(
+    process: storage::ProcessTracker,
+) -> process_tracker_api::ProcessTrackerResponse {
+    process_tracker_api::ProcessTrackerResponse {
+        id: process.id,
+        name: process.name,
+        tag: process.tag,
+        runner: process.runner,
+        retry_count: process.retry_count,
+        schedule_time: process.schedule_time,
+        status: process.status,
+        business_status: process.business_status,
+        tracking_data: process.tracking_data,
+        is_recurring: process.recurrence.is_some(),
+        created_at: process.created_at,
+        updated_at: process.updated_at,
+    }
+}
+
+fn process_tracker_audit_response(
+    audit: storage::ProcessTrackerAudit,
+) -> process_tracker_api::ProcessTrackerAuditResponse {
+    process_tracker_api::ProcessTrackerAuditResponse {
+        audit_id: audit.audit_id,
+        action: audit.action,
+        previous_status: audit.previous_status,
+        new_status: audit.new_status,
+        previous_business_status: audit.previous_business_status,
+        new_business_status: audit.new_business_status,
+        previous_schedule_time: audit.previous_schedule_time,
+        new_schedule_time: audit.new_schedule_time,
+        reason: audit.reason,
+        performed_by: audit.performed_by,
+        created_at: audit.created_at,
+    }
+}
+
+async fn find_process(
+    state: &SessionState,
+    process_id: &str,
+) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .find_process_by_id(process_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find process tracker task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Process tracker task `{process_id}` does not exist"),
+            })
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn list_processes(
+    state: SessionState,
+    constraints: process_tracker_api::ProcessTrackerListConstraints,
+) -> RouterResponse<Vec<process_tracker_api::ProcessTrackerResponse>> {
+    let limit = match constraints.limit {
+        Some(limit) if limit > PROCESS_TRACKER_LIST_MAX_LIMIT => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`limit` must be a number less than or equal to {PROCESS_TRACKER_LIST_MAX_LIMIT}"
+                ),
+            }));
+        }
+        Some(limit) if limit > 0 => limit,
+        _ => PROCESS_TRACKER_LIST_MAX_LIMIT,
+    };
+
+    let processes = state
+        .store
+        .list_processes_by_constraints(storage::ProcessTrackerListConstraints {
+            runner: constraints.runner,
+            status: constraints.status,
+            business_status: constraints.business_status,
+            tag: constraints.tag,
+            scheduled_after: constraints.scheduled_after,
+            scheduled_before: constraints.scheduled_before,
+            limit: Some(limit),
+            offset: constraints.offset,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list process tracker tasks")?;
+
+    Ok(ApplicationResponse::Json(
+        processes
+            .into_iter()
+            .map(process_tracker_response)
+            .collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_process(
+    state: SessionState,
+    process_id: String,
+) -> RouterResponse<process_tracker_api::ProcessTrackerRetrieveResponse> {
+    let process = find_process(&state, &process_id).await?;
+    let audit_trail = state
+        .store
+        .list_process_tracker_audits_by_process_tracker_id(&process_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list process tracker task audits")?;
+
+    Ok(ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerRetrieveResponse {
+            process: process_tracker_response(process),
+            audit_trail: audit_trail
+                .into_iter()
+                .map(process_tracker_audit_response)
+                .collect(),
+        },
+    ))
+}
+
+fn validate_action_request(
+    action: enums::ProcessTrackerAction,
+    request: &process_tracker_api::ProcessTrackerActionRequest,
+) -> RouterResult<()> {
+    fp_utils::when(request.reason.trim().is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`reason` must not be empty".to_string(),
+        }))
+    })?;
+    fp_utils::when(request.performed_by.trim().is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`performed_by` must not be empty".to_string(),
+        }))
+    })?;
+
+    match (action, request.schedule_time) {
+        (enums::ProcessTrackerAction::Reschedule, None) => {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "schedule_time",
+            }))
+        }
+        (enums::ProcessTrackerAction::Reschedule, Some(schedule_time))
+            if schedule_time <= common_utils::date_time::now() =>
+        {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`schedule_time` must be in the future, use the run action to run a \
+                          task right away"
+                    .to_string(),
+            }))
+        }
+        (enums::ProcessTrackerAction::Reschedule, Some(_)) | (_, None) => Ok(()),
+        (_, Some(_)) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`schedule_time` is not supported for the {action} action"),
+        })),
+    }
+}
+
+/// Returns the business status a paused task had before it was paused, so that it is restored
+/// when the task is run or rescheduled.
+async fn get_business_status_before_pause(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> RouterResult<String> {
+    let audit_trail = state
+        .store
+        .list_process_tracker_audits_by_process_tracker_id(&process.id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list process tracker task audits")?;
+
+    Ok(audit_trail
+        .into_iter()
+        .find(|audit| audit.action == enums::ProcessTrackerAction::Pause)
+        .map(|audit| audit.previous_business_status)
+        .unwrap_or_else(|| business_status::PENDING.to_string()))
+}
+
+/// Determines the status, business status and schedule time a task is to be updated to by an
+/// action.
+async fn get_updated_state(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+    action: enums::ProcessTrackerAction,
+    schedule_time: Option<time::PrimitiveDateTime>,
+) -> RouterResult<(
+    enums::ProcessTrackerStatus,
+    String,
+    Option<time::PrimitiveDateTime>,
+)> {
+    // Tasks which have been picked by the producer or the consumer are being executed, changing
+    // them could result in the task being run twice
+    fp_utils::when(
+        matches!(
+            process.status,
+            enums::ProcessTrackerStatus::Processing | enums::ProcessTrackerStatus::ProcessStarted
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Process tracker task `{}` is being executed and cannot be changed",
+                    process.id
+                ),
+            }))
+        },
+    )?;
+    let is_paused = process.business_status == business_status::PAUSED;
+    let is_finished = process.status == enums::ProcessTrackerStatus::Finish;
+
+    match action {
+        enums::ProcessTrackerAction::Pause => {
+            fp_utils::when(is_paused || is_finished, || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "Process tracker task `{}` is already {}",
+                        process.id,
+                        if is_paused { "paused" } else { "finished" }
+                    ),
+                }))
+            })?;
+            // Tasks under review are not picked by the producer
+            Ok((
+                enums::ProcessTrackerStatus::Review,
+                business_status::PAUSED.to_string(),
+                process.schedule_time,
+            ))
+        }
+        enums::ProcessTrackerAction::Cancel => {
+            fp_utils::when(is_finished, || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!("Process tracker task `{}` is already finished", process.id),
+                }))
+            })?;
+            Ok((
+                enums::ProcessTrackerStatus::Finish,
+                business_status::CANCELLED.to_string(),
+                process.schedule_time,
+            ))
+        }
+        enums::ProcessTrackerAction::Run | enums::ProcessTrackerAction::Reschedule => {
+            let business_status = if is_paused {
+                get_business_status_before_pause(state, process).await?
+            } else if is_finished {
+                business_status::PENDING.to_string()
+            } else {
+                process.business_status.clone()
+            };
+            // Tasks which have been retried before are picked as pending tasks, so that the
+            // retry count of the task is retained
+            let status = if process.retry_count > 0 {
+                enums::ProcessTrackerStatus::Pending
+            } else {
+                enums::ProcessTrackerStatus::New
+            };
+
+            Ok((
+                status,
+                business_status,
+                Some(schedule_time.unwrap_or_else(common_utils::date_time::now)),
+            ))
+        }
+    }
+}
+
+/// Applies an action to a task and records it in the audit trail of the task, atomically.
+async fn update_process(
+    state: &SessionState,
+    process_id: &str,
+    action: enums::ProcessTrackerAction,
+    request: &process_tracker_api::ProcessTrackerActionRequest,
+) -> RouterResult<storage::ProcessTracker> {
+    let process = find_process(state, process_id).await?;
+    let (status, new_business_status, schedule_time) =
+        get_updated_state(state, &process, action, request.schedule_time).await?;
+
+    let audit = storage::ProcessTrackerAuditNew {
+        audit_id: common_utils::generate_id(consts::ID_LENGTH, "pt_audit"),
+        process_tracker_id: process.id.clone(),
+        action,
+        previous_status: process.status,
+        new_status: status,
+        previous_business_status: process.business_status.clone(),
+        new_business_status: new_business_status.clone(),
+        previous_schedule_time: process.schedule_time,
+        new_schedule_time: schedule_time,
+        reason: request.reason.clone(),
+        performed_by: request.performed_by.clone(),
+        created_at: common_utils::date_time::now(),
+    };
+
+    let updated_process = state
+        .store
+        .update_process_with_audit(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time,
+                tracking_data: None,
+                business_status: Some(new_business_status),
+                status: Some(status),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+            audit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update process tracker task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Process tracker task `{process_id}` was modified while it was being \
+                     updated, please retry"
+                ),
+            })
+        })?;
+
+    logger::info!(
+        process_tracker_id = %process_id,
+        %action,
+        performed_by = %request.performed_by,
+        "Process tracker task was updated"
+    );
+
+    Ok(updated_process)
+}
+
+#[instrument(skip_all)]
+pub async fn update_process_with_action(
+    state: SessionState,
+    request: process_tracker_api::ProcessTrackerUpdateRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    validate_action_request(request.action, &request.request)?;
+    let process = update_process(
+        &state,
+        &request.process_tracker_id,
+        request.action,
+        &request.request,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(process_tracker_response(process)))
+}
+
+#[instrument(skip_all)]
+pub async fn bulk_update_processes_with_action(
+    state: SessionState,
+    request: process_tracker_api::ProcessTrackerBulkActionRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerBulkActionResponse> {
+    let action_request = process_tracker_api::ProcessTrackerActionRequest {
+        schedule_time: request.schedule_time,
+        reason: request.reason,
+        performed_by: request.performed_by,
+    };
+    validate_action_request(request.action, &action_request)?;
+
+    let mut seen_process_ids = HashSet::new();
+    let process_ids = request
+        .process_tracker_ids
+        .into_iter()
+        .filter(|process_id| seen_process_ids.insert(process_id.clone()))
+        .collect::<Vec<_>>();
+    fp_utils::when(
+        process_ids.is_empty()
+            || process_ids.len() > process_tracker_api::PROCESS_TRACKER_BULK_ACTION_MAX_TASKS,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`process_tracker_ids` must contain between 1 and {} tasks",
+                    process_tracker_api::PROCESS_TRACKER_BULK_ACTION_MAX_TASKS
+                ),
+            }))
+        },
+    )?;
+
+    // Tasks are updated one at a time, so that a task which cannot be updated does not prevent
+    // the others from being updated
+    let mut results = Vec::with_capacity(process_ids.len());
+    for process_id in process_ids {
+        let result =
+            match update_process(&state, &process_id, request.action, &action_request).await {
+                Ok(process) => process_tracker_api::ProcessTrackerBulkActionResult {
+                    process_tracker_id: process_id,
+                    process: Some(process_tracker_response(process)),
+                    error_message: None,
+                },
+                Err(error) => {
+                    logger::warn!(process_tracker_id = %process_id, ?error);
+                    process_tracker_api::ProcessTrackerBulkActionResult {
+                        process_tracker_id: process_id,
+                        process: None,
+                        error_message: Some(error.current_context().error_message()),
+                    }
+                }
+            };
+        results.push(result);
+    }
+
+    let succeeded_count = results
+        .iter()
+        .filter(|result| result.process.is_some())
+        .count();
+
+    Ok(ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerBulkActionResponse {
+            action: request.action,
+            succeeded_count,
+            failed_count: results.len() - succeeded_count,
+            results,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+
+    fn action_request(
+        schedule_time: Option<time::PrimitiveDateTime>,
+    ) -> process_tracker_api::ProcessTrackerActionRequest {
+        process_tracker_api::ProcessTrackerActionRequest {
+            schedule_time,
+            reason: "Connector outage".to_string(),
+            performed_by: "ops@example.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_actions_without_schedule_time_are_valid() {
+        for action in [
+            enums::ProcessTrackerAction::Pause,
+            enums::ProcessTrackerAction::Run,
+            enums::ProcessTrackerAction::Cancel,
+        ] {
+            assert!(validate_action_request(action, &action_request(None)).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_reason_and_performed_by_are_required() {
+        let request = process_tracker_api::ProcessTrackerActionRequest {
+            reason: "  ".to_string(),
+            ..action_request(None)
+        };
+        assert!(validate_action_request(enums::ProcessTrackerAction::Pause, &request).is_err());
+
+        let request = process_tracker_api::ProcessTrackerActionRequest {
+            performed_by: String::new(),
+            ..action_request(None)
+        };
+        assert!(validate_action_request(enums::ProcessTrackerAction::Pause, &request).is_err());
+    }
+
+    #[test]
+    fn test_reschedule_requires_future_schedule_time() {
+        let action = enums::ProcessTrackerAction::Reschedule;
+        let now = common_utils::date_time::now();
+
+        assert!(validate_action_request(action, &action_request(None)).is_err());
+        assert!(
+            validate_action_request(action, &action_request(Some(now - Duration::minutes(5))))
+                .is_err()
+        );
+        assert!(
+            validate_action_request(action, &action_request(Some(now + Duration::hours(1))))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_schedule_time_is_only_supported_for_reschedule() {
+        let schedule_time = common_utils::date_time::now() + Duration::hours(1);
+
+        for action in [
+            enums::ProcessTrackerAction::Pause,
+            enums::ProcessTrackerAction::Run,
+            enums::ProcessTrackerAction::Cancel,
+        ] {
+            assert!(validate_action_request(action, &action_request(Some(schedule_time))).is_err());
+        }
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "v1")]
+pub mod settlement;
+
 use api_models::recon as recon_api;
 #[cfg(feature = "email")]
 use common_utils::{ext_traits::AsyncExt, types::user::ThemeLineage};
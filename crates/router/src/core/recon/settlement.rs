@@ -0,0 +1,822 @@
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use api_models::recon as recon_api;
+use common_utils::{fp_utils, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::enums as api_enums,
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const RECON_JOB_TASK: &str = "RECON_SETTLEMENT_FILE";
+const RECON_JOB_TAG: &str = "RECON";
+const RECON_JOB_LIST_MAX_LIMIT: i64 = 100;
+const RECON_MISMATCH_LIST_MAX_LIMIT: i64 = 1000;
+const SETTLEMENT_FILE_CONTENT_TYPE: &str = "text/csv";
+
+#[derive(Debug, MultipartForm)]
+pub struct ReconSettlementFileForm {
+    #[multipart(limit = "10MB")]
+    pub file: Bytes,
+    pub connector: Text<api_enums::Connector>,
+}
+
+impl From<ReconSettlementFileForm> for recon_api::ReconJobCreateRequest {
+    fn from(form: ReconSettlementFileForm) -> Self {
+        Self {
+            connector: form.connector.into_inner(),
+            file_name: form.file.file_name,
+            file: form.file.data.to_vec(),
+        }
+    }
+}
+
+/// A settlement record along with the line of the settlement file it was read from.
+struct SettlementRecord {
+    line_number: i32,
+    record: recon_api::ReconSettlementRecord,
+}
+
+fn parse_settlement_file(data: &[u8]) -> RouterResult<Vec<SettlementRecord>> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_reader(data);
+    let headers = csv_reader
+        .headers()
+        .cloned()
+        .map_err(invalid_settlement_file_error)?;
+
+    csv_reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(invalid_settlement_file_error)?;
+            // The line number is taken from the position of the record, so that it accounts for
+            // the header as well as for quoted fields spanning multiple lines
+            let line_number = record
+                .position()
+                .map(|position| i32::try_from(position.line()))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Settlement file contains too many rows".to_string(),
+                })?
+                .unwrap_or_default();
+            let record = record
+                .deserialize::<recon_api::ReconSettlementRecord>(Some(&headers))
+                .map_err(invalid_settlement_file_error)?;
+
+            Ok(SettlementRecord {
+                line_number,
+                record,
+            })
+        })
+        .collect()
+}
+
+fn invalid_settlement_file_error(
+    error: csv::Error,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::PreconditionFailed {
+        message: format!("Invalid settlement file: {error}"),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn create_recon_job(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: recon_api::ReconJobCreateRequest,
+) -> RouterResponse<recon_api::ReconJobResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+
+    // Validate the settlement file upfront, so that malformed files are rejected before they are
+    // stored and scheduled for processing
+    let records = parse_settlement_file(&request.file)?;
+    fp_utils::when(records.is_empty(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Settlement file does not contain any rows".to_string(),
+        })
+    })?;
+
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = format!("{}/recon/{}", merchant_id.get_string_repr(), file_id);
+    let file_size = i32::try_from(request.file.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while converting settlement file size to i32")?;
+
+    state
+        .file_storage_client
+        .upload_file(&file_key, request.file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload settlement file")?;
+
+    db.insert_file_metadata(diesel_models::file::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: merchant_id.clone(),
+        file_name: request.file_name,
+        file_size,
+        file_type: SETTLEMENT_FILE_CONTENT_TYPE.to_string(),
+        provider_file_id: Some(file_key),
+        file_upload_provider: Some(enums::FileUploadProvider::Router),
+        available: true,
+        connector_label: Some(request.connector.to_string()),
+        profile_id: None,
+        merchant_connector_id: None,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to insert file_metadata")?;
+
+    let job_id = common_utils::generate_id(consts::ID_LENGTH, "recon");
+    let now = common_utils::date_time::now();
+    let recon_job = db
+        .insert_recon_job(storage::ReconJobNew {
+            job_id: job_id.clone(),
+            merchant_id: merchant_id.clone(),
+            file_id,
+            connector: request.connector.to_string(),
+            status: enums::ReconJobStatus::Pending,
+            total_rows: 0,
+            matched_count: 0,
+            mismatched_count: 0,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert recon job")?;
+
+    add_recon_job_task(db, &merchant_id, &job_id, now).await?;
+
+    Ok(ApplicationResponse::Json(recon_job_response(recon_job)))
+}
+
+async fn add_recon_job_task(
+    db: &dyn crate::db::StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    job_id: &str,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::ReconWorkflow;
+    let tracking_data = storage::ReconJobTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        job_id: job_id.to_owned(),
+    };
+    let process_tracker_id =
+        scheduler::utils::get_process_tracker_id(runner, RECON_JOB_TASK, job_id, merchant_id);
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        RECON_JOB_TASK,
+        runner,
+        [RECON_JOB_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct recon job process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed while inserting recon job task to process_tracker: {job_id}")
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "Recon")));
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_recon_job(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    job_id: String,
+) -> RouterResponse<recon_api::ReconJobResponse> {
+    let recon_job = state
+        .store
+        .find_recon_job_by_merchant_id_job_id(
+            merchant_context.get_merchant_account().get_id(),
+            &job_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Recon job `{job_id}` does not exist"),
+        })?;
+
+    Ok(ApplicationResponse::Json(recon_job_response(recon_job)))
+}
+
+#[instrument(skip_all)]
+pub async fn list_recon_jobs(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    constraints: recon_api::ReconJobListConstraints,
+) -> RouterResponse<Vec<recon_api::ReconJobResponse>> {
+    let limit = validate_limit(constraints.limit, RECON_JOB_LIST_MAX_LIMIT)?;
+    let recon_jobs = state
+        .store
+        .list_recon_jobs_by_merchant_id(
+            merchant_context.get_merchant_account().get_id(),
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list recon jobs")?;
+
+    Ok(ApplicationResponse::Json(
+        recon_jobs.into_iter().map(recon_job_response).collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_recon_mismatches(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: recon_api::ReconMismatchListRequest,
+) -> RouterResponse<Vec<recon_api::ReconMismatchResponse>> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let limit = validate_limit(request.constraints.limit, RECON_MISMATCH_LIST_MAX_LIMIT)?;
+
+    // Ensure that the job exists, so that an empty list is only returned for jobs without
+    // mismatches
+    state
+        .store
+        .find_recon_job_by_merchant_id_job_id(merchant_id, &request.job_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Recon job `{}` does not exist", request.job_id),
+        })?;
+
+    let mismatches = state
+        .store
+        .list_recon_mismatches_by_merchant_id_job_id(
+            merchant_id,
+            &request.job_id,
+            request.constraints.mismatch_type,
+            Some(limit),
+            request.constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list recon mismatches")?;
+
+    Ok(ApplicationResponse::Json(
+        mismatches
+            .into_iter()
+            .map(recon_mismatch_response)
+            .collect(),
+    ))
+}
+
+fn validate_limit(limit: Option<i64>, max_limit: i64) -> RouterResult<i64> {
+    match limit {
+        Some(limit) if limit > max_limit => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`limit` must be a number less than {max_limit}"),
+            }))
+        }
+        Some(limit) if limit > 0 => Ok(limit),
+        _ => Ok(max_limit),
+    }
+}
+
+/// Matches the rows of the settlement file of a recon job against the payments and refunds of
+/// the merchant, and persists the mismatches found.
+///
+/// Errors with the settlement file itself mark the job as failed, while other errors are
+/// returned to the caller, so that the job can be retried. Mismatches are inserted with
+/// deterministic IDs, hence a retried job does not duplicate the mismatches found earlier.
+#[instrument(skip_all)]
+pub async fn execute_recon_job(
+    state: &SessionState,
+    tracking_data: &storage::ReconJobTrackingData,
+) -> RouterResult<storage::ReconJob> {
+    let db = &*state.store;
+    let merchant_id = &tracking_data.merchant_id;
+    let job_id = &tracking_data.job_id;
+
+    let recon_job = db
+        .find_recon_job_by_merchant_id_job_id(merchant_id, job_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find recon job")?;
+    if matches!(
+        recon_job.status,
+        enums::ReconJobStatus::Completed | enums::ReconJobStatus::Failed
+    ) {
+        return Ok(recon_job);
+    }
+
+    db.update_recon_job_by_merchant_id_job_id(
+        merchant_id,
+        job_id,
+        storage::ReconJobUpdate::StatusUpdate {
+            status: enums::ReconJobStatus::Processing,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update recon job status")?;
+
+    let settlement_file = retrieve_settlement_file(state, &recon_job).await?;
+    let records = match parse_settlement_file(&settlement_file) {
+        Ok(records) => records,
+        Err(error) => {
+            logger::error!(?error, %job_id, "Failed to parse settlement file");
+            let error_message = match error.current_context() {
+                errors::ApiErrorResponse::PreconditionFailed { message } => message.clone(),
+                _ => "Failed to parse settlement file".to_string(),
+            };
+
+            return db
+                .update_recon_job_by_merchant_id_job_id(
+                    merchant_id,
+                    job_id,
+                    storage::ReconJobUpdate::ErrorUpdate { error_message },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update recon job status");
+        }
+    };
+
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mut matched_count = 0;
+    let mut mismatched_count = 0;
+    for settlement_record in &records {
+        let mismatches = match_settlement_record(
+            state,
+            &merchant_account,
+            &recon_job.connector,
+            settlement_record,
+        )
+        .await?;
+
+        if mismatches.is_empty() {
+            matched_count += 1;
+            continue;
+        }
+
+        mismatched_count += 1;
+        for mismatch in mismatches {
+            match db
+                .insert_recon_mismatch(
+                    mismatch.into_recon_mismatch_new(&recon_job, settlement_record),
+                )
+                .await
+            {
+                Ok(_) => Ok(()),
+                // The mismatch was already inserted by a previous attempt of the job
+                Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to insert recon mismatch"),
+            }?;
+        }
+    }
+
+    db.update_recon_job_by_merchant_id_job_id(
+        merchant_id,
+        job_id,
+        storage::ReconJobUpdate::ResultUpdate {
+            total_rows: matched_count + mismatched_count,
+            matched_count,
+            mismatched_count,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update recon job result")
+}
+
+async fn retrieve_settlement_file(
+    state: &SessionState,
+    recon_job: &storage::ReconJob,
+) -> RouterResult<Vec<u8>> {
+    let file_metadata = state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&recon_job.merchant_id, &recon_job.file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotFound)?;
+
+    let provider_file_id = match (
+        file_metadata.file_upload_provider,
+        file_metadata.provider_file_id,
+        file_metadata.available,
+    ) {
+        (Some(enums::FileUploadProvider::Router), Some(provider_file_id), true) => {
+            Ok(provider_file_id)
+        }
+        _ => Err(report!(errors::ApiErrorResponse::FileNotAvailable))
+            .attach_printable("Settlement file is not available"),
+    }?;
+
+    state
+        .file_storage_client
+        .retrieve_file(&provider_file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve settlement file")
+}
+
+/// The details of the transaction matching a settlement record.
+#[derive(Clone)]
+struct TransactionDetails {
+    payment_id: common_utils::id_type::PaymentId,
+    attempt_id: Option<String>,
+    refund_id: Option<String>,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+    status: enums::ReconSettlementStatus,
+}
+
+struct Mismatch {
+    mismatch_type: enums::ReconMismatchType,
+    transaction: Option<TransactionDetails>,
+}
+
+impl Mismatch {
+    fn into_recon_mismatch_new(
+        self,
+        recon_job: &storage::ReconJob,
+        settlement_record: &SettlementRecord,
+    ) -> storage::ReconMismatchNew {
+        let record = &settlement_record.record;
+        let transaction = self.transaction.as_ref();
+
+        storage::ReconMismatchNew {
+            mismatch_id: get_recon_mismatch_id(
+                &recon_job.job_id,
+                settlement_record.line_number,
+                self.mismatch_type,
+            ),
+            job_id: recon_job.job_id.clone(),
+            merchant_id: recon_job.merchant_id.clone(),
+            line_number: settlement_record.line_number,
+            transaction_type: record.transaction_type,
+            mismatch_type: self.mismatch_type,
+            connector_transaction_id: record.connector_transaction_id.clone(),
+            settlement_amount: record.amount,
+            settlement_currency: record.currency,
+            settlement_status: record.status,
+            payment_id: transaction.map(|transaction| transaction.payment_id.clone()),
+            attempt_id: transaction.and_then(|transaction| transaction.attempt_id.clone()),
+            refund_id: transaction.and_then(|transaction| transaction.refund_id.clone()),
+            transaction_amount: transaction.map(|transaction| transaction.amount),
+            transaction_currency: transaction.and_then(|transaction| transaction.currency),
+            transaction_status: transaction.map(|transaction| transaction.status),
+            created_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+/// The ID of a mismatch is derived from the job, line and type of the mismatch, so that retries
+/// of a job do not insert the same mismatch again.
+fn get_recon_mismatch_id(
+    job_id: &str,
+    line_number: i32,
+    mismatch_type: enums::ReconMismatchType,
+) -> String {
+    format!("{job_id}_{line_number}_{mismatch_type}")
+}
+
+async fn match_settlement_record(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector: &str,
+    settlement_record: &SettlementRecord,
+) -> RouterResult<Vec<Mismatch>> {
+    let record = &settlement_record.record;
+    let transaction = match record.transaction_type {
+        enums::ReconTransactionType::Payment => {
+            find_payment_transaction(state, merchant_account, connector, record).await?
+        }
+        enums::ReconTransactionType::Refund => {
+            find_refund_transaction(state, merchant_account, connector, record).await?
+        }
+    };
+
+    Ok(get_mismatches(record, transaction))
+}
+
+fn get_mismatches(
+    record: &recon_api::ReconSettlementRecord,
+    transaction: Option<TransactionDetails>,
+) -> Vec<Mismatch> {
+    let Some(transaction) = transaction else {
+        return vec![Mismatch {
+            mismatch_type: enums::ReconMismatchType::Missing,
+            transaction: None,
+        }];
+    };
+
+    let is_amount_drifted =
+        transaction.amount != record.amount || transaction.currency != Some(record.currency);
+    let is_status_drifted = transaction.status != record.status;
+
+    [
+        (is_amount_drifted, enums::ReconMismatchType::AmountDrift),
+        (is_status_drifted, enums::ReconMismatchType::StatusDrift),
+    ]
+    .into_iter()
+    .filter(|(is_drifted, _)| *is_drifted)
+    .map(|(_, mismatch_type)| Mismatch {
+        mismatch_type,
+        transaction: Some(transaction.clone()),
+    })
+    .collect()
+}
+
+async fn find_payment_transaction(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector: &str,
+    record: &recon_api::ReconSettlementRecord,
+) -> RouterResult<Option<TransactionDetails>> {
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            merchant_account.get_id(),
+            &record.connector_transaction_id,
+            merchant_account.storage_scheme,
+        )
+        .await;
+
+    match payment_attempt {
+        Ok(payment_attempt) if payment_attempt.connector.as_deref() == Some(connector) => {
+            Ok(Some(TransactionDetails {
+                payment_id: payment_attempt.payment_id.clone(),
+                attempt_id: Some(payment_attempt.attempt_id.clone()),
+                refund_id: None,
+                amount: payment_attempt.get_total_amount(),
+                currency: payment_attempt.currency,
+                status: enums::ReconSettlementStatus::from(payment_attempt.status),
+            }))
+        }
+        Ok(_) => Ok(None),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payment attempt by connector transaction ID"),
+    }
+}
+
+async fn find_refund_transaction(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector: &str,
+    record: &recon_api::ReconSettlementRecord,
+) -> RouterResult<Option<TransactionDetails>> {
+    let refund = state
+        .store
+        .find_refund_by_merchant_id_connector_refund_id_connector(
+            merchant_account.get_id(),
+            &record.connector_transaction_id,
+            connector,
+            merchant_account.storage_scheme,
+        )
+        .await;
+
+    match refund {
+        Ok(refund) => Ok(Some(TransactionDetails {
+            payment_id: refund.payment_id,
+            attempt_id: Some(refund.attempt_id),
+            refund_id: Some(refund.refund_id),
+            amount: refund.refund_amount,
+            currency: Some(refund.currency),
+            status: enums::ReconSettlementStatus::from(refund.refund_status),
+        })),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find refund by connector refund ID"),
+    }
+}
+
+fn recon_job_response(recon_job: storage::ReconJob) -> recon_api::ReconJobResponse {
+    recon_api::ReconJobResponse {
+        job_id: recon_job.job_id,
+        merchant_id: recon_job.merchant_id,
+        file_id: recon_job.file_id,
+        connector: recon_job.connector,
+        status: recon_job.status,
+        total_rows: recon_job.total_rows,
+        matched_count: recon_job.matched_count,
+        mismatched_count: recon_job.mismatched_count,
+        error_message: recon_job.error_message,
+        created_at: recon_job.created_at,
+        modified_at: recon_job.modified_at,
+    }
+}
+
+fn recon_mismatch_response(mismatch: storage::ReconMismatch) -> recon_api::ReconMismatchResponse {
+    recon_api::ReconMismatchResponse {
+        mismatch_id: mismatch.mismatch_id,
+        job_id: mismatch.job_id,
+        line_number: mismatch.line_number,
+        transaction_type: mismatch.transaction_type,
+        mismatch_type: mismatch.mismatch_type,
+        connector_transaction_id: mismatch.connector_transaction_id,
+        settlement_amount: mismatch.settlement_amount,
+        settlement_currency: mismatch.settlement_currency,
+        settlement_status: mismatch.settlement_status,
+        payment_id: mismatch.payment_id,
+        attempt_id: mismatch.attempt_id,
+        refund_id: mismatch.refund_id,
+        transaction_amount: mismatch.transaction_amount,
+        transaction_currency: mismatch.transaction_currency,
+        transaction_status: mismatch.transaction_status,
+        created_at: mismatch.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_settlement_record(
+        amount: i64,
+        status: enums::ReconSettlementStatus,
+    ) -> recon_api::ReconSettlementRecord {
+        recon_api::ReconSettlementRecord {
+            connector_transaction_id: "txn_123".to_string(),
+            transaction_type: enums::ReconTransactionType::Payment,
+            amount: MinorUnit::new(amount),
+            currency: enums::Currency::USD,
+            status,
+        }
+    }
+
+    fn get_transaction_details(
+        amount: i64,
+        currency: enums::Currency,
+        status: enums::ReconSettlementStatus,
+    ) -> TransactionDetails {
+        TransactionDetails {
+            payment_id: common_utils::id_type::PaymentId::default(),
+            attempt_id: Some("attempt_123".to_string()),
+            refund_id: None,
+            amount: MinorUnit::new(amount),
+            currency: Some(currency),
+            status,
+        }
+    }
+
+    fn get_mismatch_types(mismatches: &[Mismatch]) -> Vec<enums::ReconMismatchType> {
+        mismatches
+            .iter()
+            .map(|mismatch| mismatch.mismatch_type)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_settlement_file() {
+        let data = b"connector_transaction_id,transaction_type,amount,currency,status\n\
+            txn_1, payment, 1000, USD, success\n\
+            \"txn_2\",refund,500,EUR,pending\n";
+        let records = parse_settlement_file(data).unwrap();
+
+        assert_eq!(records.len(), 2);
+        let first = records.first().unwrap();
+        assert_eq!(first.record.connector_transaction_id, "txn_1");
+        assert_eq!(first.record.amount, MinorUnit::new(1000));
+        assert_eq!(first.record.status, enums::ReconSettlementStatus::Success);
+        let second = records.get(1).unwrap();
+        assert_eq!(
+            second.record.transaction_type,
+            enums::ReconTransactionType::Refund
+        );
+        assert_eq!(second.record.currency, enums::Currency::EUR);
+    }
+
+    #[test]
+    fn test_parse_settlement_file_line_numbers_account_for_header() {
+        let data = b"connector_transaction_id,transaction_type,amount,currency,status\n\
+            txn_1,payment,1000,USD,success\n\
+            \"txn\n2\",payment,1000,USD,success\n\
+            txn_3,payment,1000,USD,success\n";
+        let line_numbers = parse_settlement_file(data)
+            .unwrap()
+            .iter()
+            .map(|record| record.line_number)
+            .collect::<Vec<_>>();
+
+        assert_eq!(line_numbers, vec![2, 3, 5]);
+    }
+
+    #[test]
+    fn test_parse_settlement_file_rejects_invalid_rows() {
+        let data = b"connector_transaction_id,transaction_type,amount,currency,status\n\
+            txn_1,payment,not_a_number,USD,success\n";
+        let error = parse_settlement_file(data).err().unwrap();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_get_mismatches_without_transaction() {
+        let record = get_settlement_record(1000, enums::ReconSettlementStatus::Success);
+        let mismatches = get_mismatches(&record, None);
+
+        assert_eq!(
+            get_mismatch_types(&mismatches),
+            vec![enums::ReconMismatchType::Missing]
+        );
+        assert!(mismatches
+            .iter()
+            .all(|mismatch| mismatch.transaction.is_none()));
+    }
+
+    #[test]
+    fn test_get_mismatches_with_matching_transaction() {
+        let record = get_settlement_record(1000, enums::ReconSettlementStatus::Success);
+        let transaction = get_transaction_details(
+            1000,
+            enums::Currency::USD,
+            enums::ReconSettlementStatus::Success,
+        );
+
+        assert!(get_mismatches(&record, Some(transaction)).is_empty());
+    }
+
+    #[test]
+    fn test_get_mismatches_with_drifted_transaction() {
+        let record = get_settlement_record(1000, enums::ReconSettlementStatus::Success);
+
+        let amount_drifted = get_transaction_details(
+            900,
+            enums::Currency::USD,
+            enums::ReconSettlementStatus::Success,
+        );
+        assert_eq!(
+            get_mismatch_types(&get_mismatches(&record, Some(amount_drifted))),
+            vec![enums::ReconMismatchType::AmountDrift]
+        );
+
+        let currency_drifted = get_transaction_details(
+            1000,
+            enums::Currency::EUR,
+            enums::ReconSettlementStatus::Success,
+        );
+        assert_eq!(
+            get_mismatch_types(&get_mismatches(&record, Some(currency_drifted))),
+            vec![enums::ReconMismatchType::AmountDrift]
+        );
+
+        let drifted = get_transaction_details(
+            900,
+            enums::Currency::USD,
+            enums::ReconSettlementStatus::Pending,
+        );
+        assert_eq!(
+            get_mismatch_types(&get_mismatches(&record, Some(drifted))),
+            vec![
+                enums::ReconMismatchType::AmountDrift,
+                enums::ReconMismatchType::StatusDrift
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recon_mismatch_id_is_deterministic() {
+        assert_eq!(
+            get_recon_mismatch_id("recon_123", 2, enums::ReconMismatchType::StatusDrift),
+            "recon_123_2_status_drift"
+        );
+        assert_ne!(
+            get_recon_mismatch_id("recon_123", 2, enums::ReconMismatchType::StatusDrift),
+            get_recon_mismatch_id("recon_123", 2, enums::ReconMismatchType::AmountDrift)
+        );
+    }
+}
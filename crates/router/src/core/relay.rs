@@ -8,10 +8,14 @@ use common_utils::{
     id_type::{self, GenerateId},
 };
 use error_stack::ResultExt;
-use hyperswitch_domain_models::relay;
+use hyperswitch_domain_models::{
+    relay, router_data_v2::flow_common_types::PaymentFlowData, router_request_types,
+    router_response_types::PaymentsResponseData,
+};
 
 use super::errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt};
 use crate::{
+    consts,
     core::payments,
     routes::SessionState,
     services,
@@ -41,6 +45,68 @@ impl Validate for relay_api_models::RelayRefundRequestData {
     }
 }
 
+impl Validate for relay_api_models::RelayCaptureRequestData {
+    type Error = errors::ApiErrorResponse;
+    fn validate(&self) -> Result<(), Self::Error> {
+        fp_utils::when(self.amount_to_capture.get_amount_as_i64() <= 0, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Amount to capture should be greater than 0".to_string(),
+            })
+        })?;
+        fp_utils::when(self.amount_to_capture > self.payment_amount, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Amount to capture should not exceed the payment amount".to_string(),
+            })
+        })?;
+        Ok(())
+    }
+}
+
+impl Validate for relay_api_models::RelayVoidRequestData {
+    type Error = errors::ApiErrorResponse;
+    fn validate(&self) -> Result<(), Self::Error> {
+        fp_utils::when(
+            self.amount
+                .is_some_and(|amount| amount.get_amount_as_i64() <= 0),
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Amount should be greater than 0".to_string(),
+                })
+            },
+        )?;
+        Ok(())
+    }
+}
+
+impl Validate for relay_api_models::RelayPaymentSyncRequestData {
+    type Error = errors::ApiErrorResponse;
+    fn validate(&self) -> Result<(), Self::Error> {
+        fp_utils::when(self.amount.get_amount_as_i64() <= 0, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Amount should be greater than 0".to_string(),
+            })
+        })?;
+        Ok(())
+    }
+}
+
+impl Validate for relay_api_models::RelayIncrementalAuthorizationRequestData {
+    type Error = errors::ApiErrorResponse;
+    fn validate(&self) -> Result<(), Self::Error> {
+        fp_utils::when(self.additional_amount.get_amount_as_i64() <= 0, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Additional amount should be greater than 0".to_string(),
+            })
+        })?;
+        fp_utils::when(self.total_amount <= self.additional_amount, || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Total amount should be greater than the additional amount".to_string(),
+            })
+        })?;
+        Ok(())
+    }
+}
+
 #[async_trait]
 pub trait RelayInterface {
     type Request: Validate;
@@ -51,6 +117,10 @@ pub trait RelayInterface {
             })
     }
 
+    fn get_request_data(
+        relay_data: Option<relay_api_models::RelayData>,
+    ) -> RouterResult<Self::Request>;
+
     fn get_domain_models(
         relay_request: RelayRequestInner<Self>,
         merchant_id: &id_type::MerchantId,
@@ -64,7 +134,32 @@ pub trait RelayInterface {
         relay_record: &relay::Relay,
     ) -> RouterResult<relay::RelayUpdate>;
 
-    fn generate_response(value: relay::Relay) -> RouterResult<api_models::relay::RelayResponse>;
+    fn generate_response(value: relay::Relay) -> RouterResult<api_models::relay::RelayResponse> {
+        let error = value
+            .error_code
+            .zip(value.error_message)
+            .map(
+                |(error_code, error_message)| api_models::relay::RelayError {
+                    code: error_code,
+                    message: error_message,
+                },
+            );
+
+        let data =
+            api_models::relay::RelayData::from(value.request_data.get_required_value("RelayData")?);
+
+        Ok(api_models::relay::RelayResponse {
+            id: value.id,
+            status: value.status,
+            error,
+            connector_resource_id: value.connector_resource_id,
+            connector_id: value.connector_id,
+            profile_id: value.profile_id,
+            relay_type: value.relay_type,
+            data: Some(data),
+            connector_reference_id: value.connector_reference_id,
+        })
+    }
 }
 
 pub struct RelayRequestInner<T: RelayInterface + ?Sized> {
@@ -74,19 +169,42 @@ pub struct RelayRequestInner<T: RelayInterface + ?Sized> {
     pub data: T::Request,
 }
 
-impl RelayRequestInner<RelayRefund> {
+impl<T: RelayInterface> RelayRequestInner<T> {
     pub fn from_relay_request(relay_request: relay_api_models::RelayRequest) -> RouterResult<Self> {
-        match relay_request.data {
-            Some(relay_api_models::RelayData::Refund(ref_data)) => Ok(Self {
-                connector_resource_id: relay_request.connector_resource_id,
-                connector_id: relay_request.connector_id,
-                relay_type: PhantomData,
-                data: ref_data,
-            }),
-            None => Err(errors::ApiErrorResponse::InvalidRequestData {
-                message: "Relay data is required for relay type refund".to_string(),
-            })?,
-        }
+        let data = T::get_request_data(relay_request.data)?;
+        Ok(Self {
+            connector_resource_id: relay_request.connector_resource_id,
+            connector_id: relay_request.connector_id,
+            relay_type: PhantomData,
+            data,
+        })
+    }
+}
+
+fn construct_relay_domain_model(
+    connector_resource_id: String,
+    connector_id: id_type::MerchantConnectorAccountId,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    relay_type: common_enums::RelayType,
+    request_data: relay::RelayData,
+) -> relay::Relay {
+    let relay_id = id_type::RelayId::generate();
+    relay::Relay {
+        id: relay_id,
+        connector_resource_id,
+        connector_id,
+        profile_id: profile_id.clone(),
+        merchant_id: merchant_id.clone(),
+        relay_type,
+        request_data: Some(request_data),
+        status: RelayStatus::Created,
+        connector_reference_id: None,
+        error_code: None,
+        error_message: None,
+        created_at: common_utils::date_time::now(),
+        modified_at: common_utils::date_time::now(),
+        response_data: None,
     }
 }
 
@@ -96,29 +214,31 @@ pub struct RelayRefund;
 impl RelayInterface for RelayRefund {
     type Request = relay_api_models::RelayRefundRequestData;
 
+    fn get_request_data(
+        relay_data: Option<relay_api_models::RelayData>,
+    ) -> RouterResult<Self::Request> {
+        match relay_data {
+            Some(relay_api_models::RelayData::Refund(ref_data)) => Ok(ref_data),
+            _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Relay data is required for relay type refund".to_string(),
+            })?,
+        }
+    }
+
     fn get_domain_models(
         relay_request: RelayRequestInner<Self>,
         merchant_id: &id_type::MerchantId,
         profile_id: &id_type::ProfileId,
     ) -> relay::Relay {
-        let relay_id = id_type::RelayId::generate();
         let relay_refund: relay::RelayRefundData = relay_request.data.into();
-        relay::Relay {
-            id: relay_id.clone(),
-            connector_resource_id: relay_request.connector_resource_id.clone(),
-            connector_id: relay_request.connector_id.clone(),
-            profile_id: profile_id.clone(),
-            merchant_id: merchant_id.clone(),
-            relay_type: common_enums::RelayType::Refund,
-            request_data: Some(relay::RelayData::Refund(relay_refund)),
-            status: RelayStatus::Created,
-            connector_reference_id: None,
-            error_code: None,
-            error_message: None,
-            created_at: common_utils::date_time::now(),
-            modified_at: common_utils::date_time::now(),
-            response_data: None,
-        }
+        construct_relay_domain_model(
+            relay_request.connector_resource_id,
+            relay_request.connector_id,
+            merchant_id,
+            profile_id,
+            common_enums::RelayType::Refund,
+            relay::RelayData::Refund(relay_refund),
+        )
     }
 
     async fn process_relay(
@@ -169,53 +289,427 @@ impl RelayInterface for RelayRefund {
 
         Ok(relay_update)
     }
+}
 
-    fn generate_response(value: relay::Relay) -> RouterResult<api_models::relay::RelayResponse> {
-        let error = value
-            .error_code
-            .zip(value.error_message)
-            .map(
-                |(error_code, error_message)| api_models::relay::RelayError {
-                    code: error_code,
-                    message: error_message,
-                },
-            );
+pub struct RelayCapture;
 
-        let data =
-            api_models::relay::RelayData::from(value.request_data.get_required_value("RelayData")?);
+#[async_trait]
+impl RelayInterface for RelayCapture {
+    type Request = relay_api_models::RelayCaptureRequestData;
 
-        Ok(api_models::relay::RelayResponse {
-            id: value.id,
-            status: value.status,
-            error,
-            connector_resource_id: value.connector_resource_id,
-            connector_id: value.connector_id,
-            profile_id: value.profile_id,
-            relay_type: value.relay_type,
-            data: Some(data),
-            connector_reference_id: value.connector_reference_id,
-        })
+    fn get_request_data(
+        relay_data: Option<relay_api_models::RelayData>,
+    ) -> RouterResult<Self::Request> {
+        match relay_data {
+            Some(relay_api_models::RelayData::Capture(capture_data)) => Ok(capture_data),
+            _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Relay data is required for relay type capture".to_string(),
+            })?,
+        }
+    }
+
+    fn get_domain_models(
+        relay_request: RelayRequestInner<Self>,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> relay::Relay {
+        construct_relay_domain_model(
+            relay_request.connector_resource_id,
+            relay_request.connector_id,
+            merchant_id,
+            profile_id,
+            common_enums::RelayType::Capture,
+            relay::RelayData::Capture(relay_request.data.into()),
+        )
+    }
+
+    async fn process_relay(
+        state: &SessionState,
+        merchant_context: domain::MerchantContext,
+        connector_account: domain::MerchantConnectorAccount,
+        relay_record: &relay::Relay,
+    ) -> RouterResult<relay::RelayUpdate> {
+        let merchant_id = merchant_context.get_merchant_account().get_id();
+
+        let relay_capture_data = match relay_record.request_data.as_ref() {
+            Some(relay::RelayData::Capture(relay_capture_data)) => Ok(relay_capture_data),
+            _ => Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to obtain relay data to construct relay capture data"),
+        }?;
+
+        let request = router_request_types::PaymentsCaptureData {
+            amount_to_capture: relay_capture_data.amount_to_capture.get_amount_as_i64(),
+            currency: relay_capture_data.currency,
+            connector_transaction_id: relay_record.connector_resource_id.clone(),
+            payment_amount: relay_capture_data.payment_amount.get_amount_as_i64(),
+            multiple_capture_data: None,
+            connector_meta: None,
+            browser_info: None,
+            metadata: None,
+            capture_method: Some(common_enums::CaptureMethod::Manual),
+            split_payments: None,
+            minor_payment_amount: relay_capture_data.payment_amount,
+            minor_amount_to_capture: relay_capture_data.amount_to_capture,
+            integrity_object: None,
+            webhook_url: Some(utils::get_relay_webhook_url(
+                state,
+                merchant_id,
+                &connector_account,
+            )),
+        };
+
+        let router_data = utils::construct_relay_payments_router_data::<api::Capture, _>(
+            state,
+            merchant_id,
+            &connector_account,
+            relay_record,
+            common_enums::AttemptStatus::Authorized,
+            request,
+        )
+        .await?;
+
+        call_connector_for_relay_payment(state, &connector_account, relay_record, router_data).await
     }
 }
 
+pub struct RelayVoid;
+
+#[async_trait]
+impl RelayInterface for RelayVoid {
+    type Request = relay_api_models::RelayVoidRequestData;
+
+    fn get_request_data(
+        relay_data: Option<relay_api_models::RelayData>,
+    ) -> RouterResult<Self::Request> {
+        match relay_data {
+            Some(relay_api_models::RelayData::Void(void_data)) => Ok(void_data),
+            None => Ok(relay_api_models::RelayVoidRequestData::default()),
+            Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Relay data does not correspond to relay type void".to_string(),
+            })?,
+        }
+    }
+
+    fn get_domain_models(
+        relay_request: RelayRequestInner<Self>,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> relay::Relay {
+        construct_relay_domain_model(
+            relay_request.connector_resource_id,
+            relay_request.connector_id,
+            merchant_id,
+            profile_id,
+            common_enums::RelayType::Void,
+            relay::RelayData::Void(relay_request.data.into()),
+        )
+    }
+
+    async fn process_relay(
+        state: &SessionState,
+        merchant_context: domain::MerchantContext,
+        connector_account: domain::MerchantConnectorAccount,
+        relay_record: &relay::Relay,
+    ) -> RouterResult<relay::RelayUpdate> {
+        let merchant_id = merchant_context.get_merchant_account().get_id();
+
+        let relay_void_data = match relay_record.request_data.as_ref() {
+            Some(relay::RelayData::Void(relay_void_data)) => Ok(relay_void_data),
+            _ => Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to obtain relay data to construct relay void data"),
+        }?;
+
+        let request = router_request_types::PaymentsCancelData {
+            amount: relay_void_data
+                .amount
+                .map(|amount| amount.get_amount_as_i64()),
+            currency: relay_void_data.currency,
+            connector_transaction_id: relay_record.connector_resource_id.clone(),
+            cancellation_reason: relay_void_data.cancellation_reason.clone(),
+            connector_meta: None,
+            browser_info: None,
+            metadata: None,
+            minor_amount: relay_void_data.amount,
+            webhook_url: Some(utils::get_relay_webhook_url(
+                state,
+                merchant_id,
+                &connector_account,
+            )),
+            capture_method: Some(common_enums::CaptureMethod::Manual),
+        };
+
+        let router_data = utils::construct_relay_payments_router_data::<api::Void, _>(
+            state,
+            merchant_id,
+            &connector_account,
+            relay_record,
+            common_enums::AttemptStatus::Authorized,
+            request,
+        )
+        .await?;
+
+        call_connector_for_relay_payment(state, &connector_account, relay_record, router_data).await
+    }
+}
+
+pub struct RelayPaymentSync;
+
+#[async_trait]
+impl RelayInterface for RelayPaymentSync {
+    type Request = relay_api_models::RelayPaymentSyncRequestData;
+
+    fn get_request_data(
+        relay_data: Option<relay_api_models::RelayData>,
+    ) -> RouterResult<Self::Request> {
+        match relay_data {
+            Some(relay_api_models::RelayData::PaymentSync(payment_sync_data)) => {
+                Ok(payment_sync_data)
+            }
+            _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Relay data is required for relay type payment_sync".to_string(),
+            })?,
+        }
+    }
+
+    fn get_domain_models(
+        relay_request: RelayRequestInner<Self>,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> relay::Relay {
+        construct_relay_domain_model(
+            relay_request.connector_resource_id,
+            relay_request.connector_id,
+            merchant_id,
+            profile_id,
+            common_enums::RelayType::PaymentSync,
+            relay::RelayData::PaymentSync(relay_request.data.into()),
+        )
+    }
+
+    async fn process_relay(
+        state: &SessionState,
+        merchant_context: domain::MerchantContext,
+        connector_account: domain::MerchantConnectorAccount,
+        relay_record: &relay::Relay,
+    ) -> RouterResult<relay::RelayUpdate> {
+        let (amount, currency) = get_relay_payment_sync_details(relay_record)
+            .get_required_value("RelayPaymentSyncData")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to obtain relay data to construct relay payment sync data")?;
+
+        sync_relay_payment_with_gateway(
+            state,
+            &merchant_context,
+            relay_record,
+            &connector_account,
+            amount,
+            currency,
+        )
+        .await
+    }
+}
+
+pub struct RelayIncrementalAuthorization;
+
+#[async_trait]
+impl RelayInterface for RelayIncrementalAuthorization {
+    type Request = relay_api_models::RelayIncrementalAuthorizationRequestData;
+
+    fn get_request_data(
+        relay_data: Option<relay_api_models::RelayData>,
+    ) -> RouterResult<Self::Request> {
+        match relay_data {
+            Some(relay_api_models::RelayData::IncrementalAuthorization(
+                incremental_authorization_data,
+            )) => Ok(incremental_authorization_data),
+            _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Relay data is required for relay type incremental_authorization"
+                    .to_string(),
+            })?,
+        }
+    }
+
+    fn get_domain_models(
+        relay_request: RelayRequestInner<Self>,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> relay::Relay {
+        construct_relay_domain_model(
+            relay_request.connector_resource_id,
+            relay_request.connector_id,
+            merchant_id,
+            profile_id,
+            common_enums::RelayType::IncrementalAuthorization,
+            relay::RelayData::IncrementalAuthorization(relay_request.data.into()),
+        )
+    }
+
+    async fn process_relay(
+        state: &SessionState,
+        merchant_context: domain::MerchantContext,
+        connector_account: domain::MerchantConnectorAccount,
+        relay_record: &relay::Relay,
+    ) -> RouterResult<relay::RelayUpdate> {
+        let merchant_id = merchant_context.get_merchant_account().get_id();
+
+        let relay_incremental_authorization_data = match relay_record.request_data.as_ref() {
+            Some(relay::RelayData::IncrementalAuthorization(
+                relay_incremental_authorization_data,
+            )) => Ok(relay_incremental_authorization_data),
+            _ => Err(errors::ApiErrorResponse::InternalServerError).attach_printable(
+                "Failed to obtain relay data to construct relay incremental authorization data",
+            ),
+        }?;
+
+        let request = router_request_types::PaymentsIncrementalAuthorizationData {
+            total_amount: relay_incremental_authorization_data
+                .total_amount
+                .get_amount_as_i64(),
+            additional_amount: relay_incremental_authorization_data
+                .additional_amount
+                .get_amount_as_i64(),
+            currency: relay_incremental_authorization_data.currency,
+            reason: relay_incremental_authorization_data.reason.clone(),
+            connector_transaction_id: relay_record.connector_resource_id.clone(),
+        };
+
+        let router_data =
+            utils::construct_relay_payments_router_data::<api::IncrementalAuthorization, _>(
+                state,
+                merchant_id,
+                &connector_account,
+                relay_record,
+                common_enums::AttemptStatus::Authorized,
+                request,
+            )
+            .await?;
+
+        call_connector_for_relay_payment(state, &connector_account, relay_record, router_data).await
+    }
+}
+
+/// Calls the connector for a relay of an operation on a payment, using the same connector
+/// integration as the corresponding payment flow.
+///
+/// Relays are rejected if the connector does not implement the flow, since the router data would
+/// otherwise be returned without the connector being called.
+async fn call_connector_for_relay_payment<F, Req>(
+    state: &SessionState,
+    connector_account: &domain::MerchantConnectorAccount,
+    relay_record: &relay::Relay,
+    router_data: hyperswitch_domain_models::router_data::RouterData<F, Req, PaymentsResponseData>,
+) -> RouterResult<relay::RelayUpdate>
+where
+    F: std::fmt::Debug + Clone + Send + Sync + 'static,
+    Req: std::fmt::Debug + Clone + Send + Sync + 'static,
+    dyn api::Connector + Sync: services::api::ConnectorIntegration<F, Req, PaymentsResponseData>,
+    dyn api::ConnectorV2 + Sync:
+        services::api::ConnectorIntegrationV2<F, PaymentFlowData, Req, PaymentsResponseData>,
+{
+    let connector_name = &connector_account.get_connector_name_as_string();
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+        Some(relay_record.connector_id.clone()),
+    )?;
+
+    let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        F,
+        Req,
+        PaymentsResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let connector_request = connector_integration
+        .build_request(&router_data, &state.conf.connectors)
+        .to_payment_failed_response()?
+        .ok_or(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Relay type {} is not supported by {connector_name}",
+                relay_record.relay_type
+            ),
+        })?;
+
+    let router_data_res = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        Some(connector_request),
+        None,
+    )
+    .await
+    .to_payment_failed_response()?;
+
+    Ok(relay::RelayUpdate::from_payments_response(
+        router_data_res.response,
+        relay_record.relay_type,
+        router_data_res.status,
+    ))
+}
+
 pub async fn relay_flow_decider(
     state: SessionState,
     merchant_context: domain::MerchantContext,
     profile_id_optional: Option<id_type::ProfileId>,
     request: relay_api_models::RelayRequest,
 ) -> RouterResponse<relay_api_models::RelayResponse> {
-    let relay_flow_request = match request.relay_type {
+    match request.relay_type {
         common_enums::RelayType::Refund => {
-            RelayRequestInner::<RelayRefund>::from_relay_request(request)?
+            let relay_flow_request = RelayRequestInner::<RelayRefund>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
         }
-    };
-    relay(
-        state,
-        merchant_context,
-        profile_id_optional,
-        relay_flow_request,
-    )
-    .await
+        common_enums::RelayType::Capture => {
+            let relay_flow_request =
+                RelayRequestInner::<RelayCapture>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
+        }
+        common_enums::RelayType::Void => {
+            let relay_flow_request = RelayRequestInner::<RelayVoid>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
+        }
+        common_enums::RelayType::PaymentSync => {
+            let relay_flow_request =
+                RelayRequestInner::<RelayPaymentSync>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
+        }
+        common_enums::RelayType::IncrementalAuthorization => {
+            let relay_flow_request =
+                RelayRequestInner::<RelayIncrementalAuthorization>::from_relay_request(request)?;
+            relay(
+                state,
+                merchant_context,
+                profile_id_optional,
+                relay_flow_request,
+            )
+            .await
+        }
+    }
 }
 
 pub async fn relay<T: RelayInterface>(
@@ -282,14 +776,33 @@ pub async fn relay<T: RelayInterface>(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to insert a relay record in db")?;
 
-    let relay_response = T::process_relay(
+    let relay_response = match T::process_relay(
         &state,
         merchant_context.clone(),
         connector_account,
         &relay_record,
     )
     .await
-    .attach_printable("Failed to process relay")?;
+    {
+        Ok(relay_response) => relay_response,
+        Err(error) => {
+            if let Some(relay_update) =
+                get_relay_update_for_unprocessed_relay(error.current_context())
+            {
+                db.update_relay(
+                    key_manager_state,
+                    merchant_context.get_merchant_key_store(),
+                    relay_record,
+                    relay_update,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to mark the relay as failed")?;
+            }
+
+            return Err(error).attach_printable("Failed to process relay");
+        }
+    };
 
     let relay_update_record = db
         .update_relay(
@@ -309,6 +822,23 @@ pub async fn relay<T: RelayInterface>(
     ))
 }
 
+/// Returns the update marking a relay as failed, if it could not be processed without the
+/// connector being called, so that the relay does not remain in the created state.
+fn get_relay_update_for_unprocessed_relay(
+    error: &errors::ApiErrorResponse,
+) -> Option<relay::RelayUpdate> {
+    match error {
+        errors::ApiErrorResponse::NotSupported { message } => {
+            Some(relay::RelayUpdate::ErrorUpdate {
+                error_code: consts::NO_ERROR_CODE.to_string(),
+                error_message: message.clone(),
+                status: RelayStatus::Failure,
+            })
+        }
+        _ => None,
+    }
+}
+
 pub async fn relay_retrieve(
     state: SessionState,
     merchant_context: domain::MerchantContext,
@@ -383,7 +913,7 @@ pub async fn relay_retrieve(
 
     let relay_response = match relay_record.relay_type {
         common_enums::RelayType::Refund => {
-            if should_call_connector_for_relay_refund_status(&relay_record, req.force_sync) {
+            if should_call_connector_for_relay_status(&relay_record, req.force_sync) {
                 let relay_response = sync_relay_refund_with_gateway(
                     &state,
                     &merchant_context,
@@ -405,6 +935,38 @@ pub async fn relay_retrieve(
                 relay_record
             }
         }
+        common_enums::RelayType::Capture
+        | common_enums::RelayType::Void
+        | common_enums::RelayType::PaymentSync => {
+            match get_relay_payment_sync_details(&relay_record) {
+                Some((amount, currency))
+                    if should_call_connector_for_relay_status(&relay_record, req.force_sync) =>
+                {
+                    let relay_response = sync_relay_payment_with_gateway(
+                        &state,
+                        &merchant_context,
+                        &relay_record,
+                        &connector_account,
+                        amount,
+                        currency,
+                    )
+                    .await?;
+
+                    db.update_relay(
+                        key_manager_state,
+                        merchant_context.get_merchant_key_store(),
+                        relay_record,
+                        relay_response,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update the relay record")?
+                }
+                _ => relay_record,
+            }
+        }
+        // The outcome of an incremental authorization cannot be determined by syncing the payment
+        common_enums::RelayType::IncrementalAuthorization => relay_record,
     };
 
     let response = relay_api_models::RelayResponse::from(relay_response);
@@ -414,9 +976,9 @@ pub async fn relay_retrieve(
     ))
 }
 
-fn should_call_connector_for_relay_refund_status(relay: &relay::Relay, force_sync: bool) -> bool {
-    // This allows refund sync at connector level if force_sync is enabled, or
-    // check if the refund is in terminal state
+fn should_call_connector_for_relay_status(relay: &relay::Relay, force_sync: bool) -> bool {
+    // This allows relay sync at connector level if force_sync is enabled, or
+    // check if the relay is in terminal state
     !matches!(relay.status, RelayStatus::Failure | RelayStatus::Success) && force_sync
 }
 
@@ -473,3 +1035,95 @@ pub async fn sync_relay_refund_with_gateway(
 
     Ok(relay_response)
 }
+
+/// Returns the amount and currency of the payment a relay was made for, which are required to sync
+/// the payment with the connector.
+fn get_relay_payment_sync_details(
+    relay_record: &relay::Relay,
+) -> Option<(common_utils::types::MinorUnit, common_enums::Currency)> {
+    match relay_record.request_data.as_ref()? {
+        relay::RelayData::Capture(relay_capture_data) => Some((
+            relay_capture_data.amount_to_capture,
+            relay_capture_data.currency,
+        )),
+        relay::RelayData::Void(relay_void_data) => {
+            relay_void_data.amount.zip(relay_void_data.currency)
+        }
+        relay::RelayData::PaymentSync(relay_payment_sync_data) => Some((
+            relay_payment_sync_data.amount,
+            relay_payment_sync_data.currency,
+        )),
+        relay::RelayData::Refund(_) | relay::RelayData::IncrementalAuthorization(_) => None,
+    }
+}
+
+pub async fn sync_relay_payment_with_gateway(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    relay_record: &relay::Relay,
+    connector_account: &domain::MerchantConnectorAccount,
+    amount: common_utils::types::MinorUnit,
+    currency: common_enums::Currency,
+) -> RouterResult<relay::RelayUpdate> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    let request = router_request_types::PaymentsSyncData {
+        connector_transaction_id: router_request_types::ResponseId::ConnectorTransactionId(
+            relay_record.connector_resource_id.clone(),
+        ),
+        encoded_data: None,
+        capture_method: None,
+        connector_meta: None,
+        sync_type: router_request_types::SyncRequestType::SinglePaymentSync,
+        mandate_id: None,
+        payment_method_type: None,
+        currency,
+        payment_experience: None,
+        split_payments: None,
+        amount,
+        integrity_object: None,
+        connector_reference_id: relay_record.connector_reference_id.clone(),
+    };
+
+    let router_data = utils::construct_relay_payments_router_data::<api::PSync, _>(
+        state,
+        merchant_id,
+        connector_account,
+        relay_record,
+        common_enums::AttemptStatus::Pending,
+        request,
+    )
+    .await?;
+
+    call_connector_for_relay_payment(state, connector_account, relay_record, router_data).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsupported_relay_is_marked_as_failed() {
+        let relay_update =
+            get_relay_update_for_unprocessed_relay(&errors::ApiErrorResponse::NotSupported {
+                message: "Relay type capture is not supported by stripe".to_string(),
+            });
+
+        assert!(matches!(
+            relay_update,
+            Some(relay::RelayUpdate::ErrorUpdate {
+                ref error_message,
+                status: RelayStatus::Failure,
+                ..
+            }) if error_message == "Relay type capture is not supported by stripe"
+        ));
+    }
+
+    #[test]
+    fn test_relay_is_not_marked_as_failed_for_other_errors() {
+        assert!(get_relay_update_for_unprocessed_relay(
+            &errors::ApiErrorResponse::InternalServerError
+        )
+        .is_none());
+    }
+}
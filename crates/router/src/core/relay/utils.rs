@@ -2,7 +2,11 @@ use std::str::FromStr;
 
 use common_utils::{ext_traits::OptionExt, id_type};
 use error_stack::ResultExt;
-use hyperswitch_domain_models::{router_data::ErrorResponse, types};
+use hyperswitch_domain_models::{
+    router_data::{ErrorResponse, RouterData},
+    router_response_types::PaymentsResponseData,
+    types,
+};
 
 use crate::{
     core::payments,
@@ -34,41 +38,28 @@ pub async fn construct_relay_refund_router_data<F>(
     #[cfg(feature = "v1")]
     let connector_name = &connector_account.connector_name;
 
-    let webhook_url = Some(payments::helpers::create_webhook_url(
-        &state.base_url.clone(),
-        merchant_id,
-        connector_account.get_id().get_string_repr(),
-    ));
+    let webhook_url = Some(get_relay_webhook_url(state, merchant_id, connector_account));
 
-    let supported_connector = &state
-        .conf
-        .multiple_api_version_supported_connectors
-        .supported_connectors;
+    let connector_api_version = get_connector_api_version(state, connector_name).await?;
 
-    let connector_enum = api_models::enums::Connector::from_str(connector_name)
-        .change_context(errors::ConnectorError::InvalidConnectorName)
-        .change_context(errors::ApiErrorResponse::InvalidDataValue {
-            field_name: "connector",
-        })
-        .attach_printable_lazy(|| format!("unable to parse connector name {connector_name:?}"))?;
-
-    let connector_api_version = if supported_connector.contains(&connector_enum) {
-        state
-            .store
-            .find_config_by_key(&format!("connector_api_version_{connector_name}"))
-            .await
-            .map(|value| value.config)
-            .ok()
-    } else {
-        None
-    };
-
-    let hyperswitch_domain_models::relay::RelayData::Refund(relay_refund_data) = relay_record
+    let relay_refund_data = match relay_record
         .request_data
         .clone()
         .get_required_value("refund relay data")
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to obtain relay data to construct relay refund data")?;
+        .attach_printable("Failed to obtain relay data to construct relay refund data")?
+    {
+        hyperswitch_domain_models::relay::RelayData::Refund(relay_refund_data) => {
+            Ok(relay_refund_data)
+        }
+        hyperswitch_domain_models::relay::RelayData::Capture(_)
+        | hyperswitch_domain_models::relay::RelayData::Void(_)
+        | hyperswitch_domain_models::relay::RelayData::PaymentSync(_)
+        | hyperswitch_domain_models::relay::RelayData::IncrementalAuthorization(_) => {
+            Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Relay data does not correspond to a refund relay")
+        }
+    }?;
 
     let relay_id_string = relay_record.id.get_string_repr().to_string();
 
@@ -145,7 +136,130 @@ pub async fn construct_relay_refund_router_data<F>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
+    };
+
+    Ok(router_data)
+}
+
+pub async fn construct_relay_payments_router_data<F, Req>(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_account: &domain::MerchantConnectorAccount,
+    relay_record: &hyperswitch_domain_models::relay::Relay,
+    status: common_enums::AttemptStatus,
+    request: Req,
+) -> RouterResult<RouterData<F, Req, PaymentsResponseData>> {
+    let connector_auth_type = connector_account
+        .get_connector_account_details()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+
+    #[cfg(feature = "v2")]
+    let connector_name = &connector_account.connector_name.to_string();
+
+    #[cfg(feature = "v1")]
+    let connector_name = &connector_account.connector_name;
+
+    let connector_api_version = get_connector_api_version(state, connector_name).await?;
+
+    let relay_id_string = relay_record.id.get_string_repr().to_string();
+
+    let router_data = hyperswitch_domain_models::router_data::RouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: merchant_id.clone(),
+        customer_id: None,
+        tenant_id: state.tenant.tenant_id.clone(),
+        connector: connector_name.to_string(),
+        payment_id: IRRELEVANT_PAYMENT_INTENT_ID.to_string(),
+        attempt_id: IRRELEVANT_PAYMENT_ATTEMPT_ID.to_string(),
+        status,
+        payment_method: common_enums::PaymentMethod::default(),
+        connector_auth_type,
+        description: None,
+        address: hyperswitch_domain_models::payment_address::PaymentAddress::default(),
+        auth_type: common_enums::AuthenticationType::default(),
+        connector_meta_data: connector_account.metadata.clone(),
+        connector_wallets_details: None,
+        amount_captured: None,
+        payment_method_status: None,
+        minor_amount_captured: None,
+        request,
+        response: Err(ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        connector_customer: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        connector_request_reference_id: relay_id_string,
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode: connector_account.get_connector_test_mode(),
+        payment_method_balance: None,
+        connector_api_version,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+        additional_merchant_data: None,
+        header_payload: None,
+        connector_mandate_request_reference_id: None,
+        authentication_id: None,
+        psd2_sca_exemption_type: None,
+        raw_connector_response: None,
+        is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
 }
+
+pub fn get_relay_webhook_url(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_account: &domain::MerchantConnectorAccount,
+) -> String {
+    payments::helpers::create_webhook_url(
+        &state.base_url.clone(),
+        merchant_id,
+        connector_account.get_id().get_string_repr(),
+    )
+}
+
+async fn get_connector_api_version(
+    state: &SessionState,
+    connector_name: &str,
+) -> RouterResult<Option<String>> {
+    let supported_connector = &state
+        .conf
+        .multiple_api_version_supported_connectors
+        .supported_connectors;
+
+    let connector_enum = api_models::enums::Connector::from_str(connector_name)
+        .change_context(errors::ConnectorError::InvalidConnectorName)
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector",
+        })
+        .attach_printable_lazy(|| format!("unable to parse connector name {connector_name:?}"))?;
+
+    let connector_api_version = if supported_connector.contains(&connector_enum) {
+        state
+            .store
+            .find_config_by_key(&format!("connector_api_version_{connector_name}"))
+            .await
+            .map(|value| value.config)
+            .ok()
+    } else {
+        None
+    };
+
+    Ok(connector_api_version)
+}
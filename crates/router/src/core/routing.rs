@@ -1,4 +1,8 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod dsl;
 pub mod helpers;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod simulation;
 pub mod transformers;
 use std::collections::HashSet;
 
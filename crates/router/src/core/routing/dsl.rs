@@ -0,0 +1,103 @@
+use api_models::routing as routing_types;
+use error_stack::ResultExt;
+use euclid::frontend::ast::{parser, printer};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::errors::StorageErrorExt,
+    routes::SessionState,
+    services::api as service_api,
+    types::domain,
+    utils::{OptionExt, ValueExt},
+};
+
+fn print_program(
+    program: &euclid::frontend::ast::Program<routing_types::ConnectorSelection>,
+) -> RouterResult<String> {
+    printer::program(program)
+        .map_err(|err| {
+            error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("The routing program cannot be written as DSL: {err}"),
+            })
+        })
+        .attach_printable("Failed to print the routing program as DSL")
+}
+
+pub async fn parse_routing_dsl(
+    _state: SessionState,
+    request: routing_types::RoutingDslParseRequest,
+) -> RouterResponse<routing_types::RoutingDslParseResponse> {
+    let program = parser::parse_program::<routing_types::ConnectorSelection>(&request.dsl)
+        .map_err(|err| {
+            error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid routing DSL at {err}"),
+            })
+        })
+        .attach_printable("Failed to parse the routing DSL")?;
+
+    let dsl = print_program(&program)?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingDslParseResponse {
+            algorithm: routing_types::StaticRoutingAlgorithm::Advanced(program),
+            dsl,
+        },
+    ))
+}
+
+pub async fn retrieve_routing_algorithm_as_dsl(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingDslResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    // Only advanced algorithms are routing programs, the other kinds have no DSL representation
+    if routing_algorithm.kind != diesel_models::enums::RoutingAlgorithmKind::Advanced {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Only advanced routing algorithms can be written as DSL".to_string(),
+        }
+        .into());
+    }
+
+    let dsl = match routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::StaticRoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?
+    {
+        routing_types::StaticRoutingAlgorithm::Advanced(program) => print_program(&program)?,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Advanced routing algorithm does not contain a routing program")?,
+    };
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingDslResponse { algorithm_id, dsl },
+    ))
+}
@@ -1683,8 +1683,8 @@ pub async fn push_metrics_with_update_window_for_contract_based_routing(
     }
 }
 
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
-fn get_desired_payment_status_for_dynamic_routing_metrics(
+#[cfg(all(feature = "v1", any(feature = "dynamic_routing", feature = "olap")))]
+pub(super) fn get_desired_payment_status_for_dynamic_routing_metrics(
     attempt_status: common_enums::AttemptStatus,
 ) -> common_enums::AttemptStatus {
     match attempt_status {
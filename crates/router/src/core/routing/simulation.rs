@@ -0,0 +1,428 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::routing as routing_types;
+use error_stack::ResultExt;
+use euclid::{backend::inputs as dsl_inputs, enums as euclid_enums};
+use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+
+use super::helpers;
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments::routing::{self as payments_routing, CachedAlgorithm},
+        utils as core_utils,
+    },
+    db::errors::StorageErrorExt,
+    routes::SessionState,
+    services::api as service_api,
+    types::{api, domain, storage, transformers::ForeignInto},
+    utils::{OptionExt, ValueExt},
+};
+
+/// Outcome of the payments processed by a connector, as they actually happened and as they
+/// would have been routed by the draft algorithm
+#[derive(Debug, Default)]
+struct ConnectorSimulationOutcome {
+    actual_count: u32,
+    simulated_count: u32,
+    succeeded_count: u32,
+    failed_count: u32,
+}
+
+impl ConnectorSimulationOutcome {
+    fn historical_success_rate(&self) -> Option<f64> {
+        let decided_count = self.succeeded_count.saturating_add(self.failed_count);
+        (decided_count > 0)
+            .then(|| f64::from(self.succeeded_count) / f64::from(decided_count) * 100.0)
+    }
+}
+
+fn get_percentage(count: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        f64::from(count) / f64::from(total) * 100.0
+    }
+}
+
+/// Returns the ID of the billing address of a historical payment, the billing address of the
+/// payment method taking precedence over that of the payment
+fn get_billing_address_id<'a>(
+    payment_intent: &'a storage::PaymentIntent,
+    payment_attempt: &'a storage::PaymentAttempt,
+) -> Option<&'a String> {
+    payment_attempt
+        .payment_method_billing_address_id
+        .as_ref()
+        .or(payment_intent.billing_address_id.as_ref())
+}
+
+/// Looks up the billing country among the addresses fetched for the simulation, an address which
+/// does not exist anymore being treated as absent
+fn get_billing_country(
+    billing_address_id: Option<&String>,
+    address_countries: &HashMap<String, common_enums::CountryAlpha2>,
+) -> Option<api_models::enums::Country> {
+    billing_address_id
+        .and_then(|address_id| address_countries.get(address_id))
+        .copied()
+        .map(api_models::enums::Country::from_alpha2)
+}
+
+/// Rebuilds the routing input of a historical payment from the stored payment intent and attempt
+fn make_dsl_input_for_simulation(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    address_countries: &HashMap<String, common_enums::CountryAlpha2>,
+) -> RouterResult<dsl_inputs::BackendInput> {
+    let additional_payment_method_data = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|data| match data {
+            serde_json::Value::Null => None,
+            _ => Some(
+                data.parse_value::<api_models::payments::AdditionalPaymentData>(
+                    "AdditionalPaymentData",
+                ),
+            ),
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed to parse the AdditionalPaymentData from payment_attempt.payment_method_data",
+        )?;
+
+    let card_info = additional_payment_method_data
+        .as_ref()
+        .and_then(|pm_data| match pm_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => Some(card_info),
+            _ => None,
+        });
+
+    let billing_country = get_billing_country(
+        get_billing_address_id(payment_intent, payment_attempt),
+        address_countries,
+    );
+
+    let mandate_type = payment_attempt
+        .mandate_details
+        .as_ref()
+        .map(|mandate_type| match mandate_type {
+            hyperswitch_domain_models::mandates::MandateDataType::SingleUse(_) => {
+                euclid_enums::MandateType::SingleUse
+            }
+            hyperswitch_domain_models::mandates::MandateDataType::MultiUse(_) => {
+                euclid_enums::MandateType::MultiUse
+            }
+        });
+
+    let mandate_data = dsl_inputs::MandateData {
+        mandate_acceptance_type: None,
+        payment_type: Some(if payment_attempt.mandate_details.is_some() {
+            euclid_enums::PaymentType::SetupMandate
+        } else {
+            euclid_enums::PaymentType::NonMandate
+        }),
+        mandate_type,
+    };
+
+    let payment_method_input = dsl_inputs::PaymentMethodInput {
+        payment_method: payment_attempt.payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        card_network: card_info.and_then(|card_info| card_info.card_network.clone()),
+    };
+
+    let payment_input = dsl_inputs::PaymentInput {
+        amount: payment_attempt.get_total_amount(),
+        card_bin: card_info.and_then(|card_info| card_info.card_isin.clone()),
+        currency: payment_attempt
+            .currency
+            .or(payment_intent.currency)
+            .get_required_value("currency")?,
+        authentication_type: payment_attempt.authentication_type,
+        capture_method: payment_attempt
+            .capture_method
+            .and_then(|cm| cm.foreign_into()),
+        business_country: payment_intent
+            .business_country
+            .map(api_models::enums::Country::from_alpha2),
+        billing_country,
+        business_label: payment_intent.business_label.clone(),
+        setup_future_usage: payment_intent.setup_future_usage,
+    };
+
+    let metadata = payment_intent
+        .parse_and_get_metadata("routing_parameters")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to parse routing_parameters from metadata of payment_intent")
+        .unwrap_or(None);
+
+    Ok(dsl_inputs::BackendInput {
+        metadata,
+        payment: payment_input,
+        payment_method: payment_method_input,
+        mandate: mandate_data,
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+    })
+}
+
+/// Returns the connector the draft algorithm would have routed the payment to
+fn get_simulated_connector(
+    algorithm: &CachedAlgorithm,
+    backend_input: dsl_inputs::BackendInput,
+) -> RouterResult<Option<api::routing::RoutableConnectorChoice>> {
+    let routable_connectors = match algorithm {
+        CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
+        CachedAlgorithm::Priority(plist) => plist.clone(),
+        CachedAlgorithm::VolumeSplit(splits) => {
+            payments_routing::perform_volume_split(splits.to_vec())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Volume split connector selection failed during simulation")?
+        }
+        CachedAlgorithm::Advanced(interpreter) => {
+            payments_routing::execute_dsl_and_get_connector_v1(backend_input, interpreter)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to execute the draft algorithm during simulation")?
+        }
+    };
+
+    Ok(routable_connectors.into_iter().next())
+}
+
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&request.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    if request
+        .algorithm
+        .should_validate_connectors_in_routing_config()
+    {
+        helpers::validate_connectors_in_routing_config(
+            &state,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            &request.profile_id,
+            &request.algorithm,
+        )
+        .await?;
+    }
+
+    let limit = request.validated_limit();
+    let algorithm = CachedAlgorithm::from_static_routing_algorithm(request.algorithm)
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The draft algorithm cannot be used for routing payments".to_string(),
+        })?;
+
+    let mut fetch_constraints = PaymentIntentFetchConstraints::from(request.time_range);
+    if let PaymentIntentFetchConstraints::List(ref mut params) = fetch_constraints {
+        params.profile_id = Some(vec![request.profile_id.clone()]);
+        params.limit = Some(limit);
+    }
+
+    let payments = db
+        .get_filtered_payment_intents_attempt(
+            key_manager_state,
+            merchant_context.get_merchant_account().get_id(),
+            &fetch_constraints,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // The billing addresses of all the payments are fetched at once, rather than per payment
+    let billing_address_ids = payments
+        .iter()
+        .filter_map(|(payment_intent, payment_attempt)| {
+            get_billing_address_id(payment_intent, payment_attempt).cloned()
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let address_countries = if billing_address_ids.is_empty() {
+        HashMap::new()
+    } else {
+        db.find_addresses_by_merchant_id_address_ids(
+            key_manager_state,
+            merchant_context.get_merchant_account().get_id(),
+            billing_address_ids,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the billing addresses of the payments")?
+        .into_iter()
+        .filter_map(|address| address.country.map(|country| (address.address_id, country)))
+        .collect::<HashMap<_, _>>()
+    };
+
+    let mut outcomes: HashMap<String, ConnectorSimulationOutcome> = HashMap::new();
+    let mut total_payments = 0_u32;
+    let mut unrouted_payments = 0_u32;
+
+    // Only the payments which reached a connector have an actual outcome to compare against
+    for (payment_intent, payment_attempt) in payments {
+        let Some(actual_connector) = payment_attempt.connector.clone() else {
+            continue;
+        };
+        total_payments = total_payments.saturating_add(1);
+
+        let actual_outcome = outcomes.entry(actual_connector).or_default();
+        actual_outcome.actual_count = actual_outcome.actual_count.saturating_add(1);
+        match helpers::get_desired_payment_status_for_dynamic_routing_metrics(
+            payment_attempt.status,
+        ) {
+            common_enums::AttemptStatus::Charged => {
+                actual_outcome.succeeded_count = actual_outcome.succeeded_count.saturating_add(1)
+            }
+            common_enums::AttemptStatus::Failure => {
+                actual_outcome.failed_count = actual_outcome.failed_count.saturating_add(1)
+            }
+            _ => {}
+        }
+
+        let backend_input =
+            make_dsl_input_for_simulation(&payment_intent, &payment_attempt, &address_countries)?;
+
+        match get_simulated_connector(&algorithm, backend_input)? {
+            Some(simulated_connector) => {
+                let simulated_outcome = outcomes
+                    .entry(simulated_connector.connector.to_string())
+                    .or_default();
+                simulated_outcome.simulated_count =
+                    simulated_outcome.simulated_count.saturating_add(1);
+            }
+            None => unrouted_payments = unrouted_payments.saturating_add(1),
+        }
+    }
+
+    let (succeeded_count, decided_count) = outcomes.values().fold(
+        (0_u32, 0_u32),
+        |(succeeded_count, decided_count), outcome| {
+            (
+                succeeded_count.saturating_add(outcome.succeeded_count),
+                decided_count
+                    .saturating_add(outcome.succeeded_count)
+                    .saturating_add(outcome.failed_count),
+            )
+        },
+    );
+    let actual_success_rate =
+        (decided_count > 0).then(|| get_percentage(succeeded_count, decided_count));
+
+    // Payments routed to a connector without any historical outcome are left out of the estimate
+    let (weighted_success_rate, estimated_count) = outcomes
+        .values()
+        .filter_map(|outcome| {
+            outcome
+                .historical_success_rate()
+                .map(|success_rate| (success_rate, outcome.simulated_count))
+        })
+        .fold(
+            (0.0, 0_u32),
+            |(weighted_success_rate, estimated_count), (success_rate, simulated_count)| {
+                (
+                    weighted_success_rate + success_rate * f64::from(simulated_count),
+                    estimated_count.saturating_add(simulated_count),
+                )
+            },
+        );
+    let estimated_success_rate =
+        (estimated_count > 0).then(|| weighted_success_rate / f64::from(estimated_count));
+
+    let mut connector_distribution = outcomes
+        .into_iter()
+        .map(
+            |(connector, outcome)| routing_types::RoutingSimulationConnectorDistribution {
+                historical_success_rate: outcome.historical_success_rate(),
+                actual_share: get_percentage(outcome.actual_count, total_payments),
+                simulated_share: get_percentage(outcome.simulated_count, total_payments),
+                count_difference: i64::from(outcome.simulated_count)
+                    - i64::from(outcome.actual_count),
+                actual_count: outcome.actual_count,
+                simulated_count: outcome.simulated_count,
+                connector,
+            },
+        )
+        .collect::<Vec<_>>();
+    connector_distribution.sort_by(|a, b| a.connector.cmp(&b.connector));
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingSimulationResponse {
+            total_payments,
+            unrouted_payments,
+            actual_success_rate,
+            estimated_success_rate,
+            connector_distribution,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_billing_country_of_fetched_address() {
+        let address_countries =
+            HashMap::from([("addr_1".to_string(), common_enums::CountryAlpha2::DE)]);
+
+        assert_eq!(
+            get_billing_country(Some(&"addr_1".to_string()), &address_countries),
+            Some(api_models::enums::Country::Germany)
+        );
+    }
+
+    #[test]
+    fn test_missing_billing_address_is_treated_as_absent() {
+        let address_countries =
+            HashMap::from([("addr_1".to_string(), common_enums::CountryAlpha2::DE)]);
+
+        assert_eq!(
+            get_billing_country(Some(&"addr_2".to_string()), &address_countries),
+            None
+        );
+        assert_eq!(get_billing_country(None, &address_countries), None);
+    }
+
+    #[test]
+    fn test_historical_success_rate_only_counts_decided_payments() {
+        let outcome = ConnectorSimulationOutcome {
+            actual_count: 5,
+            simulated_count: 0,
+            succeeded_count: 3,
+            failed_count: 1,
+        };
+
+        assert_eq!(outcome.historical_success_rate(), Some(75.0));
+        assert_eq!(
+            ConnectorSimulationOutcome::default().historical_success_rate(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_percentage() {
+        assert_eq!(get_percentage(1, 4), 25.0);
+        assert_eq!(get_percentage(1, 0), 0.0);
+    }
+}
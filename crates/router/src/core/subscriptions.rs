@@ -0,0 +1,1783 @@
+use api_models::{
+    payments as payment_types,
+    subscriptions::{
+        InvoiceListConstraints, InvoiceListResponse, InvoiceResponse, SubscriptionCancelRequest,
+        SubscriptionCreateRequest, SubscriptionListConstraints, SubscriptionListResponse,
+        SubscriptionPlanCreateRequest, SubscriptionPlanListConstraints,
+        SubscriptionPlanListResponse, SubscriptionPlanResponse, SubscriptionPlanUpdateRequest,
+        SubscriptionPriceCreateRequest, SubscriptionPriceListResponse, SubscriptionPriceResponse,
+        SubscriptionResponse, SubscriptionUpdateRequest,
+    },
+    webhooks,
+};
+use common_enums::{
+    BillingInterval, EventClass, EventObjectType, EventType, IntentStatus, InvoiceBillingReason,
+    InvoiceStatus, MandateStatus, SubscriptionStatus,
+};
+use common_types::subscriptions::{InvoiceLineItem, InvoiceLineItems};
+use common_utils::{date_time, fp_utils, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::{instrument, logger, tracing, Instrument};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils, webhooks as webhooks_core,
+    },
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{api, domain, storage, transformers::ForeignFrom},
+};
+
+const SUBSCRIPTION_BILLING_TASK: &str = "SUBSCRIPTION_BILLING";
+const SUBSCRIPTION_TAG: &str = "SUBSCRIPTION";
+
+const DEFAULT_LIST_LIMIT: u32 = 20;
+const MAX_LIST_LIMIT: u32 = 100;
+
+#[instrument(skip(state, merchant_context))]
+pub async fn create_subscription_plan(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: SubscriptionPlanCreateRequest,
+) -> RouterResponse<SubscriptionPlanResponse> {
+    let db = &*state.store;
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        None,
+        None,
+        &merchant_context,
+        request.profile_id.as_ref().or(profile_id.as_ref()),
+        db,
+        true,
+    )
+    .await?;
+
+    let now = date_time::now();
+    let subscription_plan = db
+        .insert_subscription_plan(storage::SubscriptionPlanNew {
+            plan_id: common_utils::generate_id(consts::ID_LENGTH, "plan"),
+            merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+            profile_id,
+            name: request.name,
+            description: request.description,
+            is_active: true,
+            metadata: request.metadata,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription plan")?;
+
+    Ok(ApplicationResponse::Json(
+        SubscriptionPlanResponse::foreign_from(subscription_plan),
+    ))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn retrieve_subscription_plan(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    plan_id: String,
+) -> RouterResponse<SubscriptionPlanResponse> {
+    let subscription_plan = find_subscription_plan(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        profile_id,
+        &plan_id,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        SubscriptionPlanResponse::foreign_from(subscription_plan),
+    ))
+}
+
+/// Updates a subscription plan. Deactivating a plan prevents new subscriptions to its prices,
+/// existing subscriptions keep being billed.
+#[instrument(skip(state, merchant_context))]
+pub async fn update_subscription_plan(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    plan_id: String,
+    request: SubscriptionPlanUpdateRequest,
+) -> RouterResponse<SubscriptionPlanResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    find_subscription_plan(&state, merchant_id, profile_id, &plan_id).await?;
+
+    let subscription_plan = state
+        .store
+        .update_subscription_plan_by_merchant_id_plan_id(
+            merchant_id,
+            &plan_id,
+            storage::SubscriptionPlanUpdate::Update {
+                name: request.name,
+                description: request.description,
+                is_active: request.is_active,
+                metadata: request.metadata,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription plan")?;
+
+    Ok(ApplicationResponse::Json(
+        SubscriptionPlanResponse::foreign_from(subscription_plan),
+    ))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn list_subscription_plans(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    constraints: SubscriptionPlanListConstraints,
+) -> RouterResponse<SubscriptionPlanListResponse> {
+    let db = &*state.store;
+    let limit = get_list_limit(constraints.limit)?;
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        None,
+        None,
+        &merchant_context,
+        profile_id.as_ref(),
+        db,
+        false,
+    )
+    .await?;
+
+    let subscription_plans = db
+        .list_subscription_plans_by_merchant_id_profile_id(
+            merchant_context.get_merchant_account().get_id(),
+            &profile_id,
+            constraints.is_active,
+            i64::from(limit),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription plans")?;
+
+    let data: Vec<_> = subscription_plans
+        .into_iter()
+        .map(SubscriptionPlanResponse::foreign_from)
+        .collect();
+
+    Ok(ApplicationResponse::Json(SubscriptionPlanListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn create_subscription_price(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    plan_id: String,
+    request: SubscriptionPriceCreateRequest,
+) -> RouterResponse<SubscriptionPriceResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let subscription_plan =
+        find_subscription_plan(&state, merchant_id, profile_id, &plan_id).await?;
+    fp_utils::when(!subscription_plan.is_active, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Prices cannot be added to an inactive subscription plan".to_string(),
+        })
+    })?;
+    fp_utils::when(!request.amount.is_greater_than(0), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount should be greater than 0".to_string(),
+        })
+    })?;
+    fp_utils::when(request.interval_count == Some(0), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "interval_count should be greater than 0".to_string(),
+        })
+    })?;
+
+    let now = date_time::now();
+    let subscription_price = state
+        .store
+        .insert_subscription_price(storage::SubscriptionPriceNew {
+            price_id: common_utils::generate_id(consts::ID_LENGTH, "price"),
+            plan_id,
+            merchant_id: merchant_id.clone(),
+            amount: request.amount,
+            currency: request.currency,
+            billing_interval: request.billing_interval,
+            interval_count: request.interval_count.map_or(1, i32::from),
+            trial_period_days: request.trial_period_days.map(i32::from),
+            is_active: true,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription price")?;
+
+    Ok(ApplicationResponse::Json(
+        SubscriptionPriceResponse::foreign_from(subscription_price),
+    ))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn list_subscription_prices(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    plan_id: String,
+) -> RouterResponse<SubscriptionPriceListResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    find_subscription_plan(&state, merchant_id, profile_id, &plan_id).await?;
+
+    let data: Vec<_> = state
+        .store
+        .list_subscription_prices_by_merchant_id_plan_id(merchant_id, &plan_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription prices")?
+        .into_iter()
+        .map(SubscriptionPriceResponse::foreign_from)
+        .collect();
+
+    Ok(ApplicationResponse::Json(SubscriptionPriceListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+/// Subscribes a customer to a price. Subscriptions with a trial period are charged for the first
+/// time once the trial ends, other subscriptions are invoiced and charged immediately.
+#[instrument(skip(state, merchant_context))]
+pub async fn create_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request: SubscriptionCreateRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let (subscription_price, subscription_plan) =
+        find_active_subscription_price(&state, &merchant_id, profile_id, &request.price_id).await?;
+
+    db.find_customer_by_customer_id_merchant_id(
+        &(&state).into(),
+        &request.customer_id,
+        &merchant_id,
+        merchant_context.get_merchant_key_store(),
+        merchant_context.get_merchant_account().storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    validate_subscription_mandate(
+        &state,
+        &merchant_context,
+        &request.customer_id,
+        &request.mandate_id,
+    )
+    .await?;
+
+    let now = date_time::now();
+    let trial_period_days = request
+        .trial_period_days
+        .map(i64::from)
+        .or(subscription_price.trial_period_days.map(i64::from))
+        .filter(|trial_period_days| *trial_period_days > 0);
+    let (status, current_period_end, trial_end) = match trial_period_days {
+        Some(trial_period_days) => {
+            let trial_end = now.saturating_add(time::Duration::days(trial_period_days));
+            (SubscriptionStatus::Trialing, trial_end, Some(trial_end))
+        }
+        None => (
+            SubscriptionStatus::Active,
+            add_billing_interval(
+                now,
+                subscription_price.billing_interval,
+                subscription_price.interval_count,
+            )?,
+            None,
+        ),
+    };
+
+    let subscription = db
+        .insert_subscription(storage::SubscriptionNew {
+            subscription_id: common_utils::generate_id(consts::ID_LENGTH, "sub"),
+            merchant_id,
+            profile_id: subscription_plan.profile_id,
+            customer_id: request.customer_id,
+            plan_id: subscription_plan.plan_id,
+            price_id: subscription_price.price_id.clone(),
+            status,
+            mandate_id: request.mandate_id,
+            current_period_start: now,
+            current_period_end,
+            trial_end,
+            billing_cycle_anchor: trial_end.unwrap_or(now),
+            cancel_at_period_end: false,
+            canceled_at: None,
+            latest_invoice_id: None,
+            metadata: request.metadata,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription")?;
+
+    add_subscription_billing_task(&state, &subscription, None, current_period_end).await?;
+    trigger_subscription_webhook(&state, &merchant_context, &subscription).await;
+
+    let subscription = if trial_end.is_none() {
+        let line_items = vec![get_price_line_item(
+            &subscription_price,
+            now,
+            current_period_end,
+        )];
+        let (subscription, invoice) = create_subscription_invoice(
+            &state,
+            &merchant_context,
+            &subscription,
+            &subscription_price,
+            InvoiceBillingReason::SubscriptionCreate,
+            line_items,
+        )
+        .await?;
+
+        charge_subscription_invoice(&state, &merchant_context, subscription, invoice).await?
+    } else {
+        subscription
+    };
+
+    Ok(ApplicationResponse::Json(
+        SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn retrieve_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    subscription_id: String,
+) -> RouterResponse<SubscriptionResponse> {
+    let subscription = find_subscription(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        profile_id,
+        &subscription_id,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn list_subscriptions(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    constraints: SubscriptionListConstraints,
+) -> RouterResponse<SubscriptionListResponse> {
+    let db = &*state.store;
+    let limit = get_list_limit(constraints.limit)?;
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        None,
+        None,
+        &merchant_context,
+        profile_id.as_ref(),
+        db,
+        false,
+    )
+    .await?;
+
+    let subscriptions = db
+        .list_subscriptions_by_merchant_id_profile_id(
+            merchant_context.get_merchant_account().get_id(),
+            &profile_id,
+            constraints.customer_id.as_ref(),
+            constraints.status,
+            i64::from(limit),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscriptions")?;
+
+    let data: Vec<_> = subscriptions
+        .into_iter()
+        .map(SubscriptionResponse::foreign_from)
+        .collect();
+
+    Ok(ApplicationResponse::Json(SubscriptionListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+/// Updates the mandate, the metadata or the price of a subscription. When the price is changed
+/// after the trial period, the remaining time of the current period is prorated between the
+/// previous price and the new price, and the difference is invoiced immediately. The current
+/// period is kept, the billing interval of the new price applies from the next period.
+#[instrument(skip(state, merchant_context))]
+pub async fn update_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    subscription_id: String,
+    request: SubscriptionUpdateRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let mut subscription =
+        find_subscription(&state, &merchant_id, profile_id.clone(), &subscription_id).await?;
+    validate_subscription_is_not_canceled(&subscription)?;
+
+    if let Some(mandate_id) = &request.mandate_id {
+        validate_subscription_mandate(
+            &state,
+            &merchant_context,
+            &subscription.customer_id,
+            mandate_id,
+        )
+        .await?;
+    }
+    if request.mandate_id.is_some() || request.metadata.is_some() {
+        subscription = db
+            .update_uncanceled_subscription_by_merchant_id_subscription_id(
+                &merchant_id,
+                &subscription_id,
+                storage::SubscriptionUpdate::GeneralUpdate {
+                    mandate_id: request.mandate_id,
+                    metadata: request.metadata,
+                },
+            )
+            .await
+            .to_not_found_response(subscription_canceled_error())?;
+    }
+
+    let Some(price_id) = request
+        .price_id
+        .filter(|price_id| *price_id != subscription.price_id)
+    else {
+        return Ok(ApplicationResponse::Json(
+            SubscriptionResponse::foreign_from(subscription),
+        ));
+    };
+
+    let (new_price, new_plan) =
+        find_active_subscription_price(&state, &merchant_id, profile_id, &price_id).await?;
+    let previous_price = db
+        .find_subscription_price_by_merchant_id_price_id(&merchant_id, &subscription.price_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the price of the subscription")?;
+    fp_utils::when(new_plan.profile_id != subscription.profile_id, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The new price should belong to the profile of the subscription".to_string(),
+        })
+    })?;
+    fp_utils::when(new_price.currency != previous_price.currency, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The new price should have the same currency as the current price".to_string(),
+        })
+    })?;
+
+    subscription = db
+        .update_uncanceled_subscription_by_merchant_id_subscription_id(
+            &merchant_id,
+            &subscription_id,
+            storage::SubscriptionUpdate::PriceUpdate {
+                plan_id: new_plan.plan_id,
+                price_id: new_price.price_id.clone(),
+                // The billing cycles of a new billing interval are counted from the next period
+                billing_cycle_anchor: if (new_price.billing_interval, new_price.interval_count)
+                    == (
+                        previous_price.billing_interval,
+                        previous_price.interval_count,
+                    ) {
+                    subscription.billing_cycle_anchor
+                } else {
+                    subscription.current_period_end
+                },
+            },
+        )
+        .await
+        .to_not_found_response(subscription_canceled_error())?;
+
+    if subscription.status != SubscriptionStatus::Trialing {
+        let line_items =
+            get_proration_line_items(&subscription, &previous_price, &new_price, date_time::now())?;
+        let (updated_subscription, invoice) = create_subscription_invoice(
+            &state,
+            &merchant_context,
+            &subscription,
+            &new_price,
+            InvoiceBillingReason::SubscriptionUpdate,
+            line_items,
+        )
+        .await?;
+
+        subscription = if invoice.amount.is_greater_than(0) {
+            charge_subscription_invoice(&state, &merchant_context, updated_subscription, invoice)
+                .await?
+        } else {
+            let invoice =
+                update_subscription_invoice_status(&state, &invoice, InvoiceStatus::Paid).await?;
+            trigger_invoice_webhook(&state, &merchant_context, &invoice).await;
+            updated_subscription
+        };
+    }
+
+    Ok(ApplicationResponse::Json(
+        SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+/// Cancels a subscription, either immediately or at the end of the current period.
+#[instrument(skip(state, merchant_context))]
+pub async fn cancel_subscription(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    subscription_id: String,
+    request: SubscriptionCancelRequest,
+) -> RouterResponse<SubscriptionResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let subscription = find_subscription(&state, merchant_id, profile_id, &subscription_id).await?;
+    validate_subscription_is_not_canceled(&subscription)?;
+
+    let subscription = if request.cancel_at_period_end {
+        state
+            .store
+            .update_uncanceled_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                &subscription_id,
+                storage::SubscriptionUpdate::CancelAtPeriodEndUpdate {
+                    cancel_at_period_end: true,
+                },
+            )
+            .await
+            .to_not_found_response(subscription_canceled_error())?
+    } else {
+        cancel_subscription_immediately(&state, &merchant_context, &subscription)
+            .await?
+            .ok_or(subscription_canceled_error())?
+    };
+
+    Ok(ApplicationResponse::Json(
+        SubscriptionResponse::foreign_from(subscription),
+    ))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn list_subscription_invoices(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    subscription_id: String,
+    constraints: InvoiceListConstraints,
+) -> RouterResponse<InvoiceListResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let limit = get_list_limit(constraints.limit)?;
+    find_subscription(&state, merchant_id, profile_id, &subscription_id).await?;
+
+    let data: Vec<_> = state
+        .store
+        .list_subscription_invoices_by_merchant_id_subscription_id(
+            merchant_id,
+            &subscription_id,
+            i64::from(limit),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list subscription invoices")?
+        .into_iter()
+        .map(InvoiceResponse::foreign_from)
+        .collect();
+
+    Ok(ApplicationResponse::Json(InvoiceListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+#[instrument(skip(state, merchant_context))]
+pub async fn retrieve_invoice(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    invoice_id: String,
+) -> RouterResponse<InvoiceResponse> {
+    let invoice = state
+        .store
+        .find_subscription_invoice_by_merchant_id_invoice_id(
+            merchant_context.get_merchant_account().get_id(),
+            &invoice_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Invoice not found".to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &invoice)?;
+
+    Ok(ApplicationResponse::Json(InvoiceResponse::foreign_from(
+        invoice,
+    )))
+}
+
+/// Runs the billing task of a subscription. At the end of a period, the subscription is either
+/// canceled if it was canceled at period end, or renewed for the next period and invoiced. A task
+/// which retries the payment of an invoice charges the invoice again if it is still unpaid.
+#[instrument(skip(state, merchant_context))]
+pub async fn execute_subscription_billing(
+    state: &SessionState,
+    merchant_context: domain::MerchantContext,
+    tracking_data: &storage::SubscriptionBillingTrackingData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_id = merchant_context.get_merchant_account().get_id().clone();
+    let subscription = db
+        .find_subscription_by_merchant_id_subscription_id(
+            &merchant_id,
+            &tracking_data.subscription_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch subscription")?;
+
+    if subscription.status == SubscriptionStatus::Canceled {
+        logger::info!(subscription_id = %subscription.subscription_id, "Subscription is canceled");
+        return Ok(());
+    }
+
+    if let Some(invoice_id) = &tracking_data.invoice_id {
+        let invoice = db
+            .find_subscription_invoice_by_merchant_id_invoice_id(&merchant_id, invoice_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch subscription invoice")?;
+
+        if !matches!(
+            invoice.status,
+            InvoiceStatus::Open | InvoiceStatus::PaymentFailed
+        ) {
+            logger::info!(invoice_id, "Invoice is no longer awaiting payment");
+            return Ok(());
+        }
+
+        retry_subscription_invoice_payment(state, &merchant_context, subscription, invoice).await?;
+        return Ok(());
+    }
+
+    if subscription.current_period_end != tracking_data.period_end {
+        return resume_subscription_renewal(
+            state,
+            &merchant_context,
+            subscription,
+            tracking_data.period_end,
+        )
+        .await;
+    }
+
+    if subscription.cancel_at_period_end {
+        cancel_subscription_immediately(state, &merchant_context, &subscription).await?;
+        return Ok(());
+    }
+
+    let subscription_price = db
+        .find_subscription_price_by_merchant_id_price_id(&merchant_id, &subscription.price_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the price of the subscription")?;
+    let period_start = subscription.current_period_end;
+    let period_end = get_billing_period_end(
+        subscription.billing_cycle_anchor,
+        period_start,
+        subscription_price.billing_interval,
+        subscription_price.interval_count,
+    )?;
+
+    // The invoice is created before the subscription is renewed, so that a run of the task which
+    // stopped in between picks up the invoice created for the period instead of creating another
+    let (subscription, invoice) =
+        match find_subscription_cycle_invoice(state, &subscription, period_start).await? {
+            Some(invoice) => (subscription, invoice),
+            None => {
+                let line_items = vec![get_price_line_item(
+                    &subscription_price,
+                    period_start,
+                    period_end,
+                )];
+                create_subscription_invoice(
+                    state,
+                    &merchant_context,
+                    &subscription,
+                    &subscription_price,
+                    InvoiceBillingReason::SubscriptionCycle,
+                    line_items,
+                )
+                .await?
+            }
+        };
+
+    let subscription = db
+        .update_uncanceled_subscription_by_merchant_id_subscription_id(
+            &merchant_id,
+            &subscription.subscription_id,
+            storage::SubscriptionUpdate::PeriodUpdate {
+                current_period_start: period_start,
+                current_period_end: period_end,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to renew subscription for the next period")?;
+    add_subscription_billing_task(state, &subscription, None, period_end).await?;
+
+    if is_uncharged_invoice(&invoice) {
+        charge_subscription_invoice(state, &merchant_context, subscription, invoice).await?;
+    }
+
+    Ok(())
+}
+
+/// Finishes the renewal of a subscription which was renewed by a previous run of the billing task,
+/// in case that run stopped before scheduling the next billing task or charging the invoice of the
+/// new period. Nothing is done if the subscription has moved past that period since, or was
+/// renewed without a cycle invoice.
+async fn resume_subscription_renewal(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    period_start: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let invoice = if subscription.current_period_start == period_start {
+        find_subscription_cycle_invoice(state, &subscription, period_start).await?
+    } else {
+        None
+    };
+    let Some(invoice) = invoice else {
+        logger::info!(
+            subscription_id = %subscription.subscription_id,
+            "Subscription was already renewed for the period of the task"
+        );
+        return Ok(());
+    };
+
+    add_subscription_billing_task(state, &subscription, None, subscription.current_period_end)
+        .await?;
+    if is_uncharged_invoice(&invoice) {
+        charge_subscription_invoice(state, merchant_context, subscription, invoice).await?;
+    }
+
+    Ok(())
+}
+
+/// Returns the invoice of the billing cycle of the subscription starting at the given time, if the
+/// cycle has already been invoiced.
+async fn find_subscription_cycle_invoice(
+    state: &SessionState,
+    subscription: &storage::Subscription,
+    period_start: PrimitiveDateTime,
+) -> RouterResult<Option<storage::SubscriptionInvoice>> {
+    match state
+        .store
+        .find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+            InvoiceBillingReason::SubscriptionCycle,
+            period_start,
+        )
+        .await
+    {
+        Ok(invoice) => Ok(Some(invoice)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the invoice of the billing cycle")),
+    }
+}
+
+/// Returns whether the payment of the invoice has not been attempted yet.
+fn is_uncharged_invoice(invoice: &storage::SubscriptionInvoice) -> bool {
+    invoice.status == InvoiceStatus::Open && invoice.attempt_count == 0
+}
+
+/// Charges an invoice again, unless the payment of its previous attempt has succeeded or has not
+/// reached a terminal state since.
+async fn retry_subscription_invoice_payment(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    invoice: storage::SubscriptionInvoice,
+) -> RouterResult<storage::Subscription> {
+    let payment_intent = match &invoice.payment_id {
+        Some(payment_id) => state
+            .store
+            .find_payment_intent_by_payment_id_merchant_id(
+                &state.into(),
+                payment_id,
+                merchant_context.get_merchant_account().get_id(),
+                merchant_context.get_merchant_key_store(),
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .map(Some),
+        None => Ok(None),
+    };
+    // The payment is not found if the previous attempt failed before the payment was created
+    let payment_status = match payment_intent {
+        Ok(payment_intent) => payment_intent.map(|payment_intent| payment_intent.status),
+        Err(error) if error.current_context().is_db_not_found() => None,
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment of the subscription invoice"))
+        }
+    };
+
+    match payment_status {
+        Some(status) if status == IntentStatus::Succeeded || !status.is_in_terminal_state() => {
+            Box::pin(handle_subscription_invoice_payment(
+                state,
+                merchant_context,
+                subscription,
+                invoice.clone(),
+                invoice.payment_id.clone(),
+                payment_status,
+                invoice.attempt_count,
+            ))
+            .await
+        }
+        _ => charge_subscription_invoice(state, merchant_context, subscription, invoice).await,
+    }
+}
+
+/// Charges the mandate of the subscription for the invoice, and updates the invoice and the
+/// subscription with the outcome of the payment.
+async fn charge_subscription_invoice(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    invoice: storage::SubscriptionInvoice,
+) -> RouterResult<storage::Subscription> {
+    let payment_id = common_utils::id_type::PaymentId::default();
+    let request = payment_types::PaymentsRequest {
+        payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+            payment_id.clone(),
+        )),
+        amount: Some(payment_types::Amount::from(invoice.amount)),
+        currency: Some(invoice.currency),
+        customer_id: Some(subscription.customer_id.clone()),
+        mandate_id: Some(subscription.mandate_id.clone()),
+        off_session: Some(true),
+        confirm: Some(true),
+        profile_id: Some(subscription.profile_id.clone()),
+        description: Some(format!(
+            "Invoice {} of subscription {}",
+            invoice.invoice_id, subscription.subscription_id
+        )),
+        ..Default::default()
+    };
+
+    let payment_status = match Box::pin(payments::payments_core::<
+        api::Authorize,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_context.clone(),
+        Some(subscription.profile_id.clone()),
+        payments::PaymentCreate,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await
+    {
+        Ok(
+            ApplicationResponse::Json(payment_response)
+            | ApplicationResponse::JsonWithHeaders((payment_response, _)),
+        ) => Some(payment_response.status),
+        Ok(_) => None,
+        Err(error) => {
+            logger::error!(?error, invoice_id = %invoice.invoice_id, "Invoice payment failed");
+            None
+        }
+    };
+
+    let attempt_count = invoice.attempt_count.saturating_add(1);
+    Box::pin(handle_subscription_invoice_payment(
+        state,
+        merchant_context,
+        subscription,
+        invoice,
+        Some(payment_id),
+        payment_status,
+        attempt_count,
+    ))
+    .await
+}
+
+/// Marks the invoice as paid and the subscription as active when the payment succeeded. Otherwise
+/// a retry of the payment is scheduled and the subscription is marked as past due, until the
+/// maximum number of attempts is reached, at which point the invoice is marked as uncollectible
+/// and the subscription is canceled.
+async fn handle_subscription_invoice_payment(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: storage::Subscription,
+    invoice: storage::SubscriptionInvoice,
+    payment_id: Option<common_utils::id_type::PaymentId>,
+    payment_status: Option<IntentStatus>,
+    attempt_count: i32,
+) -> RouterResult<storage::Subscription> {
+    let subscription_settings = &state.conf.subscriptions;
+    let max_attempts_reached = u32::try_from(attempt_count).map_or(true, |attempt_count| {
+        attempt_count >= subscription_settings.max_payment_attempts
+    });
+    let next_payment_attempt_at = date_time::now().saturating_add(time::Duration::hours(
+        i64::from(subscription_settings.payment_retry_interval_in_hours),
+    ));
+
+    let (invoice_status, next_payment_attempt_at, subscription_status) = match payment_status {
+        Some(IntentStatus::Succeeded) => (InvoiceStatus::Paid, None, SubscriptionStatus::Active),
+        // The outcome of the payment is checked again once the retry interval has elapsed
+        Some(status) if !status.is_in_terminal_state() => (
+            InvoiceStatus::Open,
+            Some(next_payment_attempt_at),
+            subscription.status,
+        ),
+        _ if max_attempts_reached => (
+            InvoiceStatus::Uncollectible,
+            None,
+            SubscriptionStatus::Canceled,
+        ),
+        _ => (
+            InvoiceStatus::PaymentFailed,
+            Some(next_payment_attempt_at),
+            SubscriptionStatus::PastDue,
+        ),
+    };
+
+    let updated_invoice = state
+        .store
+        .update_subscription_invoice_by_merchant_id_invoice_id(
+            &invoice.merchant_id,
+            &invoice.invoice_id,
+            storage::SubscriptionInvoiceUpdate::PaymentUpdate {
+                status: invoice_status,
+                payment_id,
+                attempt_count,
+                next_payment_attempt_at,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription invoice with the payment outcome")?;
+    if updated_invoice.status != invoice.status {
+        trigger_invoice_webhook(state, merchant_context, &updated_invoice).await;
+    }
+
+    if let Some(next_payment_attempt_at) = next_payment_attempt_at {
+        add_subscription_billing_task(
+            state,
+            &subscription,
+            Some(&updated_invoice),
+            next_payment_attempt_at,
+        )
+        .await?;
+    }
+
+    if subscription_status == subscription.status {
+        return Ok(subscription);
+    }
+
+    let updated_subscription = if subscription_status == SubscriptionStatus::Canceled {
+        cancel_subscription_immediately(state, merchant_context, &subscription).await?
+    } else {
+        let updated_subscription = state
+            .store
+            .update_uncanceled_subscription_by_merchant_id_subscription_id(
+                &subscription.merchant_id,
+                &subscription.subscription_id,
+                storage::SubscriptionUpdate::StatusUpdate {
+                    status: subscription_status,
+                },
+            )
+            .await
+            .inspect_err(|error| {
+                logger::info!(?error, "Subscription was canceled during the payment");
+            })
+            .ok();
+        if let Some(updated_subscription) = &updated_subscription {
+            trigger_subscription_webhook(state, merchant_context, updated_subscription).await;
+        }
+        updated_subscription
+    };
+
+    Ok(updated_subscription.unwrap_or(subscription))
+}
+
+/// Cancels the subscription and voids its unpaid invoices. Returns `None` if the subscription was
+/// already canceled.
+async fn cancel_subscription_immediately(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+) -> RouterResult<Option<storage::Subscription>> {
+    let db = &*state.store;
+    let subscription = match db
+        .update_uncanceled_subscription_by_merchant_id_subscription_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+            storage::SubscriptionUpdate::CancellationUpdate,
+        )
+        .await
+    {
+        Ok(subscription) => subscription,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to cancel subscription"))
+        }
+    };
+    trigger_subscription_webhook(state, merchant_context, &subscription).await;
+
+    let voided_invoices = db
+        .void_unpaid_subscription_invoices_by_merchant_id_subscription_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to void the unpaid invoices of the subscription")?;
+    for invoice in &voided_invoices {
+        trigger_invoice_webhook(state, merchant_context, invoice).await;
+    }
+
+    logger::info!(subscription_id = %subscription.subscription_id, "Subscription canceled");
+    Ok(Some(subscription))
+}
+
+/// Creates an invoice for the line items and records it as the latest invoice of the subscription.
+async fn create_subscription_invoice(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+    subscription_price: &storage::SubscriptionPrice,
+    billing_reason: InvoiceBillingReason,
+    line_items: Vec<InvoiceLineItem>,
+) -> RouterResult<(storage::Subscription, storage::SubscriptionInvoice)> {
+    let db = &*state.store;
+    let line_items = InvoiceLineItems(line_items);
+    let (period_start, period_end) = line_items
+        .0
+        .iter()
+        .fold(None, |period, line_item| match period {
+            Some((period_start, period_end)) => Some((
+                std::cmp::min(period_start, line_item.period_start),
+                std::cmp::max(period_end, line_item.period_end),
+            )),
+            None => Some((line_item.period_start, line_item.period_end)),
+        })
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invoice should have at least one line item")?;
+
+    // Credits for unused time are not refunded, so nothing is due if they exceed the charges
+    let total_amount = line_items.get_total_amount();
+    let amount = if total_amount.is_greater_than(0) {
+        total_amount
+    } else {
+        MinorUnit::zero()
+    };
+
+    let now = date_time::now();
+    let invoice = db
+        .insert_subscription_invoice(storage::SubscriptionInvoiceNew {
+            invoice_id: common_utils::generate_id(consts::ID_LENGTH, "in"),
+            subscription_id: subscription.subscription_id.clone(),
+            merchant_id: subscription.merchant_id.clone(),
+            profile_id: subscription.profile_id.clone(),
+            customer_id: subscription.customer_id.clone(),
+            status: InvoiceStatus::Open,
+            billing_reason,
+            amount,
+            currency: subscription_price.currency,
+            period_start,
+            period_end,
+            line_items,
+            payment_id: None,
+            attempt_count: 0,
+            next_payment_attempt_at: None,
+            paid_at: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert subscription invoice")?;
+    trigger_invoice_webhook(state, merchant_context, &invoice).await;
+
+    let subscription = db
+        .update_subscription_by_merchant_id_subscription_id(
+            &subscription.merchant_id,
+            &subscription.subscription_id,
+            storage::SubscriptionUpdate::LatestInvoiceUpdate {
+                latest_invoice_id: invoice.invoice_id.clone(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the latest invoice of the subscription")?;
+
+    Ok((subscription, invoice))
+}
+
+async fn update_subscription_invoice_status(
+    state: &SessionState,
+    invoice: &storage::SubscriptionInvoice,
+    status: InvoiceStatus,
+) -> RouterResult<storage::SubscriptionInvoice> {
+    state
+        .store
+        .update_subscription_invoice_by_merchant_id_invoice_id(
+            &invoice.merchant_id,
+            &invoice.invoice_id,
+            storage::SubscriptionInvoiceUpdate::StatusUpdate { status },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update subscription invoice status")
+}
+
+/// Schedules the billing task of the subscription. The task either renews the subscription at the
+/// end of its period, or retries the payment of the given invoice.
+async fn add_subscription_billing_task(
+    state: &SessionState,
+    subscription: &storage::Subscription,
+    invoice: Option<&storage::SubscriptionInvoice>,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::SubscriptionBillingWorkflow;
+    let period_end = subscription.current_period_end;
+    let process_tracker_id = format!(
+        "{runner}_{SUBSCRIPTION_BILLING_TASK}_{}_{}",
+        invoice.map_or(subscription.subscription_id.as_str(), |invoice| {
+            invoice.invoice_id.as_str()
+        }),
+        schedule_time.assume_utc().unix_timestamp()
+    );
+    let tracking_data = storage::SubscriptionBillingTrackingData {
+        subscription_id: subscription.subscription_id.clone(),
+        merchant_id: subscription.merchant_id.clone(),
+        period_end,
+        invoice_id: invoice.map(|invoice| invoice.invoice_id.clone()),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SUBSCRIPTION_BILLING_TASK,
+        runner,
+        [SUBSCRIPTION_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct subscription billing process tracker task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The task was already scheduled by a previous run of the billing task
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed while inserting subscription billing task to process_tracker",
+            )),
+    }
+}
+
+async fn trigger_subscription_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    subscription: &storage::Subscription,
+) {
+    let event_type: Option<EventType> = subscription.status.into();
+    let subscription_response = SubscriptionResponse::foreign_from(subscription.clone());
+    trigger_outgoing_webhook(
+        state,
+        merchant_context,
+        &subscription.profile_id,
+        event_type,
+        EventClass::Subscriptions,
+        subscription.subscription_id.clone(),
+        EventObjectType::SubscriptionDetails,
+        webhooks::OutgoingWebhookContent::SubscriptionDetails(Box::new(subscription_response)),
+        subscription.created_at,
+    )
+    .await;
+}
+
+async fn trigger_invoice_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    invoice: &storage::SubscriptionInvoice,
+) {
+    let event_type: Option<EventType> = invoice.status.into();
+    let invoice_response = InvoiceResponse::foreign_from(invoice.clone());
+    trigger_outgoing_webhook(
+        state,
+        merchant_context,
+        &invoice.profile_id,
+        event_type,
+        EventClass::Invoices,
+        invoice.invoice_id.clone(),
+        EventObjectType::InvoiceDetails,
+        webhooks::OutgoingWebhookContent::InvoiceDetails(Box::new(invoice_response)),
+        invoice.created_at,
+    )
+    .await;
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn trigger_outgoing_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &common_utils::id_type::ProfileId,
+    event_type: Option<EventType>,
+    event_class: EventClass,
+    primary_object_id: String,
+    primary_object_type: EventObjectType,
+    content: webhooks::OutgoingWebhookContent,
+    primary_object_created_at: PrimitiveDateTime,
+) {
+    let Some(event_type) = event_type else {
+        logger::warn!("Outgoing webhook not sent because of missing event type status mapping");
+        return;
+    };
+
+    let business_profile = match state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch business profile for outgoing webhook"
+            );
+            return;
+        }
+    };
+
+    let cloned_state = state.clone();
+    let cloned_merchant_context = merchant_context.clone();
+    // This spawns this futures in a background thread, the exception inside this future won't affect
+    // the current thread and the lifecycle of spawn thread is not handled by runtime.
+    // So when server shutdown won't wait for this thread's completion.
+    tokio::spawn(
+        async move {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                cloned_merchant_context,
+                business_profile,
+                event_type,
+                event_class,
+                primary_object_id,
+                primary_object_type,
+                content,
+                Some(primary_object_created_at),
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+}
+
+async fn find_subscription_plan(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    plan_id: &str,
+) -> RouterResult<storage::SubscriptionPlan> {
+    let subscription_plan = state
+        .store
+        .find_subscription_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription plan not found".to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription_plan)?;
+
+    Ok(subscription_plan)
+}
+
+/// Fetches a price which new subscriptions can be created for, along with its plan.
+async fn find_active_subscription_price(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    price_id: &str,
+) -> RouterResult<(storage::SubscriptionPrice, storage::SubscriptionPlan)> {
+    let subscription_price = state
+        .store
+        .find_subscription_price_by_merchant_id_price_id(merchant_id, price_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription price not found".to_string(),
+        })?;
+    let subscription_plan =
+        find_subscription_plan(state, merchant_id, profile_id, &subscription_price.plan_id).await?;
+
+    fp_utils::when(
+        !subscription_price.is_active || !subscription_plan.is_active,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The subscription price or its plan is not active".to_string(),
+            })
+        },
+    )?;
+
+    Ok((subscription_price, subscription_plan))
+}
+
+async fn find_subscription(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    subscription_id: &str,
+) -> RouterResult<storage::Subscription> {
+    let subscription = state
+        .store
+        .find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Subscription not found".to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &subscription)?;
+
+    Ok(subscription)
+}
+
+/// Validates that the mandate can be charged for the subscriptions of the customer.
+async fn validate_subscription_mandate(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &common_utils::id_type::CustomerId,
+    mandate_id: &str,
+) -> RouterResult<()> {
+    let mandate = state
+        .store
+        .find_mandate_by_merchant_id_mandate_id(
+            merchant_context.get_merchant_account().get_id(),
+            mandate_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    fp_utils::when(mandate.customer_id != *customer_id, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The mandate does not belong to the customer of the subscription".to_string(),
+        })
+    })?;
+    fp_utils::when(mandate.mandate_status != MandateStatus::Active, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The mandate is not active".to_string(),
+        })
+    })
+}
+
+fn validate_subscription_is_not_canceled(subscription: &storage::Subscription) -> RouterResult<()> {
+    fp_utils::when(subscription.status == SubscriptionStatus::Canceled, || {
+        Err(subscription_canceled_error().into())
+    })
+}
+
+fn subscription_canceled_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::PreconditionFailed {
+        message: "The subscription has already been canceled".to_string(),
+    }
+}
+
+fn get_price_line_item(
+    subscription_price: &storage::SubscriptionPrice,
+    period_start: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+) -> InvoiceLineItem {
+    InvoiceLineItem {
+        description: format!("Subscription to price {}", subscription_price.price_id),
+        price_id: subscription_price.price_id.clone(),
+        amount: subscription_price.amount,
+        period_start,
+        period_end,
+        proration: false,
+    }
+}
+
+/// Returns the line items prorating the remaining time of the current period of the subscription:
+/// a credit for the unused time on the previous price and a charge for the new price.
+fn get_proration_line_items(
+    subscription: &storage::Subscription,
+    previous_price: &storage::SubscriptionPrice,
+    new_price: &storage::SubscriptionPrice,
+    proration_date: PrimitiveDateTime,
+) -> RouterResult<Vec<InvoiceLineItem>> {
+    let period_start = subscription.current_period_start;
+    let period_end = subscription.current_period_end;
+    let proration_date = proration_date.clamp(period_start, period_end);
+
+    let previous_amount = get_prorated_amount(
+        previous_price.amount,
+        period_start,
+        period_end,
+        proration_date,
+    )?;
+    let new_amount =
+        get_prorated_amount(new_price.amount, period_start, period_end, proration_date)?;
+
+    Ok(vec![
+        InvoiceLineItem {
+            description: format!("Unused time on price {}", previous_price.price_id),
+            price_id: previous_price.price_id.clone(),
+            amount: MinorUnit::zero() - previous_amount,
+            period_start: proration_date,
+            period_end,
+            proration: true,
+        },
+        InvoiceLineItem {
+            description: format!("Remaining time on price {}", new_price.price_id),
+            price_id: new_price.price_id.clone(),
+            amount: new_amount,
+            period_start: proration_date,
+            period_end,
+            proration: true,
+        },
+    ])
+}
+
+/// Returns the share of the amount which covers the time between the proration date and the end
+/// of the period.
+fn get_prorated_amount(
+    amount: MinorUnit,
+    period_start: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+    proration_date: PrimitiveDateTime,
+) -> RouterResult<MinorUnit> {
+    let period_seconds = i128::from((period_end - period_start).whole_seconds());
+    if period_seconds <= 0 {
+        return Ok(MinorUnit::zero());
+    }
+    let remaining_seconds = i128::from((period_end - proration_date).whole_seconds());
+
+    let prorated_amount =
+        i128::from(amount.get_amount_as_i64()) * remaining_seconds / period_seconds;
+    i64::try_from(prorated_amount)
+        .map(MinorUnit::new)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Prorated amount is out of range")
+}
+
+/// Returns the end of a billing period starting at the given time. Monthly and yearly periods end
+/// on the same day of the month as they start, or on the last day of the month for months which
+/// are shorter.
+fn add_billing_interval(
+    period_start: PrimitiveDateTime,
+    billing_interval: BillingInterval,
+    interval_count: i32,
+) -> RouterResult<PrimitiveDateTime> {
+    let period_end = match billing_interval {
+        BillingInterval::Day => {
+            period_start.checked_add(time::Duration::days(i64::from(interval_count)))
+        }
+        BillingInterval::Week => {
+            period_start.checked_add(time::Duration::weeks(i64::from(interval_count)))
+        }
+        BillingInterval::Month => add_months(period_start, interval_count),
+        BillingInterval::Year => interval_count
+            .checked_mul(12)
+            .and_then(|months| add_months(period_start, months)),
+    };
+
+    period_end
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Failed to compute the end of the billing period")
+}
+
+/// Returns the end of the billing period starting at the given time, which is the end of the first
+/// billing cycle counted from the billing cycle anchor that ends after the start of the period.
+/// Counting the cycles from the anchor keeps monthly and yearly periods ending on the day of the
+/// month of the anchor, even after a period which ended early in a shorter month.
+fn get_billing_period_end(
+    billing_cycle_anchor: PrimitiveDateTime,
+    period_start: PrimitiveDateTime,
+    billing_interval: BillingInterval,
+    interval_count: i32,
+) -> RouterResult<PrimitiveDateTime> {
+    fp_utils::when(interval_count < 1, || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Billing interval count should be positive")
+    })?;
+
+    let elapsed = period_start - billing_cycle_anchor;
+    let elapsed_intervals = match billing_interval {
+        BillingInterval::Day => elapsed.whole_days(),
+        BillingInterval::Week => elapsed.whole_weeks(),
+        BillingInterval::Month => get_elapsed_months(billing_cycle_anchor, period_start),
+        BillingInterval::Year => get_elapsed_months(billing_cycle_anchor, period_start) / 12,
+    };
+    // The estimate counts a cycle which has not ended yet when the day of the month of the period
+    // start is before the one of the anchor, the cycles are counted from one cycle earlier
+    let mut cycle_count = i32::try_from(elapsed_intervals / i64::from(interval_count) - 1)
+        .unwrap_or_default()
+        .max(0);
+
+    loop {
+        cycle_count = cycle_count
+            .checked_add(1)
+            .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Subscription has too many billing cycles")?;
+        let period_end = interval_count
+            .checked_mul(cycle_count)
+            .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Subscription has too many billing cycles")
+            .and_then(|interval_count| {
+                add_billing_interval(billing_cycle_anchor, billing_interval, interval_count)
+            })?;
+        if period_end > period_start {
+            return Ok(period_end);
+        }
+    }
+}
+
+fn get_elapsed_months(from: PrimitiveDateTime, to: PrimitiveDateTime) -> i64 {
+    let month_index = |date_time: PrimitiveDateTime| {
+        i64::from(date_time.year()) * 12 + i64::from(u8::from(date_time.month()))
+    };
+    month_index(to) - month_index(from)
+}
+
+fn add_months(date_time: PrimitiveDateTime, months: i32) -> Option<PrimitiveDateTime> {
+    let date = date_time.date();
+    let month_index = date
+        .year()
+        .checked_mul(12)?
+        .checked_add(i32::from(u8::from(date.month())) - 1)?
+        .checked_add(months)?;
+    let year = month_index.div_euclid(12);
+    let month = u8::try_from(month_index.rem_euclid(12) + 1)
+        .ok()
+        .and_then(|month| time::Month::try_from(month).ok())?;
+    let day = date.day().min(time::util::days_in_year_month(year, month));
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+
+    Some(PrimitiveDateTime::new(date, date_time.time()))
+}
+
+fn get_list_limit(limit: Option<u32>) -> RouterResult<u32> {
+    let limit = limit.unwrap_or(DEFAULT_LIST_LIMIT);
+    fp_utils::when(limit == 0 || limit > MAX_LIST_LIMIT, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be between 1 and {MAX_LIST_LIMIT}"),
+        })
+    })?;
+
+    Ok(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_add_billing_interval_clamps_to_end_of_month() {
+        let period_start = datetime!(2024-01-31 10:00);
+        assert_eq!(
+            add_billing_interval(period_start, BillingInterval::Month, 1).unwrap(),
+            datetime!(2024-02-29 10:00)
+        );
+        assert_eq!(
+            add_billing_interval(period_start, BillingInterval::Month, 13).unwrap(),
+            datetime!(2025-02-28 10:00)
+        );
+        assert_eq!(
+            add_billing_interval(datetime!(2024-02-29 10:00), BillingInterval::Year, 1).unwrap(),
+            datetime!(2025-02-28 10:00)
+        );
+    }
+
+    #[test]
+    fn test_billing_period_ends_do_not_drift_after_short_months() {
+        let billing_cycle_anchor = datetime!(2024-01-31 10:00);
+        let mut period_start = billing_cycle_anchor;
+        let mut period_ends = Vec::new();
+        for _ in 0..5 {
+            period_start = get_billing_period_end(
+                billing_cycle_anchor,
+                period_start,
+                BillingInterval::Month,
+                1,
+            )
+            .unwrap();
+            period_ends.push(period_start);
+        }
+
+        assert_eq!(
+            period_ends,
+            vec![
+                datetime!(2024-02-29 10:00),
+                datetime!(2024-03-31 10:00),
+                datetime!(2024-04-30 10:00),
+                datetime!(2024-05-31 10:00),
+                datetime!(2024-06-30 10:00),
+            ]
+        );
+        assert_eq!(
+            get_billing_period_end(
+                billing_cycle_anchor,
+                datetime!(2024-03-01 10:00),
+                BillingInterval::Month,
+                1
+            )
+            .unwrap(),
+            datetime!(2024-03-31 10:00)
+        );
+        assert_eq!(
+            get_billing_period_end(
+                billing_cycle_anchor,
+                datetime!(2025-02-28 10:00),
+                BillingInterval::Year,
+                1
+            )
+            .unwrap(),
+            datetime!(2026-01-31 10:00)
+        );
+        assert_eq!(
+            get_billing_period_end(
+                datetime!(2024-01-01 10:00),
+                datetime!(2024-01-15 10:00),
+                BillingInterval::Week,
+                2
+            )
+            .unwrap(),
+            datetime!(2024-01-29 10:00)
+        );
+    }
+
+    #[test]
+    fn test_add_billing_interval_for_days_and_weeks() {
+        let period_start = datetime!(2024-12-30 10:00);
+        assert_eq!(
+            add_billing_interval(period_start, BillingInterval::Day, 3).unwrap(),
+            datetime!(2025-01-02 10:00)
+        );
+        assert_eq!(
+            add_billing_interval(period_start, BillingInterval::Week, 2).unwrap(),
+            datetime!(2025-01-13 10:00)
+        );
+    }
+
+    #[test]
+    fn test_prorated_amount_covers_remaining_time() {
+        let period_start = datetime!(2024-04-01 00:00);
+        let period_end = datetime!(2024-05-01 00:00);
+        assert_eq!(
+            get_prorated_amount(
+                MinorUnit::new(3000),
+                period_start,
+                period_end,
+                datetime!(2024-04-21 00:00)
+            )
+            .unwrap(),
+            MinorUnit::new(1000)
+        );
+        assert_eq!(
+            get_prorated_amount(MinorUnit::new(3000), period_start, period_end, period_end)
+                .unwrap(),
+            MinorUnit::zero()
+        );
+    }
+
+    fn get_invoice(status: InvoiceStatus, attempt_count: i32) -> storage::SubscriptionInvoice {
+        let now = date_time::now();
+        storage::SubscriptionInvoice {
+            invoice_id: "in_1".to_string(),
+            subscription_id: "sub_1".to_string(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(
+                "merchant_1",
+            ))
+            .unwrap(),
+            profile_id: common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(
+                "profile_1",
+            ))
+            .unwrap(),
+            customer_id: common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from(
+                "customer_1",
+            ))
+            .unwrap(),
+            status,
+            billing_reason: InvoiceBillingReason::SubscriptionCycle,
+            amount: MinorUnit::new(3000),
+            currency: common_enums::Currency::USD,
+            period_start: now,
+            period_end: now,
+            line_items: InvoiceLineItems(vec![]),
+            payment_id: None,
+            attempt_count,
+            next_payment_attempt_at: None,
+            paid_at: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_only_unattempted_open_invoices_are_uncharged() {
+        assert!(is_uncharged_invoice(&get_invoice(InvoiceStatus::Open, 0)));
+        assert!(!is_uncharged_invoice(&get_invoice(InvoiceStatus::Open, 1)));
+        assert!(!is_uncharged_invoice(&get_invoice(
+            InvoiceStatus::PaymentFailed,
+            1
+        )));
+        assert!(!is_uncharged_invoice(&get_invoice(InvoiceStatus::Paid, 1)));
+        assert!(!is_uncharged_invoice(&get_invoice(InvoiceStatus::Void, 0)));
+    }
+
+    #[test]
+    fn test_list_limit_rejects_out_of_range_values() {
+        assert_eq!(get_list_limit(None).unwrap(), DEFAULT_LIST_LIMIT);
+        assert!(get_list_limit(Some(0)).is_err());
+        assert!(get_list_limit(Some(MAX_LIST_LIMIT + 1)).is_err());
+    }
+}
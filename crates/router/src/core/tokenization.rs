@@ -62,10 +62,14 @@ pub async fn create_vault_token_core(
     .attach_printable("Failed to encode Request")?;
 
     // Call the vault service
-    let resp = pm_vault::call_to_vault::<pm_types::AddVault>(&state, payload.clone())
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Call to vault failed")?;
+    let resp = pm_vault::call_to_vault::<pm_types::AddVault>(
+        &state,
+        merchant_account.get_id(),
+        payload.clone(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Call to vault failed")?;
 
     // Parse the response
     let stored_resp: pm_types::AddVaultResponse = resp
@@ -141,10 +145,11 @@ pub async fn get_token_vault_core(
         ),
     };
 
-    let vault_data = pm_vault::retrieve_value_from_vault(&state, vault_request)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to retrieve vault data")?;
+    let vault_data =
+        pm_vault::retrieve_value_from_vault(&state, merchant_account.get_id(), vault_request)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve vault data")?;
 
     let data_json = vault_data
         .get("data")
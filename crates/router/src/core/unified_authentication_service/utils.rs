@@ -126,6 +126,7 @@ pub fn construct_uas_router_data<F: Clone, Req, Res>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     })
 }
 
@@ -237,6 +237,7 @@ pub async fn construct_payout_router_data<'a, F>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
 
     Ok(router_data)
@@ -405,6 +406,7 @@ pub async fn construct_refund_router_data<'a, F>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
 
     Ok(router_data)
@@ -609,6 +611,7 @@ pub async fn construct_refund_router_data<'a, F>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: merchant_connector_account.get_mca_id(),
     };
 
     Ok(router_data)
@@ -1036,6 +1039,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1137,6 +1141,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1244,6 +1249,7 @@ pub async fn construct_upload_file_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1370,6 +1376,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<F: Clone>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1474,6 +1481,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1570,6 +1578,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     };
     Ok(router_data)
 }
@@ -1990,6 +1999,30 @@ impl GetProfileId for diesel_models::routing_algorithm::RoutingProfileMetadata {
     }
 }
 
+impl GetProfileId for storage::FrmReview {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
+impl GetProfileId for storage::SubscriptionPlan {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
+impl GetProfileId for storage::Subscription {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
+impl GetProfileId for storage::SubscriptionInvoice {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
 impl GetProfileId for domain::Profile {
     fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
         Some(self.get_id())
@@ -2002,6 +2035,18 @@ impl GetProfileId for storage::Payouts {
         Some(&self.profile_id)
     }
 }
+
+impl GetProfileId for storage::PayoutBatch {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
+impl GetProfileId for storage::PayoutBatchNew {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
 #[cfg(feature = "payouts")]
 impl<T, F, R> GetProfileId for (storage::Payouts, T, F, R) {
     fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
@@ -12,6 +12,8 @@ mod outgoing_v2;
 pub mod recovery_incoming;
 pub mod types;
 pub mod utils;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
 
@@ -19,8 +21,9 @@ pub mod webhook_events;
 pub(crate) use self::{
     incoming::{incoming_webhooks_wrapper, network_token_incoming_webhooks_wrapper},
     outgoing::{
+        add_outgoing_webhook_retry_task_to_process_tracker,
         create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
-        trigger_webhook_and_raise_event,
+        get_outgoing_webhook_retry_process_tracker_id, trigger_webhook_and_raise_event,
     },
 };
 #[cfg(feature = "v2")]
@@ -60,24 +60,91 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    if !state.conf.webhooks.outgoing_enabled {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %primary_object_id,
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
+        );
+        return Ok(());
+    }
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    let is_webhook_url_configured = get_webhook_url_from_business_profile(&business_profile)
+        .is_ok_and(|webhook_url| !webhook_url.is_empty());
+    let webhook_endpoints =
+        get_webhook_endpoints_for_event(&state, &business_profile, event_class, event_type).await;
+
+    if !is_webhook_url_configured && webhook_endpoints.is_empty() {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            %primary_object_id,
+            "Merchant webhook URL could not be obtained and no webhook endpoints are enabled for \
+             the event; skipping outgoing webhooks for event"
         );
         return Ok(());
     }
 
+    // The webhook URL configured on the business profile is represented by the absence of a
+    // webhook endpoint
+    let webhook_destinations = is_webhook_url_configured
+        .then_some(None)
+        .into_iter()
+        .chain(webhook_endpoints.into_iter().map(Some));
+
+    let mut results = Vec::new();
+    for webhook_endpoint in webhook_destinations {
+        let webhook_endpoint_id = webhook_endpoint
+            .as_ref()
+            .map(|webhook_endpoint| webhook_endpoint.endpoint_id.clone());
+        let result = Box::pin(create_event_and_trigger_outgoing_webhook_to_destination(
+            state.clone(),
+            merchant_context.clone(),
+            business_profile.clone(),
+            webhook_endpoint,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+        ))
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                ?webhook_endpoint_id,
+                "Failed to create event and trigger outgoing webhook"
+            );
+        });
+        results.push(result);
+    }
+
+    results.into_iter().collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn create_event_and_trigger_outgoing_webhook_to_destination(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    webhook_endpoint: Option<storage::WebhookEndpoint>,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let idempotent_event_id =
+        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
+    let idempotent_event_id = match webhook_endpoint.as_ref() {
+        Some(webhook_endpoint) => format!("{idempotent_event_id}_{}", webhook_endpoint.endpoint_id),
+        None => idempotent_event_id,
+    };
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -90,10 +157,14 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_context, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = get_outgoing_webhook_request(
+        &merchant_context,
+        outgoing_webhook,
+        &business_profile,
+        webhook_endpoint.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -142,6 +213,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: webhook_endpoint.map(|webhook_endpoint| webhook_endpoint.endpoint_id),
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -295,7 +367,12 @@ async fn trigger_webhook_to_merchant(
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+        get_webhook_url(
+            &state,
+            &business_profile,
+            event.webhook_endpoint_id.as_deref(),
+        )
+        .await,
         process_tracker.clone(),
     ) {
         (Ok(webhook_url), _) => Ok(webhook_url),
@@ -579,6 +656,21 @@ async fn raise_webhooks_analytics_event(
     state.event_handler().log_event(&webhook_event);
 }
 
+const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
+
+/// Obtains the ID of the process tracker task which retries the delivery of the specified event.
+pub(crate) fn get_outgoing_webhook_retry_process_tracker_id(
+    merchant_id: &common_utils::id_type::MerchantId,
+    event_id: &str,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+        OUTGOING_WEBHOOK_RETRY_TASK,
+        event_id,
+        merchant_id,
+    )
+}
+
 pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     db: &dyn StorageInterface,
     business_profile: &domain::Profile,
@@ -603,16 +695,15 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
         primary_object_id: event.primary_object_id.clone(),
         primary_object_type: event.primary_object_type,
         initial_attempt_id: event.initial_attempt_id.clone(),
+        webhook_endpoint_id: event.webhook_endpoint_id.clone(),
     };
 
     let runner = storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow;
-    let task = "OUTGOING_WEBHOOK_RETRY";
+    let task = OUTGOING_WEBHOOK_RETRY_TASK;
     let tag = ["OUTGOING_WEBHOOKS"];
-    let process_tracker_id = scheduler::utils::get_process_tracker_id(
-        runner,
-        task,
-        &event.event_id,
+    let process_tracker_id = get_outgoing_webhook_retry_process_tracker_id(
         &business_profile.merchant_id,
+        &event.event_id,
     );
     let process_tracker_entry = storage::ProcessTrackerNew::new(
         process_tracker_id,
@@ -639,6 +730,69 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+/// Obtains the webhook endpoints of the business profile which must receive an event of the
+/// specified class and type.
+async fn get_webhook_endpoints_for_event(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    event_class: enums::EventClass,
+    event_type: enums::EventType,
+) -> Vec<storage::WebhookEndpoint> {
+    state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(
+            &business_profile.merchant_id,
+            business_profile.get_id(),
+        )
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to list webhook endpoints for business profile"
+            );
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|webhook_endpoint| webhook_endpoint.is_event_enabled(event_class, event_type))
+        .collect()
+}
+
+/// Obtains the URL to which the webhook must be delivered: the URL of the webhook endpoint if the
+/// event is associated with one, or the webhook URL configured on the business profile otherwise.
+async fn get_webhook_url(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    webhook_endpoint_id: Option<&str>,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let Some(webhook_endpoint_id) = webhook_endpoint_id else {
+        return get_webhook_url_from_business_profile(business_profile);
+    };
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(
+            &business_profile.merchant_id,
+            webhook_endpoint_id,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointNotFound)
+            } else {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointRetrievalFailed)
+            }
+        })?;
+
+    if !webhook_endpoint.is_enabled {
+        return Err(report!(errors::WebhooksFlowError::WebhookEndpointDisabled))
+            .attach_printable_lazy(|| {
+                format!("Webhook endpoint `{webhook_endpoint_id}` is disabled")
+            });
+    }
+
+    Ok(webhook_endpoint.url.expose())
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &domain::Profile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -659,11 +813,13 @@ pub(crate) fn get_outgoing_webhook_request(
     merchant_context: &domain::MerchantContext,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint: Option<&storage::WebhookEndpoint>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint: Option<&storage::WebhookEndpoint>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -677,7 +833,11 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
+        // Webhooks delivered to a webhook endpoint are signed using the secret of the endpoint
+        let payment_response_hash_key = match webhook_endpoint {
+            Some(webhook_endpoint) => Some(webhook_endpoint.secret.peek().clone()),
+            None => business_profile.payment_response_hash_key.clone(),
+        };
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -703,6 +863,21 @@ pub(crate) fn get_outgoing_webhook_request(
             WebhookType::add_webhook_header(&mut headers, signature)
         }
 
+        // After the secret of the profile is rotated, webhooks are additionally signed using the
+        // previous secret until the overlap period ends
+        let previous_payment_response_hash_key = webhook_endpoint
+            .is_none()
+            .then(|| business_profile.get_active_previous_payment_response_hash_key())
+            .flatten();
+        if let Some(previous_payment_response_hash_key) = previous_payment_response_hash_key {
+            let previous_outgoing_webhooks_signature = transformed_outgoing_webhook
+                .get_outgoing_webhooks_signature(Some(previous_payment_response_hash_key))?;
+
+            if let Some(signature) = previous_outgoing_webhooks_signature.signature {
+                WebhookType::add_previous_webhook_header(&mut headers, signature)
+            }
+        }
+
         Ok(OutgoingWebhookRequestContent {
             body: outgoing_webhooks_signature.payload,
             headers: headers
@@ -717,12 +892,17 @@ pub(crate) fn get_outgoing_webhook_request(
         .get_compatible_connector()
     {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint,
         ),
     }
 }
@@ -1026,6 +1206,21 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::SubscriptionDetails(subscription_response) => {
+                Self::Subscription {
+                    subscription_id: subscription_response.subscription_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::InvoiceDetails(invoice_response) => Self::Invoice {
+                subscription_id: invoice_response.subscription_id.clone(),
+                invoice_id: invoice_response.invoice_id.clone(),
+            },
+            #[cfg(feature = "payouts")]
+            webhooks::OutgoingWebhookContent::PayoutBatchDetails(payout_batch_response) => {
+                Self::PayoutBatch {
+                    batch_id: payout_batch_response.batch_id.clone(),
+                }
+            }
         }
     }
 }
@@ -1070,5 +1265,25 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::Subscription { subscription_id } => {
+            OutgoingWebhookEventContent::Subscription {
+                subscription_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::Invoice {
+            subscription_id,
+            invoice_id,
+        } => OutgoingWebhookEventContent::Invoice {
+            subscription_id,
+            invoice_id,
+            content: serde_json::Value::Null,
+        },
+        diesel_models::EventMetadata::PayoutBatch { batch_id } => {
+            OutgoingWebhookEventContent::PayoutBatch {
+                batch_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
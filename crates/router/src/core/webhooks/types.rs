@@ -33,6 +33,8 @@ pub trait OutgoingWebhookType:
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
 
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
+
+    fn add_previous_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
 }
 
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
@@ -67,6 +69,16 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
         header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
     }
+
+    fn add_previous_webhook_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+    ) {
+        header.push((
+            headers::X_WEBHOOK_SIGNATURE_PREVIOUS.to_string(),
+            signature.into(),
+        ))
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -78,6 +90,36 @@ pub(crate) struct OutgoingWebhookTrackingData {
     pub(crate) primary_object_id: String,
     pub(crate) primary_object_type: enums::EventObjectType,
     pub(crate) initial_attempt_id: Option<String>,
+    pub(crate) webhook_endpoint_id: Option<String>,
+}
+
+/// Tracking data for bulk replays of outgoing webhooks, which also records the progress of the
+/// replay.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct WebhookReplayTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub replay_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_after: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_before: time::PrimitiveDateTime,
+    pub event_types: std::collections::HashSet<enums::EventType>,
+    pub is_delivered: bool,
+    /// Creation time of the last event processed by the replay. Events are processed in
+    /// descending order of their creation time, so the next batch starts at this time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub cursor: Option<time::PrimitiveDateTime>,
+    /// Number of events created at `cursor` which have already been processed.
+    #[serde(default)]
+    pub cursor_offset: i64,
+    pub total_count: i64,
+    #[serde(default)]
+    pub enqueued_count: i64,
+    #[serde(default)]
+    pub skipped_count: i64,
+    #[serde(default)]
+    pub failed_count: i64,
 }
 
 pub struct WebhookResponse {
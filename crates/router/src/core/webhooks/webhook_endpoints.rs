@@ -0,0 +1,258 @@
+use common_utils::{date_time, fp_utils};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api::webhook_endpoints as api, domain, storage, transformers::ForeignFrom},
+};
+
+/// The maximum number of webhook endpoints that can be configured for a business profile.
+const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 10;
+
+#[instrument(skip(state))]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    request: api::WebhookEndpointCreateRequest,
+) -> RouterResponse<api::WebhookEndpointResponse> {
+    let store = state.store.as_ref();
+    let merchant_id = key_store.merchant_id.clone();
+
+    validate_profile(&state, &key_store, &profile_id).await?;
+    validate_webhook_endpoint_url(&request.url)?;
+    validate_event_filters(
+        request.enabled_event_classes.as_deref(),
+        request.enabled_event_types.as_deref(),
+    )?;
+
+    let existing_webhook_endpoints = store
+        .list_webhook_endpoints_by_merchant_id_profile_id(&merchant_id, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints for business profile")?;
+
+    fp_utils::when(
+        existing_webhook_endpoints.len() >= MAX_WEBHOOK_ENDPOINTS_PER_PROFILE,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "A business profile can have at most {MAX_WEBHOOK_ENDPOINTS_PER_PROFILE} \
+                     webhook endpoints"
+                ),
+            })
+        },
+    )?;
+
+    let now = date_time::now();
+    let webhook_endpoint = storage::WebhookEndpointNew {
+        endpoint_id: common_utils::generate_id(consts::ID_LENGTH, "whe"),
+        merchant_id,
+        profile_id,
+        url: request.url,
+        description: request.description,
+        secret: request.secret.unwrap_or_else(|| {
+            Secret::new(common_utils::crypto::generate_cryptographically_secure_random_string(64))
+        }),
+        enabled_event_classes: request.enabled_event_classes,
+        enabled_event_types: request.enabled_event_types,
+        is_enabled: request.is_enabled.unwrap_or(true),
+        created_at: now,
+        modified_at: now,
+    };
+
+    let webhook_endpoint = store
+        .insert_webhook_endpoint(webhook_endpoint)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert webhook endpoint")?;
+
+    Ok(ApplicationResponse::Json(
+        api::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<api::WebhookEndpointResponse> {
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, &key_store.merchant_id, &profile_id, &endpoint_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        api::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResponse<Vec<api::WebhookEndpointResponse>> {
+    validate_profile(&state, &key_store, &profile_id).await?;
+
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_merchant_id_profile_id(&key_store.merchant_id, &profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints for business profile")?;
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoints
+            .into_iter()
+            .map(api::WebhookEndpointResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    endpoint_id: String,
+    request: api::WebhookEndpointUpdateRequest,
+) -> RouterResponse<api::WebhookEndpointResponse> {
+    let merchant_id = &key_store.merchant_id;
+    find_webhook_endpoint(&state, merchant_id, &profile_id, &endpoint_id).await?;
+
+    if let Some(url) = request.url.as_ref() {
+        validate_webhook_endpoint_url(url)?;
+    }
+    validate_event_filters(
+        request.enabled_event_classes.as_deref(),
+        request.enabled_event_types.as_deref(),
+    )?;
+
+    let webhook_endpoint_update = storage::WebhookEndpointUpdate::Update {
+        url: request.url,
+        description: request.description,
+        enabled_event_classes: request.enabled_event_classes,
+        enabled_event_types: request.enabled_event_types,
+        is_enabled: request.is_enabled,
+    };
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_merchant_id_endpoint_id(
+            merchant_id,
+            &endpoint_id,
+            webhook_endpoint_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook endpoint does not exist in our records".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        api::WebhookEndpointResponse::foreign_from(webhook_endpoint),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: common_utils::id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<api::WebhookEndpointDeleteResponse> {
+    let merchant_id = &key_store.merchant_id;
+    find_webhook_endpoint(&state, merchant_id, &profile_id, &endpoint_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .delete_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, &endpoint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Webhook endpoint does not exist in our records".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        api::WebhookEndpointDeleteResponse {
+            endpoint_id: webhook_endpoint.endpoint_id,
+            profile_id: webhook_endpoint.profile_id,
+            deleted: true,
+        },
+    ))
+}
+
+async fn validate_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> RouterResult<()> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            &key_store.merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+        .map(|_| ())
+}
+
+async fn find_webhook_endpoint(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+    endpoint_id: &str,
+) -> RouterResult<storage::WebhookEndpoint> {
+    let not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Webhook endpoint does not exist in our records".to_string(),
+    };
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_merchant_id_endpoint_id(merchant_id, endpoint_id)
+        .await
+        .to_not_found_response(not_found_error())?;
+
+    fp_utils::when(webhook_endpoint.profile_id != *profile_id, || {
+        Err(report!(not_found_error()))
+    })?;
+
+    Ok(webhook_endpoint)
+}
+
+fn validate_webhook_endpoint_url(url: &Secret<String>) -> RouterResult<()> {
+    url::Url::parse(url.peek())
+        .ok()
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .map(|_| ())
+        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`url` must be a valid HTTP or HTTPS URL".to_string(),
+        }))
+}
+
+fn validate_event_filters(
+    enabled_event_classes: Option<&[storage::enums::EventClass]>,
+    enabled_event_types: Option<&[storage::enums::EventType]>,
+) -> RouterResult<()> {
+    fp_utils::when(enabled_event_classes.is_some_and(<[_]>::is_empty), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`enabled_event_classes` must not be empty".to_string(),
+        })
+    })?;
+
+    fp_utils::when(enabled_event_types.is_some_and(<[_]>::is_empty), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`enabled_event_types` must not be empty".to_string(),
+        })
+    })?;
+
+    Ok(())
+}
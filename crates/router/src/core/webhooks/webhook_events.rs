@@ -1,10 +1,16 @@
 use std::collections::HashSet;
 
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
 use common_utils::{self, errors::CustomResult, fp_utils};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
 use error_stack::ResultExt;
 use masking::PeekInterface;
 use router_env::{instrument, tracing};
 
+#[cfg(feature = "v1")]
+use crate::{consts, routes::metrics};
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
     routes::SessionState,
@@ -15,6 +21,10 @@ use crate::{
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+#[cfg(feature = "v1")]
+const WEBHOOK_REPLAY_TASK: &str = "OUTGOING_WEBHOOK_REPLAY";
+#[cfg(feature = "v1")]
+const WEBHOOK_REPLAY_TAG: &str = "OUTGOING_WEBHOOKS";
 
 #[derive(Debug)]
 enum MerchantAccountOrProfile {
@@ -308,6 +318,7 @@ pub async fn retry_delivery_attempt(
         delivery_attempt: Some(delivery_attempt),
         metadata: event_to_retry.metadata,
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
     };
 
     let event = store
@@ -354,6 +365,225 @@ pub async fn retry_delivery_attempt(
     ))
 }
 
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn create_webhook_replay(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: api::webhook_events::WebhookReplayRequest,
+) -> RouterResponse<api::webhook_events::WebhookReplayResponse> {
+    let store = state.store.as_ref();
+    let profile_id = request.profile_id;
+
+    // Ensure that the business profile belongs to the merchant
+    get_account_and_key_store(state.clone(), merchant_id.clone(), Some(profile_id.clone())).await?;
+
+    let now = common_utils::date_time::now();
+    let events_list_begin_time =
+        (now.date() - time::Duration::days(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS)).midnight();
+
+    fp_utils::when(request.created_after > request.created_before, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The `created_after` timestamp must be an earlier timestamp compared to the `created_before` timestamp".to_string(),
+        })
+    })?;
+    fp_utils::when(request.created_after < events_list_begin_time, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`created_after` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days."),
+        })
+    })?;
+
+    let event_classes = request.event_classes.unwrap_or_default();
+    let mut event_types = request.event_types.unwrap_or_default();
+    if !event_classes.is_empty() {
+        event_types = finalize_event_types(event_classes, event_types).await?;
+    }
+
+    let total_count = store
+        .count_initial_events_by_constraints(
+            &merchant_id,
+            Some(profile_id.clone()),
+            request.created_after,
+            request.created_before,
+            event_types.clone(),
+            Some(request.is_delivered),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get total events count")?;
+
+    fp_utils::when(total_count == 0, || {
+        Err(errors::ApiErrorResponse::EventNotFound)
+            .attach_printable("No events found with the specified replay constraints")
+    })?;
+
+    let replay_id = common_utils::generate_id(consts::ID_LENGTH, "whr");
+    let runner = storage::ProcessTrackerRunner::WebhookReplayWorkflow;
+    let tracking_data = super::types::WebhookReplayTrackingData {
+        merchant_id: merchant_id.clone(),
+        profile_id,
+        replay_id: replay_id.clone(),
+        created_after: request.created_after,
+        created_before: request.created_before,
+        event_types,
+        is_delivered: request.is_delivered,
+        cursor: None,
+        cursor_offset: 0,
+        total_count,
+        enqueued_count: 0,
+        skipped_count: 0,
+        failed_count: 0,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_webhook_replay_process_tracker_id(&merchant_id, &replay_id),
+        WEBHOOK_REPLAY_TASK,
+        runner,
+        [WEBHOOK_REPLAY_TAG],
+        tracking_data,
+        None,
+        now,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct webhook replay process tracker task")?;
+
+    let process = store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed while inserting webhook replay task to process_tracker: {replay_id}")
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "WebhookReplay")));
+
+    Ok(ApplicationResponse::Json(get_webhook_replay_response(
+        process,
+    )?))
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_webhook_replay(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    replay_id: String,
+) -> RouterResponse<api::webhook_events::WebhookReplayResponse> {
+    let process = find_webhook_replay_process(&state, &merchant_id, &replay_id).await?;
+
+    Ok(ApplicationResponse::Json(get_webhook_replay_response(
+        process,
+    )?))
+}
+
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn cancel_webhook_replay(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    replay_id: String,
+) -> RouterResponse<api::webhook_events::WebhookReplayResponse> {
+    let store = state.store.as_ref();
+    let process = find_webhook_replay_process(&state, &merchant_id, &replay_id).await?;
+
+    fp_utils::when(
+        process.status == storage::enums::ProcessTrackerStatus::Finish,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Webhook replay `{replay_id}` has already finished"),
+            })
+        },
+    )?;
+
+    // Events which have already been enqueued for delivery are not affected, the replay only
+    // stops enqueueing the remaining events
+    store
+        .as_scheduler()
+        .finish_process_with_business_status(process, business_status::CANCELLED)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel webhook replay process tracker task")?;
+
+    let process = find_webhook_replay_process(&state, &merchant_id, &replay_id).await?;
+
+    Ok(ApplicationResponse::Json(get_webhook_replay_response(
+        process,
+    )?))
+}
+
+#[cfg(feature = "v1")]
+fn get_webhook_replay_process_tracker_id(
+    merchant_id: &common_utils::id_type::MerchantId,
+    replay_id: &str,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::WebhookReplayWorkflow,
+        WEBHOOK_REPLAY_TASK,
+        replay_id,
+        merchant_id,
+    )
+}
+
+#[cfg(feature = "v1")]
+async fn find_webhook_replay_process(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    replay_id: &str,
+) -> errors::RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .find_process_by_id(&get_webhook_replay_process_tracker_id(
+            merchant_id,
+            replay_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find webhook replay process tracker task")?
+        .ok_or_else(|| {
+            error_stack::report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Webhook replay `{replay_id}` does not exist"),
+            })
+        })
+}
+
+#[cfg(feature = "v1")]
+fn get_webhook_replay_response(
+    process: storage::ProcessTracker,
+) -> errors::RouterResult<api::webhook_events::WebhookReplayResponse> {
+    let tracking_data: super::types::WebhookReplayTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("WebhookReplayTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook replay tracking data")?;
+
+    let status = match (process.status, process.business_status.as_str()) {
+        (storage::enums::ProcessTrackerStatus::Finish, business_status::COMPLETED_BY_PT) => {
+            api::webhook_events::WebhookReplayStatus::Completed
+        }
+        (storage::enums::ProcessTrackerStatus::Finish, business_status::CANCELLED) => {
+            api::webhook_events::WebhookReplayStatus::Cancelled
+        }
+        (storage::enums::ProcessTrackerStatus::Finish, _) => {
+            api::webhook_events::WebhookReplayStatus::Failed
+        }
+        _ => api::webhook_events::WebhookReplayStatus::InProgress,
+    };
+
+    Ok(api::webhook_events::WebhookReplayResponse {
+        replay_id: tracking_data.replay_id,
+        merchant_id: tracking_data.merchant_id,
+        profile_id: tracking_data.profile_id,
+        status,
+        total_count: tracking_data.total_count,
+        enqueued_count: tracking_data.enqueued_count,
+        skipped_count: tracking_data.skipped_count,
+        failed_count: tracking_data.failed_count,
+        created: process.created_at,
+        updated: process.updated_at,
+    })
+}
+
 async fn get_account_and_key_store(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
@@ -13,10 +13,13 @@ pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
 pub mod dynamic_routing_stats;
+pub mod embedded_vault;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
+pub mod frm_review;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
@@ -29,16 +32,21 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method_session;
+pub mod payout_batch;
+pub mod process_tracker_audit;
+pub mod recon;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use ::payment_methods::state::PaymentMethodsStorageInterface;
 use common_utils::id_type;
@@ -101,10 +109,13 @@ pub trait StorageInterface:
     + customers::CustomerInterface<Error = StorageError>
     + dashboard_metadata::DashboardMetadataInterface
     + dispute::DisputeInterface
+    + embedded_vault::EmbeddedVaultInterface
     + ephemeral_key::EphemeralKeyInterface
     + ephemeral_key::ClientSecretInterface
     + events::EventInterface
     + file::FileMetadataInterface
+    + forex_rate_snapshot::ForexRateSnapshotInterface
+    + frm_review::FrmReviewInterface
     + FraudCheckInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
@@ -120,6 +131,9 @@ pub trait StorageInterface:
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface<Error = StorageError>
     + PayoutsInterface<Error = StorageError>
+    + payout_batch::PayoutBatchInterface
+    + process_tracker_audit::ProcessTrackerAuditInterface
+    + recon::ReconInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
     + CardsInfoInterface<Error = StorageError>
@@ -130,6 +144,7 @@ pub trait StorageInterface:
     + RequestIdStore
     + business_profile::ProfileInterface
     + routing_algorithm::RoutingAlgorithmInterface
+    + subscription::SubscriptionInterface
     + gsm::GsmInterface
     + unified_translations::UnifiedTranslationsInterface
     + authorization::AuthorizationInterface
@@ -143,6 +158,7 @@ pub trait StorageInterface:
     + payment_method_session::PaymentMethodsSessionInterface
     + tokenization::TokenizationInterface
     + callback_mapper::CallbackMapperInterface
+    + webhook_endpoint::WebhookEndpointInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -71,6 +71,14 @@ where
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::PaymentAddress, errors::StorageError>;
 
+    async fn find_addresses_by_merchant_id_address_ids(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        address_ids: Vec<String>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
     async fn update_address_by_merchant_id_customer_id(
         &self,
         state: &KeyManagerState,
@@ -154,6 +162,37 @@ mod storage {
             .await
         }
 
+        #[instrument(skip_all)]
+        async fn find_addresses_by_merchant_id_address_ids(
+            &self,
+            state: &KeyManagerState,
+            merchant_id: &id_type::MerchantId,
+            address_ids: Vec<String>,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::find_by_merchant_id_address_ids(&conn, merchant_id, address_ids)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+                .async_and_then(|addresses| async {
+                    let mut output = Vec::with_capacity(addresses.len());
+                    for address in addresses.into_iter() {
+                        output.push(
+                            address
+                                .convert(
+                                    state,
+                                    key_store.key.get_inner(),
+                                    merchant_id.clone().into(),
+                                )
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
         #[instrument(skip_all)]
         async fn update_address(
             &self,
@@ -416,6 +455,37 @@ mod storage {
                 .change_context(errors::StorageError::DecryptionError)
         }
 
+        #[instrument(skip_all)]
+        async fn find_addresses_by_merchant_id_address_ids(
+            &self,
+            state: &KeyManagerState,
+            merchant_id: &id_type::MerchantId,
+            address_ids: Vec<String>,
+            key_store: &domain::MerchantKeyStore,
+        ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Address::find_by_merchant_id_address_ids(&conn, merchant_id, address_ids)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+                .async_and_then(|addresses| async {
+                    let mut output = Vec::with_capacity(addresses.len());
+                    for address in addresses.into_iter() {
+                        output.push(
+                            address
+                                .convert(
+                                    state,
+                                    key_store.key.get_inner(),
+                                    merchant_id.clone().into(),
+                                )
+                                .await
+                                .change_context(errors::StorageError::DecryptionError)?,
+                        )
+                    }
+                    Ok(output)
+                })
+                .await
+        }
+
         #[instrument(skip_all)]
         async fn update_address(
             &self,
@@ -887,6 +957,40 @@ impl AddressInterface for MockDb {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    async fn find_addresses_by_merchant_id_address_ids(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        address_ids: Vec<String>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        let addresses = self
+            .addresses
+            .lock()
+            .await
+            .iter()
+            .filter(|address| {
+                address.merchant_id == *merchant_id && address_ids.contains(&address.address_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut output = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            output.push(
+                address
+                    .convert(
+                        state,
+                        key_store.key.get_inner(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)?,
+            )
+        }
+        Ok(output)
+    }
+
     async fn update_address_by_merchant_id_customer_id(
         &self,
         state: &KeyManagerState,
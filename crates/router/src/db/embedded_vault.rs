@@ -0,0 +1,355 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait EmbeddedVaultInterface {
+    async fn insert_embedded_vault_entry(
+        &self,
+        entry: storage::EmbeddedVaultEntryNew,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError>;
+
+    async fn find_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError>;
+
+    async fn update_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+        entry_update: storage::EmbeddedVaultEntryUpdate,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError>;
+
+    async fn delete_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError>;
+
+    /// Deletes the entries of the merchant which expired at or before `now`, returns whether any
+    /// entry was deleted.
+    async fn delete_expired_embedded_vault_entries(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        now: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl EmbeddedVaultInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_embedded_vault_entry(
+        &self,
+        entry: storage::EmbeddedVaultEntryNew,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        entry
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EmbeddedVaultEntry::find_by_merchant_id_vault_id(&conn, merchant_id, vault_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+        entry_update: storage::EmbeddedVaultEntryUpdate,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EmbeddedVaultEntry::update_by_merchant_id_vault_id(
+            &conn,
+            merchant_id,
+            vault_id,
+            entry_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EmbeddedVaultEntry::delete_by_merchant_id_vault_id(&conn, merchant_id, vault_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_expired_embedded_vault_entries(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        now: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EmbeddedVaultEntry::delete_expired_by_merchant_id(&conn, merchant_id, now)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddedVaultInterface for MockDb {
+    async fn insert_embedded_vault_entry(
+        &self,
+        entry: storage::EmbeddedVaultEntryNew,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        let mut entries = self.embedded_vault_entries.lock().await;
+
+        if entries
+            .iter()
+            .any(|e| e.merchant_id == entry.merchant_id && e.vault_id == entry.vault_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "embedded_vault",
+                key: Some(entry.vault_id.clone()),
+            })?;
+        }
+
+        let created_entry = storage::EmbeddedVaultEntry {
+            merchant_id: entry.merchant_id,
+            vault_id: entry.vault_id,
+            entity_id: entry.entity_id,
+            encrypted_data: entry.encrypted_data,
+            encrypted_data_key: entry.encrypted_data_key,
+            expires_at: entry.expires_at,
+            created_at: entry.created_at,
+            modified_at: entry.modified_at,
+        };
+
+        entries.push(created_entry.clone());
+
+        Ok(created_entry)
+    }
+
+    async fn find_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        self.embedded_vault_entries
+            .lock()
+            .await
+            .iter()
+            .find(|e| e.merchant_id == *merchant_id && e.vault_id == vault_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No embedded vault entry found for vault_id = {vault_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+        entry_update: storage::EmbeddedVaultEntryUpdate,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        let mut entries = self.embedded_vault_entries.lock().await;
+
+        let entry = entries
+            .iter_mut()
+            .find(|e| e.merchant_id == *merchant_id && e.vault_id == vault_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No embedded vault entry found for vault_id = {vault_id}"
+            )))?;
+
+        entry.entity_id = entry_update.entity_id;
+        entry.encrypted_data = entry_update.encrypted_data;
+        entry.encrypted_data_key = entry_update.encrypted_data_key;
+        entry.expires_at = entry_update.expires_at;
+        entry.modified_at = entry_update.modified_at;
+
+        Ok(entry.clone())
+    }
+
+    async fn delete_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        let mut entries = self.embedded_vault_entries.lock().await;
+
+        let position = entries
+            .iter()
+            .position(|e| e.merchant_id == *merchant_id && e.vault_id == vault_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No embedded vault entry found for vault_id = {vault_id}"
+            )))?;
+
+        Ok(entries.remove(position))
+    }
+
+    async fn delete_expired_embedded_vault_entries(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        now: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut entries = self.embedded_vault_entries.lock().await;
+        let entries_count = entries.len();
+
+        entries.retain(|e| {
+            e.merchant_id != *merchant_id
+                || e.expires_at.map_or(true, |expires_at| expires_at > now)
+        });
+
+        Ok(entries.len() < entries_count)
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddedVaultInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_embedded_vault_entry(
+        &self,
+        entry: storage::EmbeddedVaultEntryNew,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        self.diesel_store.insert_embedded_vault_entry(entry).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        self.diesel_store
+            .find_embedded_vault_entry(merchant_id, vault_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+        entry_update: storage::EmbeddedVaultEntryUpdate,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        self.diesel_store
+            .update_embedded_vault_entry(merchant_id, vault_id, entry_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_embedded_vault_entry(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        vault_id: &str,
+    ) -> CustomResult<storage::EmbeddedVaultEntry, errors::StorageError> {
+        self.diesel_store
+            .delete_embedded_vault_entry(merchant_id, vault_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_expired_embedded_vault_entries(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        now: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_expired_embedded_vault_entries(merchant_id, now)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_entry(
+        vault_id: &str,
+        expires_at: Option<time::PrimitiveDateTime>,
+    ) -> storage::EmbeddedVaultEntryNew {
+        let now = common_utils::date_time::now();
+        storage::EmbeddedVaultEntryNew {
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            vault_id: vault_id.to_string(),
+            entity_id: "customer_1".to_string(),
+            encrypted_data: b"data".to_vec(),
+            encrypted_data_key: b"key".to_vec(),
+            expires_at,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_expired_embedded_vault_entries() {
+        let mock_db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        let merchant_id = common_utils::id_type::MerchantId::default();
+        let now = common_utils::date_time::now();
+
+        for (vault_id, expires_at) in [
+            ("vault_1", None),
+            (
+                "vault_2",
+                Some(now.saturating_sub(time::Duration::seconds(1))),
+            ),
+            (
+                "vault_3",
+                Some(now.saturating_add(time::Duration::hours(1))),
+            ),
+        ] {
+            mock_db
+                .insert_embedded_vault_entry(get_entry(vault_id, expires_at))
+                .await
+                .unwrap();
+        }
+
+        assert!(mock_db
+            .delete_expired_embedded_vault_entries(&merchant_id, now)
+            .await
+            .unwrap());
+        assert!(mock_db
+            .find_embedded_vault_entry(&merchant_id, "vault_2")
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+        assert!(mock_db
+            .find_embedded_vault_entry(&merchant_id, "vault_1")
+            .await
+            .is_ok());
+        assert!(mock_db
+            .find_embedded_vault_entry(&merchant_id, "vault_3")
+            .await
+            .is_ok());
+        assert!(!mock_db
+            .delete_expired_embedded_vault_entries(&merchant_id, now)
+            .await
+            .unwrap());
+    }
+}
@@ -0,0 +1,126 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+use time::PrimitiveDateTime;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ForexRateSnapshotInterface {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError>;
+
+    async fn find_latest_forex_rate_snapshot_captured_at_or_before(
+        &self,
+        time: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ForexRateSnapshot>, errors::StorageError>;
+
+    async fn list_forex_rate_snapshots_captured_between(
+        &self,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ForexRateSnapshot>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        snapshot
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_latest_forex_rate_snapshot_captured_at_or_before(
+        &self,
+        time: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ForexRateSnapshot>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ForexRateSnapshot::find_latest_captured_at_or_before(&conn, time)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_forex_rate_snapshots_captured_between(
+        &self,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ForexRateSnapshot>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ForexRateSnapshot::list_captured_between(&conn, start_time, end_time)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for MockDb {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        _snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_latest_forex_rate_snapshot_captured_at_or_before(
+        &self,
+        _time: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ForexRateSnapshot>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_forex_rate_snapshots_captured_between(
+        &self,
+        _start_time: PrimitiveDateTime,
+        _end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ForexRateSnapshot>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        self.diesel_store.insert_forex_rate_snapshot(snapshot).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_latest_forex_rate_snapshot_captured_at_or_before(
+        &self,
+        time: PrimitiveDateTime,
+    ) -> CustomResult<Option<storage::ForexRateSnapshot>, errors::StorageError> {
+        self.diesel_store
+            .find_latest_forex_rate_snapshot_captured_at_or_before(time)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_forex_rate_snapshots_captured_between(
+        &self,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::ForexRateSnapshot>, errors::StorageError> {
+        self.diesel_store
+            .list_forex_rate_snapshots_captured_between(start_time, end_time)
+            .await
+    }
+}
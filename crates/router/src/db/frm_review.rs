@@ -0,0 +1,762 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums as storage_enums},
+};
+
+#[async_trait::async_trait]
+pub trait FrmReviewInterface {
+    async fn insert_frm_review(
+        &self,
+        frm_review: storage::FrmReviewNew,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError>;
+
+    /// Inserts the review along with the task which expires it, so that a review is never added
+    /// to the queue without its expiry task
+    async fn insert_frm_review_with_expiry_task(
+        &self,
+        frm_review: storage::FrmReviewNew,
+        expiry_task: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError>;
+
+    async fn find_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError>;
+
+    async fn find_frm_review_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::FrmReview>, errors::StorageError>;
+
+    async fn list_frm_reviews_by_merchant_id_profile_id_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: storage_enums::FrmReviewStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::FrmReview>, errors::StorageError>;
+
+    async fn update_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: storage::FrmReviewUpdate,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError>;
+
+    async fn update_pending_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: storage::FrmReviewUpdate,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError>;
+
+    async fn insert_frm_review_note(
+        &self,
+        frm_review_note: storage::FrmReviewNoteNew,
+    ) -> CustomResult<storage::FrmReviewNote, errors::StorageError>;
+
+    async fn list_frm_review_notes_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+    ) -> CustomResult<Vec<storage::FrmReviewNote>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl FrmReviewInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_frm_review(
+        &self,
+        frm_review: storage::FrmReviewNew,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        frm_review
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_frm_review_with_expiry_task(
+        &self,
+        frm_review: storage::FrmReviewNew,
+        expiry_task: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+
+        conn.transaction_async(|conn| async move {
+            let frm_review = frm_review
+                .insert(&conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+            expiry_task
+                .insert_process(&conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+
+            Ok::<_, errors::StorageError>(frm_review)
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FrmReview::find_by_merchant_id_review_id(&conn, merchant_id, review_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_frm_review_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::FrmReview>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FrmReview::find_optional_by_merchant_id_attempt_id(&conn, merchant_id, attempt_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_frm_reviews_by_merchant_id_profile_id_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: storage_enums::FrmReviewStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::FrmReview>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FrmReview::list_by_merchant_id_profile_id_status(
+            &conn,
+            merchant_id,
+            profile_id,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: storage::FrmReviewUpdate,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::FrmReview::update_by_merchant_id_review_id(
+            &conn,
+            merchant_id,
+            review_id,
+            frm_review_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_pending_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: storage::FrmReviewUpdate,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::FrmReview::update_pending_by_merchant_id_review_id(
+            &conn,
+            merchant_id,
+            review_id,
+            frm_review_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_frm_review_note(
+        &self,
+        frm_review_note: storage::FrmReviewNoteNew,
+    ) -> CustomResult<storage::FrmReviewNote, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        frm_review_note
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_frm_review_notes_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+    ) -> CustomResult<Vec<storage::FrmReviewNote>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FrmReviewNote::list_by_merchant_id_review_id(&conn, merchant_id, review_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl FrmReviewInterface for MockDb {
+    async fn insert_frm_review(
+        &self,
+        frm_review: storage::FrmReviewNew,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        let mut locked_frm_reviews = self.frm_reviews.lock().await;
+
+        if locked_frm_reviews.iter().any(|review| {
+            review.review_id == frm_review.review_id
+                || (review.merchant_id == frm_review.merchant_id
+                    && review.attempt_id == frm_review.attempt_id)
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "frm_review",
+                key: Some(frm_review.review_id.clone()),
+            })?;
+        }
+
+        let new_frm_review = storage::FrmReview {
+            review_id: frm_review.review_id,
+            merchant_id: frm_review.merchant_id,
+            profile_id: frm_review.profile_id,
+            payment_id: frm_review.payment_id,
+            attempt_id: frm_review.attempt_id,
+            frm_id: frm_review.frm_id,
+            frm_name: frm_review.frm_name,
+            status: frm_review.status,
+            frm_reason: frm_review.frm_reason,
+            frm_score: frm_review.frm_score,
+            assigned_to: frm_review.assigned_to,
+            decision: frm_review.decision,
+            reviewed_by: frm_review.reviewed_by,
+            reviewed_at: frm_review.reviewed_at,
+            expires_at: frm_review.expires_at,
+            created_at: frm_review.created_at,
+            modified_at: frm_review.modified_at,
+        };
+        locked_frm_reviews.push(new_frm_review.clone());
+
+        Ok(new_frm_review)
+    }
+
+    async fn insert_frm_review_with_expiry_task(
+        &self,
+        frm_review: storage::FrmReviewNew,
+        expiry_task: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        let frm_review = self.insert_frm_review(frm_review).await?;
+        scheduler::db::process_tracker::ProcessTrackerInterface::insert_process(self, expiry_task)
+            .await?;
+
+        Ok(frm_review)
+    }
+
+    async fn find_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        self.frm_reviews
+            .lock()
+            .await
+            .iter()
+            .find(|review| review.merchant_id == *merchant_id && review.review_id == review_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No manual review available for review_id = {review_id}"
+                ))
+                .into()
+            })
+    }
+
+    async fn find_frm_review_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::FrmReview>, errors::StorageError> {
+        Ok(self
+            .frm_reviews
+            .lock()
+            .await
+            .iter()
+            .find(|review| review.merchant_id == *merchant_id && review.attempt_id == attempt_id)
+            .cloned())
+    }
+
+    async fn list_frm_reviews_by_merchant_id_profile_id_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: storage_enums::FrmReviewStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::FrmReview>, errors::StorageError> {
+        let mut frm_reviews = self
+            .frm_reviews
+            .lock()
+            .await
+            .iter()
+            .filter(|review| {
+                review.merchant_id == *merchant_id
+                    && review.profile_id == *profile_id
+                    && review.status == status
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        frm_reviews.sort_by_key(|review| review.created_at);
+
+        Ok(frm_reviews
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+
+    async fn update_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: storage::FrmReviewUpdate,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        let mut locked_frm_reviews = self.frm_reviews.lock().await;
+        let frm_review = locked_frm_reviews
+            .iter_mut()
+            .find(|review| review.merchant_id == *merchant_id && review.review_id == review_id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No manual review available for review_id = {review_id}"
+                ))
+            })?;
+
+        *frm_review = storage::FrmReviewUpdateInternal::from(frm_review_update)
+            .apply_changeset(frm_review.clone());
+
+        Ok(frm_review.clone())
+    }
+
+    async fn update_pending_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: storage::FrmReviewUpdate,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        let mut locked_frm_reviews = self.frm_reviews.lock().await;
+        let frm_review = locked_frm_reviews
+            .iter_mut()
+            .find(|review| {
+                review.merchant_id == *merchant_id
+                    && review.review_id == review_id
+                    && review.status == storage_enums::FrmReviewStatus::Pending
+            })
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No pending manual review available for review_id = {review_id}"
+                ))
+            })?;
+
+        *frm_review = storage::FrmReviewUpdateInternal::from(frm_review_update)
+            .apply_changeset(frm_review.clone());
+
+        Ok(frm_review.clone())
+    }
+
+    async fn insert_frm_review_note(
+        &self,
+        _frm_review_note: storage::FrmReviewNoteNew,
+    ) -> CustomResult<storage::FrmReviewNote, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_frm_review_notes_by_merchant_id_review_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _review_id: &str,
+    ) -> CustomResult<Vec<storage::FrmReviewNote>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl FrmReviewInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_frm_review(
+        &self,
+        frm_review: storage::FrmReviewNew,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        self.diesel_store.insert_frm_review(frm_review).await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_frm_review_with_expiry_task(
+        &self,
+        frm_review: storage::FrmReviewNew,
+        expiry_task: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        self.diesel_store
+            .insert_frm_review_with_expiry_task(frm_review, expiry_task)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        self.diesel_store
+            .find_frm_review_by_merchant_id_review_id(merchant_id, review_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_frm_review_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_id: &str,
+    ) -> CustomResult<Option<storage::FrmReview>, errors::StorageError> {
+        self.diesel_store
+            .find_frm_review_by_merchant_id_attempt_id(merchant_id, attempt_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_frm_reviews_by_merchant_id_profile_id_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        status: storage_enums::FrmReviewStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::FrmReview>, errors::StorageError> {
+        self.diesel_store
+            .list_frm_reviews_by_merchant_id_profile_id_status(
+                merchant_id,
+                profile_id,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: storage::FrmReviewUpdate,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        self.diesel_store
+            .update_frm_review_by_merchant_id_review_id(merchant_id, review_id, frm_review_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_pending_frm_review_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+        frm_review_update: storage::FrmReviewUpdate,
+    ) -> CustomResult<storage::FrmReview, errors::StorageError> {
+        self.diesel_store
+            .update_pending_frm_review_by_merchant_id_review_id(
+                merchant_id,
+                review_id,
+                frm_review_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_frm_review_note(
+        &self,
+        frm_review_note: storage::FrmReviewNoteNew,
+    ) -> CustomResult<storage::FrmReviewNote, errors::StorageError> {
+        self.diesel_store
+            .insert_frm_review_note(frm_review_note)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_frm_review_notes_by_merchant_id_review_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        review_id: &str,
+    ) -> CustomResult<Vec<storage::FrmReviewNote>, errors::StorageError> {
+        self.diesel_store
+            .list_frm_review_notes_by_merchant_id_review_id(merchant_id, review_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::borrow::Cow;
+
+    use redis_interface::RedisSettings;
+
+    use super::*;
+
+    fn frm_review_new(review_id: &str, attempt_id: &str) -> storage::FrmReviewNew {
+        let now = common_utils::date_time::now();
+        storage::FrmReviewNew {
+            review_id: review_id.to_string(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .unwrap(),
+            profile_id: common_utils::id_type::ProfileId::try_from(Cow::from("profile_1")).unwrap(),
+            payment_id: common_utils::id_type::PaymentId::try_from(Cow::from("payment_1")).unwrap(),
+            attempt_id: attempt_id.to_string(),
+            frm_id: "frm_1".to_string(),
+            frm_name: "signifyd".to_string(),
+            status: storage_enums::FrmReviewStatus::Pending,
+            frm_reason: None,
+            frm_score: Some(80),
+            assigned_to: None,
+            decision: None,
+            reviewed_by: None,
+            reviewed_at: None,
+            expires_at: now + time::Duration::hours(24),
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    async fn mockdb_with_pending_review() -> MockDb {
+        let mockdb = MockDb::new(&RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        mockdb
+            .insert_frm_review(frm_review_new("review_1", "attempt_1"))
+            .await
+            .unwrap();
+        mockdb
+    }
+
+    fn merchant_id() -> common_utils::id_type::MerchantId {
+        common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_review_is_inserted_along_with_its_expiry_task() {
+        let mockdb = MockDb::new(&RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+        let frm_review = frm_review_new("review_1", "attempt_1");
+        let expiry_task = storage::ProcessTrackerNew::new(
+            "FRM_REVIEW_EXPIRY_review_1",
+            "FRM_REVIEW_EXPIRY",
+            storage::ProcessTrackerRunner::FrmReviewExpiryWorkflow,
+            ["FRM_REVIEW"],
+            "review_1",
+            None,
+            frm_review.expires_at,
+            common_types::consts::API_VERSION,
+        )
+        .unwrap();
+
+        mockdb
+            .insert_frm_review_with_expiry_task(frm_review, expiry_task)
+            .await
+            .unwrap();
+
+        assert!(mockdb
+            .find_frm_review_by_merchant_id_review_id(&merchant_id(), "review_1")
+            .await
+            .is_ok());
+        let processes = mockdb.processes.lock().await;
+        assert_eq!(processes.len(), 1);
+        assert_eq!(
+            processes.first().map(|process| process.id.as_str()),
+            Some("FRM_REVIEW_EXPIRY_review_1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pending_review_can_be_assigned_and_unassigned() {
+        let mockdb = mockdb_with_pending_review().await;
+
+        let frm_review = mockdb
+            .update_pending_frm_review_by_merchant_id_review_id(
+                &merchant_id(),
+                "review_1",
+                storage::FrmReviewUpdate::AssignmentUpdate {
+                    assigned_to: Some("user_1".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(frm_review.assigned_to.as_deref(), Some("user_1"));
+        assert_eq!(frm_review.status, storage_enums::FrmReviewStatus::Pending);
+
+        let frm_review = mockdb
+            .update_pending_frm_review_by_merchant_id_review_id(
+                &merchant_id(),
+                "review_1",
+                storage::FrmReviewUpdate::AssignmentUpdate { assigned_to: None },
+            )
+            .await
+            .unwrap();
+        assert_eq!(frm_review.assigned_to, None);
+        assert_eq!(frm_review.status, storage_enums::FrmReviewStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_decided_review_cannot_be_decided_again() {
+        let mockdb = mockdb_with_pending_review().await;
+
+        let frm_review = mockdb
+            .update_pending_frm_review_by_merchant_id_review_id(
+                &merchant_id(),
+                "review_1",
+                storage::FrmReviewUpdate::DecisionUpdate {
+                    status: storage_enums::FrmReviewStatus::Approved,
+                    decision: storage_enums::FrmReviewAction::Approve,
+                    reviewed_by: Some("user_1".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(frm_review.status, storage_enums::FrmReviewStatus::Approved);
+        assert_eq!(
+            frm_review.decision,
+            Some(storage_enums::FrmReviewAction::Approve)
+        );
+        assert_eq!(frm_review.reviewed_by.as_deref(), Some("user_1"));
+        assert!(frm_review.reviewed_at.is_some());
+
+        // Neither a reviewer nor the expiry task can resolve the review again
+        let result = mockdb
+            .update_pending_frm_review_by_merchant_id_review_id(
+                &merchant_id(),
+                "review_1",
+                storage::FrmReviewUpdate::DecisionUpdate {
+                    status: storage_enums::FrmReviewStatus::Rejected,
+                    decision: storage_enums::FrmReviewAction::Reject,
+                    reviewed_by: Some("user_2".to_string()),
+                },
+            )
+            .await;
+        assert!(result.unwrap_err().current_context().is_db_not_found());
+        let result = mockdb
+            .update_pending_frm_review_by_merchant_id_review_id(
+                &merchant_id(),
+                "review_1",
+                storage::FrmReviewUpdate::StatusUpdate {
+                    status: storage_enums::FrmReviewStatus::Expired,
+                },
+            )
+            .await;
+        assert!(result.unwrap_err().current_context().is_db_not_found());
+
+        let frm_review = mockdb
+            .find_frm_review_by_merchant_id_review_id(&merchant_id(), "review_1")
+            .await
+            .unwrap();
+        assert_eq!(frm_review.status, storage_enums::FrmReviewStatus::Approved);
+        assert_eq!(frm_review.reviewed_by.as_deref(), Some("user_1"));
+    }
+
+    #[tokio::test]
+    async fn test_expired_review_keeps_default_decision() {
+        let mockdb = mockdb_with_pending_review().await;
+
+        let frm_review = mockdb
+            .update_pending_frm_review_by_merchant_id_review_id(
+                &merchant_id(),
+                "review_1",
+                storage::FrmReviewUpdate::DecisionUpdate {
+                    status: storage_enums::FrmReviewStatus::Expired,
+                    decision: storage_enums::FrmReviewAction::Reject,
+                    reviewed_by: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(frm_review.status, storage_enums::FrmReviewStatus::Expired);
+        assert_eq!(
+            frm_review.decision,
+            Some(storage_enums::FrmReviewAction::Reject)
+        );
+        assert_eq!(frm_review.reviewed_by, None);
+
+        let result = mockdb
+            .update_pending_frm_review_by_merchant_id_review_id(
+                &merchant_id(),
+                "review_1",
+                storage::FrmReviewUpdate::AssignmentUpdate {
+                    assigned_to: Some("user_1".to_string()),
+                },
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_reviews_by_status() {
+        let mockdb = mockdb_with_pending_review().await;
+        mockdb
+            .insert_frm_review(frm_review_new("review_2", "attempt_2"))
+            .await
+            .unwrap();
+        assert!(mockdb
+            .insert_frm_review(frm_review_new("review_3", "attempt_2"))
+            .await
+            .is_err());
+
+        mockdb
+            .update_pending_frm_review_by_merchant_id_review_id(
+                &merchant_id(),
+                "review_2",
+                storage::FrmReviewUpdate::StatusUpdate {
+                    status: storage_enums::FrmReviewStatus::Expired,
+                },
+            )
+            .await
+            .unwrap();
+
+        let profile_id =
+            common_utils::id_type::ProfileId::try_from(Cow::from("profile_1")).unwrap();
+        let pending_reviews = mockdb
+            .list_frm_reviews_by_merchant_id_profile_id_status(
+                &merchant_id(),
+                &profile_id,
+                storage_enums::FrmReviewStatus::Pending,
+                10,
+                0,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            pending_reviews
+                .into_iter()
+                .map(|review| review.review_id)
+                .collect::<Vec<_>>(),
+            vec!["review_1".to_string()]
+        );
+    }
+}
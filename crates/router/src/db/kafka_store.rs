@@ -205,6 +205,18 @@ impl AddressInterface for KafkaStore {
             .await
     }
 
+    async fn find_addresses_by_merchant_id_address_ids(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        address_ids: Vec<String>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Address>, errors::StorageError> {
+        self.diesel_store
+            .find_addresses_by_merchant_id_address_ids(state, merchant_id, address_ids, key_store)
+            .await
+    }
+
     async fn update_address_by_merchant_id_customer_id(
         &self,
         state: &KeyManagerState,
@@ -2666,6 +2678,34 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_recurring_processes_to_reschedule(
+        &self,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_recurring_processes_to_reschedule(limit)
+            .await
+    }
+
+    async fn update_process_if_unmodified(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .update_process_if_unmodified(this, process)
+            .await
+    }
+
+    async fn list_processes_by_constraints(
+        &self,
+        constraints: storage::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .list_processes_by_constraints(constraints)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
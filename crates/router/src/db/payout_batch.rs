@@ -0,0 +1,387 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums as storage_enums},
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    /// Inserts the batch along with every chunk of its items and the task processing each chunk
+    /// within the same transaction, so that a batch is never left without some of its items or
+    /// tasks.
+    async fn insert_payout_batch_with_items(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        chunks: Vec<(Vec<storage::PayoutBatchItemNew>, storage::ProcessTrackerNew)>,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError>;
+
+    async fn update_processing_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn list_payout_batch_items_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        status: Option<storage_enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn list_payout_batch_items_by_merchant_id_batch_id_row_numbers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        start_row_number: i32,
+        end_row_number: i32,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn update_payout_batch_item_by_merchant_id_batch_id_row_number(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        row_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_with_items(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        chunks: Vec<(Vec<storage::PayoutBatchItemNew>, storage::ProcessTrackerNew)>,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+
+        conn.transaction_async(|conn| async move {
+            let payout_batch = payout_batch
+                .insert(&conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+            for (payout_batch_items, process) in chunks {
+                storage::PayoutBatchItemNew::batch_insert(payout_batch_items, &conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                process
+                    .insert_process(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+            }
+
+            Ok::<_, errors::StorageError>(payout_batch)
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::list_by_merchant_id(&conn, merchant_id, profile_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_processing_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatch::update_processing_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            payout_batch_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        status: Option<storage_enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::list_by_merchant_id_batch_id(
+            &conn,
+            merchant_id,
+            batch_id,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_merchant_id_batch_id_row_numbers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        start_row_number: i32,
+        end_row_number: i32,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::list_by_merchant_id_batch_id_row_numbers(
+            &conn,
+            merchant_id,
+            batch_id,
+            start_row_number,
+            end_row_number,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item_by_merchant_id_batch_id_row_number(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        row_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::update_by_merchant_id_batch_id_row_number(
+            &conn,
+            merchant_id,
+            batch_id,
+            row_number,
+            payout_batch_item_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch_with_items(
+        &self,
+        _payout_batch: storage::PayoutBatchNew,
+        _chunks: Vec<(Vec<storage::PayoutBatchItemNew>, storage::ProcessTrackerNew)>,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: Option<&common_utils::id_type::ProfileId>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_processing_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+        _payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payout_batch_items_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+        _status: Option<storage_enums::PayoutBatchItemStatus>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payout_batch_items_by_merchant_id_batch_id_row_numbers(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+        _start_row_number: i32,
+        _end_row_number: i32,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch_item_by_merchant_id_batch_id_row_number(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+        _row_number: i32,
+        _payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_with_items(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        chunks: Vec<(Vec<storage::PayoutBatchItemNew>, storage::ProcessTrackerNew)>,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_batch_with_items(payout_batch, chunks)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batches_by_merchant_id(merchant_id, profile_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_processing_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_processing_payout_batch_by_merchant_id_batch_id(
+                merchant_id,
+                batch_id,
+                payout_batch_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        status: Option<storage_enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batch_items_by_merchant_id_batch_id(
+                merchant_id,
+                batch_id,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_merchant_id_batch_id_row_numbers(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        start_row_number: i32,
+        end_row_number: i32,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batch_items_by_merchant_id_batch_id_row_numbers(
+                merchant_id,
+                batch_id,
+                start_row_number,
+                end_row_number,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item_by_merchant_id_batch_id_row_number(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        row_number: i32,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_item_by_merchant_id_batch_id_row_number(
+                merchant_id,
+                batch_id,
+                row_number,
+                payout_batch_item_update,
+            )
+            .await
+    }
+}
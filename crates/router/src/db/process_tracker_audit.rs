@@ -0,0 +1,149 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ProcessTrackerAuditInterface {
+    async fn insert_process_tracker_audit(
+        &self,
+        audit: storage::ProcessTrackerAuditNew,
+    ) -> CustomResult<storage::ProcessTrackerAudit, errors::StorageError>;
+
+    /// Updates the process only if it has not been modified since it was read, and records the
+    /// update in the audit trail of the process within the same transaction.
+    ///
+    /// Returns `None` without recording the audit if the process was modified in the meantime.
+    async fn update_process_with_audit(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+        audit: storage::ProcessTrackerAuditNew,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn list_process_tracker_audits_by_process_tracker_id(
+        &self,
+        process_tracker_id: &str,
+    ) -> CustomResult<Vec<storage::ProcessTrackerAudit>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerAuditInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_process_tracker_audit(
+        &self,
+        audit: storage::ProcessTrackerAuditNew,
+    ) -> CustomResult<storage::ProcessTrackerAudit, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_process_with_audit(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+        audit: storage::ProcessTrackerAuditNew,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+
+        conn.transaction_async(|conn| async move {
+            let Some(updated_process) = this
+                .update_if_unmodified(&conn, process)
+                .await
+                .map_err(errors::StorageError::from)?
+            else {
+                return Ok(None);
+            };
+            audit
+                .insert(&conn)
+                .await
+                .map_err(errors::StorageError::from)?;
+
+            Ok::<_, errors::StorageError>(Some(updated_process))
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_process_tracker_audits_by_process_tracker_id(
+        &self,
+        process_tracker_id: &str,
+    ) -> CustomResult<Vec<storage::ProcessTrackerAudit>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTrackerAudit::list_by_process_tracker_id(&conn, process_tracker_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerAuditInterface for MockDb {
+    async fn insert_process_tracker_audit(
+        &self,
+        _audit: storage::ProcessTrackerAuditNew,
+    ) -> CustomResult<storage::ProcessTrackerAudit, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_process_with_audit(
+        &self,
+        _this: storage::ProcessTracker,
+        _process: storage::ProcessTrackerUpdate,
+        _audit: storage::ProcessTrackerAuditNew,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_process_tracker_audits_by_process_tracker_id(
+        &self,
+        _process_tracker_id: &str,
+    ) -> CustomResult<Vec<storage::ProcessTrackerAudit>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerAuditInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_process_tracker_audit(
+        &self,
+        audit: storage::ProcessTrackerAuditNew,
+    ) -> CustomResult<storage::ProcessTrackerAudit, errors::StorageError> {
+        self.diesel_store.insert_process_tracker_audit(audit).await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_process_with_audit(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+        audit: storage::ProcessTrackerAuditNew,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .update_process_with_audit(this, process, audit)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_process_tracker_audits_by_process_tracker_id(
+        &self,
+        process_tracker_id: &str,
+    ) -> CustomResult<Vec<storage::ProcessTrackerAudit>, errors::StorageError> {
+        self.diesel_store
+            .list_process_tracker_audits_by_process_tracker_id(process_tracker_id)
+            .await
+    }
+}
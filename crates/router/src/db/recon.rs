@@ -0,0 +1,275 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait ReconInterface {
+    async fn insert_recon_job(
+        &self,
+        recon_job: storage::ReconJobNew,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError>;
+
+    async fn find_recon_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError>;
+
+    async fn list_recon_jobs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconJob>, errors::StorageError>;
+
+    async fn update_recon_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        recon_job_update: storage::ReconJobUpdate,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError>;
+
+    async fn insert_recon_mismatch(
+        &self,
+        recon_mismatch: storage::ReconMismatchNew,
+    ) -> CustomResult<storage::ReconMismatch, errors::StorageError>;
+
+    async fn list_recon_mismatches_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        mismatch_type: Option<enums::ReconMismatchType>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconMismatch>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReconInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_recon_job(
+        &self,
+        recon_job: storage::ReconJobNew,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        recon_job
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_recon_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconJob::find_by_merchant_id_job_id(&conn, merchant_id, job_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_recon_jobs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconJob>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconJob::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_recon_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        recon_job_update: storage::ReconJobUpdate,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReconJob::update_by_merchant_id_job_id(
+            &conn,
+            merchant_id,
+            job_id,
+            recon_job_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_recon_mismatch(
+        &self,
+        recon_mismatch: storage::ReconMismatchNew,
+    ) -> CustomResult<storage::ReconMismatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        recon_mismatch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_recon_mismatches_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        mismatch_type: Option<enums::ReconMismatchType>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconMismatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconMismatch::list_by_merchant_id_job_id(
+            &conn,
+            merchant_id,
+            job_id,
+            mismatch_type,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ReconInterface for MockDb {
+    async fn insert_recon_job(
+        &self,
+        _recon_job: storage::ReconJobNew,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_recon_job_by_merchant_id_job_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _job_id: &str,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_recon_jobs_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconJob>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_recon_job_by_merchant_id_job_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _job_id: &str,
+        _recon_job_update: storage::ReconJobUpdate,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_recon_mismatch(
+        &self,
+        _recon_mismatch: storage::ReconMismatchNew,
+    ) -> CustomResult<storage::ReconMismatch, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_recon_mismatches_by_merchant_id_job_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _job_id: &str,
+        _mismatch_type: Option<enums::ReconMismatchType>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconMismatch>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ReconInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_recon_job(
+        &self,
+        recon_job: storage::ReconJobNew,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError> {
+        self.diesel_store.insert_recon_job(recon_job).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_recon_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError> {
+        self.diesel_store
+            .find_recon_job_by_merchant_id_job_id(merchant_id, job_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_recon_jobs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconJob>, errors::StorageError> {
+        self.diesel_store
+            .list_recon_jobs_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_recon_job_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        recon_job_update: storage::ReconJobUpdate,
+    ) -> CustomResult<storage::ReconJob, errors::StorageError> {
+        self.diesel_store
+            .update_recon_job_by_merchant_id_job_id(merchant_id, job_id, recon_job_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_recon_mismatch(
+        &self,
+        recon_mismatch: storage::ReconMismatchNew,
+    ) -> CustomResult<storage::ReconMismatch, errors::StorageError> {
+        self.diesel_store
+            .insert_recon_mismatch(recon_mismatch)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_recon_mismatches_by_merchant_id_job_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        job_id: &str,
+        mismatch_type: Option<enums::ReconMismatchType>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconMismatch>, errors::StorageError> {
+        self.diesel_store
+            .list_recon_mismatches_by_merchant_id_job_id(
+                merchant_id,
+                job_id,
+                mismatch_type,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
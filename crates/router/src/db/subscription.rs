@@ -0,0 +1,832 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums as storage_enums},
+};
+
+#[async_trait::async_trait]
+pub trait SubscriptionInterface {
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn list_subscription_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        is_active: Option<bool>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError>;
+
+    async fn update_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError>;
+
+    async fn insert_subscription_price(
+        &self,
+        subscription_price: storage::SubscriptionPriceNew,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError>;
+
+    async fn find_subscription_price_by_merchant_id_price_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError>;
+
+    async fn list_subscription_prices_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionPrice>, errors::StorageError>;
+
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn list_subscriptions_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        status: Option<storage_enums::SubscriptionStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError>;
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn update_uncanceled_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError>;
+
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn find_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        billing_reason: storage_enums::InvoiceBillingReason,
+        period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError>;
+
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError>;
+
+    async fn void_unpaid_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription_plan
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::find_by_merchant_id_plan_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        is_active: Option<bool>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::list_by_merchant_id_profile_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            is_active,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SubscriptionPlan::update_by_merchant_id_plan_id(
+            &conn,
+            merchant_id,
+            plan_id,
+            subscription_plan_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_price(
+        &self,
+        subscription_price: storage::SubscriptionPriceNew,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription_price
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_price_by_merchant_id_price_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPrice::find_by_merchant_id_price_id(&conn, merchant_id, price_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_prices_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionPrice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPrice::list_by_merchant_id_plan_id(&conn, merchant_id, plan_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::find_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        status: Option<storage_enums::SubscriptionStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Subscription::list_by_merchant_id_profile_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            customer_id,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Subscription::update_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            subscription_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_uncanceled_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Subscription::update_uncanceled_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            subscription_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        subscription_invoice
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::find_by_merchant_id_invoice_id(&conn, merchant_id, invoice_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        billing_reason: storage_enums::InvoiceBillingReason,
+        period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::find_by_merchant_id_subscription_id_period_start(
+            &conn,
+            merchant_id,
+            subscription_id,
+            billing_reason,
+            period_start,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionInvoice::list_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SubscriptionInvoice::update_by_merchant_id_invoice_id(
+            &conn,
+            merchant_id,
+            invoice_id,
+            subscription_invoice_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn void_unpaid_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SubscriptionInvoice::void_unpaid_by_merchant_id_subscription_id(
+            &conn,
+            merchant_id,
+            subscription_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for MockDb {
+    async fn insert_subscription_plan(
+        &self,
+        _subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_plans_by_merchant_id_profile_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _is_active: Option<bool>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+        _subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription_price(
+        &self,
+        _subscription_price: storage::SubscriptionPriceNew,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_price_by_merchant_id_price_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _price_id: &str,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_prices_by_merchant_id_plan_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _plan_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionPrice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription(
+        &self,
+        _subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscriptions_by_merchant_id_profile_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _customer_id: Option<&common_utils::id_type::CustomerId>,
+        _status: Option<storage_enums::SubscriptionStatus>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_uncanceled_subscription_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription_invoice(
+        &self,
+        _subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _invoice_id: &str,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _billing_reason: storage_enums::InvoiceBillingReason,
+        _period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _invoice_id: &str,
+        _subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn void_unpaid_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        subscription_plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .insert_subscription_plan(subscription_plan)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_plan_by_merchant_id_plan_id(merchant_id, plan_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        is_active: Option<bool>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_plans_by_merchant_id_profile_id(
+                merchant_id,
+                profile_id,
+                is_active,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_plan_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+        subscription_plan_update: storage::SubscriptionPlanUpdate,
+    ) -> CustomResult<storage::SubscriptionPlan, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_plan_by_merchant_id_plan_id(
+                merchant_id,
+                plan_id,
+                subscription_plan_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_price(
+        &self,
+        subscription_price: storage::SubscriptionPriceNew,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        self.diesel_store
+            .insert_subscription_price(subscription_price)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_price_by_merchant_id_price_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        price_id: &str,
+    ) -> CustomResult<storage::SubscriptionPrice, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_price_by_merchant_id_price_id(merchant_id, price_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_prices_by_merchant_id_plan_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        plan_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionPrice>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_prices_by_merchant_id_plan_id(merchant_id, plan_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription(
+        &self,
+        subscription: storage::SubscriptionNew,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store.insert_subscription(subscription).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_by_merchant_id_subscription_id(merchant_id, subscription_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscriptions_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        customer_id: Option<&common_utils::id_type::CustomerId>,
+        status: Option<storage_enums::SubscriptionStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::Subscription>, errors::StorageError> {
+        self.diesel_store
+            .list_subscriptions_by_merchant_id_profile_id(
+                merchant_id,
+                profile_id,
+                customer_id,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+                subscription_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_uncanceled_subscription_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        subscription_update: storage::SubscriptionUpdate,
+    ) -> CustomResult<storage::Subscription, errors::StorageError> {
+        self.diesel_store
+            .update_uncanceled_subscription_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+                subscription_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_invoice(
+        &self,
+        subscription_invoice: storage::SubscriptionInvoiceNew,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .insert_subscription_invoice(subscription_invoice)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_invoice_by_merchant_id_invoice_id(merchant_id, invoice_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        billing_reason: storage_enums::InvoiceBillingReason,
+        period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .find_subscription_invoice_by_merchant_id_subscription_id_period_start(
+                merchant_id,
+                subscription_id,
+                billing_reason,
+                period_start,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        self.diesel_store
+            .list_subscription_invoices_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_subscription_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+        subscription_invoice_update: storage::SubscriptionInvoiceUpdate,
+    ) -> CustomResult<storage::SubscriptionInvoice, errors::StorageError> {
+        self.diesel_store
+            .update_subscription_invoice_by_merchant_id_invoice_id(
+                merchant_id,
+                invoice_id,
+                subscription_invoice_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn void_unpaid_subscription_invoices_by_merchant_id_subscription_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        subscription_id: &str,
+    ) -> CustomResult<Vec<storage::SubscriptionInvoice>, errors::StorageError> {
+        self.diesel_store
+            .void_unpaid_subscription_invoices_by_merchant_id_subscription_id(
+                merchant_id,
+                subscription_id,
+            )
+            .await
+    }
+}
@@ -72,6 +72,21 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    #[cfg(feature = "v1")]
+    Subscription {
+        subscription_id: String,
+        content: Value,
+    },
+    #[cfg(feature = "v1")]
+    Invoice {
+        subscription_id: String,
+        invoice_id: String,
+        content: Value,
+    },
+    PayoutBatch {
+        batch_id: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -111,6 +126,27 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::SubscriptionDetails(subscription_payload) => {
+                Some(OutgoingWebhookEventContent::Subscription {
+                    subscription_id: subscription_payload.subscription_id.clone(),
+                    content: masking::masked_serialize(&subscription_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::InvoiceDetails(invoice_payload) => Some(OutgoingWebhookEventContent::Invoice {
+                subscription_id: invoice_payload.subscription_id.clone(),
+                invoice_id: invoice_payload.invoice_id.clone(),
+                content: masking::masked_serialize(&invoice_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
+            #[cfg(feature = "payouts")]
+            Self::PayoutBatchDetails(payout_batch_payload) => {
+                Some(OutgoingWebhookEventContent::PayoutBatch {
+                    batch_id: payout_batch_payload.batch_id.clone(),
+                    content: masking::masked_serialize(&payout_batch_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
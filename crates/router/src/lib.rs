@@ -59,7 +59,11 @@ pub mod headers {
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
     pub const NONCE: &str = "nonce";
+    pub const RATE_LIMIT_LIMIT: &str = "ratelimit-limit";
+    pub const RATE_LIMIT_REMAINING: &str = "ratelimit-remaining";
+    pub const RATE_LIMIT_RESET: &str = "ratelimit-reset";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
     pub const USER_AGENT: &str = "User-Agent";
@@ -75,9 +79,11 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_PREVIOUS: &str = "X-Webhook-Signature-512-Previous";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
+    pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE_PREVIOUS: &str = "Stripe-Signature-Previous";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
     pub const X_CLIENT_VERSION: &str = "X-Client-Version";
     pub const X_CLIENT_SOURCE: &str = "X-Client-Source";
@@ -169,6 +175,7 @@ pub fn mk_app(
             server_app = server_app
                 .service(routes::Refunds::server(state.clone()))
                 .service(routes::Mandates::server(state.clone()))
+                .service(routes::Subscriptions::server(state.clone()))
                 .service(routes::Authentication::server(state.clone()));
         }
     }
@@ -204,7 +211,8 @@ pub fn mk_app(
                 .service(routes::Verify::server(state.clone()))
                 .service(routes::Analytics::server(state.clone()))
                 .service(routes::WebhookEvents::server(state.clone()))
-                .service(routes::FeatureMatrix::server(state.clone()));
+                .service(routes::FeatureMatrix::server(state.clone()))
+                .service(routes::ProcessTracker::server(state.clone()));
         }
 
         #[cfg(feature = "v2")]
@@ -237,6 +245,11 @@ pub fn mk_app(
         server_app = server_app.service(routes::Recon::server(state.clone()));
     }
 
+    #[cfg(all(feature = "olap", feature = "frm", feature = "v1"))]
+    {
+        server_app = server_app.service(routes::FrmReviews::server(state.clone()));
+    }
+
     server_app = server_app.service(routes::Cache::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state.clone()));
 
@@ -380,6 +393,7 @@ pub fn get_application_builder(
         ))
         .wrap(middleware::default_response_headers())
         .wrap(middleware::RequestId)
+        .wrap(middleware::RateLimitHeaders)
         .wrap(cors::cors(cors))
         // this middleware works only for Http1.1 requests
         .wrap(middleware::Http400RequestDetailsLogger)
@@ -481,3 +481,89 @@ where
         })
     }
 }
+
+/// Middleware to include the rate limit state of a request in the `RateLimit-*` response headers,
+/// along with the `Retry-After` header for requests rejected for exceeding their rate limit.
+pub struct RateLimitHeaders;
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RateLimitHeaders
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = RateLimitHeadersMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(RateLimitHeadersMiddleware { service }))
+    }
+}
+
+pub struct RateLimitHeadersMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for RateLimitHeadersMiddleware<S>
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let response_fut = self.service.call(req);
+
+        Box::pin(async move {
+            let mut response = response_fut.await?;
+
+            // The rate limit state is recorded by the route handler, once the request has been
+            // authenticated
+            let rate_limit_status = response
+                .request()
+                .extensions()
+                .get::<crate::services::rate_limit::RateLimitStatus>()
+                .cloned();
+
+            if let Some(rate_limit_status) = rate_limit_status {
+                let response_headers = response.headers_mut();
+                response_headers.insert(
+                    http::header::HeaderName::from_static(headers::RATE_LIMIT_LIMIT),
+                    http::HeaderValue::from(rate_limit_status.limit),
+                );
+                response_headers.insert(
+                    http::header::HeaderName::from_static(headers::RATE_LIMIT_REMAINING),
+                    http::HeaderValue::from(rate_limit_status.remaining),
+                );
+                response_headers.insert(
+                    http::header::HeaderName::from_static(headers::RATE_LIMIT_RESET),
+                    http::HeaderValue::from(rate_limit_status.reset_in_seconds),
+                );
+                if rate_limit_status.is_exceeded {
+                    response_headers.insert(
+                        http::header::RETRY_AFTER,
+                        http::HeaderValue::from(rate_limit_status.reset_in_seconds),
+                    );
+                }
+            }
+
+            Ok(response)
+        })
+    }
+}
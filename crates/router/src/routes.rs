@@ -8,6 +8,8 @@ pub mod authentication;
 pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod circuit_breaker;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
@@ -50,6 +52,8 @@ pub mod recon;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod subscriptions;
 pub mod three_ds_decision_rule;
 pub mod tokenization;
 #[cfg(feature = "olap")]
@@ -61,6 +65,8 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
+#[cfg(all(feature = "olap", feature = "v1"))]
 pub mod webhook_events;
 pub mod webhooks;
 
@@ -77,6 +83,8 @@ pub mod proxy;
 
 #[cfg(feature = "dummy_connector")]
 pub use self::app::DummyConnector;
+#[cfg(all(feature = "olap", feature = "frm", feature = "v1"))]
+pub use self::app::FrmReviews;
 #[cfg(feature = "v2")]
 pub use self::app::PaymentMethodSession;
 #[cfg(all(feature = "oltp", feature = "v2"))]
@@ -90,7 +98,7 @@ pub use self::app::{
     ConnectorOnboarding, Customers, Disputes, EphemeralKey, FeatureMatrix, Files, Forex, Gsm,
     Health, Hypersense, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink,
     PaymentMethods, Payments, Poll, ProcessTracker, Profile, ProfileAcquirer, ProfileNew, Refunds,
-    Relay, RelayWebhooks, SessionState, ThreeDsDecisionRule, User, Webhooks,
+    Relay, RelayWebhooks, SessionState, Subscriptions, ThreeDsDecisionRule, User, Webhooks,
 };
 #[cfg(feature = "olap")]
 pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
@@ -24,6 +24,8 @@ use hyperswitch_interfaces::{
 };
 use router_env::tracing_actix_web::RequestId;
 use scheduler::SchedulerInterface;
+#[cfg(feature = "dynamic_routing")]
+use storage_impl::redis::kv_store::RedisConnInterface;
 use storage_impl::{config::TenantConfig, redis::RedisStore, MockDb};
 use tokio::sync::oneshot;
 
@@ -63,11 +65,13 @@ use super::{
     relay, user, user_role,
 };
 #[cfg(feature = "v1")]
-use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
+use super::{
+    apple_pay_certificates_migration, blocklist, payment_link, webhook_endpoints, webhook_events,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers, payments};
 #[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
-use super::{mandates::*, refunds::*};
+use super::{mandates::*, refunds::*, subscriptions as subscription_routes};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 #[cfg(feature = "olap")]
@@ -82,7 +86,7 @@ use crate::routes::cards_info::{
 };
 #[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::feature_matrix;
-#[cfg(all(feature = "frm", feature = "oltp"))]
+#[cfg(all(feature = "frm", any(feature = "oltp", feature = "olap")))]
 use crate::routes::fraud_check as frm_routes;
 #[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::profile_acquirer;
@@ -122,6 +126,7 @@ pub struct SessionState {
     #[cfg(feature = "olap")]
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub encryption_client: Arc<dyn EncryptionManagementInterface>,
     pub request_id: Option<RequestId>,
     pub base_url: String,
     pub tenant: Tenant,
@@ -408,6 +413,12 @@ impl AppState {
             let theme_storage_client = conf.theme.storage.get_file_storage_client().await;
             let crm_client = conf.crm.get_crm_client().await;
 
+            #[cfg(feature = "dynamic_routing")]
+            let grpc_client = conf
+                .grpc_client
+                .get_grpc_client_interface(cache_store.get_redis_conn().ok())
+                .await;
+            #[cfg(not(feature = "dynamic_routing"))]
             let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
             let infra_component_values = Self::process_env_mappings(conf.infra_values.clone());
             Self {
@@ -514,6 +525,7 @@ impl AppState {
             #[cfg(feature = "olap")]
             pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
             file_storage_client: self.file_storage_client.clone(),
+            encryption_client: self.encryption_client.clone(),
             request_id: self.request_id,
             base_url: tenant_conf.base_url.clone(),
             tenant: tenant_conf.clone(),
@@ -937,6 +949,11 @@ impl Routing {
                     routing::migrate_routing_rules_for_profile(state, req, query)
                 },
             )))
+            .service(
+                web::resource("/simulate")
+                    .route(web::post().to(routing::routing_simulate_algorithm)),
+            )
+            .service(web::resource("/dsl/parse").route(web::post().to(routing::routing_parse_dsl)))
             .service(
                 web::resource("/deactivate").route(web::post().to(|state, req, payload| {
                     routing::routing_unlink_config(state, req, payload, None)
@@ -1087,6 +1104,10 @@ impl Routing {
                 web::resource("/{algorithm_id}")
                     .route(web::get().to(routing::routing_retrieve_config)),
             )
+            .service(
+                web::resource("/{algorithm_id}/dsl")
+                    .route(web::get().to(routing::routing_retrieve_dsl)),
+            )
             .service(
                 web::resource("/{algorithm_id}/activate").route(web::post().to(
                     |state, req, payload, path| {
@@ -1276,9 +1297,18 @@ impl Payouts {
                 .service(
                     web::resource("/profile/filter")
                         .route(web::post().to(payouts_list_available_filters_for_profile)),
-                );
+                )
+                .service(web::resource("/batches/list").route(web::get().to(payout_batch_list)));
         }
         route = route
+            .service(web::resource("/batches").route(web::post().to(payout_batch_create)))
+            .service(
+                web::resource("/batches/{batch_id}").route(web::get().to(payout_batch_retrieve)),
+            )
+            .service(
+                web::resource("/batches/{batch_id}/rows")
+                    .route(web::get().to(payout_batch_item_list)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
@@ -1477,6 +1507,19 @@ impl Recon {
                 web::resource("/verify_token")
                     .route(web::get().to(recon_routes::verify_recon_token)),
             )
+            .service(
+                web::resource("/settlement_files")
+                    .route(web::post().to(recon_routes::create_recon_job)),
+            )
+            .service(web::resource("/jobs").route(web::get().to(recon_routes::list_recon_jobs)))
+            .service(
+                web::resource("/jobs/{job_id}")
+                    .route(web::get().to(recon_routes::retrieve_recon_job)),
+            )
+            .service(
+                web::resource("/jobs/{job_id}/mismatches")
+                    .route(web::get().to(recon_routes::list_recon_mismatches)),
+            )
     }
 }
 
@@ -1501,6 +1544,37 @@ impl Hypersense {
     }
 }
 
+#[cfg(all(feature = "olap", feature = "frm", feature = "v1"))]
+pub struct FrmReviews;
+
+#[cfg(all(feature = "olap", feature = "frm", feature = "v1"))]
+impl FrmReviews {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/frm/reviews")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(frm_routes::frm_review_list)))
+            .service(
+                web::resource("/{review_id}").route(web::get().to(frm_routes::frm_review_retrieve)),
+            )
+            .service(
+                web::resource("/{review_id}/assign")
+                    .route(web::post().to(frm_routes::frm_review_assign)),
+            )
+            .service(
+                web::resource("/{review_id}/approve")
+                    .route(web::post().to(frm_routes::frm_review_approve)),
+            )
+            .service(
+                web::resource("/{review_id}/reject")
+                    .route(web::post().to(frm_routes::frm_review_reject)),
+            )
+            .service(
+                web::resource("/{review_id}/notes")
+                    .route(web::post().to(frm_routes::frm_review_add_note)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Blocklist;
 
@@ -1668,6 +1742,16 @@ impl MerchantConnectorAccount {
                         .route(web::get().to(connector_retrieve))
                         .route(web::post().to(connector_update))
                         .route(web::delete().to(connector_delete)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/circuit_breakers")
+                        .route(web::get().to(super::circuit_breaker::list_circuit_breakers)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/circuit_breaker/reset",
+                    )
+                    .route(web::post().to(super::circuit_breaker::reset_circuit_breaker)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1725,6 +1809,54 @@ impl Mandates {
     }
 }
 
+pub struct Subscriptions;
+
+#[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
+impl Subscriptions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/subscriptions")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("").route(web::post().to(subscription_routes::subscription_create)),
+            )
+            .service(
+                web::resource("/list").route(web::get().to(subscription_routes::subscription_list)),
+            )
+            .service(
+                web::resource("/plans")
+                    .route(web::post().to(subscription_routes::subscription_plan_create))
+                    .route(web::get().to(subscription_routes::subscription_plan_list)),
+            )
+            .service(
+                web::resource("/plans/{plan_id}")
+                    .route(web::get().to(subscription_routes::subscription_plan_retrieve))
+                    .route(web::post().to(subscription_routes::subscription_plan_update)),
+            )
+            .service(
+                web::resource("/plans/{plan_id}/prices")
+                    .route(web::post().to(subscription_routes::subscription_price_create))
+                    .route(web::get().to(subscription_routes::subscription_price_list)),
+            )
+            .service(
+                web::resource("/invoices/{invoice_id}")
+                    .route(web::get().to(subscription_routes::invoice_retrieve)),
+            )
+            .service(
+                web::resource("/{subscription_id}")
+                    .route(web::get().to(subscription_routes::subscription_retrieve))
+                    .route(web::post().to(subscription_routes::subscription_update)),
+            )
+            .service(
+                web::resource("/{subscription_id}/cancel")
+                    .route(web::post().to(subscription_routes::subscription_cancel)),
+            )
+            .service(
+                web::resource("/{subscription_id}/invoices")
+                    .route(web::get().to(subscription_routes::subscription_invoice_list)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(all(feature = "oltp", feature = "v1"))]
@@ -2158,24 +2290,50 @@ impl Profile {
             );
         }
 
-        route = route.service(
-            web::scope("/{profile_id}")
-                .service(
-                    web::resource("")
-                        .route(web::get().to(profiles::profile_retrieve))
-                        .route(web::post().to(profiles::profile_update))
-                        .route(web::delete().to(profiles::profile_delete)),
-                )
-                .service(
-                    web::resource("/toggle_extended_card_info")
-                        .route(web::post().to(profiles::toggle_extended_card_info)),
-                )
-                .service(
-                    web::resource("/toggle_connector_agnostic_mit")
-                        .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
-                ),
+        let profile_scope = web::scope("/{profile_id}")
+            .service(
+                web::resource("")
+                    .route(web::get().to(profiles::profile_retrieve))
+                    .route(web::post().to(profiles::profile_update))
+                    .route(web::delete().to(profiles::profile_delete)),
+            )
+            .service(
+                web::resource("/toggle_extended_card_info")
+                    .route(web::post().to(profiles::toggle_extended_card_info)),
+            )
+            .service(
+                web::resource("/toggle_connector_agnostic_mit")
+                    .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+            )
+            .service(
+                web::resource("/rotate_webhook_secret")
+                    .route(web::post().to(profiles::rotate_webhook_secret)),
+            )
+            .service(
+                web::scope("/webhook_endpoints")
+                    .service(
+                        web::resource("")
+                            .route(web::post().to(webhook_endpoints::webhook_endpoint_create))
+                            .route(web::get().to(webhook_endpoints::webhook_endpoint_list)),
+                    )
+                    .service(
+                        web::resource("/{endpoint_id}")
+                            .route(web::get().to(webhook_endpoints::webhook_endpoint_retrieve))
+                            .route(web::post().to(webhook_endpoints::webhook_endpoint_update))
+                            .route(web::delete().to(webhook_endpoints::webhook_endpoint_delete)),
+                    ),
+            );
+
+        #[cfg(feature = "frm")]
+        let profile_scope = profile_scope.service(
+            web::resource("/fraud_risk_engine")
+                .route(web::put().to(frm_routes::fraud_risk_engine_upsert))
+                .route(web::get().to(frm_routes::fraud_risk_engine_retrieve))
+                .route(web::delete().to(frm_routes::fraud_risk_engine_delete)),
         );
 
+        route = route.service(profile_scope);
+
         route
     }
 }
@@ -2637,6 +2795,21 @@ impl WebhookEvents {
                     .service(web::resource("").route(
                         web::post().to(webhook_events::list_initial_webhook_delivery_attempts),
                     ))
+                    .service(
+                        web::scope("/replay")
+                            .service(
+                                web::resource("")
+                                    .route(web::post().to(webhook_events::create_webhook_replay)),
+                            )
+                            .service(
+                                web::resource("/{replay_id}")
+                                    .route(web::get().to(webhook_events::retrieve_webhook_replay)),
+                            )
+                            .service(
+                                web::resource("/{replay_id}/cancel")
+                                    .route(web::post().to(webhook_events::cancel_webhook_replay)),
+                            ),
+                    )
                     .service(
                         web::scope("/{event_id}")
                             .service(web::resource("attempts").route(
@@ -2665,6 +2838,28 @@ impl FeatureMatrix {
 #[cfg(feature = "olap")]
 pub struct ProcessTracker;
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker;
+        web::scope("/process_tracker")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(process_tracker::list_processes)))
+            .service(
+                web::resource("/bulk")
+                    .route(web::post().to(process_tracker::bulk_update_processes)),
+            )
+            .service(
+                web::resource("/{process_tracker_id}")
+                    .route(web::get().to(process_tracker::retrieve_process)),
+            )
+            .service(
+                web::resource("/{process_tracker_id}/{action}")
+                    .route(web::post().to(process_tracker::update_process)),
+            )
+    }
+}
+
 #[cfg(all(feature = "olap", feature = "v2"))]
 impl ProcessTracker {
     pub fn server(state: AppState) -> Scope {
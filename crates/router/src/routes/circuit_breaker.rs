@@ -0,0 +1,72 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, circuit_breaker},
+    services::{api, authentication as auth},
+    types::api::admin,
+};
+
+/// Circuit Breaker - List
+///
+/// List the circuit breakers of the enabled connector accounts of a merchant
+#[instrument(skip_all, fields(flow = ?Flow::CircuitBreakerList))]
+pub async fn list_circuit_breakers(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::CircuitBreakerList;
+    let payload = admin::MerchantId {
+        merchant_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| circuit_breaker::list_circuit_breakers(state, req.merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Circuit Breaker - Reset
+///
+/// Close the circuit of a connector account, discarding the outcomes counted so far
+#[instrument(skip_all, fields(flow = ?Flow::CircuitBreakerReset))]
+pub async fn reset_circuit_breaker(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::CircuitBreakerReset;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id,
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            circuit_breaker::reset_circuit_breaker(
+                state,
+                req.merchant_id,
+                req.merchant_connector_id,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
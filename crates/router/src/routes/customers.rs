@@ -3,6 +3,8 @@ use common_utils::id_type;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::idempotency;
 use crate::{
     core::{api_locking, customers::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -49,16 +51,31 @@ pub async fn customers_create(
     json_payload: web::Json<customers::CustomerRequest>,
 ) -> HttpResponse {
     let flow = Flow::CustomersCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_context = match idempotency::IdempotencyContext::from_request(
+        req.headers(),
+        flow.clone(),
+        &payload,
+    ) {
+        Ok(idempotency_context) => idempotency_context,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_id = auth.merchant_account.get_id().clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            create_customer(state, merchant_context, req, None)
+            idempotency::execute_idempotently(
+                state.clone(),
+                merchant_id,
+                idempotency_context.clone(),
+                create_customer(state, merchant_context, req, None),
+            )
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
@@ -64,13 +64,29 @@ pub async fn get_create_file_request(
         Err(errors::ApiErrorResponse::MissingFile)
             .attach_printable("Missing / Invalid file in the request")?
     }
-    // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
-        .parse::<mime::Mime>()
-        .change_context(errors::ApiErrorResponse::MissingFileContentType)
-        .attach_printable("File content type error")?;
+    let file_type = match purpose {
+        // Get file mime type using 'infer'
+        files::FilePurpose::DisputeEvidence => {
+            let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
+            kind.mime_type()
+                .parse::<mime::Mime>()
+                .change_context(errors::ApiErrorResponse::MissingFileContentType)
+                .attach_printable("File content type error")?
+        }
+        // Text files have no magic number to be detected from, so the mime type is derived from
+        // the extension of the file name instead
+        files::FilePurpose::PayoutBatch => match file_name
+            .as_deref()
+            .and_then(|name| name.rsplit_once('.'))
+            .map(|(_, extension)| extension.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("csv") => mime::TEXT_CSV,
+            Some("json") => mime::APPLICATION_JSON,
+            _ => Err(errors::ApiErrorResponse::MissingFileContentType)
+                .attach_printable("Payout batch file name should have a csv or json extension")?,
+        },
+    };
     Ok(CreateFileRequest {
         file,
         file_name,
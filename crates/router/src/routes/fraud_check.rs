@@ -1,6 +1,20 @@
 use actix_web::{web, HttpRequest, HttpResponse};
+#[cfg(feature = "v1")]
+use api_models::fraud_risk_engine::{FraudRiskEngineConfig, FraudRiskEngineConfigRequestInternal};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use api_models::frm_review::{
+    FrmReviewAssignRequest, FrmReviewAssignRequestInternal, FrmReviewDecisionRequest,
+    FrmReviewDecisionRequestInternal, FrmReviewIdInternal, FrmReviewListConstraints,
+    FrmReviewNoteRequest, FrmReviewNoteRequestInternal,
+};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use common_enums::FrmReviewAction;
 use router_env::Flow;
+#[cfg(feature = "v1")]
+use router_env::{instrument, tracing};
 
+#[cfg(feature = "v1")]
+use crate::services::{authentication as auth, authorization::permissions::Permission};
 use crate::{
     core::{api_locking, fraud_check as frm_core},
     services::{self, api},
@@ -34,3 +48,361 @@ pub async fn frm_fulfillment(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::FraudRiskEngineUpsert))]
+pub async fn fraud_risk_engine_upsert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<FraudRiskEngineConfig>,
+) -> HttpResponse {
+    let flow = Flow::FraudRiskEngineUpsert;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    let request_internal = FraudRiskEngineConfigRequestInternal {
+        profile_id: profile_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth_data, request_internal, _| {
+            frm_core::risk_engine::upsert_risk_engine_config(
+                state,
+                auth_data.key_store,
+                request_internal.profile_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id,
+                profile_id,
+                required_permission: Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::FraudRiskEngineRetrieve))]
+pub async fn fraud_risk_engine_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::FraudRiskEngineRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth_data, profile_id, _| {
+            frm_core::risk_engine::retrieve_risk_engine_config(
+                state,
+                auth_data.key_store,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id,
+                profile_id,
+                required_permission: Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::FraudRiskEngineDelete))]
+pub async fn fraud_risk_engine_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::FraudRiskEngineDelete;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth_data, profile_id, _| {
+            frm_core::risk_engine::delete_risk_engine_config(state, auth_data.key_store, profile_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id,
+                profile_id,
+                required_permission: Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewList))]
+pub async fn frm_review_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<FrmReviewListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, (auth, _user_id): auth::AuthenticationDataWithUserId, constraints, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            frm_core::manual_review::list_frm_reviews(
+                state,
+                merchant_context,
+                auth.profile_id,
+                constraints,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfilePaymentRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewRetrieve))]
+pub async fn frm_review_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewRetrieve;
+    let request_internal = FrmReviewIdInternal {
+        review_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, (auth, _user_id): auth::AuthenticationDataWithUserId, request_internal, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            frm_core::manual_review::retrieve_frm_review(
+                state,
+                merchant_context,
+                auth.profile_id,
+                request_internal.review_id,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfilePaymentRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewAssign))]
+pub async fn frm_review_assign(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<FrmReviewAssignRequest>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewAssign;
+    let request_internal = FrmReviewAssignRequestInternal {
+        review_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, (auth, _user_id): auth::AuthenticationDataWithUserId, request_internal, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            frm_core::manual_review::assign_frm_review(
+                state,
+                merchant_context,
+                auth.profile_id,
+                request_internal.review_id,
+                request_internal.request,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfilePaymentWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewApprove))]
+pub async fn frm_review_approve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<FrmReviewDecisionRequest>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewApprove;
+    let request_internal = FrmReviewDecisionRequestInternal {
+        review_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state,
+         (auth, user_id): auth::AuthenticationDataWithUserId,
+         request_internal,
+         req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            frm_core::manual_review::decide_frm_review(
+                state,
+                req_state,
+                merchant_context,
+                auth.profile_id,
+                user_id,
+                request_internal.review_id,
+                FrmReviewAction::Approve,
+                request_internal.request,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfilePaymentWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewReject))]
+pub async fn frm_review_reject(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<FrmReviewDecisionRequest>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewReject;
+    let request_internal = FrmReviewDecisionRequestInternal {
+        review_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state,
+         (auth, user_id): auth::AuthenticationDataWithUserId,
+         request_internal,
+         req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            frm_core::manual_review::decide_frm_review(
+                state,
+                req_state,
+                merchant_context,
+                auth.profile_id,
+                user_id,
+                request_internal.review_id,
+                FrmReviewAction::Reject,
+                request_internal.request,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfilePaymentWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::FrmReviewAddNote))]
+pub async fn frm_review_add_note(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<FrmReviewNoteRequest>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewAddNote;
+    let request_internal = FrmReviewNoteRequestInternal {
+        review_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, request_internal, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            frm_core::manual_review::add_frm_review_note(
+                state,
+                merchant_context,
+                auth.profile_id,
+                user_id,
+                request_internal.review_id,
+                request_internal.request,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfilePaymentWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -48,6 +48,7 @@ pub enum ApiIdentifier {
     ProfileAcquirer,
     ThreeDsDecisionRule,
     GenericTokenization,
+    Subscriptions,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -75,12 +76,18 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingSimulateAlgorithm
+            | Flow::RoutingParseDsl
+            | Flow::RoutingRetrieveDsl
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::ToggleDynamicRouting
             | Flow::UpdateDynamicRoutingConfigs
             | Flow::DecisionManagerUpsertConfig
             | Flow::DecisionEngineRuleMigration
+            | Flow::FraudRiskEngineUpsert
+            | Flow::FraudRiskEngineRetrieve
+            | Flow::FraudRiskEngineDelete
             | Flow::VolumeSplitOnRoutingType => Self::Routing,
 
             Flow::RetrieveForexFlow => Self::Forex,
@@ -94,7 +101,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::CircuitBreakerList
+            | Flow::CircuitBreakerReset => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
@@ -114,6 +123,20 @@ impl From<Flow> for ApiIdentifier {
             Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
             Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
 
+            Flow::SubscriptionPlanCreate
+            | Flow::SubscriptionPlanRetrieve
+            | Flow::SubscriptionPlanUpdate
+            | Flow::SubscriptionPlanList
+            | Flow::SubscriptionPriceCreate
+            | Flow::SubscriptionPriceList
+            | Flow::SubscriptionCreate
+            | Flow::SubscriptionRetrieve
+            | Flow::SubscriptionUpdate
+            | Flow::SubscriptionCancel
+            | Flow::SubscriptionList
+            | Flow::InvoiceList
+            | Flow::InvoiceRetrieve => Self::Subscriptions,
+
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
             | Flow::PaymentMethodsList
@@ -163,7 +186,13 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentStartRedirection
             | Flow::ProxyConfirmIntent
             | Flow::PaymentsRetrieveUsingMerchantReferenceId
-            | Flow::PaymentAttemptsList => Self::Payments,
+            | Flow::PaymentAttemptsList
+            | Flow::FrmReviewList
+            | Flow::FrmReviewRetrieve
+            | Flow::FrmReviewAssign
+            | Flow::FrmReviewApprove
+            | Flow::FrmReviewReject
+            | Flow::FrmReviewAddNote => Self::Payments,
 
             Flow::PayoutsCreate
             | Flow::PayoutsRetrieve
@@ -174,6 +203,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
+            | Flow::PayoutBatchCreate
+            | Flow::PayoutBatchRetrieve
+            | Flow::PayoutBatchList
+            | Flow::PayoutBatchItemList
             | Flow::PayoutLinkInitiate => Self::Payouts,
 
             Flow::RefundsCreate
@@ -192,6 +225,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventReplayCreate
+            | Flow::WebhookEventReplayRetrieve
+            | Flow::WebhookEventReplayCancel
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
             | Flow::RecoveryIncomingWebhookReceive
             | Flow::IncomingNetworkTokenWebhookReceive => Self::Webhooks,
 
@@ -225,7 +266,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::RotateWebhookSecret => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
@@ -326,7 +368,11 @@ impl From<Flow> for ApiIdentifier {
             Flow::ReconMerchantUpdate
             | Flow::ReconTokenRequest
             | Flow::ReconServiceRequest
-            | Flow::ReconVerifyToken => Self::Recon,
+            | Flow::ReconVerifyToken
+            | Flow::ReconJobCreate
+            | Flow::ReconJobRetrieve
+            | Flow::ReconJobList
+            | Flow::ReconMismatchList => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
 
@@ -347,7 +393,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodSessionDeleteSavedPaymentMethod
             | Flow::PaymentMethodSessionUpdate => Self::PaymentMethodSession,
 
-            Flow::RevenueRecoveryRetrieve => Self::ProcessTracker,
+            Flow::RevenueRecoveryRetrieve
+            | Flow::ProcessTrackerList
+            | Flow::ProcessTrackerRetrieve
+            | Flow::ProcessTrackerUpdate
+            | Flow::ProcessTrackerBulkUpdate => Self::ProcessTracker,
 
             Flow::AuthenticationCreate => Self::Authentication,
             Flow::Proxy => Self::Proxy,
@@ -140,3 +140,11 @@ histogram_metric_f64!(CHECK_NETWORK_TOKEN_STATUS_TIME, GLOBAL_METER);
 
 // A counter to indicate allowed payment method types mismatch
 counter_metric!(PAYMENT_METHOD_TYPES_MISCONFIGURATION_METRIC, GLOBAL_METER);
+
+// Rate limiting metrics
+counter_metric!(RATE_LIMIT_EXCEEDED_COUNT, GLOBAL_METER); // Requests rejected for exceeding their rate limit
+counter_metric!(RATE_LIMIT_CHECK_FAILURE_COUNT, GLOBAL_METER); // Requests allowed as the rate limit could not be checked
+
+counter_metric!(CIRCUIT_BREAKER_OPENED_COUNT, GLOBAL_METER); // Circuits of connector accounts opened
+counter_metric!(CIRCUIT_BREAKER_REJECTED_COUNT, GLOBAL_METER); // Connector calls rejected by an open circuit
+counter_metric!(CIRCUIT_BREAKER_CHECK_FAILURE_COUNT, GLOBAL_METER); // Connector calls let through as the circuit breaker could not be checked
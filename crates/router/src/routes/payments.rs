@@ -14,6 +14,7 @@ use crate::{
     self as app,
     core::{
         errors::{self, http_not_implemented},
+        idempotency,
         payments::{self, PaymentRedirectFlow},
     },
     routes::lock_utils,
@@ -37,6 +38,14 @@ pub async fn payments_create(
 ) -> impl Responder {
     let flow = Flow::PaymentsCreate;
     let mut payload = json_payload.into_inner();
+    let idempotency_context = match idempotency::IdempotencyContext::from_request(
+        req.headers(),
+        flow.clone(),
+        &payload,
+    ) {
+        Ok(idempotency_context) => idempotency_context,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
     if let Err(err) = payload
         .validate()
         .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })
@@ -80,18 +89,24 @@ pub async fn payments_create(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_id = auth.merchant_account.get_id().clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            authorize_verify_select::<_>(
-                payments::PaymentCreate,
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                header_payload.clone(),
-                req,
-                api::AuthFlow::Client,
+            idempotency::execute_idempotently(
+                state.clone(),
+                merchant_id,
+                idempotency_context.clone(),
+                authorize_verify_select::<_>(
+                    payments::PaymentCreate,
+                    state,
+                    req_state,
+                    merchant_context,
+                    auth.profile_id,
+                    header_payload.clone(),
+                    req,
+                    api::AuthFlow::Client,
+                ),
             )
         },
         match env::which() {
@@ -847,6 +862,14 @@ pub async fn payments_confirm(
 ) -> impl Responder {
     let flow = Flow::PaymentsConfirm;
     let mut payload = json_payload.into_inner();
+    let idempotency_context = match idempotency::IdempotencyContext::from_request(
+        req.headers(),
+        flow.clone(),
+        &(&*path, &payload),
+    ) {
+        Ok(idempotency_context) => idempotency_context,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
     if let Err(err) = payload
         .validate()
         .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })
@@ -893,18 +916,24 @@ pub async fn payments_confirm(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_id = auth.merchant_account.get_id().clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            authorize_verify_select::<_>(
-                payments::PaymentConfirm,
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                header_payload.clone(),
-                req,
-                auth_flow,
+            idempotency::execute_idempotently(
+                state.clone(),
+                merchant_id,
+                idempotency_context.clone(),
+                authorize_verify_select::<_>(
+                    payments::PaymentConfirm,
+                    state,
+                    req_state,
+                    merchant_context,
+                    auth.profile_id,
+                    header_payload.clone(),
+                    req,
+                    auth_flow,
+                ),
             )
         },
         &*auth_type,
@@ -929,6 +958,14 @@ pub async fn payments_capture(
         payment_id,
         ..json_payload.into_inner()
     };
+    let idempotency_context = match idempotency::IdempotencyContext::from_request(
+        req.headers(),
+        flow.clone(),
+        &payload,
+    ) {
+        Ok(idempotency_context) => idempotency_context,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -938,27 +975,33 @@ pub async fn payments_capture(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_id = auth.merchant_account.get_id().clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            payments::payments_core::<
-                api_types::Capture,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::Capture>,
-            >(
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                payments::PaymentCapture,
-                payload,
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
+            idempotency::execute_idempotently(
+                state.clone(),
+                merchant_id,
+                idempotency_context.clone(),
+                payments::payments_core::<
+                    api_types::Capture,
+                    payment_types::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    payments::PaymentData<api_types::Capture>,
+                >(
+                    state,
+                    req_state,
+                    merchant_context,
+                    auth.profile_id,
+                    payments::PaymentCapture,
+                    payload,
+                    api::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    HeaderPayload::default(),
+                ),
             )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
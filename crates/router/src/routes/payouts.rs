@@ -7,7 +7,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, payouts::*},
+    core::{api_locking, idempotency, payouts::*},
     services::{
         api,
         authentication::{self as auth},
@@ -24,17 +24,32 @@ pub async fn payouts_create(
     json_payload: web::Json<payout_types::PayoutCreateRequest>,
 ) -> HttpResponse {
     let flow = Flow::PayoutsCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_context = match idempotency::IdempotencyContext::from_request(
+        req.headers(),
+        flow.clone(),
+        &payload,
+    ) {
+        Ok(idempotency_context) => idempotency_context,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_id = auth.merchant_account.get_id().clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            payouts_create_core(state, merchant_context, req)
+            idempotency::execute_idempotently(
+                state.clone(),
+                merchant_id,
+                idempotency_context.clone(),
+                payouts_create_core(state, merchant_context, req),
+            )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
@@ -456,6 +471,160 @@ pub async fn payouts_list_available_filters_for_profile(
     .await
 }
 
+/// Payout Batches - Create
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchCreate))]
+pub async fn payout_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::payouts::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchCreate;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::create_payout_batch(state, merchant_context, auth.profile_id, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payout Batches - Retrieve
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchRetrieve))]
+pub async fn payout_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchRetrieve;
+    let payload = api_models::payouts::PayoutBatchIdInternal {
+        batch_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::retrieve_payout_batch(state, merchant_context, auth.profile_id, req.batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payout Batches - List
+#[cfg(all(feature = "olap", feature = "payouts", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchList))]
+pub async fn payout_batch_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<api_models::payouts::PayoutBatchListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchList;
+    let payload = query_params.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::list_payout_batches(state, merchant_context, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payout Batches - List rows
+#[cfg(all(feature = "v1", feature = "payouts"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchItemList))]
+pub async fn payout_batch_item_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_params: web::Query<api_models::payouts::PayoutBatchItemListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchItemList;
+    let payload = api_models::payouts::PayoutBatchItemListRequestInternal {
+        batch_id: path.into_inner(),
+        constraints: query_params.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            batch::list_payout_batch_items(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.batch_id,
+                req.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
@@ -1,2 +1,111 @@
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
+
+#[cfg(feature = "v1")]
+use actix_web::{web, HttpRequest, HttpResponse};
+#[cfg(feature = "v1")]
+use api_models::{enums, process_tracker as process_tracker_api};
+#[cfg(feature = "v1")]
+use router_env::{instrument, tracing, Flow};
+
+#[cfg(feature = "v1")]
+use super::AppState;
+#[cfg(feature = "v1")]
+use crate::{
+    core::{api_locking, process_tracker},
+    services::{api, authentication as auth},
+};
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerList))]
+pub async fn list_processes(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<process_tracker_api::ProcessTrackerListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints, _| process_tracker::list_processes(state, constraints),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetrieve))]
+pub async fn retrieve_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerRetrieve;
+    let payload = process_tracker_api::ProcessTrackerId {
+        process_tracker_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, request, _| process_tracker::retrieve_process(state, request.process_tracker_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerUpdate))]
+pub async fn update_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, enums::ProcessTrackerAction)>,
+    json_payload: web::Json<process_tracker_api::ProcessTrackerActionRequest>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerUpdate;
+    let (process_tracker_id, action) = path.into_inner();
+    let payload = process_tracker_api::ProcessTrackerUpdateRequest {
+        process_tracker_id,
+        action,
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, request, _| process_tracker::update_process_with_action(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerBulkUpdate))]
+pub async fn bulk_update_processes(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<process_tracker_api::ProcessTrackerBulkActionRequest>,
+) -> HttpResponse {
+    let flow = Flow::ProcessTrackerBulkUpdate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| process_tracker::bulk_update_processes_with_action(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,8 +1,12 @@
+#[cfg(feature = "v1")]
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, HttpResponse};
 use api_models::recon as recon_api;
 use router_env::Flow;
 
 use super::AppState;
+#[cfg(feature = "v1")]
+use crate::{core::recon::settlement, types::domain};
 use crate::{
     core::{api_locking, recon},
     services::{api, authentication, authorization::permissions::Permission},
@@ -77,3 +81,120 @@ pub async fn verify_recon_token(state: web::Data<AppState>, http_req: HttpReques
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+pub async fn create_recon_job(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<settlement::ReconSettlementFileForm>,
+) -> HttpResponse {
+    let flow = Flow::ReconJobCreate;
+    let payload = recon_api::ReconJobCreateRequest::from(form);
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::create_recon_job(state, merchant_context, req)
+        },
+        &authentication::JWTAuth {
+            permission: Permission::MerchantReconUploadWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn list_recon_jobs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<recon_api::ReconJobListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::ReconJobList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth, constraints, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::list_recon_jobs(state, merchant_context, constraints)
+        },
+        &authentication::JWTAuth {
+            permission: Permission::MerchantReconFilesRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_recon_job(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ReconJobRetrieve;
+    let payload = recon_api::ReconJobId {
+        job_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::retrieve_recon_job(state, merchant_context, req.job_id)
+        },
+        &authentication::JWTAuth {
+            permission: Permission::MerchantReconFilesRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn list_recon_mismatches(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query: web::Query<recon_api::ReconMismatchListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::ReconMismatchList;
+    let payload = recon_api::ReconMismatchListRequest {
+        job_id: path.into_inner(),
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            settlement::list_recon_mismatches(state, merchant_context, req)
+        },
+        &authentication::JWTAuth {
+            permission: Permission::MerchantReconReportsRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -4,6 +4,8 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 #[cfg(feature = "v1")]
+use crate::core::idempotency;
+#[cfg(feature = "v1")]
 use crate::core::refunds::*;
 #[cfg(feature = "v2")]
 use crate::core::refunds_v2::*;
@@ -56,16 +58,31 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_context = match idempotency::IdempotencyContext::from_request(
+        req.headers(),
+        flow.clone(),
+        &payload,
+    ) {
+        Ok(idempotency_context) => idempotency_context,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_id = auth.merchant_account.get_id().clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            refund_create_core(state, merchant_context, auth.profile_id, req)
+            idempotency::execute_idempotently(
+                state.clone(),
+                merchant_id,
+                idempotency_context.clone(),
+                refund_create_core(state, merchant_context, auth.profile_id, req),
+            )
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
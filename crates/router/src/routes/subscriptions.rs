@@ -0,0 +1,592 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::subscriptions;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, subscriptions as subscriptions_core},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::domain,
+};
+
+/// Subscription Plans - Create
+///
+/// Create a subscription plan, which customers can subscribe to through its prices
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanCreate))]
+pub async fn subscription_plan_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscriptions::SubscriptionPlanCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanCreate;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::create_subscription_plan(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Plans - List
+///
+/// List the subscription plans of a business profile
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanList))]
+pub async fn subscription_plan_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<subscriptions::SubscriptionPlanListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanList;
+    let payload = query_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::list_subscription_plans(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Plans - Retrieve
+///
+/// Retrieve a subscription plan
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanRetrieve))]
+pub async fn subscription_plan_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanRetrieve;
+    let payload = subscriptions::SubscriptionPlanIdInternal {
+        plan_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::retrieve_subscription_plan(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.plan_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Plans - Update
+///
+/// Update the name, the description, the metadata or the status of a subscription plan
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPlanUpdate))]
+pub async fn subscription_plan_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscriptions::SubscriptionPlanUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPlanUpdate;
+    let payload = subscriptions::SubscriptionPlanUpdateRequestInternal {
+        plan_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::update_subscription_plan(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.plan_id,
+                req.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Prices - Create
+///
+/// Create a price of a subscription plan
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPriceCreate))]
+pub async fn subscription_price_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscriptions::SubscriptionPriceCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPriceCreate;
+    let payload = subscriptions::SubscriptionPriceCreateRequestInternal {
+        plan_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::create_subscription_price(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.plan_id,
+                req.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscription Prices - List
+///
+/// List the prices of a subscription plan
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionPriceList))]
+pub async fn subscription_price_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionPriceList;
+    let payload = subscriptions::SubscriptionPlanIdInternal {
+        plan_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::list_subscription_prices(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.plan_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Create
+///
+/// Subscribe a customer to a price, charged through a mandate of the customer on each billing cycle
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCreate))]
+pub async fn subscription_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscriptions::SubscriptionCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionCreate;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::create_subscription(state, merchant_context, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - List
+///
+/// List the subscriptions of a business profile
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionList))]
+pub async fn subscription_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<subscriptions::SubscriptionListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionList;
+    let payload = query_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::list_subscriptions(state, merchant_context, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Retrieve
+///
+/// Retrieve a subscription
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionRetrieve))]
+pub async fn subscription_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionRetrieve;
+    let payload = subscriptions::SubscriptionIdInternal {
+        subscription_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::retrieve_subscription(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.subscription_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Update
+///
+/// Update the price, the mandate or the metadata of a subscription
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionUpdate))]
+pub async fn subscription_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscriptions::SubscriptionUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionUpdate;
+    let payload = subscriptions::SubscriptionUpdateRequestInternal {
+        subscription_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::update_subscription(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.subscription_id,
+                req.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Subscriptions - Cancel
+///
+/// Cancel a subscription, either immediately or at the end of its current period
+#[instrument(skip_all, fields(flow = ?Flow::SubscriptionCancel))]
+pub async fn subscription_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<subscriptions::SubscriptionCancelRequest>,
+) -> HttpResponse {
+    let flow = Flow::SubscriptionCancel;
+    let payload = subscriptions::SubscriptionCancelRequestInternal {
+        subscription_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::cancel_subscription(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.subscription_id,
+                req.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - List
+///
+/// List the invoices of a subscription
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceList))]
+pub async fn subscription_invoice_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<subscriptions::InvoiceListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::InvoiceList;
+    let payload = subscriptions::InvoiceListRequestInternal {
+        subscription_id: path.into_inner(),
+        constraints: query_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::list_subscription_invoices(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.subscription_id,
+                req.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Retrieve
+///
+/// Retrieve an invoice of a subscription
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceRetrieve))]
+pub async fn invoice_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::InvoiceRetrieve;
+    let payload = subscriptions::InvoiceIdInternal {
+        invoice_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            subscriptions_core::retrieve_invoice(
+                state,
+                merchant_context,
+                auth.profile_id,
+                req.invoice_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
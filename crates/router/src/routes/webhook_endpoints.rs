@@ -0,0 +1,230 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, webhooks::webhook_endpoints},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::api::webhook_endpoints::{
+        WebhookEndpointCreateRequest, WebhookEndpointCreateRequestInternal,
+        WebhookEndpointIdInternal, WebhookEndpointUpdateRequest,
+        WebhookEndpointUpdateRequestInternal,
+    },
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointCreate))]
+pub async fn webhook_endpoint_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+    json_payload: web::Json<WebhookEndpointCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    let request_internal = WebhookEndpointCreateRequestInternal {
+        profile_id: profile_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth_data, request_internal, _| {
+            webhook_endpoints::create_webhook_endpoint(
+                state,
+                auth_data.key_store,
+                request_internal.profile_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id,
+                profile_id,
+                required_permission: Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointRetrieve))]
+pub async fn webhook_endpoint_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointRetrieve;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    let request_internal = WebhookEndpointIdInternal {
+        profile_id: profile_id.clone(),
+        endpoint_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth_data, request_internal, _| {
+            webhook_endpoints::retrieve_webhook_endpoint(
+                state,
+                auth_data.key_store,
+                request_internal.profile_id,
+                request_internal.endpoint_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id,
+                profile_id,
+                required_permission: Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointList))]
+pub async fn webhook_endpoint_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth_data, profile_id, _| {
+            webhook_endpoints::list_webhook_endpoints(state, auth_data.key_store, profile_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id,
+                profile_id,
+                required_permission: Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointUpdate))]
+pub async fn webhook_endpoint_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+    json_payload: web::Json<WebhookEndpointUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointUpdate;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    let request_internal = WebhookEndpointUpdateRequestInternal {
+        profile_id: profile_id.clone(),
+        endpoint_id,
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth_data, request_internal, _| {
+            webhook_endpoints::update_webhook_endpoint(
+                state,
+                auth_data.key_store,
+                request_internal.profile_id,
+                request_internal.endpoint_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id,
+                profile_id,
+                required_permission: Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointDelete))]
+pub async fn webhook_endpoint_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::ProfileId,
+        String,
+    )>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointDelete;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    let request_internal = WebhookEndpointIdInternal {
+        profile_id: profile_id.clone(),
+        endpoint_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, auth_data, request_internal, _| {
+            webhook_endpoints::delete_webhook_endpoint(
+                state,
+                auth_data.key_store,
+                request_internal.profile_id,
+                request_internal.endpoint_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id,
+                profile_id,
+                required_permission: Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
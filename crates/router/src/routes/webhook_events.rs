@@ -11,7 +11,8 @@ use crate::{
     },
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryRetryRequestInternal, WebhookReplayIdInternal, WebhookReplayRequest,
+        WebhookReplayRequestInternal,
     },
 };
 
@@ -174,3 +175,124 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplayCreate))]
+#[cfg(feature = "v1")]
+pub async fn create_webhook_replay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<WebhookReplayRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplayCreate;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookReplayRequestInternal {
+        merchant_id: merchant_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::create_webhook_replay(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplayRetrieve))]
+#[cfg(feature = "v1")]
+pub async fn retrieve_webhook_replay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplayRetrieve;
+    let (merchant_id, replay_id) = path.into_inner();
+
+    let request_internal = WebhookReplayIdInternal {
+        merchant_id: merchant_id.clone(),
+        replay_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::retrieve_webhook_replay(
+                state,
+                request_internal.merchant_id,
+                request_internal.replay_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplayCancel))]
+#[cfg(feature = "v1")]
+pub async fn cancel_webhook_replay(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplayCancel;
+    let (merchant_id, replay_id) = path.into_inner();
+
+    let request_internal = WebhookReplayIdInternal {
+        merchant_id: merchant_id.clone(),
+        replay_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::cancel_webhook_replay(
+                state,
+                request_internal.merchant_id,
+                request_internal.replay_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
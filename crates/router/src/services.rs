@@ -10,6 +10,7 @@ pub mod jwt;
 pub mod kafka;
 pub mod logger;
 pub mod pm_auth;
+pub mod rate_limit;
 
 pub mod card_testing_guard;
 #[cfg(feature = "olap")]
@@ -1,3 +1,4 @@
+pub mod circuit_breaker;
 pub mod client;
 pub mod generic_link_response;
 pub mod request;
@@ -52,8 +53,10 @@ use serde_json::json;
 use tera::{Context, Error as TeraError, Tera};
 
 use super::{
-    authentication::AuthenticateAndFetch,
+    authentication::{AuthenticateAndFetch, AuthenticationType},
+    authorization::api_key_scopes,
     connector_integration_interface::BoxedConnectorIntegrationInterface,
+    rate_limit,
 };
 use crate::{
     configs::Settings,
@@ -240,6 +243,37 @@ where
                     };
                     let request_url = request.url.clone();
                     let request_method = request.method;
+                    let circuit_breaker_merchant_connector_id =
+                        req.merchant_connector_id.as_ref().filter(|_| {
+                            state.conf.circuit_breaker.enabled
+                                && circuit_breaker::is_gated_flow::<T>()
+                        });
+                    if let Some(merchant_connector_id) = circuit_breaker_merchant_connector_id {
+                        if !circuit_breaker::try_acquire(
+                            state,
+                            &req.merchant_id,
+                            merchant_connector_id,
+                            &req.connector,
+                        )
+                        .await
+                        {
+                            router_data.response = Err(ErrorResponse {
+                                code: consts::CIRCUIT_BREAKER_OPEN_ERROR_CODE.to_string(),
+                                message: consts::CIRCUIT_BREAKER_OPEN_ERROR_MESSAGE.to_string(),
+                                reason: Some(
+                                    consts::CIRCUIT_BREAKER_OPEN_ERROR_MESSAGE.to_string(),
+                                ),
+                                status_code: 503,
+                                attempt_status: None,
+                                connector_transaction_id: None,
+                                network_advice_code: None,
+                                network_decline_code: None,
+                                network_error_message: None,
+                            });
+                            router_data.connector_http_status_code = Some(503);
+                            return Ok(router_data);
+                        }
+                    }
                     let current_time = Instant::now();
                     let response =
                         call_connector_api(state, request, "execute_connector_processing_step")
@@ -253,6 +287,20 @@ where
                                 .map_or_else(|value| value.status_code, |value| value.status_code)
                         })
                         .unwrap_or_default();
+                    if let Some(merchant_connector_id) = circuit_breaker_merchant_connector_id {
+                        circuit_breaker::record_outcome(
+                            state,
+                            &req.merchant_id,
+                            merchant_connector_id,
+                            &req.connector,
+                            circuit_breaker::is_failed_call(
+                                &state.conf.circuit_breaker,
+                                response.as_ref().ok().map(|_| status_code),
+                                external_latency,
+                            ),
+                        )
+                        .await;
+                    }
                     let mut connector_event = ConnectorEvent::new(
                         state.tenant.tenant_id.clone(),
                         req.connector.clone(),
@@ -623,9 +671,29 @@ where
         tenant_id.get_string_repr().to_string(),
     ));
 
+    rate_limit::check_failed_authentication_limit(&session_state, request)
+        .await
+        .switch()?;
+
     // Currently auth failures are not recorded as API events
-    let (auth_out, auth_type) = api_auth
+    let auth_result = api_auth
         .authenticate_and_fetch(request.headers(), &session_state)
+        .await;
+    if auth_result.is_err() {
+        rate_limit::record_failed_authentication(&session_state, request).await;
+    }
+    let (auth_out, auth_type) = auth_result.switch()?;
+
+    if let AuthenticationType::ApiKey {
+        scopes: Some(scopes),
+        ..
+    } = &auth_type
+    {
+        api_key_scopes::check_api_key_scopes(scopes, &flow.to_string(), request.method())
+            .switch()?;
+    }
+
+    rate_limit::check_rate_limit(&session_state, &flow.to_string(), &auth_type, request)
         .await
         .switch()?;
 
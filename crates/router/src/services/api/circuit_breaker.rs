@@ -0,0 +1,514 @@
+use std::{any::TypeId, collections::HashMap};
+
+use api_models::{circuit_breaker::CircuitBreakerState, routing::RoutableConnectorChoice};
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::CircuitBreakerSettings,
+    core::errors::{self, RouterResult},
+    routes::{metrics, SessionState},
+    types::api,
+};
+
+pub const CIRCUIT_BREAKER_PREFIX: &str = "CIRCUIT_BREAKER";
+
+/// Decides whether a connector call can go through the circuit. An open circuit rejects the calls
+/// until it has been open for the open duration, after which it turns half-open and lets a limited
+/// number of trial calls through. Trial calls which did not record their outcome within the open
+/// duration are given up on, so that the circuit cannot get stuck in the half-open state.
+///
+/// KEYS: circuit breaker hash of the merchant connector account
+/// ARGV: current unix timestamp, open_duration_in_seconds, half_open_max_requests
+/// Returns 1 if the call is allowed, 0 otherwise
+const ACQUIRE_SCRIPT: &str = r#"
+local state = redis.call('HGET', KEYS[1], 'state')
+if not state or state == 'closed' then
+    return 1
+end
+
+local now = tonumber(ARGV[1])
+local open_duration = tonumber(ARGV[2])
+local half_open_max = tonumber(ARGV[3])
+
+if state == 'open' then
+    local opened_at = tonumber(redis.call('HGET', KEYS[1], 'opened_at') or '0')
+    if now - opened_at < open_duration then
+        return 0
+    end
+    redis.call('HSET', KEYS[1], 'state', 'half_open', 'half_open_at', now, 'half_open_calls', 1, 'half_open_successes', 0)
+    return 1
+end
+
+local half_open_at = tonumber(redis.call('HGET', KEYS[1], 'half_open_at') or '0')
+if now - half_open_at >= open_duration then
+    redis.call('HSET', KEYS[1], 'half_open_at', now, 'half_open_calls', 1, 'half_open_successes', 0)
+    return 1
+end
+
+local half_open_calls = tonumber(redis.call('HGET', KEYS[1], 'half_open_calls') or '0')
+if half_open_calls >= half_open_max then
+    return 0
+end
+redis.call('HINCRBY', KEYS[1], 'half_open_calls', 1)
+return 1
+"#;
+
+/// Records the outcome of a connector call. In the closed state, the circuit is opened once the
+/// window has seen the minimum number of calls and the error rate has reached the threshold. In
+/// the half-open state, a failed trial call opens the circuit again, and the circuit is closed
+/// once enough trial calls have succeeded.
+///
+/// KEYS: circuit breaker hash of the merchant connector account
+/// ARGV: current unix timestamp, whether the call failed (0 or 1), window_in_seconds,
+///       minimum_requests, error_rate_threshold_percentage, half_open_max_requests, ttl_in_seconds
+/// Returns 1 if the outcome opened the circuit, 0 otherwise
+const RECORD_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local is_failure = tonumber(ARGV[2])
+local window = tonumber(ARGV[3])
+local minimum_requests = tonumber(ARGV[4])
+local threshold = tonumber(ARGV[5])
+local half_open_max = tonumber(ARGV[6])
+local ttl = tonumber(ARGV[7])
+
+local state = redis.call('HGET', KEYS[1], 'state') or 'closed'
+if state == 'open' then
+    return 0
+end
+
+if state == 'half_open' then
+    if is_failure == 1 then
+        redis.call('HSET', KEYS[1], 'state', 'open', 'opened_at', now)
+        redis.call('HDEL', KEYS[1], 'half_open_at', 'half_open_calls', 'half_open_successes')
+        redis.call('EXPIRE', KEYS[1], ttl)
+        return 1
+    end
+    local successes = redis.call('HINCRBY', KEYS[1], 'half_open_successes', 1)
+    if successes >= half_open_max then
+        redis.call('DEL', KEYS[1])
+    end
+    return 0
+end
+
+local window_start = tonumber(redis.call('HGET', KEYS[1], 'window_start') or '0')
+if now - window_start >= window then
+    redis.call('HSET', KEYS[1], 'state', 'closed', 'window_start', now, 'total', 0, 'failures', 0)
+end
+
+local total = redis.call('HINCRBY', KEYS[1], 'total', 1)
+local failures = redis.call('HINCRBY', KEYS[1], 'failures', is_failure)
+redis.call('EXPIRE', KEYS[1], ttl)
+
+if total >= minimum_requests and failures * 100 >= threshold * total then
+    redis.call('HSET', KEYS[1], 'state', 'open', 'opened_at', now)
+    return 1
+end
+return 0
+"#;
+
+/// The state of the circuit breaker of a merchant connector account, as stored in Redis
+#[derive(Debug, PartialEq)]
+pub struct CircuitBreakerStatus {
+    pub state: CircuitBreakerState,
+    pub total_requests: u32,
+    pub failed_requests: u32,
+    /// Unix timestamp at which the circuit was last opened
+    pub opened_at: Option<i64>,
+}
+
+impl CircuitBreakerStatus {
+    /// Builds the status from the fields of the circuit breaker hash. An open circuit which has
+    /// been open for the open duration is reported as half-open, as the next call is let through.
+    fn from_hash_fields(
+        fields: &HashMap<String, String>,
+        settings: &CircuitBreakerSettings,
+        now: i64,
+    ) -> Self {
+        let get_number = |field: &str| {
+            fields
+                .get(field)
+                .and_then(|value| value.parse::<i64>().ok())
+        };
+        let opened_at = get_number("opened_at");
+
+        let state = match fields.get("state").map(String::as_str) {
+            Some("open")
+                if opened_at.is_some_and(|opened_at| {
+                    now.saturating_sub(opened_at) < i64::from(settings.open_duration_in_seconds)
+                }) =>
+            {
+                CircuitBreakerState::Open
+            }
+            Some("open") | Some("half_open") => CircuitBreakerState::HalfOpen,
+            _ => CircuitBreakerState::Closed,
+        };
+
+        Self {
+            state,
+            total_requests: get_number("total")
+                .and_then(|total| u32::try_from(total).ok())
+                .unwrap_or_default(),
+            failed_requests: get_number("failures")
+                .and_then(|failures| u32::try_from(failures).ok())
+                .unwrap_or_default(),
+            opened_at: match state {
+                CircuitBreakerState::Closed => None,
+                CircuitBreakerState::Open | CircuitBreakerState::HalfOpen => opened_at,
+            },
+        }
+    }
+}
+
+fn get_circuit_breaker_key(
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> String {
+    format!(
+        "{CIRCUIT_BREAKER_PREFIX}:{}:{}",
+        merchant_id.get_string_repr(),
+        merchant_connector_id.get_string_repr()
+    )
+}
+
+/// Returns whether calls of the flow go through the circuit breaker. Only the flows which start a
+/// new payment or payout are gated, so that the payments already made with the connector can
+/// still be synced, captured, voided and refunded while its circuit is open.
+pub fn is_gated_flow<F: 'static>() -> bool {
+    let flow = TypeId::of::<F>();
+    #[cfg(feature = "payouts")]
+    if flow == TypeId::of::<api::PoCreate>() {
+        return true;
+    }
+    flow == TypeId::of::<api::Authorize>()
+}
+
+/// Returns whether the outcome of a connector call counts as a failure for the circuit breaker.
+/// Calls which did not get a response from the connector, server errors, and calls slower than
+/// the latency threshold are counted as failures. Client errors are caused by the request, and do
+/// not indicate that the connector is unhealthy.
+pub fn is_failed_call(
+    settings: &CircuitBreakerSettings,
+    response_status_code: Option<u16>,
+    latency_in_milliseconds: u128,
+) -> bool {
+    response_status_code.map_or(true, |status_code| status_code >= 500)
+        || latency_in_milliseconds > u128::from(settings.latency_threshold_in_milliseconds)
+}
+
+/// Returns whether a call to the connector account can go through its circuit. Calls are allowed if
+/// the circuit breaker could not be checked, so that an unavailable Redis does not take down the
+/// connector calls.
+#[instrument(skip_all)]
+pub async fn try_acquire(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    connector: &str,
+) -> bool {
+    let settings = &state.conf.circuit_breaker;
+    let result = async {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?;
+
+        redis_conn
+            .evaluate_redis_script::<_, i64>(
+                ACQUIRE_SCRIPT,
+                vec![redis_conn
+                    .add_prefix(&get_circuit_breaker_key(merchant_id, merchant_connector_id))],
+                vec![
+                    date_time::now_unix_timestamp().to_string(),
+                    settings.open_duration_in_seconds.to_string(),
+                    settings.half_open_max_requests.to_string(),
+                ],
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to acquire the circuit breaker")
+    }
+    .await;
+
+    match result {
+        Ok(1) => true,
+        Ok(_) => {
+            metrics::CIRCUIT_BREAKER_REJECTED_COUNT.add(
+                1,
+                router_env::metric_attributes!(
+                    ("connector", connector.to_owned()),
+                    ("merchant_id", merchant_id.clone()),
+                ),
+            );
+            false
+        }
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to check the circuit breaker of the connector"
+            );
+            metrics::CIRCUIT_BREAKER_CHECK_FAILURE_COUNT.add(
+                1,
+                router_env::metric_attributes!(("connector", connector.to_owned())),
+            );
+            true
+        }
+    }
+}
+
+/// Records the outcome of a call to the connector account which went through its circuit
+#[instrument(skip_all)]
+pub async fn record_outcome(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    connector: &str,
+    is_failure: bool,
+) {
+    let settings = &state.conf.circuit_breaker;
+    let result = async {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?;
+
+        let ttl_in_seconds = settings
+            .window_in_seconds
+            .saturating_add(settings.open_duration_in_seconds)
+            .saturating_mul(2);
+
+        redis_conn
+            .evaluate_redis_script::<_, i64>(
+                RECORD_SCRIPT,
+                vec![redis_conn
+                    .add_prefix(&get_circuit_breaker_key(merchant_id, merchant_connector_id))],
+                vec![
+                    date_time::now_unix_timestamp().to_string(),
+                    u8::from(is_failure).to_string(),
+                    settings.window_in_seconds.to_string(),
+                    settings.minimum_requests.to_string(),
+                    settings.error_rate_threshold_percentage.to_string(),
+                    settings.half_open_max_requests.to_string(),
+                    ttl_in_seconds.to_string(),
+                ],
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the outcome of the connector call")
+    }
+    .await;
+
+    match result {
+        Ok(1) => {
+            logger::warn!(
+                connector,
+                merchant_connector_id = merchant_connector_id.get_string_repr(),
+                "Circuit breaker of the connector account opened"
+            );
+            metrics::CIRCUIT_BREAKER_OPENED_COUNT.add(
+                1,
+                router_env::metric_attributes!(
+                    ("connector", connector.to_owned()),
+                    ("merchant_id", merchant_id.clone()),
+                ),
+            );
+        }
+        Ok(_) => {}
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to record the outcome of the connector call in the circuit breaker"
+            );
+            metrics::CIRCUIT_BREAKER_CHECK_FAILURE_COUNT.add(
+                1,
+                router_env::metric_attributes!(("connector", connector.to_owned())),
+            );
+        }
+    }
+}
+
+/// Returns the state of the circuit breaker of the connector account, without counting a call
+pub async fn get_status(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<CircuitBreakerStatus> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let fields = redis_conn
+        .get_hash_fields::<HashMap<String, String>>(
+            &get_circuit_breaker_key(merchant_id, merchant_connector_id)
+                .as_str()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the circuit breaker of the connector account")?;
+
+    Ok(CircuitBreakerStatus::from_hash_fields(
+        &fields,
+        &state.conf.circuit_breaker,
+        date_time::now_unix_timestamp(),
+    ))
+}
+
+/// Closes the circuit of the connector account, discarding the outcomes counted so far
+pub async fn reset(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .delete_key(
+            &get_circuit_breaker_key(merchant_id, merchant_connector_id)
+                .as_str()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reset the circuit breaker of the connector account")?;
+
+    Ok(())
+}
+
+/// Removes the connectors whose circuit is open from the routing choices, so that the payment
+/// falls through to the next choice instead of waiting on a failing connector. The choices are
+/// left untouched if the circuit of every choice is open.
+#[instrument(skip_all)]
+pub async fn filter_connectors_with_open_circuit(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> Vec<RoutableConnectorChoice> {
+    if !state.conf.circuit_breaker.enabled {
+        return connectors;
+    }
+
+    let mut available_connectors = Vec::with_capacity(connectors.len());
+    for connector in &connectors {
+        let is_open = match &connector.merchant_connector_id {
+            Some(merchant_connector_id) => {
+                match get_status(state, merchant_id, merchant_connector_id).await {
+                    Ok(status) => status.state == CircuitBreakerState::Open,
+                    Err(error) => {
+                        logger::error!(?error, "Failed to check the circuit breaker for routing");
+                        false
+                    }
+                }
+            }
+            None => false,
+        };
+
+        if is_open {
+            logger::info!(
+                connector = %connector.connector,
+                "Skipping connector with an open circuit during routing"
+            );
+        } else {
+            available_connectors.push(connector.clone());
+        }
+    }
+
+    if available_connectors.is_empty() {
+        logger::warn!("Circuit of every routable connector is open, not skipping any connector");
+        connectors
+    } else {
+        available_connectors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_fields(fields: &[(&str, &str)]) -> HashMap<String, String> {
+        fields
+            .iter()
+            .map(|(field, value)| (field.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_only_flows_starting_a_payment_are_gated() {
+        assert!(is_gated_flow::<api::Authorize>());
+        assert!(!is_gated_flow::<api::PSync>());
+        assert!(!is_gated_flow::<api::Capture>());
+        assert!(!is_gated_flow::<api::Void>());
+        assert!(!is_gated_flow::<api::Execute>());
+        assert!(!is_gated_flow::<api::RSync>());
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_payout_create_is_gated() {
+        assert!(is_gated_flow::<api::PoCreate>());
+        assert!(!is_gated_flow::<api::PoSync>());
+    }
+
+    #[test]
+    fn test_status_of_missing_circuit_breaker() {
+        let status = CircuitBreakerStatus::from_hash_fields(
+            &HashMap::new(),
+            &CircuitBreakerSettings::default(),
+            1000,
+        );
+
+        assert_eq!(
+            status,
+            CircuitBreakerStatus {
+                state: CircuitBreakerState::Closed,
+                total_requests: 0,
+                failed_requests: 0,
+                opened_at: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_open_circuit_turns_half_open_after_open_duration() {
+        let settings = CircuitBreakerSettings::default();
+        let fields = hash_fields(&[
+            ("state", "open"),
+            ("opened_at", "1000"),
+            ("total", "20"),
+            ("failures", "15"),
+        ]);
+
+        let status = CircuitBreakerStatus::from_hash_fields(&fields, &settings, 1010);
+        assert_eq!(status.state, CircuitBreakerState::Open);
+        assert_eq!(status.failed_requests, 15);
+        assert_eq!(status.opened_at, Some(1000));
+
+        let now = 1000 + i64::from(settings.open_duration_in_seconds);
+        let status = CircuitBreakerStatus::from_hash_fields(&fields, &settings, now);
+        assert_eq!(status.state, CircuitBreakerState::HalfOpen);
+    }
+
+    #[test]
+    fn test_failed_calls() {
+        let settings = CircuitBreakerSettings::default();
+
+        assert!(is_failed_call(&settings, None, 100));
+        assert!(is_failed_call(&settings, Some(503), 100));
+        assert!(!is_failed_call(&settings, Some(200), 100));
+        assert!(!is_failed_call(&settings, Some(400), 100));
+        assert!(is_failed_call(
+            &settings,
+            Some(200),
+            u128::from(settings.latency_threshold_in_milliseconds) + 1
+        ));
+    }
+}
@@ -26,7 +26,7 @@ use self::blacklist::BlackList;
 use self::detached::ExtractedPayload;
 #[cfg(feature = "partial-auth")]
 use self::detached::GetAuthType;
-use super::authorization::{self, permissions::Permission};
+use super::authorization::{self, api_key_scopes::ApiKeyScopes, permissions::Permission};
 #[cfg(feature = "olap")]
 use super::jwt;
 #[cfg(feature = "olap")]
@@ -116,6 +116,8 @@ pub enum AuthenticationType {
     ApiKey {
         merchant_id: id_type::MerchantId,
         key_id: id_type::ApiKeyId,
+        #[serde(skip)]
+        scopes: Option<ApiKeyScopes>,
     },
     AdminApiKey,
     AdminApiAuthWithMerchantId {
@@ -172,10 +174,7 @@ impl events::EventInfo for AuthenticationType {
 impl AuthenticationType {
     pub fn get_merchant_id(&self) -> Option<&id_type::MerchantId> {
         match self {
-            Self::ApiKey {
-                merchant_id,
-                key_id: _,
-            }
+            Self::ApiKey { merchant_id, .. }
             | Self::AdminApiAuthWithMerchantId { merchant_id }
             | Self::MerchantId { merchant_id }
             | Self::PublishableKey { merchant_id }
@@ -457,6 +456,11 @@ where
                 .attach_printable("API key has expired");
         }
 
+        authorization::api_key_scopes::get_permitted_profile_id(
+            &stored_api_key,
+            Some(profile_id.clone()),
+        )?;
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -525,6 +529,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
+                scopes: ApiKeyScopes::from_api_key(&stored_api_key),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -575,6 +580,17 @@ where
                 .attach_printable("API key has expired");
         }
 
+        let profile_id =
+            get_header_value_by_key(headers::X_PROFILE_ID.to_string(), request_headers)?
+                .map(id_type::ProfileId::from_str)
+                .transpose()
+                .change_context(errors::ValidationError::IncorrectValueProvided {
+                    field_name: "X-Profile-Id",
+                })
+                .change_context(errors::ApiErrorResponse::Unauthorized)?;
+        let profile_id =
+            authorization::api_key_scopes::get_permitted_profile_id(&stored_api_key, profile_id)?;
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -588,15 +604,6 @@ where
             .change_context(errors::ApiErrorResponse::Unauthorized)
             .attach_printable("Failed to fetch merchant key store for the merchant id")?;
 
-        let profile_id =
-            get_header_value_by_key(headers::X_PROFILE_ID.to_string(), request_headers)?
-                .map(id_type::ProfileId::from_str)
-                .transpose()
-                .change_context(errors::ValidationError::IncorrectValueProvided {
-                    field_name: "X-Profile-Id",
-                })
-                .change_context(errors::ApiErrorResponse::Unauthorized)?;
-
         let merchant = state
             .store()
             .find_merchant_account_by_merchant_id(
@@ -645,6 +652,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
+                scopes: ApiKeyScopes::from_api_key(&stored_api_key),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -759,6 +767,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        authorization::api_key_scopes::reject_profile_restricted_api_key(&stored_api_key)?;
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -801,6 +811,7 @@ where
             }),
             AuthenticationType::ApiKey {
                 merchant_id: merchant_account.get_id().clone(),
+                scopes: ApiKeyScopes::from_api_key(&stored_api_key),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -848,6 +859,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        authorization::api_key_scopes::reject_profile_restricted_api_key(&stored_api_key)?;
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -895,6 +908,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
+                scopes: ApiKeyScopes::from_api_key(&stored_api_key),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -998,6 +1012,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        authorization::api_key_scopes::reject_profile_restricted_api_key(&stored_api_key)?;
+
         let (_, platform_merchant) =
             Self::fetch_key_store_and_account(&stored_api_key.merchant_id, state).await?;
 
@@ -1025,6 +1041,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: platform_merchant.get_id().clone(),
+                scopes: ApiKeyScopes::from_api_key(&stored_api_key),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -1099,6 +1116,18 @@ where
                     merchant_id: Some(merchant_id),
                     key_id: Some(key_id),
                 } => {
+                    let stored_api_key = state
+                        .store()
+                        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to retrieve API key")?
+                        .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+                        .attach_printable("API key from the detached payload not found")?;
+                    let profile_id = authorization::api_key_scopes::get_permitted_profile_id(
+                        &stored_api_key,
+                        profile_id,
+                    )?;
                     let auth = construct_authentication_data(
                         state,
                         &merchant_id,
@@ -1110,6 +1139,7 @@ where
                         auth.clone(),
                         AuthenticationType::ApiKey {
                             merchant_id: auth.merchant_account.get_id().clone(),
+                            scopes: ApiKeyScopes::from_api_key(&stored_api_key),
                             key_id,
                         },
                     ))
@@ -1720,6 +1750,8 @@ where
                 .attach_printable("API key has expired");
         }
 
+        authorization::api_key_scopes::reject_profile_restricted_api_key(&stored_api_key)?;
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -1762,6 +1794,7 @@ where
                     organization_id: merchant.organization_id,
                 }),
                 AuthenticationType::ApiKey {
+                    scopes: ApiKeyScopes::from_api_key(&stored_api_key),
                     merchant_id: stored_api_key.merchant_id,
                     key_id: stored_api_key.key_id,
                 },
@@ -1865,6 +1898,10 @@ where
                 .attach_printable("API key has expired");
         }
 
+        // The request is authenticated as the merchant from the route, which does not carry the
+        // restrictions of the API key
+        authorization::api_key_scopes::reject_restricted_api_key(&stored_api_key)?;
+
         if fallback_merchant_ids
             .merchant_ids
             .contains(&stored_api_key.merchant_id)
@@ -2491,6 +2528,11 @@ where
                 .attach_printable("API key has expired");
         }
 
+        authorization::api_key_scopes::get_permitted_profile_id(
+            &stored_api_key,
+            Some(profile_id.clone()),
+        )?;
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -2559,6 +2601,7 @@ where
             auth.clone(),
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
+                scopes: ApiKeyScopes::from_api_key(&stored_api_key),
                 key_id: stored_api_key.key_id,
             },
         ))
@@ -4702,3 +4745,134 @@ impl ExternalToken {
         )?)
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use std::{borrow::Cow, sync::Arc};
+
+    use actix_web::http::header::{HeaderName, HeaderValue};
+    use tokio::sync::oneshot;
+
+    use super::*;
+    use crate::{
+        db::api_keys::ApiKeyInterface,
+        routes::{
+            self,
+            app::{settings::Settings, StorageImpl},
+            SessionState,
+        },
+        services,
+    };
+
+    async fn get_session_state() -> SessionState {
+        let conf = Settings::new().expect("invalid settings");
+        let tx: oneshot::Sender<()> = oneshot::channel().0;
+        let app_state = Box::pin(routes::AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        Arc::new(app_state)
+            .get_session_state(
+                &id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+                None,
+                || {},
+            )
+            .unwrap()
+    }
+
+    /// Stores an API key restricted to `profile_1`, and returns the request headers using it
+    async fn get_headers_with_profile_restricted_api_key(
+        state: &SessionState,
+        profile_id: Option<&'static str>,
+    ) -> HeaderMap {
+        let plaintext_api_key = api_keys::PlaintextApiKey::new(64);
+        let hash_key = state.conf.api_keys.get_inner().get_hash_key().unwrap();
+        state
+            .store
+            .insert_api_key(storage::ApiKeyNew {
+                key_id: id_type::ApiKeyId::try_from(Cow::from("key_1")).unwrap(),
+                merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap(),
+                name: "Key 1".into(),
+                description: None,
+                hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+                prefix: plaintext_api_key.prefix(),
+                created_at: date_time::now(),
+                expires_at: None,
+                last_used: None,
+                permission_groups: None,
+                resources: None,
+                profile_ids: Some(vec![
+                    id_type::ProfileId::try_from(Cow::from("profile_1")).unwrap()
+                ]),
+            })
+            .await
+            .unwrap();
+
+        let mut request_headers = HeaderMap::new();
+        request_headers.insert(
+            HeaderName::from_static("api-key"),
+            HeaderValue::from_str(plaintext_api_key.peek()).unwrap(),
+        );
+        if let Some(profile_id) = profile_id {
+            request_headers.insert(
+                HeaderName::try_from(headers::X_PROFILE_ID).unwrap(),
+                HeaderValue::from_static(profile_id),
+            );
+        }
+        request_headers
+    }
+
+    fn is_access_forbidden<T>(result: &RouterResult<T>) -> bool {
+        matches!(
+            result
+                .as_ref()
+                .map(|_| ())
+                .map_err(|error| error.current_context()),
+            Err(errors::ApiErrorResponse::AccessForbidden { .. })
+        )
+    }
+
+    #[tokio::test]
+    async fn test_api_key_auth_rejects_profile_not_permitted_for_the_key() {
+        let state = get_session_state().await;
+        let request_headers =
+            get_headers_with_profile_restricted_api_key(&state, Some("profile_2")).await;
+
+        let result = ApiKeyAuth::default()
+            .authenticate_and_fetch(&request_headers, &state)
+            .await;
+        assert!(is_access_forbidden(&result));
+    }
+
+    #[tokio::test]
+    async fn test_platform_auth_rejects_profile_restricted_api_key() {
+        let state = get_session_state().await;
+        let request_headers =
+            get_headers_with_profile_restricted_api_key(&state, Some("profile_1")).await;
+
+        let result: RouterResult<(AuthenticationData, AuthenticationType)> =
+            PlatformOrgAdminAuth::default()
+                .authenticate_and_fetch(&request_headers, &state)
+                .await;
+        assert!(is_access_forbidden(&result));
+
+        let result: RouterResult<(Option<AuthenticationDataWithOrg>, AuthenticationType)> =
+            PlatformOrgAdminAuth::default()
+                .authenticate_and_fetch(&request_headers, &state)
+                .await;
+        assert!(is_access_forbidden(&result));
+
+        let result = PlatformOrgAdminAuthWithMerchantIdFromRoute {
+            merchant_id_from_route: id_type::MerchantId::try_from(Cow::from("merchant_2")).unwrap(),
+            is_admin_auth_allowed: false,
+        }
+        .authenticate_and_fetch(&request_headers, &state)
+        .await;
+        assert!(is_access_forbidden(&result));
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
     routes::app::SessionStateInfo,
 };
 
+pub mod api_key_scopes;
 #[cfg(feature = "olap")]
 pub mod info;
 pub mod permission_groups;
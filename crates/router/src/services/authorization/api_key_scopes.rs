@@ -0,0 +1,227 @@
+use std::str::FromStr;
+
+use actix_web::http::Method;
+use common_enums::{PermissionGroup, PermissionScope, Resource};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::Flow;
+
+use super::permission_groups::PermissionGroupExt;
+use crate::{
+    core::errors::{ApiErrorResponse, RouterResult},
+    routes::lock_utils::ApiIdentifier,
+    types::storage,
+};
+
+/// The permission restrictions configured on an API key.
+///
+/// An API key without any restrictions has access to every API available to the merchant, and
+/// is represented by the absence of this struct.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApiKeyScopes {
+    permission_groups: Option<Vec<PermissionGroup>>,
+    resources: Option<Vec<Resource>>,
+}
+
+impl ApiKeyScopes {
+    pub fn from_api_key(api_key: &storage::ApiKey) -> Option<Self> {
+        (api_key.permission_groups.is_some() || api_key.resources.is_some()).then(|| Self {
+            permission_groups: api_key.permission_groups.clone(),
+            resources: api_key.resources.clone(),
+        })
+    }
+
+    pub fn check_permission_exists(&self, resource: Resource, scope: PermissionScope) -> bool {
+        let is_resource_allowed = self
+            .resources
+            .as_ref()
+            .map_or(true, |resources| resources.contains(&resource));
+
+        let is_scope_allowed = self.permission_groups.as_ref().map_or(true, |groups| {
+            groups
+                .iter()
+                .flat_map(|group| group.accessible_groups())
+                .any(|group| scope <= group.scope() && group.resources().contains(&resource))
+        });
+
+        is_resource_allowed && is_scope_allowed
+    }
+}
+
+/// Checks whether a restricted API key is allowed to call the API identified by `flow`.
+///
+/// The resource is derived from the API the flow belongs to, and the scope from the request
+/// method: `GET` and `HEAD` requests require read access, while all other requests require write
+/// access. Restricted API keys are denied access to APIs which do not belong to any resource, see
+/// [`get_resource_for_api`].
+pub fn check_api_key_scopes(
+    scopes: &ApiKeyScopes,
+    flow: &str,
+    method: &Method,
+) -> RouterResult<()> {
+    let resource = Flow::from_str(flow)
+        .ok()
+        .map(ApiIdentifier::from)
+        .and_then(|api_identifier| get_resource_for_api(&api_identifier));
+
+    let scope = match *method {
+        Method::GET | Method::HEAD => PermissionScope::Read,
+        _ => PermissionScope::Write,
+    };
+
+    resource
+        .filter(|resource| scopes.check_permission_exists(*resource, scope))
+        .map(|_| ())
+        .ok_or(report!(ApiErrorResponse::AccessForbidden {
+            resource: flow.to_string(),
+        }))
+}
+
+/// Validates the profile requested using an API key against the profiles the key is restricted
+/// to, and returns the profile the request must be scoped to.
+///
+/// If the API key is restricted to a single profile, requests without a profile are scoped to
+/// that profile. If the API key is restricted to multiple profiles, a profile must be provided.
+pub fn get_permitted_profile_id(
+    api_key: &storage::ApiKey,
+    profile_id: Option<id_type::ProfileId>,
+) -> RouterResult<Option<id_type::ProfileId>> {
+    let Some(permitted_profile_ids) = api_key.profile_ids.as_ref() else {
+        return Ok(profile_id);
+    };
+
+    match profile_id {
+        Some(profile_id) if permitted_profile_ids.contains(&profile_id) => Ok(Some(profile_id)),
+        Some(profile_id) => Err(report!(ApiErrorResponse::AccessForbidden {
+            resource: profile_id.get_string_repr().to_owned(),
+        }))
+        .attach_printable("API key is not permitted to access the requested profile"),
+        None => match permitted_profile_ids.as_slice() {
+            [permitted_profile_id] => Ok(Some(permitted_profile_id.clone())),
+            _ => Err(report!(ApiErrorResponse::Unauthorized)).attach_printable(
+                "X-Profile-Id header is required for API keys restricted to multiple profiles",
+            ),
+        },
+    }
+}
+
+/// Rejects API keys restricted to profiles on the authentication paths which do not scope the
+/// request to a profile, such as the platform and admin API key fallback paths.
+pub fn reject_profile_restricted_api_key(api_key: &storage::ApiKey) -> RouterResult<()> {
+    match api_key.profile_ids {
+        Some(_) => Err(report!(ApiErrorResponse::AccessForbidden {
+            resource: "profile".to_string(),
+        }))
+        .attach_printable("API key restricted to profiles cannot be used for this request"),
+        None => Ok(()),
+    }
+}
+
+/// Rejects API keys with any restrictions on the authentication paths which do not carry the
+/// restrictions of the key over to the request.
+pub fn reject_restricted_api_key(api_key: &storage::ApiKey) -> RouterResult<()> {
+    reject_profile_restricted_api_key(api_key)?;
+    match ApiKeyScopes::from_api_key(api_key) {
+        Some(_) => Err(report!(ApiErrorResponse::AccessForbidden {
+            resource: "api".to_string(),
+        }))
+        .attach_printable("Restricted API key cannot be used for this request"),
+        None => Ok(()),
+    }
+}
+
+/// Returns the resource an API belongs to. The APIs which do not belong to any resource are only
+/// exposed with the admin API key, JWT authentication or without authentication, and are hence
+/// never called with a restricted API key.
+fn get_resource_for_api(api_identifier: &ApiIdentifier) -> Option<Resource> {
+    match api_identifier {
+        ApiIdentifier::Payments
+        | ApiIdentifier::PaymentLink
+        | ApiIdentifier::CardsInfo
+        | ApiIdentifier::Forex
+        | ApiIdentifier::Poll
+        | ApiIdentifier::Relay
+        | ApiIdentifier::Authentication
+        | ApiIdentifier::Proxy
+        | ApiIdentifier::Subscriptions => Some(Resource::Payment),
+        ApiIdentifier::Refunds => Some(Resource::Refund),
+        ApiIdentifier::Disputes | ApiIdentifier::Files => Some(Resource::Dispute),
+        ApiIdentifier::Mandates => Some(Resource::Mandate),
+        ApiIdentifier::Customers
+        | ApiIdentifier::PaymentMethods
+        | ApiIdentifier::PaymentMethodSession
+        | ApiIdentifier::Ephemeral
+        | ApiIdentifier::CardNetworkTokenization
+        | ApiIdentifier::GenericTokenization => Some(Resource::Customer),
+        ApiIdentifier::Payouts => Some(Resource::Payout),
+        ApiIdentifier::Organization
+        | ApiIdentifier::MerchantAccount
+        | ApiIdentifier::Profile
+        | ApiIdentifier::ProfileAcquirer
+        | ApiIdentifier::Verification
+        | ApiIdentifier::Blocklist => Some(Resource::Account),
+        ApiIdentifier::MerchantConnector | ApiIdentifier::PaymentMethodAuth => {
+            Some(Resource::Connector)
+        }
+        ApiIdentifier::Routing => Some(Resource::Routing),
+        ApiIdentifier::ThreeDsDecisionRule => Some(Resource::ThreeDsDecisionManager),
+        ApiIdentifier::ApiKeys => Some(Resource::ApiKey),
+        ApiIdentifier::Webhooks => Some(Resource::WebhookEvent),
+        ApiIdentifier::Recon => Some(Resource::ReconToken),
+        ApiIdentifier::User | ApiIdentifier::UserRole | ApiIdentifier::Role => Some(Resource::User),
+        ApiIdentifier::ProcessTracker => Some(Resource::RevenueRecovery),
+        ApiIdentifier::Configs
+        | ApiIdentifier::Health
+        | ApiIdentifier::Cache
+        | ApiIdentifier::RustLockerMigration
+        | ApiIdentifier::Gsm
+        | ApiIdentifier::ConnectorOnboarding
+        | ApiIdentifier::ApplePayCertificatesMigration
+        | ApiIdentifier::Documentation
+        | ApiIdentifier::Hypersense => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permission_groups_restrict_scope() {
+        let scopes = ApiKeyScopes {
+            permission_groups: Some(vec![PermissionGroup::OperationsView]),
+            resources: None,
+        };
+
+        assert!(scopes.check_permission_exists(Resource::Payment, PermissionScope::Read));
+        assert!(!scopes.check_permission_exists(Resource::Payment, PermissionScope::Write));
+        assert!(!scopes.check_permission_exists(Resource::ApiKey, PermissionScope::Read));
+    }
+
+    #[test]
+    fn test_resources_restrict_access() {
+        let scopes = ApiKeyScopes {
+            permission_groups: Some(vec![PermissionGroup::OperationsManage]),
+            resources: Some(vec![Resource::Payment, Resource::Customer]),
+        };
+
+        assert!(scopes.check_permission_exists(Resource::Payment, PermissionScope::Write));
+        assert!(scopes.check_permission_exists(Resource::Customer, PermissionScope::Read));
+        assert!(!scopes.check_permission_exists(Resource::Refund, PermissionScope::Write));
+        assert!(!scopes.check_permission_exists(Resource::Payout, PermissionScope::Read));
+    }
+
+    #[test]
+    fn test_api_key_scopes_for_flow() {
+        let scopes = ApiKeyScopes {
+            permission_groups: Some(vec![PermissionGroup::OperationsView]),
+            resources: None,
+        };
+
+        assert!(check_api_key_scopes(&scopes, "PaymentsRetrieve", &Method::GET).is_ok());
+        assert!(check_api_key_scopes(&scopes, "PaymentsCreate", &Method::POST).is_err());
+        assert!(check_api_key_scopes(&scopes, "HealthCheck", &Method::GET).is_err());
+        assert!(check_api_key_scopes(&scopes, "CardsInfo", &Method::GET).is_ok());
+        assert!(check_api_key_scopes(&scopes, "RetrieveForexFlow", &Method::GET).is_ok());
+    }
+}
@@ -0,0 +1,544 @@
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+};
+
+use actix_web::HttpRequest;
+use common_utils::{date_time, ext_traits::StringExt, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::RateLimitSettings,
+    core::errors::{self, RouterResult},
+    routes::{metrics, SessionState},
+    services::authentication::AuthenticationType,
+};
+
+pub const RATE_LIMIT_PREFIX: &str = "RATE_LIMIT";
+
+const DEFAULT_ROUTE_GROUP: &str = "default";
+
+/// Route group under which the failed authentications of a client IP address are counted
+const FAILED_AUTHENTICATION_ROUTE_GROUP: &str = "failed_authentication";
+
+/// Counts the request in the sliding window of its identity, unless the limit has been reached.
+/// The requests of the previous window are weighted by the part of it which overlaps the sliding
+/// window.
+///
+/// KEYS: counter of the current window, counter of the previous window
+/// ARGV: limit, window_in_secs, seconds elapsed in the current window, whether the request is
+/// counted (`1`) or the limit is only checked (`0`)
+/// Returns whether the request is allowed, and the number of requests in the sliding window
+const SLIDING_WINDOW_SCRIPT: &str = r#"
+local limit = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local elapsed = tonumber(ARGV[3])
+local should_count = ARGV[4] == '1'
+
+local current = tonumber(redis.call('GET', KEYS[1]) or '0')
+local previous = tonumber(redis.call('GET', KEYS[2]) or '0')
+local count = math.floor(previous * (window - elapsed) / window) + current
+
+if count >= limit then
+    return {0, count}
+end
+
+if not should_count then
+    return {1, count}
+end
+
+redis.call('INCR', KEYS[1])
+redis.call('EXPIRE', KEYS[1], window * 2)
+return {1, count + 1}
+"#;
+
+/// The rate limit state of a request, used to populate the `RateLimit-*` response headers
+#[derive(Clone, Debug)]
+pub struct RateLimitStatus {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_in_seconds: u32,
+    pub is_exceeded: bool,
+}
+
+/// The identity against which the requests are counted
+#[derive(Debug, PartialEq)]
+enum RateLimitIdentity {
+    ApiKey {
+        merchant_id: id_type::MerchantId,
+        key_id: id_type::ApiKeyId,
+    },
+    PublishableKey {
+        merchant_id: id_type::MerchantId,
+    },
+    Merchant {
+        merchant_id: id_type::MerchantId,
+    },
+    ClientIp(String),
+}
+
+impl RateLimitIdentity {
+    fn from_request(
+        auth_type: &AuthenticationType,
+        request: &HttpRequest,
+        trusted_proxies: &HashSet<IpAddr>,
+    ) -> Option<Self> {
+        match auth_type {
+            AuthenticationType::ApiKey {
+                merchant_id,
+                key_id,
+                ..
+            } => Some(Self::ApiKey {
+                merchant_id: merchant_id.clone(),
+                key_id: key_id.clone(),
+            }),
+            AuthenticationType::PublishableKey { merchant_id } => Some(Self::PublishableKey {
+                merchant_id: merchant_id.clone(),
+            }),
+            // Requests authenticated with the admin API key are made by the operators of the
+            // deployment, and are not rate limited
+            AuthenticationType::AdminApiKey => None,
+            _ => match auth_type.get_merchant_id() {
+                Some(merchant_id) => Some(Self::Merchant {
+                    merchant_id: merchant_id.clone(),
+                }),
+                None => get_client_ip(request, trusted_proxies)
+                    .map(|ip_address| Self::ClientIp(ip_address.to_string())),
+            },
+        }
+    }
+
+    fn get_merchant_id(&self) -> Option<&id_type::MerchantId> {
+        match self {
+            Self::ApiKey { merchant_id, .. }
+            | Self::PublishableKey { merchant_id }
+            | Self::Merchant { merchant_id } => Some(merchant_id),
+            Self::ClientIp(_) => None,
+        }
+    }
+
+    fn get_key_repr(&self) -> String {
+        match self {
+            Self::ApiKey { key_id, .. } => format!("api_key_{}", key_id.get_string_repr()),
+            Self::PublishableKey { merchant_id } => {
+                format!("publishable_key_{}", merchant_id.get_string_repr())
+            }
+            Self::Merchant { merchant_id } => format!("merchant_{}", merchant_id.get_string_repr()),
+            Self::ClientIp(ip_address) => format!("client_ip_{ip_address}"),
+        }
+    }
+}
+
+/// Counts the request against the rate limit of the route group of the flow, and fails with
+/// [`errors::ApiErrorResponse::RateLimitExceeded`] once the limit is reached. The rate limit state
+/// is stored in the extensions of the request, to be added to the response headers.
+///
+/// Requests are allowed if the rate limit could not be checked, so that an unavailable Redis does
+/// not take down the API.
+#[instrument(skip_all)]
+pub async fn check_rate_limit(
+    state: &SessionState,
+    flow: &str,
+    auth_type: &AuthenticationType,
+    request: &HttpRequest,
+) -> RouterResult<()> {
+    let settings = &state.conf.rate_limit;
+    if !settings.enabled {
+        return Ok(());
+    }
+    let Some(identity) =
+        RateLimitIdentity::from_request(auth_type, request, &settings.trusted_proxies)
+    else {
+        return Ok(());
+    };
+
+    let (route_group, configured_limit) = get_route_group(settings, flow);
+    let limit = match identity.get_merchant_id() {
+        Some(merchant_id) => {
+            get_merchant_limit(state, merchant_id, route_group, configured_limit).await
+        }
+        None => settings.client_ip_limit,
+    };
+
+    let status = match count_request(
+        state,
+        &identity,
+        route_group,
+        limit,
+        settings.window_in_seconds,
+        true,
+    )
+    .await
+    {
+        Ok(status) => status,
+        Err(error) => {
+            logger::error!(?error, "Failed to check the rate limit of the request");
+            metrics::RATE_LIMIT_CHECK_FAILURE_COUNT
+                .add(1, router_env::metric_attributes!(("flow", flow.to_owned())));
+            return Ok(());
+        }
+    };
+
+    let is_exceeded = status.is_exceeded;
+    request.extensions_mut().insert(status);
+
+    if is_exceeded {
+        metrics::RATE_LIMIT_EXCEEDED_COUNT.add(
+            1,
+            router_env::metric_attributes!(
+                ("flow", flow.to_owned()),
+                ("route_group", route_group.to_owned())
+            ),
+        );
+        return Err(errors::ApiErrorResponse::RateLimitExceeded.into());
+    }
+
+    Ok(())
+}
+
+/// Fails with [`errors::ApiErrorResponse::RateLimitExceeded`] once the client IP address of the
+/// request has reached the limit of failed authentications, without counting the request. This is
+/// checked before authenticating the request, so that the credentials cannot be brute forced.
+#[instrument(skip_all)]
+pub async fn check_failed_authentication_limit(
+    state: &SessionState,
+    request: &HttpRequest,
+) -> RouterResult<()> {
+    let settings = &state.conf.rate_limit;
+    if !settings.enabled {
+        return Ok(());
+    }
+    let Some(ip_address) = get_client_ip(request, &settings.trusted_proxies) else {
+        return Ok(());
+    };
+
+    let status = match count_request(
+        state,
+        &RateLimitIdentity::ClientIp(ip_address.to_string()),
+        FAILED_AUTHENTICATION_ROUTE_GROUP,
+        settings.failed_authentication_limit,
+        settings.window_in_seconds,
+        false,
+    )
+    .await
+    {
+        Ok(status) => status,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to check the failed authentication limit of the request"
+            );
+            return Ok(());
+        }
+    };
+
+    if status.is_exceeded {
+        request.extensions_mut().insert(status);
+        metrics::RATE_LIMIT_EXCEEDED_COUNT.add(
+            1,
+            router_env::metric_attributes!((
+                "route_group",
+                FAILED_AUTHENTICATION_ROUTE_GROUP.to_owned()
+            )),
+        );
+        return Err(errors::ApiErrorResponse::RateLimitExceeded.into());
+    }
+
+    Ok(())
+}
+
+/// Counts a request which failed authentication against the client IP address of the request
+#[instrument(skip_all)]
+pub async fn record_failed_authentication(state: &SessionState, request: &HttpRequest) {
+    let settings = &state.conf.rate_limit;
+    if !settings.enabled {
+        return;
+    }
+    let Some(ip_address) = get_client_ip(request, &settings.trusted_proxies) else {
+        return;
+    };
+
+    if let Err(error) = count_request(
+        state,
+        &RateLimitIdentity::ClientIp(ip_address.to_string()),
+        FAILED_AUTHENTICATION_ROUTE_GROUP,
+        settings.failed_authentication_limit,
+        settings.window_in_seconds,
+        true,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to record the failed authentication");
+    }
+}
+
+/// Returns the IP address of the client which made the request. The `X-Forwarded-For` header is
+/// only considered when the request was received from a trusted proxy, in which case the closest
+/// address which is not a trusted proxy is used.
+fn get_client_ip(request: &HttpRequest, trusted_proxies: &HashSet<IpAddr>) -> Option<IpAddr> {
+    let peer_ip = request.peer_addr()?.ip();
+    if !trusted_proxies.contains(&peer_ip) {
+        return Some(peer_ip);
+    }
+
+    let forwarded_for = request
+        .headers()
+        .get_all(actix_web::http::header::X_FORWARDED_FOR)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect::<Vec<_>>();
+
+    let mut client_ip = peer_ip;
+    for address in forwarded_for.into_iter().rev() {
+        // Addresses before an invalid entry cannot be trusted, since they could have been
+        // supplied by the client
+        let Ok(ip_address) = address.parse::<IpAddr>() else {
+            break;
+        };
+        client_ip = ip_address;
+        if !trusted_proxies.contains(&ip_address) {
+            break;
+        }
+    }
+
+    Some(client_ip)
+}
+
+/// Returns the route group of the flow along with its limit, falling back to the default limit
+/// for the flows which are not part of any route group
+fn get_route_group<'a>(settings: &'a RateLimitSettings, flow: &str) -> (&'a str, u32) {
+    settings
+        .route_groups
+        .iter()
+        .find(|(_, route_group)| route_group.flows.contains(flow))
+        .map(|(group_name, route_group)| (group_name.as_str(), route_group.limit))
+        .unwrap_or((DEFAULT_ROUTE_GROUP, settings.default_limit))
+}
+
+/// Returns the limit of the route group for the merchant, which can be overridden through the
+/// `rate_limit_overrides_{merchant_id}` config as a map of route group names to limits
+async fn get_merchant_limit(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    route_group: &str,
+    configured_limit: u32,
+) -> u32 {
+    let overrides = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_rate_limit_overrides_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct::<HashMap<String, u32>>("RateLimitOverrides")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        });
+
+    match overrides {
+        Ok(overrides) => overrides
+            .get(route_group)
+            .copied()
+            .unwrap_or(configured_limit),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the rate limit overrides of the merchant"
+            );
+            configured_limit
+        }
+    }
+}
+
+async fn count_request(
+    state: &SessionState,
+    identity: &RateLimitIdentity,
+    route_group: &str,
+    limit: u32,
+    window_in_seconds: u32,
+    should_count: bool,
+) -> RouterResult<RateLimitStatus> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let window = i64::from(window_in_seconds);
+    let now = date_time::now_unix_timestamp();
+    let window_index = now.div_euclid(window);
+    let elapsed = now.rem_euclid(window);
+
+    // The identity is used as the hash tag of the keys, so that both counters are stored in the
+    // same slot when running against a Redis cluster
+    let key_prefix = format!(
+        "{RATE_LIMIT_PREFIX}:{{{}}}:{route_group}",
+        identity.get_key_repr()
+    );
+    let result = redis_conn
+        .evaluate_redis_script::<_, Vec<i64>>(
+            SLIDING_WINDOW_SCRIPT,
+            vec![
+                redis_conn.add_prefix(&format!("{key_prefix}:{window_index}")),
+                redis_conn.add_prefix(&format!("{key_prefix}:{}", window_index.saturating_sub(1))),
+            ],
+            vec![
+                limit.to_string(),
+                window.to_string(),
+                elapsed.to_string(),
+                u8::from(should_count).to_string(),
+            ],
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the request against the rate limit")?;
+
+    let (is_allowed, count) = match result.as_slice() {
+        [is_allowed, count] => (*is_allowed == 1, *count),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from the rate limit script")?,
+    };
+
+    Ok(RateLimitStatus {
+        limit,
+        remaining: limit.saturating_sub(u32::try_from(count).unwrap_or(limit)),
+        reset_in_seconds: u32::try_from(window.saturating_sub(elapsed))
+            .unwrap_or(window_in_seconds),
+        is_exceeded: !is_allowed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::configs::settings::RateLimitRouteGroup;
+
+    fn settings() -> RateLimitSettings {
+        RateLimitSettings {
+            enabled: true,
+            route_groups: HashMap::from([(
+                "payments".to_string(),
+                RateLimitRouteGroup {
+                    flows: HashSet::from(["PaymentsCreate".to_string()]),
+                    limit: 10,
+                },
+            )]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_route_group_of_flow() {
+        let settings = settings();
+
+        assert_eq!(
+            get_route_group(&settings, "PaymentsCreate"),
+            ("payments", 10)
+        );
+        assert_eq!(
+            get_route_group(&settings, "RefundsCreate"),
+            (DEFAULT_ROUTE_GROUP, settings.default_limit)
+        );
+    }
+
+    #[test]
+    fn test_identity_of_api_key_request() {
+        let merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_123")).unwrap();
+        let key_id = id_type::ApiKeyId::try_from(std::borrow::Cow::from("dev_key_123")).unwrap();
+        let request = actix_web::test::TestRequest::default().to_http_request();
+
+        let identity = RateLimitIdentity::from_request(
+            &AuthenticationType::ApiKey {
+                merchant_id: merchant_id.clone(),
+                key_id: key_id.clone(),
+                scopes: None,
+            },
+            &request,
+            &HashSet::new(),
+        );
+
+        assert_eq!(
+            identity,
+            Some(RateLimitIdentity::ApiKey {
+                merchant_id,
+                key_id
+            })
+        );
+    }
+
+    #[test]
+    fn test_identity_of_unauthenticated_request() {
+        let request = actix_web::test::TestRequest::default()
+            .peer_addr("127.0.0.1:8080".parse().unwrap())
+            .to_http_request();
+
+        let identity =
+            RateLimitIdentity::from_request(&AuthenticationType::NoAuth, &request, &HashSet::new());
+
+        assert_eq!(
+            identity,
+            Some(RateLimitIdentity::ClientIp("127.0.0.1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_admin_requests_are_not_rate_limited() {
+        let request = actix_web::test::TestRequest::default().to_http_request();
+
+        let identity = RateLimitIdentity::from_request(
+            &AuthenticationType::AdminApiKey,
+            &request,
+            &HashSet::new(),
+        );
+
+        assert_eq!(identity, None);
+    }
+
+    #[test]
+    fn test_forwarded_for_header_of_untrusted_peer_is_ignored() {
+        let request = actix_web::test::TestRequest::default()
+            .peer_addr("127.0.0.1:8080".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "10.0.0.1"))
+            .insert_header(("X-Real-IP", "10.0.0.2"))
+            .to_http_request();
+
+        assert_eq!(
+            get_client_ip(&request, &HashSet::new()),
+            Some("127.0.0.1".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_client_ip_behind_trusted_proxies() {
+        let trusted_proxies =
+            HashSet::from(["127.0.0.1".parse().unwrap(), "10.0.0.1".parse().unwrap()]);
+        let request = actix_web::test::TestRequest::default()
+            .peer_addr("127.0.0.1:8080".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "192.0.2.1, 198.51.100.1, 10.0.0.1"))
+            .to_http_request();
+
+        // The leftmost address is supplied by the client, and is not trusted
+        assert_eq!(
+            get_client_ip(&request, &trusted_proxies),
+            Some("198.51.100.1".parse().unwrap())
+        );
+
+        let request = actix_web::test::TestRequest::default()
+            .peer_addr("127.0.0.1:8080".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "unknown, 10.0.0.1"))
+            .to_http_request();
+
+        assert_eq!(
+            get_client_ip(&request, &trusted_proxies),
+            Some("10.0.0.1".parse().unwrap())
+        );
+    }
+}
@@ -1154,6 +1154,7 @@ impl<F1, F2, T1, T2> ForeignFrom<(&RouterData<F1, T1, PaymentsResponseData>, T2)
             psd2_sca_exemption_type: data.psd2_sca_exemption_type,
             raw_connector_response: data.raw_connector_response.clone(),
             is_payment_id_from_merchant: data.is_payment_id_from_merchant,
+            merchant_connector_id: data.merchant_connector_id.clone(),
         }
     }
 }
@@ -1223,6 +1224,7 @@ impl<F1, F2>
             connector_mandate_request_reference_id: None,
             raw_connector_response: None,
             is_payment_id_from_merchant: data.is_payment_id_from_merchant,
+            merchant_connector_id: data.merchant_connector_id.clone(),
         }
     }
 }
@@ -71,6 +71,10 @@ pub struct Event {
 
     /// Indicates whether the event was ultimately delivered.
     pub is_overall_delivery_successful: Option<bool>,
+
+    /// The webhook endpoint to which the event is delivered. Events delivered to the webhook URL
+    /// configured on the business profile do not have an associated webhook endpoint.
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -130,6 +134,7 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 
@@ -180,6 +185,7 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
             is_overall_delivery_successful: item.is_overall_delivery_successful,
+            webhook_endpoint_id: item.webhook_endpoint_id,
         })
     }
 
@@ -202,6 +208,7 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 }
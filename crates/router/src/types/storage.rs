@@ -14,11 +14,14 @@ pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
 pub mod dynamic_routing_stats;
+pub mod embedded_vault;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
+pub mod frm_review;
 pub mod generic_link;
 pub mod gsm;
 #[cfg(feature = "kv_store")]
@@ -32,21 +35,25 @@ pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
+pub mod recon;
 pub mod refund;
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod subscription;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 pub use diesel_models::{
-    process_tracker::business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner,
-    ProcessTrackerUpdate,
+    process_tracker::business_status, ProcessTracker, ProcessTrackerAudit, ProcessTrackerAuditNew,
+    ProcessTrackerListConstraints, ProcessTrackerNew, ProcessTrackerRunner, ProcessTrackerUpdate,
 };
 #[cfg(feature = "v1")]
 pub use hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptNew;
@@ -71,9 +78,11 @@ pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
     capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
-    dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
+    dynamic_routing_stats::*, embedded_vault::*, ephemeral_key::*, events::*, file::*,
+    forex_rate_snapshot::*, fraud_check::*, frm_review::*, generic_link::*, gsm::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_link::*, payment_method::*, payout_batch::*, process_tracker::*,
+    recon::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*, subscription::*,
     unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    webhook_endpoint::*,
 };
@@ -1 +1,3 @@
+#[cfg(feature = "v1")]
+pub use diesel_models::business_profile::WebhookSecretRotationTrackingData;
 pub use diesel_models::business_profile::{Profile, ProfileNew, ProfileUpdateInternal};
@@ -0,0 +1,3 @@
+pub use diesel_models::embedded_vault::{
+    EmbeddedVaultEntry, EmbeddedVaultEntryNew, EmbeddedVaultEntryUpdate,
+};
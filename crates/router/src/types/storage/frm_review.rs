@@ -0,0 +1,4 @@
+pub use diesel_models::frm_review::{
+    FrmReview, FrmReviewExpiryTrackingData, FrmReviewNew, FrmReviewNote, FrmReviewNoteNew,
+    FrmReviewUpdate, FrmReviewUpdateInternal,
+};
@@ -0,0 +1,3 @@
+pub use diesel_models::recon::{
+    ReconJob, ReconJobNew, ReconJobTrackingData, ReconJobUpdate, ReconMismatch, ReconMismatchNew,
+};
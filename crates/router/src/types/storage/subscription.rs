@@ -0,0 +1,5 @@
+pub use diesel_models::subscription::{
+    Subscription, SubscriptionBillingTrackingData, SubscriptionInvoice, SubscriptionInvoiceNew,
+    SubscriptionInvoiceUpdate, SubscriptionNew, SubscriptionPlan, SubscriptionPlanNew,
+    SubscriptionPlanUpdate, SubscriptionPrice, SubscriptionPriceNew, SubscriptionUpdate,
+};
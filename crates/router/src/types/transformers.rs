@@ -610,6 +610,9 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permission_groups: api_key.permission_groups,
+            resources: api_key.resources,
+            profile_ids: api_key.profile_ids,
         }
     }
 }
@@ -624,6 +627,9 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            permission_groups: api_key.permission_groups,
+            resources: api_key.resources,
+            profile_ids: api_key.profile_ids,
         }
     }
 }
@@ -637,6 +643,9 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            permission_groups: api_key.permission_groups,
+            resources: api_key.resources,
+            profile_ids: api_key.profile_ids,
         }
     }
 }
@@ -767,6 +776,122 @@ impl ForeignFrom<storage::FileMetadata> for api_models::files::FileMetadataRespo
     }
 }
 
+impl ForeignFrom<storage::FrmReview> for api_models::frm_review::FrmReviewResponse {
+    fn foreign_from(frm_review: storage::FrmReview) -> Self {
+        Self {
+            review_id: frm_review.review_id,
+            payment_id: frm_review.payment_id,
+            attempt_id: frm_review.attempt_id,
+            profile_id: frm_review.profile_id,
+            frm_name: frm_review.frm_name,
+            status: frm_review.status,
+            risk_signals: frm_review.frm_reason,
+            frm_score: frm_review.frm_score,
+            assigned_to: frm_review.assigned_to,
+            decision: frm_review.decision,
+            reviewed_by: frm_review.reviewed_by,
+            reviewed_at: frm_review.reviewed_at,
+            expires_at: frm_review.expires_at,
+            created: frm_review.created_at,
+            notes: None,
+        }
+    }
+}
+
+impl ForeignFrom<storage::FrmReviewNote> for api_models::frm_review::FrmReviewNoteResponse {
+    fn foreign_from(frm_review_note: storage::FrmReviewNote) -> Self {
+        Self {
+            note_id: frm_review_note.note_id,
+            user_id: frm_review_note.user_id,
+            note: frm_review_note.note,
+            created: frm_review_note.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ForeignFrom<storage::SubscriptionPlan>
+    for api_models::subscriptions::SubscriptionPlanResponse
+{
+    fn foreign_from(subscription_plan: storage::SubscriptionPlan) -> Self {
+        Self {
+            plan_id: subscription_plan.plan_id,
+            profile_id: subscription_plan.profile_id,
+            name: subscription_plan.name,
+            description: subscription_plan.description,
+            is_active: subscription_plan.is_active,
+            metadata: subscription_plan.metadata,
+            created: subscription_plan.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ForeignFrom<storage::SubscriptionPrice>
+    for api_models::subscriptions::SubscriptionPriceResponse
+{
+    fn foreign_from(subscription_price: storage::SubscriptionPrice) -> Self {
+        Self {
+            price_id: subscription_price.price_id,
+            plan_id: subscription_price.plan_id,
+            amount: subscription_price.amount,
+            currency: subscription_price.currency,
+            billing_interval: subscription_price.billing_interval,
+            interval_count: subscription_price.interval_count,
+            trial_period_days: subscription_price.trial_period_days,
+            is_active: subscription_price.is_active,
+            created: subscription_price.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ForeignFrom<storage::Subscription> for api_models::subscriptions::SubscriptionResponse {
+    fn foreign_from(subscription: storage::Subscription) -> Self {
+        Self {
+            subscription_id: subscription.subscription_id,
+            profile_id: subscription.profile_id,
+            customer_id: subscription.customer_id,
+            plan_id: subscription.plan_id,
+            price_id: subscription.price_id,
+            status: subscription.status,
+            mandate_id: subscription.mandate_id,
+            current_period_start: subscription.current_period_start,
+            current_period_end: subscription.current_period_end,
+            trial_end: subscription.trial_end,
+            cancel_at_period_end: subscription.cancel_at_period_end,
+            canceled_at: subscription.canceled_at,
+            latest_invoice_id: subscription.latest_invoice_id,
+            metadata: subscription.metadata,
+            created: subscription.created_at,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ForeignFrom<storage::SubscriptionInvoice> for api_models::subscriptions::InvoiceResponse {
+    fn foreign_from(invoice: storage::SubscriptionInvoice) -> Self {
+        Self {
+            invoice_id: invoice.invoice_id,
+            subscription_id: invoice.subscription_id,
+            profile_id: invoice.profile_id,
+            customer_id: invoice.customer_id,
+            status: invoice.status,
+            billing_reason: invoice.billing_reason,
+            amount: invoice.amount,
+            currency: invoice.currency,
+            period_start: invoice.period_start,
+            period_end: invoice.period_end,
+            line_items: invoice.line_items.0,
+            payment_id: invoice.payment_id,
+            attempt_count: invoice.attempt_count,
+            next_payment_attempt_at: invoice.next_payment_attempt_at,
+            paid_at: invoice.paid_at,
+            created: invoice.created_at,
+        }
+    }
+}
+
 impl ForeignFrom<diesel_models::cards_info::CardInfo> for api_models::cards_info::CardInfoResponse {
     fn foreign_from(item: diesel_models::cards_info::CardInfo) -> Self {
         Self {
@@ -1720,6 +1845,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             is_delivery_successful: item.is_overall_delivery_successful,
             initial_attempt_id,
             created: item.created_at,
+            webhook_endpoint_id: item.webhook_endpoint_id,
         })
     }
 }
@@ -1763,6 +1889,27 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveRespons
     }
 }
 
+#[cfg(feature = "olap")]
+impl ForeignFrom<storage::WebhookEndpoint>
+    for api_models::webhook_endpoints::WebhookEndpointResponse
+{
+    fn foreign_from(item: storage::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: item.endpoint_id,
+            merchant_id: item.merchant_id,
+            profile_id: item.profile_id,
+            url: item.url,
+            description: item.description,
+            secret: item.secret,
+            enabled_event_classes: item.enabled_event_classes,
+            enabled_event_types: item.enabled_event_types,
+            is_enabled: item.is_enabled,
+            created: item.created_at,
+            modified: item.modified_at,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::admin::AuthenticationConnectorDetails>
     for diesel_models::business_profile::AuthenticationConnectorDetails
 {
@@ -2,12 +2,26 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod forex_rate_snapshot;
+#[cfg(all(feature = "frm", feature = "v1"))]
+pub mod frm_review_expiry;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_batch;
+#[cfg(feature = "recon")]
+pub mod recon;
 
 pub mod refund_router;
 
+#[cfg(feature = "v1")]
+pub mod subscription_billing;
+
 pub mod tokenized_data;
 
+pub mod webhook_replay;
+
+pub mod webhook_secret_rotation;
+
 pub mod revenue_recovery;
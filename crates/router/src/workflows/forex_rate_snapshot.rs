@@ -0,0 +1,65 @@
+use common_utils::{date_time, ext_traits::Encode};
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts, errors as core_errors, logger, routes::SessionState, types::storage,
+    utils::currency::get_forex_rates,
+};
+
+pub struct ForexRateSnapshotWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ForexRateSnapshotWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let forex_api = state.conf.forex_api.get_inner();
+
+        let forex_rates = get_forex_rates(state, forex_api.data_expiration_delay_in_seconds)
+            .await
+            .change_context(core_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch forex rates for the snapshot")?;
+
+        let rates = forex_rates
+            .data
+            .conversion
+            .encode_to_value()
+            .change_context(core_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize forex rates for the snapshot")?;
+
+        let captured_at = date_time::now();
+        let snapshot = db
+            .insert_forex_rate_snapshot(storage::ForexRateSnapshotNew {
+                snapshot_id: common_utils::generate_id(consts::ID_LENGTH, "fxs"),
+                base_currency: forex_rates.data.base_currency,
+                rates,
+                captured_at,
+                created_at: captured_at,
+            })
+            .await?;
+        logger::info!(snapshot_id = %snapshot.snapshot_id, "Persisted forex rate snapshot");
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
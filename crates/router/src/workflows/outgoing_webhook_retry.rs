@@ -120,6 +120,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -176,6 +177,16 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                 match event_type {
                     // Resource status is same as the event type of the current event
                     Some(event_type) if event_type == tracking_data.event_type => {
+                        let webhook_endpoint = match event.webhook_endpoint_id.as_deref() {
+                            Some(webhook_endpoint_id) => Some(
+                                db.find_webhook_endpoint_by_merchant_id_endpoint_id(
+                                    &business_profile.merchant_id,
+                                    webhook_endpoint_id,
+                                )
+                                .await?,
+                            ),
+                            None => None,
+                        };
                         let outgoing_webhook = OutgoingWebhook {
                             merchant_id: tracking_data.merchant_id.clone(),
                             event_id: event.event_id.clone(),
@@ -188,6 +199,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             &merchant_context,
                             outgoing_webhook,
                             &business_profile,
+                            webhook_endpoint.as_ref(),
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -371,6 +383,7 @@ async fn get_outgoing_webhook_content_and_event_type(
             mandate::get_mandate,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
+            subscriptions::{retrieve_invoice, retrieve_subscription},
         },
         services::{ApplicationResponse, AuthFlow},
         types::{
@@ -535,6 +548,70 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::Subscriptions => {
+            let subscription_id = tracking_data.primary_object_id.clone();
+
+            let subscription_response =
+                match retrieve_subscription(state, merchant_context.clone(), None, subscription_id)
+                    .await?
+                {
+                    ApplicationResponse::Json(subscription_response)
+                    | ApplicationResponse::JsonWithHeaders((subscription_response, _)) => {
+                        Ok(subscription_response)
+                    }
+                    ApplicationResponse::StatusOk
+                    | ApplicationResponse::TextPlain(_)
+                    | ApplicationResponse::JsonForRedirection(_)
+                    | ApplicationResponse::Form(_)
+                    | ApplicationResponse::GenericLinkForm(_)
+                    | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::FileData(_) => {
+                        Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                            resource_name: tracking_data.primary_object_id.clone(),
+                        })
+                    }
+                }
+                .map(Box::new)?;
+            let event_type: Option<EventType> = subscription_response.status.into();
+            logger::debug!(current_resource_status=%subscription_response.status);
+
+            Ok((
+                OutgoingWebhookContent::SubscriptionDetails(subscription_response),
+                event_type,
+            ))
+        }
+
+        diesel_models::enums::EventClass::Invoices => {
+            let invoice_id = tracking_data.primary_object_id.clone();
+
+            let invoice_response =
+                match retrieve_invoice(state, merchant_context.clone(), None, invoice_id).await? {
+                    ApplicationResponse::Json(invoice_response)
+                    | ApplicationResponse::JsonWithHeaders((invoice_response, _)) => {
+                        Ok(invoice_response)
+                    }
+                    ApplicationResponse::StatusOk
+                    | ApplicationResponse::TextPlain(_)
+                    | ApplicationResponse::JsonForRedirection(_)
+                    | ApplicationResponse::Form(_)
+                    | ApplicationResponse::GenericLinkForm(_)
+                    | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::FileData(_) => {
+                        Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                            resource_name: tracking_data.primary_object_id.clone(),
+                        })
+                    }
+                }
+                .map(Box::new)?;
+            let event_type: Option<EventType> = invoice_response.status.into();
+            logger::debug!(current_resource_status=%invoice_response.status);
+
+            Ok((
+                OutgoingWebhookContent::InvoiceDetails(invoice_response),
+                event_type,
+            ))
+        }
         #[cfg(feature = "payouts")]
         diesel_models::enums::EventClass::Payouts => {
             let payout_id = tracking_data.primary_object_id.clone();
@@ -564,5 +641,42 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+        #[cfg(feature = "payouts")]
+        diesel_models::enums::EventClass::PayoutBatches => {
+            let batch_id = tracking_data.primary_object_id.clone();
+
+            let payout_batch_response = match payouts::batch::retrieve_payout_batch(
+                state,
+                merchant_context.clone(),
+                None,
+                batch_id,
+            )
+            .await?
+            {
+                ApplicationResponse::Json(payout_batch_response)
+                | ApplicationResponse::JsonWithHeaders((payout_batch_response, _)) => {
+                    Ok(payout_batch_response)
+                }
+                ApplicationResponse::StatusOk
+                | ApplicationResponse::TextPlain(_)
+                | ApplicationResponse::JsonForRedirection(_)
+                | ApplicationResponse::Form(_)
+                | ApplicationResponse::GenericLinkForm(_)
+                | ApplicationResponse::PaymentLinkForm(_)
+                | ApplicationResponse::FileData(_) => {
+                    Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                        resource_name: tracking_data.primary_object_id.clone(),
+                    })
+                }
+            }
+            .map(Box::new)?;
+            let event_type: Option<EventType> = payout_batch_response.status.into();
+            logger::debug!(current_resource_status=%payout_batch_response.status);
+
+            Ok((
+                OutgoingWebhookContent::PayoutBatchDetails(payout_batch_response),
+                event_type,
+            ))
+        }
     }
 }
@@ -0,0 +1,97 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+#[cfg(feature = "v1")]
+use scheduler::{consumer::types::process_data, utils as pt_utils};
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{core::recon::settlement, logger};
+use crate::{errors as core_errors, routes::SessionState, types::storage};
+
+pub struct ReconWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ReconWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::ReconJobTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ReconJobTrackingData")?;
+
+        let error = match settlement::execute_recon_job(state, &tracking_data).await {
+            Ok(_) => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into);
+            }
+            Err(error) => error,
+        };
+
+        // Errors other than those with the settlement file are usually transient, hence the job
+        // is retried, and is only marked as failed once the retries are exhausted
+        logger::error!(?error, job_id = %tracking_data.job_id, "Failed to execute recon job");
+        let mapping = process_data::ConnectorPTMapping::default();
+        let time_delta = if process.retry_count == 0 {
+            Some(mapping.default_mapping.start_after)
+        } else {
+            pt_utils::get_delay(
+                process.retry_count + 1,
+                &mapping.default_mapping.frequencies,
+            )
+        };
+
+        match pt_utils::get_time_from_delta(time_delta) {
+            Some(schedule_time) => db
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .map_err(Into::into),
+            None => {
+                db.update_recon_job_by_merchant_id_job_id(
+                    &tracking_data.merchant_id,
+                    &tracking_data.job_id,
+                    storage::ReconJobUpdate::ErrorUpdate {
+                        error_message: "Failed to process settlement file".to_string(),
+                    },
+                )
+                .await?;
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                    .await
+                    .map_err(Into::into)
+            }
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,247 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::{Encode, ValueExt};
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::webhooks::{self as webhooks_core, types::WebhookReplayTrackingData},
+    logger,
+    types::domain,
+};
+use crate::{errors as core_errors, routes::SessionState, types::storage};
+
+pub struct WebhookReplayWorkflow;
+
+#[cfg(feature = "v1")]
+enum ReplayOutcome {
+    Enqueued,
+    Skipped,
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookReplayWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: WebhookReplayTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookReplayTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let business_profile = db
+            .find_business_profile_by_merchant_id_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.merchant_id,
+                &tracking_data.profile_id,
+            )
+            .await?;
+
+        let batch_size = state.conf.webhooks.replay.batch_size;
+        let events = db
+            .list_initial_events_by_profile_id_constraints(
+                key_manager_state,
+                &tracking_data.profile_id,
+                tracking_data.created_after,
+                tracking_data.cursor.unwrap_or(tracking_data.created_before),
+                Some(i64::from(batch_size)),
+                Some(tracking_data.cursor_offset).filter(|offset| *offset > 0),
+                tracking_data.event_types.clone(),
+                Some(tracking_data.is_delivered),
+                &key_store,
+            )
+            .await?;
+
+        for event in &events {
+            match enqueue_event_for_replay(state, &key_store, &business_profile, event).await {
+                Ok(ReplayOutcome::Enqueued) => tracking_data.enqueued_count += 1,
+                Ok(ReplayOutcome::Skipped) => tracking_data.skipped_count += 1,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        replay_id = %tracking_data.replay_id,
+                        event_id = %event.event_id,
+                        "Failed to enqueue event for replay"
+                    );
+                    tracking_data.failed_count += 1;
+                }
+            }
+        }
+
+        // Events are listed in descending order of their creation time, and multiple events may
+        // have been created at the same time, so the number of events processed at the cursor is
+        // tracked to resume from the right position in the next batch
+        if let Some(last_event) = events.last() {
+            let processed_at_cursor = i64::try_from(
+                events
+                    .iter()
+                    .filter(|event| event.created_at == last_event.created_at)
+                    .count(),
+            )
+            .map_err(|_| errors::ProcessTrackerError::TypeConversionError)?;
+
+            if tracking_data.cursor == Some(last_event.created_at) {
+                tracking_data.cursor_offset += processed_at_cursor;
+            } else {
+                tracking_data.cursor = Some(last_event.created_at);
+                tracking_data.cursor_offset = processed_at_cursor;
+            }
+        }
+
+        let is_last_batch =
+            usize::try_from(batch_size).map_or(true, |batch_size| events.len() < batch_size);
+        let updated_tracking_data = tracking_data
+            .encode_to_value()
+            .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+        // The replay may have been cancelled while this batch was being processed, in which case
+        // only the progress of the replay is recorded
+        let latest_process = db
+            .find_process_by_id(&process.id)
+            .await?
+            .unwrap_or_else(|| process.clone());
+        let now = common_utils::date_time::now();
+
+        let process_tracker_update =
+            if latest_process.status == storage::enums::ProcessTrackerStatus::Finish {
+                logger::info!(
+                    replay_id = %tracking_data.replay_id,
+                    "Webhook replay has been cancelled, not scheduling the next batch"
+                );
+
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: None,
+                    tracking_data: Some(updated_tracking_data),
+                    business_status: None,
+                    status: None,
+                    updated_at: Some(now),
+                }
+            } else if is_last_batch {
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: None,
+                    tracking_data: Some(updated_tracking_data),
+                    business_status: Some(String::from(business_status::COMPLETED_BY_PT)),
+                    status: Some(storage::enums::ProcessTrackerStatus::Finish),
+                    updated_at: Some(now),
+                }
+            } else {
+                let batch_interval = time::Duration::seconds(i64::from(
+                    state.conf.webhooks.replay.batch_interval_in_seconds,
+                ));
+
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(process.retry_count + 1),
+                    schedule_time: Some(now.saturating_add(batch_interval)),
+                    tracking_data: Some(updated_tracking_data),
+                    business_status: None,
+                    status: Some(storage::enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                }
+            };
+
+        db.as_scheduler()
+            .update_process(latest_process, process_tracker_update)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Schedules the delivery of the specified initial event to be retried, unless the delivery of
+/// any attempt of the event is already being retried.
+#[cfg(feature = "v1")]
+async fn enqueue_event_for_replay(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    event: &domain::Event,
+) -> core_errors::CustomResult<ReplayOutcome, core_errors::StorageError> {
+    let db = &*state.store;
+
+    let delivery_attempts = db
+        .list_events_by_merchant_id_initial_attempt_id(
+            &state.into(),
+            &business_profile.merchant_id,
+            &event.event_id,
+            key_store,
+        )
+        .await?;
+
+    // The delivery attempts include the initial event, and the retry task of any of them may still
+    // be retrying the delivery of the event
+    for delivery_attempt in &delivery_attempts {
+        let retry_process_tracker_id = webhooks_core::get_outgoing_webhook_retry_process_tracker_id(
+            &business_profile.merchant_id,
+            &delivery_attempt.event_id,
+        );
+        let is_retry_in_progress = db
+            .find_process_by_id(&retry_process_tracker_id)
+            .await?
+            .is_some_and(|process| process.status != storage::enums::ProcessTrackerStatus::Finish);
+        if is_retry_in_progress {
+            return Ok(ReplayOutcome::Skipped);
+        }
+    }
+
+    // Retries are scheduled for the latest delivery attempt of the event, since the process
+    // tracker task of the initial attempt may already exist
+    let latest_delivery_attempt = delivery_attempts
+        .into_iter()
+        .max_by_key(|delivery_attempt| delivery_attempt.created_at)
+        .unwrap_or_else(|| event.clone());
+
+    match webhooks_core::add_outgoing_webhook_retry_task_to_process_tracker(
+        db,
+        business_profile,
+        &latest_delivery_attempt,
+    )
+    .await
+    {
+        Ok(_) => Ok(ReplayOutcome::Enqueued),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            Ok(ReplayOutcome::Skipped)
+        }
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{errors as core_errors, routes::SessionState, types::storage};
+#[cfg(feature = "v1")]
+use crate::{logger, types::domain};
+
+pub struct WebhookSecretRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for WebhookSecretRotationWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::WebhookSecretRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("WebhookSecretRotationTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let business_profile = db
+            .find_business_profile_by_merchant_id_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.merchant_id,
+                &tracking_data.profile_id,
+            )
+            .await?;
+
+        // The secret was rotated again after this task was scheduled, in which case the previous
+        // secret will be retired by the task scheduled by the latest rotation
+        if business_profile.previous_payment_response_hash_key_expires_at
+            != Some(tracking_data.previous_payment_response_hash_key_expires_at)
+        {
+            logger::info!(
+                profile_id = ?tracking_data.profile_id,
+                "Previous webhook secret has already been replaced, skipping retirement"
+            );
+
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        db.update_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            business_profile,
+            domain::ProfileUpdate::PreviousPaymentResponseHashKeyRetirementUpdate,
+        )
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -135,6 +135,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     }
 }
 
@@ -209,6 +210,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         psd2_sca_exemption_type: None,
         raw_connector_response: None,
         is_payment_id_from_merchant: None,
+        merchant_connector_id: None,
     }
 }
 
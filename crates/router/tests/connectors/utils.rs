@@ -555,6 +555,7 @@ pub trait ConnectorActions: Connector {
             authentication_id: None,
             raw_connector_response: None,
             is_payment_id_from_merchant: None,
+            merchant_connector_id: None,
         }
     }
 
@@ -50,7 +50,7 @@ pub enum Tag {
 }
 
 /// API Flow
-#[derive(Debug, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq, EnumString)]
 pub enum Flow {
     /// Health check
     HealthCheck,
@@ -82,6 +82,10 @@ pub enum Flow {
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
+    /// Circuit breakers of merchant connectors list flow.
+    CircuitBreakerList,
+    /// Circuit breaker of merchant connector reset flow.
+    CircuitBreakerReset,
     /// Merchant Transfer Keys
     MerchantTransferKey,
     /// ConfigKey create flow.
@@ -114,6 +118,32 @@ pub enum Flow {
     MandatesRevoke,
     /// Mandates list flow.
     MandatesList,
+    /// Subscription plan create flow.
+    SubscriptionPlanCreate,
+    /// Subscription plan retrieve flow.
+    SubscriptionPlanRetrieve,
+    /// Subscription plan update flow.
+    SubscriptionPlanUpdate,
+    /// Subscription plan list flow.
+    SubscriptionPlanList,
+    /// Subscription price create flow.
+    SubscriptionPriceCreate,
+    /// Subscription price list flow.
+    SubscriptionPriceList,
+    /// Subscription create flow.
+    SubscriptionCreate,
+    /// Subscription retrieve flow.
+    SubscriptionRetrieve,
+    /// Subscription update flow.
+    SubscriptionUpdate,
+    /// Subscription cancel flow.
+    SubscriptionCancel,
+    /// Subscription list flow.
+    SubscriptionList,
+    /// Subscription invoice list flow.
+    InvoiceList,
+    /// Subscription invoice retrieve flow.
+    InvoiceRetrieve,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
@@ -205,6 +235,18 @@ pub enum Flow {
     #[cfg(feature = "payouts")]
     /// Payouts filter flow.
     PayoutsFilter,
+    #[cfg(feature = "payouts")]
+    /// Payout batch create flow.
+    PayoutBatchCreate,
+    #[cfg(feature = "payouts")]
+    /// Payout batch retrieve flow.
+    PayoutBatchRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payout batch list flow.
+    PayoutBatchList,
+    #[cfg(feature = "payouts")]
+    /// Payout batch item list flow.
+    PayoutBatchItemList,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payout link initiate flow
@@ -237,6 +279,14 @@ pub enum Flow {
     ReconServiceRequest,
     /// Recon token verification flow
     ReconVerifyToken,
+    /// Recon settlement file upload flow
+    ReconJobCreate,
+    /// Recon job retrieve flow
+    ReconJobRetrieve,
+    /// Recon job list flow
+    ReconJobList,
+    /// Recon mismatch list flow
+    ReconMismatchList,
     /// Routing create flow,
     RoutingCreateConfig,
     /// Routing link config
@@ -259,6 +309,12 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing simulate algorithm against historical payments
+    RoutingSimulateAlgorithm,
+    /// Routing parse DSL program
+    RoutingParseDsl,
+    /// Routing retrieve config as DSL program
+    RoutingRetrieveDsl,
     /// Toggle dynamic routing
     ToggleDynamicRouting,
     /// Update dynamic routing config
@@ -367,6 +423,24 @@ pub enum Flow {
     DecisionManagerRetrieveConfig,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
+    /// Create or update the fraud risk engine config of a business profile
+    FraudRiskEngineUpsert,
+    /// Retrieve the fraud risk engine config of a business profile
+    FraudRiskEngineRetrieve,
+    /// Delete the fraud risk engine config of a business profile
+    FraudRiskEngineDelete,
+    /// List the payments in the FRM manual review queue
+    FrmReviewList,
+    /// Retrieve an FRM manual review
+    FrmReviewRetrieve,
+    /// Assign an FRM manual review to a dashboard user
+    FrmReviewAssign,
+    /// Approve the payment under FRM manual review
+    FrmReviewApprove,
+    /// Reject the payment under FRM manual review
+    FrmReviewReject,
+    /// Record a note on an FRM manual review
+    FrmReviewAddNote,
     /// Get connectors feature matrix
     FeatureMatrix,
     /// Change password flow
@@ -527,12 +601,30 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Replay the delivery of webhook events in bulk
+    WebhookEventReplayCreate,
+    /// Retrieve the progress of a bulk replay of webhook events
+    WebhookEventReplayRetrieve,
+    /// Cancel a bulk replay of webhook events
+    WebhookEventReplayCancel,
+    /// Create a webhook endpoint for a business profile
+    WebhookEndpointCreate,
+    /// Retrieve a webhook endpoint
+    WebhookEndpointRetrieve,
+    /// List webhook endpoints of a business profile
+    WebhookEndpointList,
+    /// Update a webhook endpoint
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint
+    WebhookEndpointDelete,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
     ToggleConnectorAgnosticMit,
+    /// Rotate the webhook signing secret of a profile
+    RotateWebhookSecret,
     /// Get the extended card info associated to a payment_id
     GetExtendedCardInfo,
     /// Manually update the refund details like status, error code, error message etc.
@@ -590,6 +682,14 @@ pub enum Flow {
     TotalPaymentMethodCount,
     /// Process Tracker Revenue Recovery Workflow Retrieve
     RevenueRecoveryRetrieve,
+    /// Process tracker task list flow
+    ProcessTrackerList,
+    /// Process tracker task retrieve flow
+    ProcessTrackerRetrieve,
+    /// Process tracker task pause, run, reschedule or cancel flow
+    ProcessTrackerUpdate,
+    /// Process tracker task bulk pause, run, reschedule or cancel flow
+    ProcessTrackerBulkUpdate,
     /// Tokenization flow
     TokenizationCreate,
     /// Tokenization retrieve flow
@@ -7,6 +7,7 @@ impl Default for super::settings::SchedulerSettings {
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
             server: super::settings::Server::default(),
+            runners: std::collections::HashMap::new(),
         }
     }
 }
@@ -19,6 +20,7 @@ impl Default for super::settings::ProducerSettings {
             lock_key: "PRODUCER_LOCKING_KEY".into(),
             lock_ttl: 160,
             batch_size: 200,
+            recurring_fetch_limit: 100,
         }
     }
 }
@@ -1,3 +1,6 @@
+use std::{collections::HashMap, str::FromStr};
+
+use diesel_models::process_tracker::ProcessTrackerRunner;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::Deserialize;
 
@@ -10,6 +13,10 @@ pub struct SchedulerSettings {
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
     pub server: Server,
+    /// Settings for the tasks of specific runners. Tasks of runners without any settings are run
+    /// in the default lane, without a concurrency limit.
+    #[serde(deserialize_with = "deserialize_runner_settings")]
+    pub runners: HashMap<ProcessTrackerRunner, RunnerSettings>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -29,6 +36,9 @@ pub struct ProducerSettings {
     pub lock_key: String,
     pub lock_ttl: i64,
     pub batch_size: usize,
+    /// Maximum number of finished recurring tasks scheduled for their next occurrence in a
+    /// single run of the producer.
+    pub recurring_fetch_limit: i64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -37,3 +47,83 @@ pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
 }
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RunnerSettings {
+    /// Priority of the lane the tasks of the runner are run in. Tasks in lanes with a higher
+    /// priority are picked up by the consumer first, and are never queued behind the tasks of
+    /// lanes with a lower priority.
+    pub priority: u8,
+    /// Maximum number of tasks of the runner executed concurrently by a consumer. The tasks of
+    /// runners without a limit are executed in the batches picked by the consumer, the consumer
+    /// waiting for a batch to finish before picking the next one.
+    pub max_concurrency: Option<usize>,
+    /// Maximum number of tasks of the runner pushed by the producer under a single entry in the
+    /// stream, defaults to the batch size of the producer.
+    pub batch_size: Option<usize>,
+}
+
+/// Runners are specified by their name in snake case, such as `payments_sync_workflow`.
+fn deserialize_runner_settings<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<ProcessTrackerRunner, RunnerSettings>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    HashMap::<String, RunnerSettings>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(runner, settings)| {
+            ProcessTrackerRunner::from_str(&runner.to_ascii_uppercase())
+                .map(|runner| (runner, settings))
+                .map_err(|_| {
+                    serde::de::Error::custom(format!("Invalid process tracker runner `{runner}`"))
+                })
+        })
+        .collect()
+}
+
+impl SchedulerSettings {
+    /// Returns the settings of the runner with the specified name, if any.
+    pub fn get_runner_settings(&self, runner: Option<&str>) -> Option<&RunnerSettings> {
+        runner
+            .and_then(|runner| ProcessTrackerRunner::from_str(runner).ok())
+            .and_then(|runner| self.runners.get(&runner))
+    }
+
+    /// Returns the name of the stream of a lane. The lane with the lowest priority uses the
+    /// scheduler stream itself.
+    pub fn get_lane_stream_name(&self, priority: u8) -> String {
+        match priority {
+            0 => self.stream.clone(),
+            priority => format!("{}_PRIORITY_{priority}", self.stream),
+        }
+    }
+
+    /// Returns the name of the stream the tasks of a runner are pushed to.
+    pub fn get_runner_stream_name(&self, runner: Option<&str>) -> String {
+        self.get_lane_stream_name(
+            self.get_runner_settings(runner)
+                .map(|settings| settings.priority)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Returns the names of the streams of all the lanes, in the decreasing order of their
+    /// priorities.
+    pub fn get_lane_stream_names(&self) -> Vec<String> {
+        let mut priorities = self
+            .runners
+            .values()
+            .map(|settings| settings.priority)
+            .chain(std::iter::once(0))
+            .collect::<Vec<_>>();
+        priorities.sort_unstable_by(|a, b| b.cmp(a));
+        priorities.dedup();
+
+        priorities
+            .into_iter()
+            .map(|priority| self.get_lane_stream_name(priority))
+            .collect()
+    }
+}
@@ -21,6 +21,10 @@ impl super::settings::SchedulerSettings {
 
         self.server.validate()?;
 
+        for (runner, runner_settings) in &self.runners {
+            runner_settings.validate(*runner)?;
+        }
+
         Ok(())
     }
 }
@@ -31,6 +35,31 @@ impl super::settings::ProducerSettings {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "producer lock key must not be empty".into(),
             ))
+        })?;
+
+        common_utils::fp_utils::when(self.recurring_fetch_limit <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "producer recurring fetch limit must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RunnerSettings {
+    pub fn validate(
+        &self,
+        runner: diesel_models::process_tracker::ProcessTrackerRunner,
+    ) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.max_concurrency == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "scheduler max concurrency of runner {runner} must be greater than zero"
+            )))
+        })?;
+
+        common_utils::fp_utils::when(self.batch_size == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "scheduler batch size of runner {runner} must be greater than zero"
+            )))
         })
     }
 }
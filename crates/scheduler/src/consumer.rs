@@ -1,6 +1,7 @@
 // TODO: Figure out what to log
 
 use std::{
+    collections::HashMap,
     sync::{self, atomic},
     time as std_time,
 };
@@ -18,7 +19,7 @@ use router_env::{
     tracing::{self, Instrument},
 };
 use time::PrimitiveDateTime;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore};
 use uuid::Uuid;
 
 use super::env::logger;
@@ -65,6 +66,9 @@ where
         tokio::time::interval(Duration::from_millis(settings.graceful_shutdown_interval));
 
     let consumer_operation_counter = sync::Arc::new(atomic::AtomicU64::new(0));
+    // The permits are shared by all the polls of the consumer, so that the concurrency limits of
+    // runners also hold for tasks picked in different polls
+    let runner_permits = RunnerPermits::new(&settings);
     let signal = get_allowed_signals()
         .map_err(|error| {
             logger::error!(?error, "Signal Handler Error");
@@ -96,6 +100,8 @@ where
                             logger::error!(?error, "Failed to perform consumer operation");
                         },
                         workflow_selector,
+                        &runner_permits,
+                        &consumer_operation_counter,
                     )
                     .await;
                 }
@@ -134,54 +140,243 @@ where
     Ok(())
 }
 
+/// Permits limiting the number of tasks of a runner executed at a time by the consumer, for
+/// runners with a concurrency limit.
+#[derive(Debug, Clone, Default)]
+pub struct RunnerPermits {
+    permits: HashMap<String, sync::Arc<Semaphore>>,
+}
+
+impl RunnerPermits {
+    pub fn new(settings: &SchedulerSettings) -> Self {
+        let permits = settings
+            .runners
+            .iter()
+            .filter_map(|(runner, runner_settings)| {
+                runner_settings.max_concurrency.map(|max_concurrency| {
+                    (
+                        runner.to_string(),
+                        sync::Arc::new(Semaphore::new(max_concurrency)),
+                    )
+                })
+            })
+            .collect();
+
+        Self { permits }
+    }
+
+    /// Acquires a permit to execute a task of the runner, returns `None` if the runner has reached
+    /// its concurrency limit, and `Some(None)` if the runner has no concurrency limit.
+    fn try_acquire(&self, runner: Option<&str>) -> Option<Option<OwnedSemaphorePermit>> {
+        match runner.and_then(|runner| self.permits.get(runner)) {
+            Some(permits) => permits.clone().try_acquire_owned().ok().map(Some),
+            None => Some(None),
+        }
+    }
+}
+
+/// Keeps track of a task being executed by the consumer, so that the consumer waits for the task
+/// to finish when it is shut down.
+struct ActiveTaskGuard(sync::Arc<atomic::AtomicU64>);
+
+impl ActiveTaskGuard {
+    fn new(active_tasks: &sync::Arc<atomic::AtomicU64>) -> Self {
+        active_tasks.fetch_add(1, atomic::Ordering::SeqCst);
+        Self(active_tasks.clone())
+    }
+}
+
+impl Drop for ActiveTaskGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, atomic::Ordering::SeqCst);
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn consumer_operations<T: SchedulerSessionState + 'static>(
     state: &T,
     settings: &SchedulerSettings,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
+    runner_permits: &RunnerPermits,
+    active_tasks: &sync::Arc<atomic::AtomicU64>,
 ) -> CustomResult<(), errors::ProcessTrackerError> {
-    let stream_name = settings.stream.clone();
     let group_name = settings.consumer.consumer_group.clone();
     let consumer_name = format!("consumer_{}", Uuid::new_v4());
 
-    let _group_created = &mut state
-        .get_db()
-        .consumer_group_create(&stream_name, &group_name, &RedisEntryId::AfterLastID)
-        .await;
-
-    let mut tasks = state
-        .get_db()
-        .as_scheduler()
-        .fetch_consumer_tasks(&stream_name, &group_name, &consumer_name)
-        .await?;
+    // Lanes are read in the decreasing order of their priorities, so that tasks in lanes with a
+    // higher priority are claimed first
+    let mut tasks = Vec::new();
+    for stream_name in settings.get_lane_stream_names() {
+        let _group_created = &mut state
+            .get_db()
+            .consumer_group_create(&stream_name, &group_name, &RedisEntryId::AfterLastID)
+            .await;
+
+        let lane_tasks = state
+            .get_db()
+            .as_scheduler()
+            .fetch_consumer_tasks(&stream_name, &group_name, &consumer_name)
+            .await?;
+
+        if !lane_tasks.is_empty() {
+            logger::info!(
+                "{} picked {} tasks from {}",
+                consumer_name,
+                lane_tasks.len(),
+                stream_name
+            );
+        }
+        tasks.extend(
+            lane_tasks
+                .into_iter()
+                .map(|task| (stream_name.clone(), task)),
+        );
+    }
 
-    if !tasks.is_empty() {
-        logger::info!("{} picked {} tasks", consumer_name, tasks.len());
+    // Tasks are only claimed if a permit of their runner is available, tasks of runners which
+    // have reached their concurrency limit are returned to the producer to be picked again
+    let mut claimed_tasks = Vec::with_capacity(tasks.len());
+    let mut released_tasks = Vec::new();
+    for (stream_name, task) in tasks {
+        match runner_permits.try_acquire(task.runner.as_deref()) {
+            Some(permit) => claimed_tasks.push((stream_name, task, permit)),
+            None => released_tasks.push(task),
+        }
     }
-    let mut handler = vec![];
 
-    for task in tasks.iter_mut() {
+    claim_tasks(state, &mut claimed_tasks).await?;
+
+    // The workflows of runners with a concurrency limit are not awaited, so that tasks of other
+    // runners are picked in the next poll while the workflows of long running tasks are being
+    // executed. The workflows of runners without a concurrency limit are awaited, so that the
+    // number of tasks executed at a time stays bounded by the batch size of the consumer.
+    let mut unlimited_workflows = Vec::new();
+    for (stream_name, mut task, permit) in claimed_tasks {
         let pickup_time = common_utils::date_time::now();
 
-        pt_utils::add_histogram_metrics(&pickup_time, task, &stream_name);
+        pt_utils::add_histogram_metrics(&pickup_time, &mut task, &stream_name);
 
         metrics::TASK_CONSUMED.add(1, &[]);
 
-        handler.push(tokio::task::spawn(start_workflow(
-            state.clone(),
-            task.clone(),
-            pickup_time,
-            workflow_selector,
-        )))
+        let active_task = ActiveTaskGuard::new(active_tasks);
+        let is_limited = permit.is_some();
+        let state = state.clone();
+        let handle = tokio::task::spawn(
+            async move {
+                let _active_task = active_task;
+                let _permit = permit;
+                start_workflow(state, task, pickup_time, workflow_selector).await
+            }
+            .in_current_span(),
+        );
+        if !is_limited {
+            unlimited_workflows.push(handle);
+        }
+    }
+
+    release_tasks(state, released_tasks).await?;
+    future::join_all(unlimited_workflows).await;
+
+    Ok(())
+}
+
+/// Marks the tasks picked by the consumer as started.
+async fn claim_tasks<T: SchedulerSessionState>(
+    state: &T,
+    tasks: &mut [(
+        String,
+        storage::ProcessTracker,
+        Option<OwnedSemaphorePermit>,
+    )],
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    // Returning early to avoid execution of database queries when `tasks` is empty
+    if tasks.is_empty() {
+        return Ok(());
+    }
+
+    let task_ids = tasks
+        .iter()
+        .map(|(_, task, _)| task.id.to_owned())
+        .collect::<Vec<_>>();
+
+    state
+        .get_db()
+        .as_scheduler()
+        .process_tracker_update_process_status_by_ids(
+            task_ids,
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: enums::ProcessTrackerStatus::ProcessStarted,
+                business_status: None,
+            },
+        )
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?;
+    tasks
+        .iter_mut()
+        .for_each(|(_, task, _)| task.status = enums::ProcessTrackerStatus::ProcessStarted);
+
+    Ok(())
+}
+
+/// Returns tasks which could not be claimed due to the concurrency limit of their runner to the
+/// producer, so that they are picked again once their runner has free permits.
+async fn release_tasks<T: SchedulerSessionState>(
+    state: &T,
+    tasks: Vec<storage::ProcessTracker>,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let mut released_tasks = HashMap::<_, Vec<_>>::new();
+    let mut throttled_tasks = HashMap::<String, u64>::new();
+    for task in tasks {
+        if let Some(runner) = &task.runner {
+            *throttled_tasks.entry(runner.clone()).or_default() += 1;
+        }
+        released_tasks
+            .entry(get_released_task_status(&task))
+            .or_default()
+            .push(task.id);
+    }
+
+    for (runner, throttled_count) in throttled_tasks {
+        metrics::TASKS_THROTTLED.add(
+            throttled_count,
+            router_env::metric_attributes!(("runner", runner)),
+        );
+    }
+
+    for (status, task_ids) in released_tasks {
+        state
+            .get_db()
+            .as_scheduler()
+            .process_tracker_update_process_status_by_ids(
+                task_ids,
+                storage::ProcessTrackerUpdate::StatusUpdate {
+                    status,
+                    business_status: None,
+                },
+            )
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
     }
-    future::join_all(handler).await;
 
     Ok(())
 }
 
-#[instrument(skip(db, redis_conn))]
+/// Returns the status a task released by the consumer is picked again with by the producer.
+///
+/// Tasks which have been retried before are picked as pending tasks, so that the retry count of
+/// the task is retained.
+fn get_released_task_status(task: &storage::ProcessTracker) -> enums::ProcessTrackerStatus {
+    if task.retry_count > 0 {
+        enums::ProcessTrackerStatus::Pending
+    } else {
+        enums::ProcessTrackerStatus::New
+    }
+}
+
+/// Reads the tasks pushed to a lane by the producer, the tasks are claimed by the consumer only
+/// once they are marked as started.
+#[instrument(skip(redis_conn))]
 pub async fn fetch_consumer_tasks(
-    db: &dyn ProcessTrackerInterface,
     redis_conn: &RedisConnectionPool,
     stream_name: &str,
     group_name: &str,
@@ -189,12 +384,7 @@ pub async fn fetch_consumer_tasks(
 ) -> CustomResult<Vec<storage::ProcessTracker>, errors::ProcessTrackerError> {
     let batches = pt_utils::get_batches(redis_conn, stream_name, group_name, consumer_name).await?;
 
-    // Returning early to avoid execution of database queries when `batches` is empty
-    if batches.is_empty() {
-        return Ok(Vec::new());
-    }
-
-    let mut tasks = batches.into_iter().fold(Vec::new(), |mut acc, batch| {
+    let tasks = batches.into_iter().fold(Vec::new(), |mut acc, batch| {
         acc.extend_from_slice(
             batch
                 .trackers
@@ -205,23 +395,6 @@ pub async fn fetch_consumer_tasks(
         );
         acc
     });
-    let task_ids = tasks
-        .iter()
-        .map(|task| task.id.to_owned())
-        .collect::<Vec<_>>();
-
-    db.process_tracker_update_process_status_by_ids(
-        task_ids,
-        storage::ProcessTrackerUpdate::StatusUpdate {
-            status: enums::ProcessTrackerStatus::ProcessStarted,
-            business_status: None,
-        },
-    )
-    .await
-    .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?;
-    tasks
-        .iter_mut()
-        .for_each(|x| x.status = enums::ProcessTrackerStatus::ProcessStarted);
     Ok(tasks)
 }
 
@@ -276,3 +449,75 @@ pub async fn create_task(
     db.insert_process(process_tracker_entry).await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::settings::RunnerSettings;
+
+    #[test]
+    fn test_runner_permits_are_held_until_tasks_finish() {
+        let mut settings = SchedulerSettings::default();
+        settings.runners.insert(
+            storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+            RunnerSettings {
+                priority: 0,
+                max_concurrency: Some(1),
+                batch_size: None,
+            },
+        );
+        let runner_permits = RunnerPermits::new(&settings);
+        let limited_runner = storage::ProcessTrackerRunner::PaymentsSyncWorkflow.to_string();
+        let unlimited_runner =
+            storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow.to_string();
+
+        let permit = runner_permits.try_acquire(Some(&limited_runner));
+        assert!(matches!(permit, Some(Some(_))));
+        // A task picked in a later poll is not claimed while the first task is being executed
+        assert!(runner_permits.try_acquire(Some(&limited_runner)).is_none());
+
+        assert!(matches!(
+            runner_permits.try_acquire(Some(&unlimited_runner)),
+            Some(None)
+        ));
+        assert!(matches!(runner_permits.try_acquire(None), Some(None)));
+
+        drop(permit);
+        assert!(matches!(
+            runner_permits.try_acquire(Some(&limited_runner)),
+            Some(Some(_))
+        ));
+    }
+
+    #[test]
+    fn test_released_task_status() {
+        let now = common_utils::date_time::now();
+        let mut task = storage::ProcessTracker {
+            id: "task_1".to_owned(),
+            name: None,
+            tag: Vec::new(),
+            runner: None,
+            retry_count: 0,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: serde_json::Value::Null,
+            business_status: storage::business_status::PENDING.to_owned(),
+            status: enums::ProcessTrackerStatus::Processing,
+            event: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            version: common_types::consts::API_VERSION,
+            recurrence: None,
+        };
+        assert_eq!(
+            get_released_task_status(&task),
+            enums::ProcessTrackerStatus::New
+        );
+
+        task.retry_count = 2;
+        assert_eq!(
+            get_released_task_status(&task),
+            enums::ProcessTrackerStatus::Pending
+        );
+    }
+}
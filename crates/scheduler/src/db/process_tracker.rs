@@ -32,6 +32,19 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         task_update: storage::ProcessTrackerUpdate,
     ) -> CustomResult<usize, errors::StorageError>;
 
+    /// Updates the process only if it has not been modified since it was read, returns `None` if
+    /// the process was modified in the meantime.
+    async fn update_process_if_unmodified(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn list_processes_by_constraints(
+        &self,
+        constraints: storage::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -62,6 +75,11 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_recurring_processes_to_reschedule(
+        &self,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -107,6 +125,20 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn find_recurring_processes_to_reschedule(
+        &self,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_recurring_processes_to_reschedule(
+            &conn,
+            limit,
+            common_types::consts::API_VERSION,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -192,6 +224,31 @@ impl ProcessTrackerInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    async fn update_process_if_unmodified(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_if_unmodified(&conn, process)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn list_processes_by_constraints(
+        &self,
+        constraints: storage::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::list_by_constraints(
+            &conn,
+            constraints,
+            common_types::consts::API_VERSION,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -230,6 +287,37 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_recurring_processes_to_reschedule(
+        &self,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let excluded_business_statuses = [
+            storage::process_tracker::business_status::CANCELLED,
+            storage::process_tracker::business_status::REVOKED,
+            storage::process_tracker::business_status::INVALID_RECURRENCE,
+        ];
+        let processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                process.status == storage_enums::ProcessTrackerStatus::Finish
+                    && process.recurrence.is_some()
+                    && !process.is_valid_business_status(&excluded_business_statuses)
+            })
+            .take(
+                limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .cloned()
+            .collect();
+
+        Ok(processes)
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -250,6 +338,7 @@ impl ProcessTrackerInterface for MockDb {
             created_at: new.created_at,
             updated_at: new.updated_at,
             version: new.version,
+            recurrence: new.recurrence,
         };
         processes.push(process.clone());
         Ok(process)
@@ -299,4 +388,74 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn update_process_if_unmodified(
+        &self,
+        _this: storage::ProcessTracker,
+        _process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_processes_by_constraints(
+        &self,
+        constraints: storage::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let runner = constraints.runner.map(|runner| runner.to_string());
+        let mut processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                runner
+                    .as_ref()
+                    .map_or(true, |runner| process.runner.as_ref() == Some(runner))
+                    && constraints
+                        .status
+                        .map_or(true, |status| process.status == status)
+                    && constraints
+                        .business_status
+                        .as_ref()
+                        .map_or(true, |business_status| {
+                            &process.business_status == business_status
+                        })
+                    && constraints
+                        .tag
+                        .as_ref()
+                        .map_or(true, |tag| process.tag.contains(tag))
+                    && constraints.scheduled_after.map_or(true, |scheduled_after| {
+                        process
+                            .schedule_time
+                            .is_some_and(|schedule_time| schedule_time >= scheduled_after)
+                    })
+                    && constraints
+                        .scheduled_before
+                        .map_or(true, |scheduled_before| {
+                            process
+                                .schedule_time
+                                .is_some_and(|schedule_time| schedule_time <= scheduled_before)
+                        })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        processes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(processes
+            .into_iter()
+            .skip(
+                constraints
+                    .offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or(0),
+            )
+            .take(
+                constraints
+                    .limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .collect())
+    }
 }
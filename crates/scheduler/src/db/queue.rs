@@ -51,7 +51,6 @@ impl QueueInterface for Store {
         consumer_name: &str,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
         crate::consumer::fetch_consumer_tasks(
-            self,
             &self
                 .get_redis_conn()
                 .map_err(ProcessTrackerError::ERedisError)?
@@ -63,6 +63,8 @@ pub enum ProcessTrackerError {
     TypeConversionError,
     #[error("Tenant not found")]
     TenantNotFound,
+    #[error("Invalid recurrence: {message}")]
+    InvalidRecurrence { message: String },
 }
 
 #[macro_export]
@@ -1,4 +1,4 @@
-use router_env::{counter_metric, global_meter, histogram_metric_f64};
+use router_env::{counter_metric, gauge_metric, global_meter, histogram_metric_f64};
 
 global_meter!(PT_METER, "PROCESS_TRACKER");
 
@@ -12,3 +12,7 @@ counter_metric!(TASK_CONSUMED, PT_METER); // Tasks consumed by consumer
 counter_metric!(TASK_PROCESSED, PT_METER); // Tasks completed processing
 counter_metric!(TASK_FINISHED, PT_METER); // Tasks finished
 counter_metric!(TASK_RETRIED, PT_METER); // Tasks added for retries
+counter_metric!(RECURRING_TASKS_RESCHEDULED, PT_METER); // Recurring tasks scheduled for their next run
+counter_metric!(TASKS_THROTTLED, PT_METER); // Tasks returned to the producer due to the concurrency limit of their runner
+
+gauge_metric!(QUEUE_DEPTH, PT_METER); // Tasks due to be run, by runner
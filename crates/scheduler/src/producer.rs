@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use common_utils::{errors::CustomResult, id_type};
 use diesel_models::enums::ProcessTrackerStatus;
@@ -7,6 +7,7 @@ use router_env::{
     instrument,
     tracing::{self, Instrument},
 };
+use strum::IntoEnumIterator;
 use time::Duration;
 use tokio::sync::mpsc;
 
@@ -15,7 +16,7 @@ use super::{
     metrics,
 };
 use crate::{
-    configs::settings::SchedulerSettings, errors, flow::SchedulerFlow,
+    configs::settings::SchedulerSettings, errors, flow::SchedulerFlow, recurrence,
     scheduler::SchedulerInterface, utils::*, SchedulerAppState, SchedulerSessionState,
 };
 
@@ -107,10 +108,15 @@ where
     T: SchedulerSessionState,
 {
     lock_acquire_release::<_, _, _>(state.get_db().as_scheduler(), settings, move || async {
+        // Recurring tasks are scheduled for their next run first, so that runs which are due are
+        // picked up right away
+        reschedule_recurring_tasks(state.get_db().as_scheduler(), settings).await?;
+
         let tasks = fetch_producer_tasks(state.get_db().as_scheduler(), settings).await?;
         debug!("Producer count of tasks {}", tasks.len());
+        record_queue_depth(&tasks);
 
-        // [#268]: Allow task based segregation of tasks
+        // Tasks are segregated by their runner into the lanes configured for the runners
 
         divide_and_append_tasks(
             state.get_db().as_scheduler(),
@@ -127,6 +133,61 @@ where
     Ok(())
 }
 
+/// Schedules the recurring tasks which have finished for their next run, according to their
+/// recurrence.
+#[instrument(skip_all)]
+pub async fn reschedule_recurring_tasks(
+    db: &dyn SchedulerInterface,
+    conf: &SchedulerSettings,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let tasks = db
+        .find_recurring_processes_to_reschedule(Some(conf.producer.recurring_fetch_limit))
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?;
+    let now = common_utils::date_time::now();
+
+    for task in tasks {
+        let task_id = task.id.clone();
+        match recurrence::get_recurrence_update(&task, now) {
+            Ok(Some(update)) => {
+                db.update_process(task, update)
+                    .await
+                    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+                metrics::RECURRING_TASKS_RESCHEDULED.add(1, &[]);
+            }
+            Ok(None) => (),
+            Err(error) => {
+                // The recurrence can never be evaluated, so the task is not picked up again
+                error!(?error, %task_id, "Failed to determine the next run of recurring task");
+                db.finish_process_with_business_status(
+                    task,
+                    storage::business_status::INVALID_RECURRENCE,
+                )
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Records the number of tasks due to be run for every runner.
+fn record_queue_depth(tasks: &[storage::ProcessTracker]) {
+    // Runners without any due tasks are recorded as well, so that the depth of their queue drops
+    // to zero once their tasks are picked
+    let mut queue_depth = storage::ProcessTrackerRunner::iter()
+        .map(|runner| (runner.to_string(), 0_u64))
+        .collect::<HashMap<_, _>>();
+    for runner in tasks.iter().filter_map(|task| task.runner.as_ref()) {
+        *queue_depth.entry(runner.clone()).or_default() += 1;
+    }
+
+    for (runner, depth) in queue_depth {
+        metrics::QUEUE_DEPTH.record(depth, router_env::metric_attributes!(("runner", runner)));
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn fetch_producer_tasks(
     db: &dyn SchedulerInterface,
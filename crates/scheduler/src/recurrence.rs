@@ -0,0 +1,356 @@
+use std::str::FromStr;
+
+use common_utils::errors::CustomResult;
+pub use diesel_models::process_tracker::{MissedRunPolicy, ProcessTrackerRecurrence};
+use diesel_models::process_tracker::{ProcessTracker, ProcessTrackerUpdate};
+use error_stack::{report, ResultExt};
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+use crate::errors;
+
+/// A validated schedule of a recurring task, built from a cron expression evaluated in a time
+/// zone.
+#[derive(Debug, Clone)]
+pub struct RecurringSchedule {
+    cron_expression: String,
+    schedule: cron::Schedule,
+    timezone: chrono_tz::Tz,
+    missed_run_policy: MissedRunPolicy,
+}
+
+impl RecurringSchedule {
+    /// Creates a schedule from a cron expression with the minute, hour, day of month, month and
+    /// day of week fields, such as `0 9 * * MON-FRI`, and an IANA time zone, such as
+    /// `Asia/Kolkata`.
+    ///
+    /// Days of the week are numbered from 0 (or 7) for Sunday to 6 for Saturday, and may also be
+    /// specified by name.
+    pub fn new(
+        cron_expression: &str,
+        timezone: &str,
+        missed_run_policy: MissedRunPolicy,
+    ) -> CustomResult<Self, errors::ProcessTrackerError> {
+        let cron_expression = cron_expression
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if cron_expression.split(' ').count() != 5 {
+            return Err(report!(errors::ProcessTrackerError::InvalidRecurrence {
+                message: format!(
+                    "Cron expression `{cron_expression}` must have exactly five fields"
+                ),
+            }));
+        }
+
+        let invalid_cron_expression = |error: String| {
+            report!(errors::ProcessTrackerError::InvalidRecurrence {
+                message: format!("Invalid cron expression `{cron_expression}`: {error}"),
+            })
+        };
+        let (fields, day_of_week) = cron_expression
+            .rsplit_once(' ')
+            .ok_or_else(|| invalid_cron_expression("Missing day of week".to_owned()))?;
+        let day_of_week = translate_day_of_week(day_of_week).map_err(invalid_cron_expression)?;
+
+        // The `cron` crate expects a leading seconds field, tasks are always run at the start of
+        // the minute
+        let schedule = cron::Schedule::from_str(&format!("0 {fields} {day_of_week}"))
+            .map_err(|error| invalid_cron_expression(error.to_string()))?;
+        let timezone = chrono_tz::Tz::from_str(timezone).map_err(|error| {
+            report!(errors::ProcessTrackerError::InvalidRecurrence {
+                message: format!("Invalid time zone `{timezone}`: {error}"),
+            })
+        })?;
+
+        Ok(Self {
+            cron_expression,
+            schedule,
+            timezone,
+            missed_run_policy,
+        })
+    }
+
+    pub fn from_recurrence(
+        recurrence: &ProcessTrackerRecurrence,
+    ) -> CustomResult<Self, errors::ProcessTrackerError> {
+        Self::new(
+            &recurrence.cron_expression,
+            &recurrence.timezone,
+            recurrence.missed_run_policy,
+        )
+    }
+
+    /// Returns the recurrence to be stored with a task, with the occurrence which the next run of
+    /// the task corresponds to.
+    pub fn to_recurrence(&self, occurrence_at: PrimitiveDateTime) -> ProcessTrackerRecurrence {
+        ProcessTrackerRecurrence {
+            cron_expression: self.cron_expression.clone(),
+            timezone: self.timezone.name().to_owned(),
+            missed_run_policy: self.missed_run_policy,
+            occurrence_at: Some(occurrence_at),
+        }
+    }
+
+    /// Returns the first occurrence of the schedule strictly after the specified UTC time.
+    pub fn next_occurrence_after(
+        &self,
+        time: PrimitiveDateTime,
+    ) -> CustomResult<PrimitiveDateTime, errors::ProcessTrackerError> {
+        let time = chrono::DateTime::from_timestamp(time.assume_utc().unix_timestamp(), 0)
+            .ok_or(errors::ProcessTrackerError::TypeConversionError)
+            .attach_printable("Failed to convert the time to a chrono date time")?
+            .with_timezone(&self.timezone);
+
+        let next_occurrence = self.schedule.after(&time).next().ok_or_else(|| {
+            report!(errors::ProcessTrackerError::InvalidRecurrence {
+                message: format!(
+                    "Cron expression `{}` has no occurrences after {time}",
+                    self.cron_expression
+                ),
+            })
+        })?;
+
+        OffsetDateTime::from_unix_timestamp(next_occurrence.timestamp())
+            .map(common_utils::date_time::convert_to_pdt)
+            .change_context(errors::ProcessTrackerError::TypeConversionError)
+            .attach_printable("Failed to convert the next occurrence to a primitive date time")
+    }
+
+    /// Determines the time at which a task is to be run next, given the occurrence its last run
+    /// corresponded to, and the occurrence which that run corresponds to.
+    ///
+    /// Occurrences which are already in the past are handled according to the missed run policy.
+    pub fn get_next_run(
+        &self,
+        last_occurrence_at: PrimitiveDateTime,
+        now: PrimitiveDateTime,
+    ) -> CustomResult<(PrimitiveDateTime, PrimitiveDateTime), errors::ProcessTrackerError> {
+        let next_occurrence = self.next_occurrence_after(last_occurrence_at)?;
+        if next_occurrence > now {
+            return Ok((next_occurrence, next_occurrence));
+        }
+
+        match self.missed_run_policy {
+            MissedRunPolicy::Skip => {
+                let upcoming_occurrence = self.next_occurrence_after(now)?;
+                Ok((upcoming_occurrence, upcoming_occurrence))
+            }
+            // The occurrences following the run are computed from the current time, which skips
+            // the rest of the missed occurrences
+            MissedRunPolicy::CatchUpOnce => Ok((now, now)),
+            // The next missed occurrence is run right away, and the ones after it are run in the
+            // following runs of the producer
+            MissedRunPolicy::CatchUpAll => Ok((now, next_occurrence)),
+        }
+    }
+}
+
+/// Translates the day of week field of a cron expression from the standard numbering, where
+/// Sunday is 0 (or 7) and Saturday is 6, to the numbering of the `cron` crate, where Sunday is 1
+/// and Saturday is 7.
+///
+/// Numeric values, ranges and steps are expanded to lists of days, while named days and `*` are
+/// kept as they are, since their meaning does not depend on the numbering.
+fn translate_day_of_week(field: &str) -> Result<String, String> {
+    let parse_day = |day: &str| {
+        day.parse::<u8>()
+            .ok()
+            .filter(|day| *day <= 7)
+            .ok_or_else(|| format!("Invalid day of week `{day}`"))
+    };
+
+    let mut parts = Vec::new();
+    for part in field.split(',') {
+        if part.starts_with('*')
+            || part == "?"
+            || part
+                .chars()
+                .any(|character| character.is_ascii_alphabetic())
+        {
+            parts.push(part.to_owned());
+            continue;
+        }
+
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (
+                range,
+                Some(
+                    step.parse::<usize>()
+                        .ok()
+                        .filter(|step| *step > 0)
+                        .ok_or_else(|| format!("Invalid step `{step}`"))?,
+                ),
+            ),
+            None => (part, None),
+        };
+        let (start, end) = match range.split_once('-') {
+            Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+            // A single day with a step repeats until the end of the week
+            None if step.is_some() => (parse_day(range)?, 6),
+            None => {
+                let day = parse_day(range)?;
+                (day, day)
+            }
+        };
+        if start > end {
+            return Err(format!("Invalid day of week range `{range}`"));
+        }
+
+        parts.extend(
+            (start..=end)
+                .step_by(step.unwrap_or(1))
+                .map(|day| (day % 7 + 1).to_string()),
+        );
+    }
+
+    Ok(parts.join(","))
+}
+
+/// Returns the update scheduling a finished recurring task for its next run, or `None` if the task
+/// is not recurring.
+pub fn get_recurrence_update(
+    process: &ProcessTracker,
+    now: PrimitiveDateTime,
+) -> CustomResult<Option<ProcessTrackerUpdate>, errors::ProcessTrackerError> {
+    let Some(recurrence) = &process.recurrence else {
+        return Ok(None);
+    };
+
+    let schedule = RecurringSchedule::from_recurrence(recurrence)?;
+    let last_occurrence_at = recurrence
+        .occurrence_at
+        .or(process.schedule_time)
+        .unwrap_or(process.created_at);
+    let (schedule_time, occurrence_at) = schedule.get_next_run(last_occurrence_at, now)?;
+
+    Ok(Some(ProcessTrackerUpdate::RecurrenceUpdate {
+        schedule_time,
+        recurrence: schedule.to_recurrence(occurrence_at),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn july_2025(day: u8, hour: u8, minute: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2025, time::Month::July, day).unwrap(),
+            time::Time::from_hms(hour, minute, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_next_occurrence_in_timezone() {
+        let schedule =
+            RecurringSchedule::new("30 9 * * *", "Asia/Kolkata", MissedRunPolicy::Skip).unwrap();
+
+        // 09:30 in Asia/Kolkata is 04:00 in UTC
+        assert_eq!(
+            schedule.next_occurrence_after(july_2025(16, 3, 0)).unwrap(),
+            july_2025(16, 4, 0)
+        );
+        assert_eq!(
+            schedule.next_occurrence_after(july_2025(16, 4, 0)).unwrap(),
+            july_2025(17, 4, 0)
+        );
+    }
+
+    #[test]
+    fn test_missed_run_policies() {
+        let last_occurrence_at = july_2025(16, 10, 0);
+        let now = july_2025(16, 13, 30);
+        let next_run = |missed_run_policy| {
+            RecurringSchedule::new("0 * * * *", "UTC", missed_run_policy)
+                .unwrap()
+                .get_next_run(last_occurrence_at, now)
+                .unwrap()
+        };
+
+        assert_eq!(
+            next_run(MissedRunPolicy::Skip),
+            (july_2025(16, 14, 0), july_2025(16, 14, 0))
+        );
+        assert_eq!(next_run(MissedRunPolicy::CatchUpOnce), (now, now));
+        assert_eq!(
+            next_run(MissedRunPolicy::CatchUpAll),
+            (now, july_2025(16, 11, 0))
+        );
+    }
+
+    #[test]
+    fn test_numeric_weekdays() {
+        let schedule = RecurringSchedule::new("0 9 * * 1-5", "UTC", MissedRunPolicy::Skip).unwrap();
+
+        // 18 July 2025 is a Friday, the next weekday is Monday 21 July 2025
+        assert_eq!(
+            schedule.next_occurrence_after(july_2025(18, 9, 0)).unwrap(),
+            july_2025(21, 9, 0)
+        );
+        // 16 July 2025 is a Wednesday
+        assert_eq!(
+            schedule.next_occurrence_after(july_2025(16, 8, 0)).unwrap(),
+            july_2025(16, 9, 0)
+        );
+    }
+
+    #[test]
+    fn test_numeric_sunday() {
+        // 16 July 2025 is a Wednesday, the next Sunday is 20 July 2025
+        for day_of_week in ["0", "7"] {
+            let schedule = RecurringSchedule::new(
+                &format!("0 9 * * {day_of_week}"),
+                "UTC",
+                MissedRunPolicy::Skip,
+            )
+            .unwrap();
+            assert_eq!(
+                schedule.next_occurrence_after(july_2025(16, 9, 0)).unwrap(),
+                july_2025(20, 9, 0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_named_and_numeric_weekdays_match() {
+        let named =
+            RecurringSchedule::new("0 9 * * MON-FRI", "UTC", MissedRunPolicy::Skip).unwrap();
+        let numeric = RecurringSchedule::new("0 9 * * 1-5", "UTC", MissedRunPolicy::Skip).unwrap();
+
+        let mut time = july_2025(1, 0, 0);
+        for _ in 0..10 {
+            let next = named.next_occurrence_after(time).unwrap();
+            assert_eq!(numeric.next_occurrence_after(time).unwrap(), next);
+            time = next;
+        }
+    }
+
+    #[test]
+    fn test_translate_day_of_week() {
+        assert_eq!(translate_day_of_week("0").unwrap(), "1");
+        assert_eq!(translate_day_of_week("7").unwrap(), "1");
+        assert_eq!(translate_day_of_week("6").unwrap(), "7");
+        assert_eq!(translate_day_of_week("1-5").unwrap(), "2,3,4,5,6");
+        assert_eq!(translate_day_of_week("5-7").unwrap(), "6,7,1");
+        assert_eq!(translate_day_of_week("0,3").unwrap(), "1,4");
+        assert_eq!(translate_day_of_week("1/2").unwrap(), "2,4,6");
+        assert_eq!(translate_day_of_week("0-6/3").unwrap(), "1,4,7");
+        assert_eq!(translate_day_of_week("*").unwrap(), "*");
+        assert_eq!(translate_day_of_week("MON-FRI").unwrap(), "MON-FRI");
+
+        assert!(translate_day_of_week("8").is_err());
+        assert!(translate_day_of_week("5-1").is_err());
+        assert!(translate_day_of_week("1/0").is_err());
+        assert!(translate_day_of_week("").is_err());
+    }
+
+    #[test]
+    fn test_invalid_schedule() {
+        assert!(RecurringSchedule::new("* * * *", "UTC", MissedRunPolicy::Skip).is_err());
+        assert!(
+            RecurringSchedule::new("0 * * * *", "Mars/Olympus", MissedRunPolicy::Skip).is_err()
+        );
+    }
+}
@@ -1,4 +1,8 @@
-use std::sync;
+use std::{
+    cmp,
+    collections::HashMap,
+    sync::{self, atomic},
+};
 
 use common_utils::errors::CustomResult;
 use diesel_models::enums::{self, ProcessTrackerStatus};
@@ -149,14 +153,44 @@ pub fn divide(
     conf: &SchedulerSettings,
 ) -> Vec<ProcessTrackerBatch> {
     let now = common_utils::date_time::now();
-    let batch_size = conf.producer.batch_size;
-    divide_into_batches(batch_size, tasks, now, conf)
+
+    // Tasks are batched separately for every runner, so that every batch is pushed to the lane
+    // of its runner
+    let mut tasks_by_runner = HashMap::<Option<String>, Vec<storage::ProcessTracker>>::new();
+    for task in tasks {
+        tasks_by_runner
+            .entry(task.runner.clone())
+            .or_default()
+            .push(task);
+    }
+
+    let get_priority = |runner: Option<&str>| {
+        conf.get_runner_settings(runner)
+            .map(|runner_settings| runner_settings.priority)
+            .unwrap_or_default()
+    };
+    let mut tasks_by_runner = tasks_by_runner.into_iter().collect::<Vec<_>>();
+    // Batches of runners with a higher priority are pushed first
+    tasks_by_runner.sort_by_key(|(runner, _)| cmp::Reverse(get_priority(runner.as_deref())));
+
+    tasks_by_runner
+        .into_iter()
+        .flat_map(|(runner, tasks)| {
+            let batch_size = conf
+                .get_runner_settings(runner.as_deref())
+                .and_then(|runner_settings| runner_settings.batch_size)
+                .unwrap_or(conf.producer.batch_size);
+            let stream_name = conf.get_runner_stream_name(runner.as_deref());
+            divide_into_batches(batch_size, tasks, now, stream_name, conf)
+        })
+        .collect()
 }
 
 pub fn divide_into_batches(
     batch_size: usize,
     tasks: Vec<storage::ProcessTracker>,
     batch_creation_time: time::PrimitiveDateTime,
+    stream_name: String,
     conf: &SchedulerSettings,
 ) -> Vec<ProcessTrackerBatch> {
     let batch_id = Uuid::new_v4().to_string();
@@ -167,7 +201,7 @@ pub fn divide_into_batches(
             let batch = ProcessTrackerBatch {
                 id: batch_id.clone(),
                 group_name: conf.consumer.consumer_group.clone(),
-                stream_name: conf.stream.clone(),
+                stream_name: stream_name.clone(),
                 connection_name: String::new(),
                 created_time: batch_creation_time,
                 rule: String::new(), // is it required?
@@ -269,12 +303,22 @@ pub async fn consumer_operation_handler<E, T>(
     settings: sync::Arc<SchedulerSettings>,
     error_handler_fun: E,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
+    runner_permits: &consumer::RunnerPermits,
+    active_tasks: &sync::Arc<atomic::AtomicU64>,
 ) where
     // Error handler function
     E: FnOnce(error_stack::Report<errors::ProcessTrackerError>),
     T: SchedulerSessionState + Send + Sync + 'static,
 {
-    match consumer::consumer_operations(&state, &settings, workflow_selector).await {
+    match consumer::consumer_operations(
+        &state,
+        &settings,
+        workflow_selector,
+        runner_permits,
+        active_tasks,
+    )
+    .await
+    {
         Ok(_) => (),
         Err(err) => error_handler_fun(err),
     }
@@ -426,6 +470,97 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::configs::settings::RunnerSettings;
+
+    fn process(id: &str, runner: storage::ProcessTrackerRunner) -> storage::ProcessTracker {
+        let now = common_utils::date_time::now();
+        storage::ProcessTracker {
+            id: id.to_owned(),
+            name: None,
+            tag: Vec::new(),
+            runner: Some(runner.to_string()),
+            retry_count: 0,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: serde_json::Value::Null,
+            business_status: storage::business_status::PENDING.to_owned(),
+            status: ProcessTrackerStatus::New,
+            event: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            version: common_types::consts::API_VERSION,
+            recurrence: None,
+        }
+    }
+
+    #[test]
+    fn test_divide_into_runner_lanes() {
+        let mut settings = SchedulerSettings::default();
+        settings.producer.batch_size = 2;
+        settings.runners.insert(
+            storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+            RunnerSettings {
+                priority: 2,
+                max_concurrency: None,
+                batch_size: Some(1),
+            },
+        );
+
+        let tasks = vec![
+            process(
+                "webhook_1",
+                storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+            ),
+            process(
+                "webhook_2",
+                storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+            ),
+            process(
+                "webhook_3",
+                storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+            ),
+            process(
+                "sync_1",
+                storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+            ),
+            process(
+                "sync_2",
+                storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+            ),
+        ];
+        let batches = divide(tasks, &settings)
+            .into_iter()
+            .map(|batch| {
+                (
+                    batch.stream_name,
+                    batch
+                        .trackers
+                        .into_iter()
+                        .map(|process| process.id)
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let priority_stream = "SCHEDULER_STREAM_PRIORITY_2".to_owned();
+        let default_stream = "SCHEDULER_STREAM".to_owned();
+        assert_eq!(
+            batches,
+            vec![
+                (priority_stream.clone(), vec!["sync_1".to_owned()]),
+                (priority_stream, vec!["sync_2".to_owned()]),
+                (
+                    default_stream.clone(),
+                    vec!["webhook_1".to_owned(), "webhook_2".to_owned()]
+                ),
+                (default_stream, vec!["webhook_3".to_owned()]),
+            ]
+        );
+        assert_eq!(
+            settings.get_lane_stream_names(),
+            vec!["SCHEDULER_STREAM_PRIORITY_2", "SCHEDULER_STREAM"]
+        );
+    }
 
     #[test]
     fn test_get_delay() {
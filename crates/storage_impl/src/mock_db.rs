@@ -41,7 +41,9 @@ pub struct MockDb {
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
+    pub frm_reviews: Arc<Mutex<Vec<store::frm_review::FrmReview>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
+    pub embedded_vault_entries: Arc<Mutex<Vec<store::embedded_vault::EmbeddedVaultEntry>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<store::capture::Capture>>>,
     pub merchant_key_store: Arc<Mutex<Vec<store::merchant_key_store::MerchantKeyStore>>>,
@@ -90,7 +92,9 @@ impl MockDb {
             cards_info: Default::default(),
             events: Default::default(),
             disputes: Default::default(),
+            frm_reviews: Default::default(),
             lockers: Default::default(),
+            embedded_vault_entries: Default::default(),
             mandates: Default::default(),
             captures: Default::default(),
             merchant_key_store: Default::default(),
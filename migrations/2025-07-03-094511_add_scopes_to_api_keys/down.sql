@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE api_keys
+DROP COLUMN IF EXISTS permission_groups,
+DROP COLUMN IF EXISTS resources,
+DROP COLUMN IF EXISTS profile_ids;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE api_keys
+ADD COLUMN IF NOT EXISTS permission_groups TEXT[],
+ADD COLUMN IF NOT EXISTS resources TEXT[],
+ADD COLUMN IF NOT EXISTS profile_ids TEXT[];
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS recon_mismatch_merchant_id_job_id_index;
+
+DROP TABLE IF EXISTS recon_mismatch;
+
+DROP INDEX IF EXISTS recon_job_merchant_id_created_at_index;
+
+DROP TABLE IF EXISTS recon_job;
@@ -0,0 +1,38 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS recon_job (
+    job_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    file_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    total_rows INTEGER NOT NULL DEFAULT 0,
+    matched_count INTEGER NOT NULL DEFAULT 0,
+    mismatched_count INTEGER NOT NULL DEFAULT 0,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS recon_job_merchant_id_created_at_index ON recon_job (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS recon_mismatch (
+    mismatch_id VARCHAR(64) PRIMARY KEY,
+    job_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    line_number INTEGER NOT NULL,
+    transaction_type VARCHAR(32) NOT NULL,
+    mismatch_type VARCHAR(32) NOT NULL,
+    connector_transaction_id VARCHAR(128) NOT NULL,
+    settlement_amount BIGINT NOT NULL,
+    settlement_currency "Currency" NOT NULL,
+    settlement_status VARCHAR(32) NOT NULL,
+    payment_id VARCHAR(64),
+    attempt_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    transaction_amount BIGINT,
+    transaction_currency "Currency",
+    transaction_status VARCHAR(32),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS recon_mismatch_merchant_id_job_id_index ON recon_mismatch (merchant_id, job_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS process_tracker_recurring_status_index;
+
+ALTER TABLE process_tracker DROP COLUMN IF EXISTS recurrence;
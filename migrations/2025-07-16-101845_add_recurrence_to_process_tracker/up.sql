@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE process_tracker ADD COLUMN IF NOT EXISTS recurrence JSONB;
+
+CREATE INDEX IF NOT EXISTS process_tracker_recurring_status_index ON process_tracker (status)
+WHERE recurrence IS NOT NULL;
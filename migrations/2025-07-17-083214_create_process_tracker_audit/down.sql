@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS process_tracker_audit_process_tracker_id_created_at_index;
+
+DROP TABLE IF EXISTS process_tracker_audit;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS process_tracker_audit (
+    audit_id VARCHAR(64) PRIMARY KEY,
+    process_tracker_id VARCHAR(127) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    previous_status "ProcessTrackerStatus" NOT NULL,
+    new_status "ProcessTrackerStatus" NOT NULL,
+    previous_business_status VARCHAR(255) NOT NULL,
+    new_business_status VARCHAR(255) NOT NULL,
+    previous_schedule_time TIMESTAMP,
+    new_schedule_time TIMESTAMP,
+    reason TEXT NOT NULL,
+    performed_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS process_tracker_audit_process_tracker_id_created_at_index ON process_tracker_audit (process_tracker_id, created_at);
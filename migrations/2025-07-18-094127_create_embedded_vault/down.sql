@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS embedded_vault_merchant_id_expires_at_index;
+
+DROP TABLE IF EXISTS embedded_vault;
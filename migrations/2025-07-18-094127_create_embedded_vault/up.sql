@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS embedded_vault (
+    merchant_id VARCHAR(64) NOT NULL,
+    vault_id VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(64) NOT NULL,
+    encrypted_data BYTEA NOT NULL,
+    encrypted_data_key BYTEA NOT NULL,
+    expires_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, vault_id)
+);
+
+CREATE INDEX IF NOT EXISTS embedded_vault_merchant_id_expires_at_index ON embedded_vault (merchant_id, expires_at);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS forex_rate_snapshot_captured_at_index;
+
+DROP TABLE IF EXISTS forex_rate_snapshot;
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS forex_rate_snapshot (
+    snapshot_id VARCHAR(64) PRIMARY KEY,
+    base_currency "Currency" NOT NULL,
+    rates JSONB NOT NULL,
+    captured_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS forex_rate_snapshot_captured_at_index ON forex_rate_snapshot (captured_at);
@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS frm_review_note_merchant_id_review_id_index;
+
+DROP TABLE IF EXISTS frm_review_note;
+
+DROP INDEX IF EXISTS frm_review_merchant_id_profile_id_status_index;
+
+DROP INDEX IF EXISTS frm_review_merchant_id_attempt_id_index;
+
+DROP TABLE IF EXISTS frm_review;
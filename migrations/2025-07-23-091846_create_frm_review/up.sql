@@ -0,0 +1,35 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS frm_review (
+    review_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    frm_id VARCHAR(64) NOT NULL,
+    frm_name VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    frm_reason JSONB,
+    frm_score INTEGER,
+    assigned_to VARCHAR(64),
+    decision VARCHAR(32),
+    reviewed_by VARCHAR(64),
+    reviewed_at TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS frm_review_merchant_id_attempt_id_index ON frm_review (merchant_id, attempt_id);
+
+CREATE INDEX IF NOT EXISTS frm_review_merchant_id_profile_id_status_index ON frm_review (merchant_id, profile_id, status, created_at);
+
+CREATE TABLE IF NOT EXISTS frm_review_note (
+    note_id VARCHAR(64) PRIMARY KEY,
+    review_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    user_id VARCHAR(64) NOT NULL,
+    note TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS frm_review_note_merchant_id_review_id_index ON frm_review_note (merchant_id, review_id);
@@ -0,0 +1,20 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS subscription_invoice_subscription_id_period_start_index;
+
+DROP INDEX IF EXISTS subscription_invoice_merchant_id_subscription_id_index;
+
+DROP TABLE IF EXISTS subscription_invoice;
+
+DROP INDEX IF EXISTS subscription_merchant_id_customer_id_index;
+
+DROP INDEX IF EXISTS subscription_merchant_id_profile_id_index;
+
+DROP TABLE IF EXISTS subscription;
+
+DROP INDEX IF EXISTS subscription_price_merchant_id_plan_id_index;
+
+DROP TABLE IF EXISTS subscription_price;
+
+DROP INDEX IF EXISTS subscription_plan_merchant_id_profile_id_index;
+
+DROP TABLE IF EXISTS subscription_plan;
@@ -0,0 +1,82 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS subscription_plan (
+    plan_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_plan_merchant_id_profile_id_index ON subscription_plan (merchant_id, profile_id, created_at);
+
+CREATE TABLE IF NOT EXISTS subscription_price (
+    price_id VARCHAR(64) PRIMARY KEY,
+    plan_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    billing_interval VARCHAR(32) NOT NULL,
+    interval_count INTEGER NOT NULL DEFAULT 1,
+    trial_period_days INTEGER,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_price_merchant_id_plan_id_index ON subscription_price (merchant_id, plan_id);
+
+CREATE TABLE IF NOT EXISTS subscription (
+    subscription_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    plan_id VARCHAR(64) NOT NULL,
+    price_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    mandate_id VARCHAR(64) NOT NULL,
+    current_period_start TIMESTAMP NOT NULL,
+    current_period_end TIMESTAMP NOT NULL,
+    trial_end TIMESTAMP,
+    billing_cycle_anchor TIMESTAMP NOT NULL,
+    cancel_at_period_end BOOLEAN NOT NULL DEFAULT FALSE,
+    canceled_at TIMESTAMP,
+    latest_invoice_id VARCHAR(64),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_profile_id_index ON subscription (merchant_id, profile_id, created_at);
+
+CREATE INDEX IF NOT EXISTS subscription_merchant_id_customer_id_index ON subscription (merchant_id, customer_id);
+
+CREATE TABLE IF NOT EXISTS subscription_invoice (
+    invoice_id VARCHAR(64) PRIMARY KEY,
+    subscription_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    billing_reason VARCHAR(32) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    period_start TIMESTAMP NOT NULL,
+    period_end TIMESTAMP NOT NULL,
+    line_items JSONB NOT NULL,
+    payment_id VARCHAR(64),
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    next_payment_attempt_at TIMESTAMP,
+    paid_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS subscription_invoice_merchant_id_subscription_id_index ON subscription_invoice (merchant_id, subscription_id, created_at);
+
+-- A subscription is invoiced at most once for each billing cycle
+CREATE UNIQUE INDEX IF NOT EXISTS subscription_invoice_subscription_id_period_start_index ON subscription_invoice (subscription_id, period_start)
+WHERE billing_reason = 'subscription_cycle';
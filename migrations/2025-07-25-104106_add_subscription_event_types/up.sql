@@ -0,0 +1,16 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'subscriptions';
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'invoices';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'subscription_details';
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'invoice_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_trialing';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_active';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_past_due';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'subscription_canceled';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_created';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_paid';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_payment_failed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_uncollectible';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_voided';
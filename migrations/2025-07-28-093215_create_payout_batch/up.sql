@@ -0,0 +1,41 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_batch (
+    batch_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    merchant_batch_reference_id VARCHAR(255),
+    file_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    total_rows INTEGER NOT NULL,
+    created_rows INTEGER NOT NULL DEFAULT 0,
+    failed_rows INTEGER NOT NULL DEFAULT 0,
+    result_file_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_merchant_id_profile_id_index ON payout_batch (merchant_id, profile_id, created_at);
+
+CREATE UNIQUE INDEX IF NOT EXISTS payout_batch_merchant_id_file_id_index ON payout_batch (merchant_id, file_id);
+
+CREATE UNIQUE INDEX IF NOT EXISTS payout_batch_merchant_id_merchant_batch_reference_id_index ON payout_batch (merchant_id, merchant_batch_reference_id)
+WHERE merchant_batch_reference_id IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS payout_batch_item (
+    batch_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    row_number INTEGER NOT NULL,
+    payout_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    connector VARCHAR(64),
+    payout_status "PayoutStatus",
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    payout_request BYTEA NOT NULL,
+    PRIMARY KEY (batch_id, row_number)
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_item_merchant_id_batch_id_status_index ON payout_batch_item (merchant_id, batch_id, status);
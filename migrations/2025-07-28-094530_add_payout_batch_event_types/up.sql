@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payout_batches';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payout_batch_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_batch_completed';